@@ -0,0 +1,58 @@
+-- ============================================
+-- Igreja Manager — Migration: Estornos de Lançamentos (RN-FIN-010)
+-- 1. Referência do lançamento compensatório ao original
+-- 2. Motivo obrigatório do estorno
+-- 3. Normalização de estornos antigos (sem lançamento compensatório)
+-- 4. Trigger de saldo de campanha recalcula em qualquer mudança de status
+--
+-- ⚠️  REGRA DE OURO: NUNCA modifique uma migration já aplicada!
+--     Todas as alterações devem ir em novas migrations.
+-- ============================================
+
+-- ============================
+-- 1. COLUNAS DE ESTORNO
+-- ============================
+
+-- Preenchido apenas no lançamento compensatório; aponta para o original estornado
+ALTER TABLE financial_entries
+    ADD COLUMN IF NOT EXISTS reversal_of UUID REFERENCES financial_entries(id);
+
+ALTER TABLE financial_entries
+    ADD COLUMN IF NOT EXISTS reversal_reason TEXT;
+
+-- Um lançamento só pode ser estornado uma vez
+CREATE UNIQUE INDEX IF NOT EXISTS idx_fin_entries_reversal_of
+    ON financial_entries(reversal_of) WHERE reversal_of IS NOT NULL;
+
+-- ============================
+-- 2. ESTORNOS ANTIGOS
+-- ============================
+
+-- Até aqui o status 'estornado' só podia ser definido via PUT, que apenas
+-- revertia o saldo bancário sem gerar lançamento compensatório — o mesmo
+-- efeito de um cancelamento. A partir desta migration, 'estornado' significa
+-- "possui lançamento compensatório" e continua compondo os relatórios.
+UPDATE financial_entries
+SET status = 'cancelado'
+WHERE status = 'estornado';
+
+-- ============================
+-- 3. SALDO DE CAMPANHAS
+-- ============================
+
+CREATE OR REPLACE FUNCTION update_campaign_balance()
+RETURNS TRIGGER AS $$
+BEGIN
+    IF NEW.campaign_id IS NOT NULL THEN
+        UPDATE campaigns SET raised_amount = (
+            SELECT COALESCE(SUM(amount), 0)
+            FROM financial_entries
+            WHERE campaign_id = NEW.campaign_id
+              AND status = 'confirmado'
+              AND deleted_at IS NULL
+        )
+        WHERE id = NEW.campaign_id;
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
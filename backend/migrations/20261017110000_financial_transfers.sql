@@ -0,0 +1,16 @@
+-- ============================================
+-- Igreja Manager — Migration: Transferências entre Contas (RN-FIN-009)
+-- Uma transferência gera dois lançamentos ligados pelo mesmo transfer_id:
+-- despesa na conta de origem e receita na conta de destino.
+-- Os dois lançamentos movimentam current_balance, mas ficam fora dos
+-- totais de receitas/despesas dos relatórios e fechamentos.
+--
+-- ⚠️  REGRA DE OURO: NUNCA modifique uma migration já aplicada!
+--     Todas as alterações devem ir em novas migrations.
+-- ============================================
+
+ALTER TABLE financial_entries
+    ADD COLUMN IF NOT EXISTS transfer_id UUID;
+
+CREATE INDEX IF NOT EXISTS idx_fin_entries_transfer
+    ON financial_entries(transfer_id) WHERE transfer_id IS NOT NULL;
@@ -0,0 +1,76 @@
+-- ============================================
+-- Igreja Manager — Migration: Lançamentos Recorrentes
+-- 1. Modelos de recorrência (aluguel, contas de consumo, prebenda pastoral...)
+-- 2. Vínculo dos lançamentos gerados via financial_entries.recurring_id
+-- 3. Uma única ocorrência por modelo e vencimento
+--
+-- ⚠️  REGRA DE OURO: NUNCA modifique uma migration já aplicada!
+--     Todas as alterações devem ir em novas migrations.
+-- ============================================
+
+-- ============================
+-- 1. MODELOS DE RECORRÊNCIA
+-- ============================
+
+CREATE TABLE IF NOT EXISTS financial_recurrences (
+    id              UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    church_id       UUID NOT NULL REFERENCES churches(id),
+
+    -- Dados copiados para cada ocorrência gerada
+    type            VARCHAR(10) NOT NULL CHECK (type IN ('receita', 'despesa')),
+    account_plan_id UUID NOT NULL REFERENCES account_plans(id),
+    bank_account_id UUID NOT NULL REFERENCES bank_accounts(id),
+    campaign_id     UUID REFERENCES campaigns(id),
+    amount          DECIMAL(15,2) NOT NULL CHECK (amount > 0),
+    description     TEXT NOT NULL,
+    payment_method  VARCHAR(30) CHECK (payment_method IN (
+        'dinheiro', 'pix', 'transferencia', 'cartao_debito',
+        'cartao_credito', 'cheque', 'boleto', 'outro'
+    )),
+    member_id       UUID REFERENCES members(id),
+    supplier_name   VARCHAR(200),
+    congregation_id UUID REFERENCES congregations(id) ON DELETE SET NULL,
+    notes           TEXT,
+
+    -- Agenda
+    frequency       VARCHAR(20) NOT NULL DEFAULT 'mensal' CHECK (frequency IN (
+        'mensal', 'bimestral', 'trimestral', 'semestral', 'anual'
+    )),
+    day_of_month    SMALLINT NOT NULL CHECK (day_of_month BETWEEN 1 AND 31),
+    start_date      DATE NOT NULL,
+    end_date        DATE,
+    -- Quantos dias antes do vencimento a ocorrência é gerada
+    lead_days       SMALLINT NOT NULL DEFAULT 30 CHECK (lead_days BETWEEN 0 AND 365),
+    -- Próximo vencimento ainda não gerado (cursor do gerador)
+    next_due_date   DATE NOT NULL,
+
+    is_active       BOOLEAN NOT NULL DEFAULT TRUE,
+    created_by      UUID NOT NULL REFERENCES users(id),
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    deleted_at      TIMESTAMPTZ,
+
+    CHECK (end_date IS NULL OR end_date >= start_date)
+);
+
+CREATE INDEX IF NOT EXISTS idx_fin_recurrences_church ON financial_recurrences(church_id);
+CREATE INDEX IF NOT EXISTS idx_fin_recurrences_due
+    ON financial_recurrences(next_due_date) WHERE is_active = TRUE AND deleted_at IS NULL;
+
+CREATE OR REPLACE TRIGGER trg_financial_recurrences_updated
+    BEFORE UPDATE ON financial_recurrences
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at();
+
+-- ============================
+-- 2. VÍNCULO DAS OCORRÊNCIAS
+-- ============================
+
+-- NOT VALID: recurring_id nunca foi preenchido pela API, mas não validamos linhas antigas
+ALTER TABLE financial_entries
+    ADD CONSTRAINT fk_fin_entries_recurring
+    FOREIGN KEY (recurring_id) REFERENCES financial_recurrences(id) NOT VALID;
+
+-- O gerador pode rodar em paralelo (várias instâncias) sem duplicar vencimentos
+CREATE UNIQUE INDEX IF NOT EXISTS idx_fin_entries_recurring_due
+    ON financial_entries(recurring_id, due_date)
+    WHERE recurring_id IS NOT NULL AND deleted_at IS NULL;
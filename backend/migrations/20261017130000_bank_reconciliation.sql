@@ -0,0 +1,71 @@
+-- ============================================
+-- Igreja Manager — Migration: Conciliação Bancária
+-- 1. Extratos importados (OFX/CSV) por conta bancária
+-- 2. Linhas do extrato, com o lançamento conciliado
+-- 3. Status de conciliação no lançamento financeiro
+--
+-- ⚠️  REGRA DE OURO: NUNCA modifique uma migration já aplicada!
+--     Todas as alterações devem ir em novas migrations.
+-- ============================================
+
+-- ============================
+-- 1. EXTRATOS
+-- ============================
+
+CREATE TABLE IF NOT EXISTS bank_statements (
+    id                UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    church_id         UUID NOT NULL REFERENCES churches(id),
+    bank_account_id   UUID NOT NULL REFERENCES bank_accounts(id),
+    file_name         VARCHAR(255),
+    format            VARCHAR(10) NOT NULL CHECK (format IN ('ofx', 'csv')),
+    period_start      DATE,
+    period_end        DATE,
+    -- Saldo informado pelo banco (LEDGERBAL do OFX, coluna de saldo do CSV ou informado no upload)
+    statement_balance DECIMAL(15,2),
+    balance_date      DATE,
+    imported_by       UUID NOT NULL REFERENCES users(id),
+    created_at        TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_bank_statements_account
+    ON bank_statements(bank_account_id, created_at DESC);
+
+-- ============================
+-- 2. LINHAS DO EXTRATO
+-- ============================
+
+CREATE TABLE IF NOT EXISTS bank_statement_lines (
+    id               UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    statement_id     UUID NOT NULL REFERENCES bank_statements(id) ON DELETE CASCADE,
+    church_id        UUID NOT NULL REFERENCES churches(id),
+    bank_account_id  UUID NOT NULL REFERENCES bank_accounts(id),
+    line_date        DATE NOT NULL,
+    -- Positivo = crédito, negativo = débito
+    amount           DECIMAL(15,2) NOT NULL CHECK (amount <> 0),
+    description      TEXT NOT NULL DEFAULT '',
+    -- FITID do OFX ou hash da linha do CSV; evita importar a mesma movimentação duas vezes
+    external_id      VARCHAR(255) NOT NULL,
+    status           VARCHAR(20) NOT NULL DEFAULT 'pendente'
+                     CHECK (status IN ('pendente', 'conciliado')),
+    matched_entry_id UUID REFERENCES financial_entries(id),
+    matched_at       TIMESTAMPTZ,
+    matched_by       UUID REFERENCES users(id),
+    created_at       TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    UNIQUE(bank_account_id, external_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_bank_statement_lines_statement ON bank_statement_lines(statement_id);
+CREATE UNIQUE INDEX IF NOT EXISTS idx_bank_statement_lines_entry
+    ON bank_statement_lines(matched_entry_id) WHERE matched_entry_id IS NOT NULL;
+
+-- ============================
+-- 3. STATUS NO LANÇAMENTO
+-- ============================
+
+ALTER TABLE financial_entries
+    ADD COLUMN IF NOT EXISTS reconciled_at TIMESTAMPTZ;
+
+CREATE INDEX IF NOT EXISTS idx_fin_entries_unreconciled
+    ON financial_entries(bank_account_id, entry_date)
+    WHERE reconciled_at IS NULL AND deleted_at IS NULL;
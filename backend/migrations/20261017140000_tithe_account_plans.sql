@@ -0,0 +1,27 @@
+-- ============================================
+-- Igreja Manager — Migration: Categorias de Dízimo
+-- 1. Marca as categorias do plano de contas que representam dízimos
+--    (base da regularidade de dizimistas — RN-FIN-006)
+--
+-- ⚠️  REGRA DE OURO: NUNCA modifique uma migration já aplicada!
+--     Todas as alterações devem ir em novas migrations.
+-- ============================================
+
+ALTER TABLE account_plans
+    ADD COLUMN IF NOT EXISTS is_tithe BOOLEAN NOT NULL DEFAULT FALSE;
+
+ALTER TABLE account_plans
+    ADD CONSTRAINT chk_account_plans_tithe_receita CHECK (NOT is_tithe OR type = 'receita');
+
+-- Categorias de receita já existentes com "dízimo" no nome
+UPDATE account_plans
+SET is_tithe = TRUE
+WHERE type = 'receita'
+  AND immutable_unaccent(lower(name)) LIKE '%dizimo%';
+
+CREATE INDEX IF NOT EXISTS idx_account_plans_tithe
+    ON account_plans(church_id) WHERE is_tithe = TRUE;
+
+CREATE INDEX IF NOT EXISTS idx_fin_entries_member_date
+    ON financial_entries(church_id, member_id, entry_date)
+    WHERE member_id IS NOT NULL AND deleted_at IS NULL;
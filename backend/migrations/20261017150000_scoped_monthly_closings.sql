@@ -0,0 +1,32 @@
+-- ============================================
+-- Igreja Manager — Migration: Fechamento Mensal por Congregação
+-- 1. Fechamentos por congregação (congregation_id preenchido), cada um com
+--    seu próprio saldo acumulado; congregation_id NULL = fechamento geral
+-- 2. Saldo de fechamento de cada conta bancária, gravado no fechamento
+--
+-- ⚠️  REGRA DE OURO: NUNCA modifique uma migration já aplicada!
+--     Todas as alterações devem ir em novas migrations.
+-- ============================================
+
+-- 1. Um fechamento por mês em cada escopo (geral ou congregação)
+ALTER TABLE monthly_closings
+    DROP CONSTRAINT IF EXISTS monthly_closings_church_id_reference_month_key;
+
+CREATE UNIQUE INDEX IF NOT EXISTS uq_monthly_closings_church_month
+    ON monthly_closings(church_id, reference_month) WHERE congregation_id IS NULL;
+
+CREATE UNIQUE INDEX IF NOT EXISTS uq_monthly_closings_congregation_month
+    ON monthly_closings(church_id, congregation_id, reference_month) WHERE congregation_id IS NOT NULL;
+
+-- 2. Saldos das contas bancárias no último dia do mês fechado
+CREATE TABLE IF NOT EXISTS monthly_closing_bank_balances (
+    id              UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    closing_id      UUID NOT NULL REFERENCES monthly_closings(id) ON DELETE CASCADE,
+    bank_account_id UUID NOT NULL REFERENCES bank_accounts(id),
+    closing_balance DECIMAL(15,2) NOT NULL,
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE(closing_id, bank_account_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_closing_bank_balances_account
+    ON monthly_closing_bank_balances(bank_account_id);
@@ -0,0 +1,37 @@
+-- ============================================
+-- Igreja Manager — Migration: Orçamento
+-- 1. Orçamento anual ou mensal por categoria do plano de contas,
+--    da igreja (congregation_id NULL) ou de uma congregação
+--
+-- ⚠️  REGRA DE OURO: NUNCA modifique uma migration já aplicada!
+--     Todas as alterações devem ir em novas migrations.
+-- ============================================
+
+CREATE TABLE IF NOT EXISTS budgets (
+    id              UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    church_id       UUID NOT NULL REFERENCES churches(id),
+    account_plan_id UUID NOT NULL REFERENCES account_plans(id),
+    congregation_id UUID REFERENCES congregations(id) ON DELETE CASCADE,
+    year            SMALLINT NOT NULL CHECK (year BETWEEN 2000 AND 2100),
+    -- NULL = orçamento do ano inteiro
+    month           SMALLINT CHECK (month BETWEEN 1 AND 12),
+    amount          DECIMAL(15,2) NOT NULL CHECK (amount >= 0),
+    notes           TEXT,
+    created_by      UUID NOT NULL REFERENCES users(id),
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    deleted_at      TIMESTAMPTZ
+);
+
+-- Um valor por categoria, escopo e período
+CREATE UNIQUE INDEX IF NOT EXISTS uq_budgets_period
+    ON budgets(church_id, account_plan_id,
+               COALESCE(congregation_id, '00000000-0000-0000-0000-000000000000'::uuid),
+               year, COALESCE(month, 0))
+    WHERE deleted_at IS NULL;
+
+CREATE INDEX IF NOT EXISTS idx_budgets_church_year
+    ON budgets(church_id, year) WHERE deleted_at IS NULL;
+
+CREATE OR REPLACE TRIGGER trg_budgets_updated BEFORE UPDATE ON budgets
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at();
@@ -0,0 +1,23 @@
+-- ============================================
+-- Igreja Manager — Migration: Contas a Pagar
+-- 1. Índice das despesas pendentes por vencimento
+-- 2. Registro do resumo diário enviado aos tesoureiros
+--    (garante um único envio por igreja e dia)
+--
+-- ⚠️  REGRA DE OURO: NUNCA modifique uma migration já aplicada!
+--     Todas as alterações devem ir em novas migrations.
+-- ============================================
+
+-- 1. Despesas pendentes por vencimento
+CREATE INDEX IF NOT EXISTS idx_fin_entries_payables
+    ON financial_entries(church_id, due_date)
+    WHERE type = 'despesa' AND status = 'pendente' AND deleted_at IS NULL;
+
+-- 2. Resumo diário de contas a pagar
+CREATE TABLE IF NOT EXISTS payables_digests (
+    church_id       UUID NOT NULL REFERENCES churches(id),
+    digest_date     DATE NOT NULL,
+    recipients      INT NOT NULL DEFAULT 0,
+    sent_at         TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (church_id, digest_date)
+);
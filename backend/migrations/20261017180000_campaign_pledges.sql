@@ -0,0 +1,33 @@
+-- ============================================
+-- Igreja Manager — Migration: Promessas de Campanha
+-- 1. Promessas (votos) de membros para campanhas, com parcelas mensais
+--
+-- ⚠️  REGRA DE OURO: NUNCA modifique uma migration já aplicada!
+--     Todas as alterações devem ir em novas migrations.
+-- ============================================
+
+CREATE TABLE IF NOT EXISTS campaign_pledges (
+    id              UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    church_id       UUID NOT NULL REFERENCES churches(id),
+    campaign_id     UUID NOT NULL REFERENCES campaigns(id),
+    member_id       UUID NOT NULL REFERENCES members(id),
+    pledged_amount  DECIMAL(15,2) NOT NULL CHECK (pledged_amount > 0),
+    -- Parcelas mensais a partir de start_date
+    installments    SMALLINT NOT NULL DEFAULT 1 CHECK (installments BETWEEN 1 AND 120),
+    start_date      DATE NOT NULL,
+    notes           TEXT,
+    created_by      UUID NOT NULL REFERENCES users(id),
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    deleted_at      TIMESTAMPTZ
+);
+
+-- Uma promessa por membro em cada campanha
+CREATE UNIQUE INDEX IF NOT EXISTS uq_campaign_pledges_member
+    ON campaign_pledges(campaign_id, member_id) WHERE deleted_at IS NULL;
+
+CREATE INDEX IF NOT EXISTS idx_campaign_pledges_church
+    ON campaign_pledges(church_id) WHERE deleted_at IS NULL;
+
+CREATE OR REPLACE TRIGGER trg_campaign_pledges_updated BEFORE UPDATE ON campaign_pledges
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at();
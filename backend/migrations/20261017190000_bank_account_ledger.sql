@@ -0,0 +1,21 @@
+-- ============================================
+-- Igreja Manager — Migration: Saldo de Conta pelo Razão
+-- 1. Função que calcula o saldo de uma conta a partir dos lançamentos
+--    (saldo inicial + receitas − despesas confirmadas/estornadas, transferências incluídas)
+--
+-- ⚠️  REGRA DE OURO: NUNCA modifique uma migration já aplicada!
+--     Todas as alterações devem ir em novas migrations.
+-- ============================================
+
+CREATE OR REPLACE FUNCTION bank_account_ledger_balance(p_bank_account_id UUID)
+RETURNS DECIMAL(15,2) AS $$
+    SELECT ba.initial_balance + COALESCE((
+        SELECT SUM(CASE WHEN fe.type = 'receita' THEN fe.amount ELSE -fe.amount END)
+        FROM financial_entries fe
+        WHERE fe.bank_account_id = ba.id
+          AND fe.status IN ('confirmado', 'estornado')
+          AND fe.deleted_at IS NULL
+    ), 0)
+    FROM bank_accounts ba
+    WHERE ba.id = p_bank_account_id;
+$$ LANGUAGE sql STABLE;
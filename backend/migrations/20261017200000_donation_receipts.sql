@@ -0,0 +1,69 @@
+-- ============================================
+-- Igreja Manager — Migration: Recibos de Doação
+-- 1. Numeração sequencial de recibos por igreja (números nunca são reutilizados)
+-- 2. Recibos emitidos para lançamentos de receita
+-- 3. Cancelamento automático do recibo quando o lançamento é cancelado/estornado
+--
+-- ⚠️  REGRA DE OURO: NUNCA modifique uma migration já aplicada!
+--     Todas as alterações devem ir em novas migrations.
+-- ============================================
+
+-- ============================
+-- 1. NUMERAÇÃO
+-- ============================
+CREATE TABLE IF NOT EXISTS receipt_counters (
+    church_id       UUID PRIMARY KEY REFERENCES churches(id),
+    last_number     INT NOT NULL DEFAULT 0
+);
+
+-- ============================
+-- 2. RECIBOS
+-- ============================
+CREATE TABLE IF NOT EXISTS donation_receipts (
+    id                  UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    church_id           UUID NOT NULL REFERENCES churches(id),
+    number              INT NOT NULL,
+    financial_entry_id  UUID NOT NULL REFERENCES financial_entries(id),
+    member_id           UUID REFERENCES members(id),
+    donor_name          VARCHAR(200) NOT NULL,
+    donor_document      VARCHAR(20),
+    amount              DECIMAL(15,2) NOT NULL CHECK (amount > 0),
+    donation_date       DATE NOT NULL,
+    description         VARCHAR(300) NOT NULL,
+    status              VARCHAR(20) NOT NULL DEFAULT 'emitido'
+                        CHECK (status IN ('emitido', 'cancelado')),
+    issued_by           UUID NOT NULL REFERENCES users(id),
+    issued_at           TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    voided_at           TIMESTAMPTZ,
+    void_reason         VARCHAR(300),
+    UNIQUE (church_id, number)
+);
+
+-- Um recibo válido por lançamento
+CREATE UNIQUE INDEX IF NOT EXISTS uq_donation_receipts_entry
+    ON donation_receipts(financial_entry_id) WHERE status = 'emitido';
+
+CREATE INDEX IF NOT EXISTS idx_donation_receipts_church
+    ON donation_receipts(church_id, issued_at);
+
+-- ============================
+-- 3. CANCELAMENTO AUTOMÁTICO
+-- ============================
+CREATE OR REPLACE FUNCTION void_donation_receipts()
+RETURNS TRIGGER AS $$
+BEGIN
+    IF NEW.status IN ('cancelado', 'estornado') OR NEW.deleted_at IS NOT NULL THEN
+        UPDATE donation_receipts
+        SET status = 'cancelado', voided_at = NOW(),
+            void_reason = CASE WHEN NEW.status = 'estornado'
+                               THEN 'Lançamento estornado'
+                               ELSE 'Lançamento cancelado' END
+        WHERE financial_entry_id = NEW.id AND status = 'emitido';
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE OR REPLACE TRIGGER trg_void_donation_receipts
+AFTER UPDATE ON financial_entries
+FOR EACH ROW EXECUTE FUNCTION void_donation_receipts();
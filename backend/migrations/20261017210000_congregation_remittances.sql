@@ -0,0 +1,34 @@
+-- ============================================
+-- Igreja Manager — Migration: Repasses das Congregações à Sede
+-- 1. Repasse mensal devido por congregação (regra em congregations.settings->'remittance')
+--
+-- ⚠️  REGRA DE OURO: NUNCA modifique uma migration já aplicada!
+--     Todas as alterações devem ir em novas migrations.
+-- ============================================
+
+CREATE TABLE IF NOT EXISTS congregation_remittances (
+    id                  UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    church_id           UUID NOT NULL REFERENCES churches(id),
+    congregation_id     UUID NOT NULL REFERENCES congregations(id),
+    reference_month     DATE NOT NULL,
+    -- Receitas das categorias-base no mês e percentual aplicado
+    base_amount         DECIMAL(15,2) NOT NULL,
+    percentage          DECIMAL(5,2) NOT NULL CHECK (percentage > 0 AND percentage <= 100),
+    amount_due          DECIMAL(15,2) NOT NULL CHECK (amount_due >= 0),
+    status              VARCHAR(20) NOT NULL DEFAULT 'pendente'
+                        CHECK (status IN ('pendente', 'pago')),
+    -- Par de lançamentos do pagamento: despesa na congregação, receita na sede
+    expense_entry_id    UUID REFERENCES financial_entries(id),
+    income_entry_id     UUID REFERENCES financial_entries(id),
+    paid_at             TIMESTAMPTZ,
+    paid_by             UUID REFERENCES users(id),
+    created_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (congregation_id, reference_month)
+);
+
+CREATE INDEX IF NOT EXISTS idx_congregation_remittances_pending
+    ON congregation_remittances(church_id, congregation_id) WHERE status = 'pendente';
+
+CREATE OR REPLACE TRIGGER trg_congregation_remittances_updated BEFORE UPDATE ON congregation_remittances
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at();
@@ -0,0 +1,34 @@
+-- ============================================
+-- Igreja Manager — Migration: PIX (BR Code estático)
+-- 1. Chave PIX nas contas bancárias
+-- 2. Cobranças PIX geradas: o txid identifica a campanha/categoria do crédito
+--
+-- ⚠️  REGRA DE OURO: NUNCA modifique uma migration já aplicada!
+--     Todas as alterações devem ir em novas migrations.
+-- ============================================
+
+ALTER TABLE bank_accounts ADD COLUMN IF NOT EXISTS pix_key_type VARCHAR(20)
+    CHECK (pix_key_type IN ('cpf', 'cnpj', 'email', 'telefone', 'aleatoria'));
+ALTER TABLE bank_accounts ADD COLUMN IF NOT EXISTS pix_key VARCHAR(77);
+
+CREATE TABLE IF NOT EXISTS pix_charges (
+    id                  UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    church_id           UUID NOT NULL REFERENCES churches(id),
+    bank_account_id     UUID NOT NULL REFERENCES bank_accounts(id),
+    -- Identificador do BR Code (campo 62-05), usado para casar o crédito recebido
+    txid                VARCHAR(25) NOT NULL,
+    -- NULL = valor livre, digitado por quem paga
+    amount              DECIMAL(15,2) CHECK (amount > 0),
+    description         VARCHAR(100),
+    account_plan_id     UUID REFERENCES account_plans(id),
+    campaign_id         UUID REFERENCES campaigns(id),
+    congregation_id     UUID REFERENCES congregations(id),
+    -- Payload "copia e cola" com CRC, gravado como foi impresso
+    payload             TEXT NOT NULL,
+    created_by          UUID REFERENCES users(id),
+    created_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (church_id, txid)
+);
+
+CREATE INDEX IF NOT EXISTS idx_pix_charges_church ON pix_charges(church_id, created_at DESC);
+CREATE INDEX IF NOT EXISTS idx_pix_charges_campaign ON pix_charges(campaign_id) WHERE campaign_id IS NOT NULL;
@@ -0,0 +1,55 @@
+-- ============================================
+-- Igreja Manager — Migration: Webhooks de Provedores de Pagamento
+-- 1. Integrações por igreja/provedor (segredo HMAC do webhook)
+-- 2. Notificações de pagamento recebidas (deduplicadas) e fila de revisão
+--
+-- ⚠️  REGRA DE OURO: NUNCA modifique uma migration já aplicada!
+--     Todas as alterações devem ir em novas migrations.
+-- ============================================
+
+CREATE TABLE IF NOT EXISTS payment_integrations (
+    id                  UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    church_id           UUID NOT NULL REFERENCES churches(id),
+    provider            VARCHAR(30) NOT NULL,
+    webhook_secret      VARCHAR(200) NOT NULL,
+    -- Usuário que aparece como autor dos lançamentos criados pelo webhook
+    registered_by       UUID NOT NULL REFERENCES users(id),
+    is_active           BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (church_id, provider)
+);
+
+CREATE OR REPLACE TRIGGER trg_payment_integrations_updated BEFORE UPDATE ON payment_integrations
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at();
+
+CREATE TABLE IF NOT EXISTS payment_notifications (
+    id                      UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    church_id               UUID NOT NULL REFERENCES churches(id),
+    provider                VARCHAR(30) NOT NULL,
+    provider_transaction_id VARCHAR(100) NOT NULL,
+    method                  VARCHAR(20) NOT NULL CHECK (method IN ('pix', 'cartao')),
+    amount                  DECIMAL(15,2) NOT NULL CHECK (amount > 0),
+    paid_at                 TIMESTAMPTZ NOT NULL,
+    -- txid do QR Code PIX (ou referência do pedido no cartão)
+    txid                    VARCHAR(50),
+    payer_name              VARCHAR(200),
+    payer_document          VARCHAR(20),
+    pix_charge_id           UUID REFERENCES pix_charges(id),
+    status                  VARCHAR(20) NOT NULL DEFAULT 'pendente_revisao'
+                            CHECK (status IN ('pendente_revisao', 'lancado', 'descartado')),
+    review_reason           VARCHAR(300),
+    entry_id                UUID REFERENCES financial_entries(id),
+    resolved_by             UUID REFERENCES users(id),
+    resolved_at             TIMESTAMPTZ,
+    raw_payload             JSONB NOT NULL,
+    created_at              TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at              TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (church_id, provider, provider_transaction_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_payment_notifications_review
+    ON payment_notifications(church_id, created_at) WHERE status = 'pendente_revisao';
+
+CREATE OR REPLACE TRIGGER trg_payment_notifications_updated BEFORE UPDATE ON payment_notifications
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at();
@@ -0,0 +1,28 @@
+-- ============================================
+-- Igreja Manager — Migration: Exportação Contábil
+-- 1. Layouts de exportação para o contador (colunas e de-para de contas)
+--
+-- ⚠️  REGRA DE OURO: NUNCA modifique uma migration já aplicada!
+--     Todas as alterações devem ir em novas migrations.
+-- ============================================
+
+CREATE TABLE IF NOT EXISTS accounting_export_layouts (
+    id                  UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    church_id           UUID NOT NULL REFERENCES churches(id),
+    name                VARCHAR(100) NOT NULL,
+    -- [{"header": "Conta Débito", "field": "debit_account"}, ...]
+    columns             JSONB NOT NULL,
+    -- De-para: código do plano de contas → conta do contador (vale para as subcategorias)
+    account_codes       JSONB NOT NULL DEFAULT '{}',
+    -- De-para: id da conta bancária → conta do contador
+    bank_account_codes  JSONB NOT NULL DEFAULT '{}',
+    delimiter           VARCHAR(1) NOT NULL DEFAULT ';',
+    decimal_comma       BOOLEAN NOT NULL DEFAULT TRUE,
+    include_totals      BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (church_id, name)
+);
+
+CREATE OR REPLACE TRIGGER trg_accounting_export_layouts_updated BEFORE UPDATE ON accounting_export_layouts
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at();
@@ -0,0 +1,52 @@
+-- ============================================
+-- Igreja Manager — Migration: Aprovação de Despesas
+-- 1. Regras de aprovação por igreja (valor e/ou categoria)
+-- 2. Status 'aguardando_aprovacao' e dados da decisão nos lançamentos
+-- 3. Permissão financial:approve para o pastor
+--
+-- ⚠️  REGRA DE OURO: NUNCA modifique uma migration já aplicada!
+--     Todas as alterações devem ir em novas migrations.
+-- ============================================
+
+-- 1. Regras: despesas acima do valor e/ou na categoria (ou subcategorias) exigem aprovação
+CREATE TABLE IF NOT EXISTS expense_approval_rules (
+    id                  UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    church_id           UUID NOT NULL REFERENCES churches(id),
+    name                VARCHAR(100) NOT NULL,
+    amount_above        DECIMAL(15,2) CHECK (amount_above >= 0),
+    account_plan_id     UUID REFERENCES account_plans(id),
+    is_active           BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (amount_above IS NOT NULL OR account_plan_id IS NOT NULL)
+);
+
+CREATE INDEX IF NOT EXISTS idx_expense_approval_rules_church
+    ON expense_approval_rules(church_id) WHERE is_active = TRUE;
+
+CREATE OR REPLACE TRIGGER trg_expense_approval_rules_updated BEFORE UPDATE ON expense_approval_rules
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at();
+
+-- 2. Despesas aguardando aprovação não movimentam saldo
+ALTER TABLE financial_entries DROP CONSTRAINT IF EXISTS financial_entries_status_check;
+ALTER TABLE financial_entries ADD CONSTRAINT financial_entries_status_check CHECK (status IN (
+    'pendente', 'confirmado', 'cancelado', 'estornado', 'aguardando_aprovacao'
+));
+
+ALTER TABLE financial_entries
+    -- Status que a despesa assume quando aprovada ('pendente' ou 'confirmado')
+    ADD COLUMN IF NOT EXISTS requested_status VARCHAR(20),
+    ADD COLUMN IF NOT EXISTS approved_by UUID REFERENCES users(id),
+    ADD COLUMN IF NOT EXISTS approved_at TIMESTAMPTZ,
+    ADD COLUMN IF NOT EXISTS rejection_reason TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_fin_entries_awaiting_approval
+    ON financial_entries(church_id, entry_date)
+    WHERE status = 'aguardando_aprovacao' AND deleted_at IS NULL;
+
+-- 3. O pastor assina como segundo aprovador (o tesoureiro já tem financial:*)
+UPDATE roles
+SET permissions = permissions || '["financial:approve"]'::jsonb,
+    updated_at = NOW()
+WHERE name = 'pastor'
+  AND NOT permissions ? 'financial:approve';
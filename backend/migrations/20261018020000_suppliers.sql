@@ -0,0 +1,115 @@
+-- ============================================
+-- Igreja Manager — Migration: Cadastro de Fornecedores
+-- 1. Fornecedores por igreja (CPF/CNPJ, contato, categoria)
+-- 2. Chave de comparação de nomes (sem acento, pontuação e sufixo societário)
+-- 3. Vínculo de lançamentos e manutenções ao fornecedor
+-- 4. Agrupamento dos nomes digitados até aqui em fornecedores
+-- 5. Nome digitado sem fornecedor escolhido é vinculado pelo nome
+--
+-- ⚠️  REGRA DE OURO: NUNCA modifique uma migration já aplicada!
+--     Todas as alterações devem ir em novas migrations.
+-- ============================================
+
+-- 1. Fornecedores
+CREATE TABLE IF NOT EXISTS suppliers (
+    id                  UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    church_id           UUID NOT NULL REFERENCES churches(id),
+    name                VARCHAR(200) NOT NULL,
+    -- CPF (11) ou CNPJ (14), só dígitos
+    document            VARCHAR(14) CHECK (document ~ '^([0-9]{11}|[0-9]{14})$'),
+    category            VARCHAR(100),
+    contact_name        VARCHAR(200),
+    phone               VARCHAR(20),
+    email               VARCHAR(255),
+    notes               TEXT,
+    is_active           BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    deleted_at          TIMESTAMPTZ
+);
+
+CREATE INDEX IF NOT EXISTS idx_suppliers_church ON suppliers(church_id) WHERE deleted_at IS NULL;
+CREATE UNIQUE INDEX IF NOT EXISTS idx_suppliers_document
+    ON suppliers(church_id, document) WHERE document IS NOT NULL AND deleted_at IS NULL;
+
+CREATE OR REPLACE TRIGGER trg_suppliers_updated BEFORE UPDATE ON suppliers
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at();
+
+-- 2. "Casa do Construtor Ltda." e "CASA DO CONSTRUTOR" → "casa do construtor"
+CREATE OR REPLACE FUNCTION supplier_name_key(p_name TEXT) RETURNS TEXT AS $$
+    SELECT NULLIF(TRIM(REGEXP_REPLACE(
+        REGEXP_REPLACE(LOWER(unaccent(p_name)), '[^a-z0-9]+', ' ', 'g'),
+        ' (ltda|me|epp|eireli|sa|s a)\s*$', ''
+    )), '')
+$$ LANGUAGE sql STABLE;
+
+-- 3. Vínculos (supplier_name continua com o nome exibido)
+ALTER TABLE financial_entries ADD COLUMN IF NOT EXISTS supplier_id UUID REFERENCES suppliers(id);
+ALTER TABLE maintenances ADD COLUMN IF NOT EXISTS supplier_id UUID REFERENCES suppliers(id);
+
+CREATE INDEX IF NOT EXISTS idx_fin_entries_supplier
+    ON financial_entries(supplier_id) WHERE supplier_id IS NOT NULL;
+CREATE INDEX IF NOT EXISTS idx_maintenances_supplier
+    ON maintenances(supplier_id) WHERE supplier_id IS NOT NULL;
+
+-- 4. Um fornecedor por nome normalizado, com a grafia mais usada
+INSERT INTO suppliers (church_id, name)
+SELECT DISTINCT ON (church_id, name_key) church_id, name
+FROM (
+    SELECT church_id, TRIM(supplier_name) AS name, supplier_name_key(supplier_name) AS name_key,
+           COUNT(*) AS uses
+    FROM (
+        SELECT church_id, supplier_name FROM financial_entries
+        WHERE supplier_name IS NOT NULL AND deleted_at IS NULL
+        UNION ALL
+        SELECT church_id, supplier_name FROM maintenances WHERE supplier_name IS NOT NULL
+    ) typed
+    GROUP BY church_id, TRIM(supplier_name), supplier_name_key(supplier_name)
+) spellings
+WHERE name_key IS NOT NULL
+  AND NOT EXISTS (SELECT 1 FROM suppliers s
+                  WHERE s.church_id = spellings.church_id
+                    AND supplier_name_key(s.name) = spellings.name_key)
+ORDER BY church_id, name_key, uses DESC, name;
+
+UPDATE financial_entries fe SET supplier_id = s.id
+FROM suppliers s
+WHERE fe.supplier_id IS NULL AND fe.supplier_name IS NOT NULL
+  AND s.church_id = fe.church_id AND s.deleted_at IS NULL
+  AND supplier_name_key(s.name) = supplier_name_key(fe.supplier_name);
+
+UPDATE maintenances m SET supplier_id = s.id
+FROM suppliers s
+WHERE m.supplier_id IS NULL AND m.supplier_name IS NOT NULL
+  AND s.church_id = m.church_id AND s.deleted_at IS NULL
+  AND supplier_name_key(s.name) = supplier_name_key(m.supplier_name);
+
+-- 5. Lançamentos e manutenções com nome digitado (recorrências, extrato, clientes
+--    antigos) ficam vinculados ao fornecedor de mesmo nome, quando houver
+CREATE OR REPLACE FUNCTION link_supplier_by_name()
+RETURNS TRIGGER AS $$
+BEGIN
+    IF NEW.supplier_name IS NOT NULL AND (
+        (TG_OP = 'INSERT' AND NEW.supplier_id IS NULL)
+        OR (TG_OP = 'UPDATE' AND NEW.supplier_name IS DISTINCT FROM OLD.supplier_name
+            AND NEW.supplier_id IS NOT DISTINCT FROM OLD.supplier_id)
+    ) THEN
+        NEW.supplier_id := (
+            SELECT id FROM suppliers
+            WHERE church_id = NEW.church_id AND deleted_at IS NULL
+              AND supplier_name_key(name) = supplier_name_key(NEW.supplier_name)
+            ORDER BY created_at
+            LIMIT 1
+        );
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE OR REPLACE TRIGGER trg_fin_entries_link_supplier
+BEFORE INSERT OR UPDATE OF supplier_name ON financial_entries
+FOR EACH ROW EXECUTE FUNCTION link_supplier_by_name();
+
+CREATE OR REPLACE TRIGGER trg_maintenances_link_supplier
+BEFORE INSERT OR UPDATE OF supplier_name ON maintenances
+FOR EACH ROW EXECUTE FUNCTION link_supplier_by_name();
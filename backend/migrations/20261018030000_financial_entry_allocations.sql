@@ -0,0 +1,94 @@
+-- ============================================
+-- Igreja Manager — Migration: Lançamentos Divididos (Rateio)
+-- 1. Linhas de rateio de um lançamento (categoria, campanha, membro, valor)
+-- 2. View com as linhas de todos os lançamentos: os não divididos
+--    aparecem como uma única linha, com os dados do próprio lançamento
+-- 3. Saldo arrecadado das campanhas calculado pelas linhas
+--
+-- ⚠️  REGRA DE OURO: NUNCA modifique uma migration já aplicada!
+--     Todas as alterações devem ir em novas migrations.
+-- ============================================
+
+-- 1. Linhas de rateio (a soma é igual ao valor do lançamento)
+CREATE TABLE IF NOT EXISTS financial_entry_allocations (
+    id              UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    church_id       UUID NOT NULL REFERENCES churches(id),
+    entry_id        UUID NOT NULL REFERENCES financial_entries(id) ON DELETE CASCADE,
+    account_plan_id UUID NOT NULL REFERENCES account_plans(id),
+    campaign_id     UUID REFERENCES campaigns(id),
+    member_id       UUID REFERENCES members(id),
+    amount          DECIMAL(15,2) NOT NULL CHECK (amount > 0),
+    description     VARCHAR(200),
+    sort_order      SMALLINT NOT NULL DEFAULT 0,
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_fin_allocations_entry ON financial_entry_allocations(entry_id);
+CREATE INDEX IF NOT EXISTS idx_fin_allocations_plan ON financial_entry_allocations(account_plan_id);
+CREATE INDEX IF NOT EXISTS idx_fin_allocations_campaign
+    ON financial_entry_allocations(campaign_id) WHERE campaign_id IS NOT NULL;
+CREATE INDEX IF NOT EXISTS idx_fin_allocations_member
+    ON financial_entry_allocations(member_id) WHERE member_id IS NOT NULL;
+
+-- 2. Linhas de todos os lançamentos, para relatórios por categoria, campanha e membro
+CREATE OR REPLACE VIEW financial_entry_lines AS
+SELECT a.entry_id, a.id AS allocation_id, a.account_plan_id, a.campaign_id,
+       a.member_id, a.amount
+FROM financial_entry_allocations a
+UNION ALL
+SELECT fe.id AS entry_id, NULL::uuid AS allocation_id, fe.account_plan_id, fe.campaign_id,
+       fe.member_id, fe.amount
+FROM financial_entries fe
+WHERE NOT EXISTS (SELECT 1 FROM financial_entry_allocations a WHERE a.entry_id = fe.id);
+
+-- 3. Saldo das campanhas pelas linhas (lançamentos divididos podem ter várias campanhas)
+CREATE OR REPLACE FUNCTION refresh_campaign_raised(p_campaign_id UUID)
+RETURNS VOID AS $$
+BEGIN
+    UPDATE campaigns SET raised_amount = (
+        SELECT COALESCE(SUM(l.amount), 0)
+        FROM financial_entry_lines l
+        JOIN financial_entries fe ON fe.id = l.entry_id
+        WHERE l.campaign_id = p_campaign_id
+          AND fe.status = 'confirmado'
+          AND fe.deleted_at IS NULL
+    )
+    WHERE id = p_campaign_id;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE OR REPLACE FUNCTION update_campaign_balance()
+RETURNS TRIGGER AS $$
+DECLARE
+    v_campaign_id UUID;
+BEGIN
+    FOR v_campaign_id IN
+        SELECT NEW.campaign_id WHERE NEW.campaign_id IS NOT NULL
+        UNION
+        SELECT OLD.campaign_id WHERE TG_OP = 'UPDATE' AND OLD.campaign_id IS NOT NULL
+        UNION
+        SELECT a.campaign_id FROM financial_entry_allocations a
+        WHERE a.entry_id = NEW.id AND a.campaign_id IS NOT NULL
+    LOOP
+        PERFORM refresh_campaign_raised(v_campaign_id);
+    END LOOP;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE OR REPLACE FUNCTION update_campaign_balance_from_allocation()
+RETURNS TRIGGER AS $$
+BEGIN
+    IF TG_OP IN ('INSERT', 'UPDATE') AND NEW.campaign_id IS NOT NULL THEN
+        PERFORM refresh_campaign_raised(NEW.campaign_id);
+    END IF;
+    IF TG_OP IN ('UPDATE', 'DELETE') AND OLD.campaign_id IS NOT NULL THEN
+        PERFORM refresh_campaign_raised(OLD.campaign_id);
+    END IF;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE OR REPLACE TRIGGER trg_update_campaign_balance_allocations
+AFTER INSERT OR UPDATE OR DELETE ON financial_entry_allocations
+FOR EACH ROW EXECUTE FUNCTION update_campaign_balance_from_allocation();
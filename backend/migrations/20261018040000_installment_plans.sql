@@ -0,0 +1,66 @@
+-- ============================================
+-- Igreja Manager — Migration: Despesas Parceladas
+-- 1. Planos de parcelamento (compra paga em N parcelas por boleto ou cartão)
+-- 2. Vínculo das parcelas via financial_entries.installment_plan_id
+--
+-- ⚠️  REGRA DE OURO: NUNCA modifique uma migration já aplicada!
+--     Todas as alterações devem ir em novas migrations.
+-- ============================================
+
+-- ============================
+-- 1. PLANOS DE PARCELAMENTO
+-- ============================
+
+CREATE TABLE IF NOT EXISTS installment_plans (
+    id                UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    church_id         UUID NOT NULL REFERENCES churches(id),
+
+    -- Dados copiados para cada parcela gerada
+    account_plan_id   UUID NOT NULL REFERENCES account_plans(id),
+    bank_account_id   UUID NOT NULL REFERENCES bank_accounts(id),
+    description       TEXT NOT NULL,
+    payment_method    VARCHAR(30) CHECK (payment_method IN (
+        'dinheiro', 'pix', 'transferencia', 'cartao_debito',
+        'cartao_credito', 'cheque', 'boleto', 'outro'
+    )),
+    supplier_id       UUID REFERENCES suppliers(id),
+    supplier_name     VARCHAR(200),
+    congregation_id   UUID REFERENCES congregations(id) ON DELETE SET NULL,
+    notes             TEXT,
+
+    -- Data da compra e valor total (parcelas pagas + parcelas em aberto)
+    purchase_date     DATE NOT NULL,
+    total_amount      DECIMAL(15,2) NOT NULL CHECK (total_amount > 0),
+    installment_count SMALLINT NOT NULL CHECK (installment_count BETWEEN 1 AND 120),
+    first_due_date    DATE NOT NULL,
+    -- Última renegociação das parcelas em aberto
+    renegotiated_at   TIMESTAMPTZ,
+
+    created_by        UUID NOT NULL REFERENCES users(id),
+    created_at        TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at        TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    deleted_at        TIMESTAMPTZ
+);
+
+CREATE INDEX IF NOT EXISTS idx_installment_plans_church ON installment_plans(church_id);
+
+CREATE OR REPLACE TRIGGER trg_installment_plans_updated
+    BEFORE UPDATE ON installment_plans
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at();
+
+-- ============================
+-- 2. VÍNCULO DAS PARCELAS
+-- ============================
+
+ALTER TABLE financial_entries
+    ADD COLUMN IF NOT EXISTS installment_plan_id UUID REFERENCES installment_plans(id),
+    ADD COLUMN IF NOT EXISTS installment_number SMALLINT;
+
+-- Número da parcela só existe em lançamentos vinculados a um plano
+ALTER TABLE financial_entries DROP CONSTRAINT IF EXISTS chk_fin_entries_installment;
+ALTER TABLE financial_entries ADD CONSTRAINT chk_fin_entries_installment
+    CHECK ((installment_plan_id IS NULL) = (installment_number IS NULL));
+
+CREATE INDEX IF NOT EXISTS idx_fin_entries_installment_plan
+    ON financial_entries(installment_plan_id, installment_number)
+    WHERE installment_plan_id IS NOT NULL AND deleted_at IS NULL;
@@ -0,0 +1,116 @@
+-- ============================================
+-- Igreja Manager — Migration: Contagem de Ofertas
+-- 1. Folhas de contagem do gazofilácio após o culto
+-- 2. Contagem por cédula e moeda
+-- 3. Envelopes de dízimo vinculados a membros
+-- 4. Vínculo dos lançamentos gerados via financial_entries.offering_count_id
+-- 5. Papel de contador(a) de ofertas (financial:count)
+--
+-- ⚠️  REGRA DE OURO: NUNCA modifique uma migration já aplicada!
+--     Todas as alterações devem ir em novas migrations.
+-- ============================================
+
+-- ============================
+-- 1. FOLHAS DE CONTAGEM
+-- ============================
+
+CREATE TABLE IF NOT EXISTS offering_counts (
+    id                       UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    church_id                UUID NOT NULL REFERENCES churches(id),
+    congregation_id          UUID REFERENCES congregations(id) ON DELETE SET NULL,
+    service_date             DATE NOT NULL,
+    -- Ex.: "Culto de domingo à noite"
+    service_name             VARCHAR(100),
+
+    -- Destino dos lançamentos gerados
+    bank_account_id          UUID NOT NULL REFERENCES bank_accounts(id),
+    offering_account_plan_id UUID NOT NULL REFERENCES account_plans(id),
+    -- Categoria padrão dos envelopes
+    tithe_account_plan_id    UUID REFERENCES account_plans(id),
+
+    status                   VARCHAR(20) NOT NULL DEFAULT 'em_contagem'
+                             CHECK (status IN ('em_contagem', 'confirmada')),
+    -- Dupla conferência: dois usuários distintos confirmam a folha
+    first_counter_id         UUID REFERENCES users(id),
+    first_confirmed_at       TIMESTAMPTZ,
+    second_counter_id        UUID REFERENCES users(id),
+    second_confirmed_at      TIMESTAMPTZ,
+    notes                    TEXT,
+
+    created_by               UUID NOT NULL REFERENCES users(id),
+    created_at               TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at               TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    deleted_at               TIMESTAMPTZ,
+
+    CHECK (second_counter_id IS NULL OR second_counter_id <> first_counter_id),
+    CHECK (status = 'em_contagem' OR second_counter_id IS NOT NULL)
+);
+
+CREATE INDEX IF NOT EXISTS idx_offering_counts_church
+    ON offering_counts(church_id, service_date) WHERE deleted_at IS NULL;
+
+CREATE OR REPLACE TRIGGER trg_offering_counts_updated
+    BEFORE UPDATE ON offering_counts
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at();
+
+-- ============================
+-- 2. CÉDULAS E MOEDAS
+-- ============================
+
+-- Todo o dinheiro da caixa, inclusive o dos envelopes
+CREATE TABLE IF NOT EXISTS offering_count_denominations (
+    id           UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    count_id     UUID NOT NULL REFERENCES offering_counts(id) ON DELETE CASCADE,
+    denomination DECIMAL(7,2) NOT NULL CHECK (denomination IN (
+        0.01, 0.05, 0.10, 0.25, 0.50, 1.00,
+        2.00, 5.00, 10.00, 20.00, 50.00, 100.00, 200.00
+    )),
+    quantity     INTEGER NOT NULL CHECK (quantity > 0),
+    UNIQUE (count_id, denomination)
+);
+
+-- ============================
+-- 3. ENVELOPES
+-- ============================
+
+CREATE TABLE IF NOT EXISTS offering_count_envelopes (
+    id               UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    count_id         UUID NOT NULL REFERENCES offering_counts(id) ON DELETE CASCADE,
+    member_id        UUID REFERENCES members(id),
+    -- Envelope sem membro cadastrado (visitante)
+    contributor_name VARCHAR(200),
+    -- Padrão: tithe_account_plan_id da folha
+    account_plan_id  UUID REFERENCES account_plans(id),
+    amount           DECIMAL(15,2) NOT NULL CHECK (amount > 0),
+    payment_method   VARCHAR(30) NOT NULL DEFAULT 'dinheiro'
+                     CHECK (payment_method IN ('dinheiro', 'cheque')),
+    sort_order       SMALLINT NOT NULL DEFAULT 0,
+
+    CHECK (member_id IS NOT NULL OR contributor_name IS NOT NULL)
+);
+
+CREATE INDEX IF NOT EXISTS idx_offering_envelopes_count ON offering_count_envelopes(count_id);
+
+-- ============================
+-- 4. VÍNCULO DOS LANÇAMENTOS
+-- ============================
+
+ALTER TABLE financial_entries
+    ADD COLUMN IF NOT EXISTS offering_count_id UUID REFERENCES offering_counts(id);
+
+CREATE INDEX IF NOT EXISTS idx_fin_entries_offering_count
+    ON financial_entries(offering_count_id) WHERE offering_count_id IS NOT NULL;
+
+-- ============================
+-- 5. PAPEL DE CONTADOR(A)
+-- ============================
+
+-- Diáconos que contam e confirmam a folha sem acesso ao restante do financeiro
+INSERT INTO roles (name, display_name, description, permissions, is_system)
+VALUES (
+    'offering_counter',
+    'Contador(a) de Ofertas',
+    'Registra e confirma a contagem das ofertas após o culto.',
+    '["financial:count"]'::jsonb,
+    TRUE
+) ON CONFLICT (name) DO NOTHING;
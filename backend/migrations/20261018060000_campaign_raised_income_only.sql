@@ -0,0 +1,36 @@
+-- ============================================
+-- Igreja Manager — Migration: Saldo de Campanhas só com Receitas
+-- 1. O saldo arrecadado soma apenas receitas que não são estornos,
+--    como o relatório da campanha (o original estornado deixa de contar)
+-- 2. Estornos não ficam vinculados às campanhas das linhas do original
+-- 3. Recalcula o saldo de todas as campanhas
+--
+-- ⚠️  REGRA DE OURO: NUNCA modifique uma migration já aplicada!
+--     Todas as alterações devem ir em novas migrations.
+-- ============================================
+
+-- 1. Saldo pelas linhas, só de receitas
+CREATE OR REPLACE FUNCTION refresh_campaign_raised(p_campaign_id UUID)
+RETURNS VOID AS $$
+BEGIN
+    UPDATE campaigns SET raised_amount = (
+        SELECT COALESCE(SUM(l.amount), 0)
+        FROM financial_entry_lines l
+        JOIN financial_entries fe ON fe.id = l.entry_id
+        WHERE l.campaign_id = p_campaign_id
+          AND fe.type = 'receita'
+          AND fe.reversal_of IS NULL
+          AND fe.status = 'confirmado'
+          AND fe.deleted_at IS NULL
+    )
+    WHERE id = p_campaign_id;
+END;
+$$ LANGUAGE plpgsql;
+
+-- 2. Estornos de lançamentos divididos copiaram a campanha das linhas
+UPDATE financial_entry_allocations a SET campaign_id = NULL
+FROM financial_entries fe
+WHERE fe.id = a.entry_id AND fe.reversal_of IS NOT NULL AND a.campaign_id IS NOT NULL;
+
+-- 3. Recalcula os saldos
+SELECT refresh_campaign_raised(id) FROM campaigns;
@@ -0,0 +1,30 @@
+-- ============================================
+-- Igreja Manager — Migration: Recibos Cancelados ao Alterar o Lançamento
+-- 1. O recibo emitido também é cancelado quando o lançamento deixa de estar
+--    confirmado (ex.: volta a pendente) ou muda valor, membro ou data,
+--    pois deixaria de corresponder à doação registrada
+--
+-- ⚠️  REGRA DE OURO: NUNCA modifique uma migration já aplicada!
+--     Todas as alterações devem ir em novas migrations.
+-- ============================================
+
+CREATE OR REPLACE FUNCTION void_donation_receipts()
+RETURNS TRIGGER AS $$
+BEGIN
+    IF NEW.status <> 'confirmado' OR NEW.deleted_at IS NOT NULL
+       OR NEW.amount <> OLD.amount
+       OR NEW.member_id IS DISTINCT FROM OLD.member_id
+       OR NEW.entry_date <> OLD.entry_date
+       OR NEW.payment_date IS DISTINCT FROM OLD.payment_date THEN
+        UPDATE donation_receipts
+        SET status = 'cancelado', voided_at = NOW(),
+            void_reason = CASE WHEN NEW.status = 'estornado' THEN 'Lançamento estornado'
+                               WHEN NEW.status = 'cancelado' OR NEW.deleted_at IS NOT NULL
+                               THEN 'Lançamento cancelado'
+                               WHEN NEW.status <> 'confirmado' THEN 'Lançamento voltou a pendente'
+                               ELSE 'Lançamento alterado' END
+        WHERE financial_entry_id = NEW.id AND status = 'emitido';
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
@@ -1,4 +1,7 @@
+use actix_multipart::Multipart;
 use actix_web::{delete, get, post, put, web, HttpRequest, HttpResponse};
+use chrono::Datelike;
+use futures_util::StreamExt;
 use serde::Deserialize;
 use sqlx::PgPool;
 use validator::Validate;
@@ -6,17 +9,39 @@ use validator::Validate;
 use crate::api::middleware;
 use crate::api::response::{ApiResponse, PaginationParams};
 use crate::application::dto::{
-    BalanceReportFilter, CreateAccountPlanRequest, CreateBankAccountRequest,
-    CreateCampaignRequest, CreateFinancialEntryRequest, FinancialEntryFilter,
-    MonthlyClosingRequest, UpdateAccountPlanRequest, UpdateBankAccountRequest,
-    UpdateCampaignRequest, UpdateFinancialEntryRequest,
+    AccountingExportParams, AccountsPayableFilter, AssemblyReportFilter,
+    BalanceReportFilter, BankAccountStatementParams, BudgetFilter, BudgetReportFilter,
+    CancelRecurrenceParams, CreateAccountPlanRequest, CreateBankAccountRequest,
+    CreateBudgetRequest, CreateCampaignPledgeRequest, CreateCampaignRequest,
+    CreateEntryFromLineRequest, CreateExpenseApprovalRuleRequest, CreateExportLayoutRequest,
+    CreateFinancialEntryRequest, CreateInstallmentPlanRequest, CreateOfferingCountRequest,
+    CreatePixChargeRequest, CreateRecurrenceRequest, CreateSupplierRequest,
+    CreateTransferRequest, DiscardPaymentNotificationRequest, FinancialEntryFilter,
+    GenerateRemittancesRequest, IncomeStatementFilter, InstallmentPlanFilter,
+    IssueReceiptRequest, MatchStatementLineRequest, MonthlyClosingFilter,
+    MonthlyClosingRequest, OfferingCountFilter, PayFinancialEntryRequest,
+    PayRemittanceRequest, PaymentNotificationFilter, PixChargeFilter, PixQrParams,
+    ReceiptFilter, ReconciliationParams, RecurrenceFilter, RejectExpenseRequest,
+    RemittanceFilter, RenegotiateInstallmentsRequest, ReopenMonthlyClosingRequest,
+    ResolvePaymentNotificationRequest, ReverseFinancialEntryRequest, SupplierFilter,
+    SupplierSpendingFilter, TitheStatementParams, TitherRegularityFilter, TransferFilter,
+    UpdateAccountPlanRequest, UpdateBankAccountRequest, UpdateBudgetRequest,
+    UpdateCampaignPledgeRequest, UpdateCampaignRequest, UpdateExpenseApprovalRuleRequest,
+    UpdateExportLayoutRequest, UpdateFinancialEntryRequest, UpdateOfferingCountRequest,
+    UpdatePaymentIntegrationRequest, UpdateRecurrenceRequest, UpdateRemittanceRuleRequest,
+    UpdateSupplierRequest,
 };
 use crate::application::services::{
-    AccountPlanService, BankAccountService, CampaignService, FinancialEntryService,
-    MonthlyClosingService, AuditService,
+    AccountPlanService, AccountingExportService, BankAccountService, BankStatementService,
+    BudgetService, CampaignService, DonationReceiptService, ExpenseApprovalService,
+    FinancialEntryService, FinancialReportService, InstallmentService, MonthlyClosingService,
+    AuditService, OfferingCountService, PayablesService, PaymentWebhookService, PixService,
+    RecurrenceService, RemittanceService, SupplierService, TitheService, TransferService,
 };
 use crate::config::AppConfig;
 use crate::errors::AppError;
+use crate::infrastructure::bank_statement::parse_amount;
+use crate::infrastructure::cache::CacheService;
 
 // ==========================================
 // Account Plans
@@ -127,6 +152,7 @@ pub async fn update_account_plan(
     req: HttpRequest,
     pool: web::Data<PgPool>,
     config: web::Data<AppConfig>,
+    cache: web::Data<CacheService>,
     path: web::Path<uuid::Uuid>,
     body: web::Json<UpdateAccountPlanRequest>,
 ) -> Result<HttpResponse, AppError> {
@@ -140,6 +166,9 @@ pub async fn update_account_plan(
 
     let plan = AccountPlanService::update(pool.get_ref(), church_id, plan_id, &body).await?;
 
+    // Invalidate tither regularity cache
+    cache.del_pattern(&format!("tithes:*:{church_id}:*")).await;
+
     Ok(HttpResponse::Ok().json(ApiResponse::with_message(
         plan,
         "Plano de contas atualizado com sucesso",
@@ -271,359 +300,3956 @@ pub async fn update_bank_account(
     )))
 }
 
-// ==========================================
-// Campaigns
-// ==========================================
-
-#[derive(Debug, Deserialize)]
-pub struct CampaignFilter {
-    pub status: Option<String>,
-}
-
-/// List campaigns
+/// Statement of a bank account with opening, running and closing balances
 #[utoipa::path(
     get,
-    path = "/api/v1/financial/campaigns",
+    path = "/api/v1/financial/bank-accounts/{id}/statement",
     params(
-        ("page" = Option<i64>, Query, description = "Page number"),
-        ("per_page" = Option<i64>, Query, description = "Items per page"),
-        ("search" = Option<String>, Query, description = "Search by name"),
-        ("status" = Option<String>, Query, description = "Filter by status"),
+        ("id" = uuid::Uuid, Path, description = "Bank account ID"),
+        ("from" = Option<String>, Query, description = "Start date (default: first day of the current month)"),
+        ("to" = Option<String>, Query, description = "End date (default: today)"),
+        ("format" = Option<String>, Query, description = "json (default), csv or pdf"),
     ),
     responses(
-        (status = 200, description = "List of campaigns"),
-        (status = 401, description = "Not authenticated")
+        (status = 200, description = "Bank account statement"),
+        (status = 404, description = "Not found")
     ),
     security(("bearer_auth" = []))
 )]
-#[get("/api/v1/financial/campaigns")]
-pub async fn list_campaigns(
+#[get("/api/v1/financial/bank-accounts/{id}/statement")]
+pub async fn bank_account_statement(
     req: HttpRequest,
     pool: web::Data<PgPool>,
     config: web::Data<AppConfig>,
-    pagination: web::Query<PaginationParams>,
-    filter: web::Query<CampaignFilter>,
+    path: web::Path<uuid::Uuid>,
+    params: web::Query<BankAccountStatementParams>,
 ) -> Result<HttpResponse, AppError> {
     let claims = middleware::auth_middleware(req, config).await?;
     middleware::require_permission(&claims, "financial:read")?;
     let church_id = middleware::get_church_id(&claims)?;
+    let bank_account_id = path.into_inner();
 
-    let (campaigns, total) = CampaignService::list(
+    let today = chrono::Local::now().date_naive();
+    let date_to = params.to.unwrap_or(today);
+    let date_from = params
+        .from
+        .unwrap_or_else(|| date_to.with_day(1).unwrap_or(date_to));
+    if date_from > date_to {
+        return Err(AppError::validation("'from' deve ser anterior ou igual a 'to'"));
+    }
+
+    let statement = FinancialReportService::bank_account_statement(
         pool.get_ref(),
         church_id,
-        filter.status.as_deref(),
-        &pagination.search,
-        pagination.per_page(),
-        pagination.offset(),
+        bank_account_id,
+        date_from,
+        date_to,
     )
     .await?;
 
-    Ok(HttpResponse::Ok().json(ApiResponse::paginated(
-        campaigns,
-        pagination.page(),
-        pagination.per_page(),
-        total,
-    )))
+    if !middleware::can_access_congregation(&claims, statement.congregation_id) {
+        return Err(AppError::Forbidden(
+            "Sem permissão para ver o extrato desta conta".into(),
+        ));
+    }
+
+    let filename = format!(
+        "extrato-{}-{}",
+        date_from.format("%Y-%m-%d"),
+        date_to.format("%Y-%m-%d")
+    );
+
+    match params.format.as_deref() {
+        None | Some("json") => Ok(HttpResponse::Ok().json(ApiResponse::ok(statement))),
+        Some("csv") => {
+            let csv = FinancialReportService::bank_account_statement_csv(&statement)?;
+            Ok(HttpResponse::Ok()
+                .content_type("text/csv; charset=utf-8")
+                .insert_header((
+                    "Content-Disposition",
+                    format!("attachment; filename=\"{filename}.csv\""),
+                ))
+                .body(csv))
+        }
+        Some("pdf") => {
+            let pdf = FinancialReportService::bank_account_statement_pdf(
+                pool.get_ref(),
+                church_id,
+                &statement,
+            )
+            .await?;
+            Ok(HttpResponse::Ok()
+                .content_type("application/pdf")
+                .insert_header((
+                    "Content-Disposition",
+                    format!("attachment; filename=\"{filename}.pdf\""),
+                ))
+                .body(pdf))
+        }
+        Some(_) => Err(AppError::validation("Formato inválido. Use json, csv ou pdf")),
+    }
 }
 
-/// Get campaign by ID
+/// Compare cached bank balances with the entries ledger
 #[utoipa::path(
     get,
-    path = "/api/v1/financial/campaigns/{id}",
-    params(("id" = uuid::Uuid, Path, description = "Campaign ID")),
+    path = "/api/v1/financial/bank-accounts/balance-check",
     responses(
-        (status = 200, description = "Campaign details"),
-        (status = 404, description = "Not found")
+        (status = 200, description = "Accounts whose cached balance drifted from the ledger"),
+        (status = 401, description = "Not authenticated")
     ),
     security(("bearer_auth" = []))
 )]
-#[get("/api/v1/financial/campaigns/{id}")]
-pub async fn get_campaign(
+#[get("/api/v1/financial/bank-accounts/balance-check")]
+pub async fn check_bank_balances(
     req: HttpRequest,
     pool: web::Data<PgPool>,
     config: web::Data<AppConfig>,
-    path: web::Path<uuid::Uuid>,
 ) -> Result<HttpResponse, AppError> {
     let claims = middleware::auth_middleware(req, config).await?;
     middleware::require_permission(&claims, "financial:read")?;
     let church_id = middleware::get_church_id(&claims)?;
-    let campaign_id = path.into_inner();
 
-    let campaign = CampaignService::get_by_id(pool.get_ref(), church_id, campaign_id).await?;
+    let result = BankAccountService::verify_balances(pool.get_ref(), church_id, false).await?;
 
-    Ok(HttpResponse::Ok().json(ApiResponse::ok(campaign)))
+    Ok(HttpResponse::Ok().json(ApiResponse::ok(result)))
 }
 
-/// Create a new campaign
+/// Repair cached bank balances that drifted from the entries ledger
 #[utoipa::path(
     post,
-    path = "/api/v1/financial/campaigns",
-    request_body = CreateCampaignRequest,
+    path = "/api/v1/financial/bank-accounts/balance-check/repair",
     responses(
-        (status = 201, description = "Campaign created"),
-        (status = 400, description = "Validation error")
+        (status = 200, description = "Drifted balances recomputed from the ledger"),
+        (status = 403, description = "Forbidden")
     ),
     security(("bearer_auth" = []))
 )]
-#[post("/api/v1/financial/campaigns")]
-pub async fn create_campaign(
+#[post("/api/v1/financial/bank-accounts/balance-check/repair")]
+pub async fn repair_bank_balances(
     req: HttpRequest,
     pool: web::Data<PgPool>,
     config: web::Data<AppConfig>,
-    body: web::Json<CreateCampaignRequest>,
 ) -> Result<HttpResponse, AppError> {
+    let ip_address = req.peer_addr().map(|a| a.ip().to_string());
+    let user_agent = req
+        .headers()
+        .get("user-agent")
+        .and_then(|v| v.to_str().ok())
+        .map(String::from);
+
     let claims = middleware::auth_middleware(req, config).await?;
-    middleware::require_permission(&claims, "financial:write")?;
+    middleware::require_permission(&claims, "financial:close")?;
+    if !middleware::can_access_congregation(&claims, None) {
+        return Err(AppError::Forbidden(
+            "Sem permissão para corrigir saldos de toda a igreja".into(),
+        ));
+    }
     let church_id = middleware::get_church_id(&claims)?;
+    let user_id = middleware::get_user_id(&claims)?;
 
-    body.validate()
-        .map_err(|e| AppError::validation(e.to_string()))?;
+    let result = BankAccountService::verify_balances(pool.get_ref(), church_id, true).await?;
 
-    let campaign = CampaignService::create(pool.get_ref(), church_id, &body).await?;
+    // Audit log
+    for check in &result.drifted {
+        AuditService::log(
+            pool.get_ref(),
+            church_id,
+            Some(user_id),
+            "repair_balance",
+            "bank_account",
+            check.bank_account_id,
+            Some(&serde_json::json!({ "current_balance": check.current_balance })),
+            Some(&serde_json::json!({ "current_balance": check.ledger_balance })),
+            ip_address.clone(),
+            user_agent.clone(),
+        )
+        .await
+        .ok();
+    }
 
-    Ok(HttpResponse::Created().json(ApiResponse::with_message(
-        campaign,
-        "Campanha criada com sucesso",
+    Ok(HttpResponse::Ok().json(ApiResponse::with_message(
+        result,
+        "Saldos conferidos com os lançamentos",
     )))
 }
 
-/// Update a campaign
+// ==========================================
+// PIX
+// ==========================================
+
+/// Generate a static PIX BR Code for a bank account
 #[utoipa::path(
-    put,
-    path = "/api/v1/financial/campaigns/{id}",
-    params(("id" = uuid::Uuid, Path, description = "Campaign ID")),
-    request_body = UpdateCampaignRequest,
+    post,
+    path = "/api/v1/financial/bank-accounts/{id}/pix-charges",
+    params(("id" = uuid::Uuid, Path, description = "Bank account ID")),
+    request_body = CreatePixChargeRequest,
     responses(
-        (status = 200, description = "Campaign updated"),
-        (status = 404, description = "Not found")
+        (status = 201, description = "PIX code generated"),
+        (status = 400, description = "Bank account without PIX key")
     ),
     security(("bearer_auth" = []))
 )]
-#[put("/api/v1/financial/campaigns/{id}")]
-pub async fn update_campaign(
+#[post("/api/v1/financial/bank-accounts/{id}/pix-charges")]
+pub async fn create_pix_charge(
     req: HttpRequest,
     pool: web::Data<PgPool>,
     config: web::Data<AppConfig>,
     path: web::Path<uuid::Uuid>,
-    body: web::Json<UpdateCampaignRequest>,
+    body: web::Json<CreatePixChargeRequest>,
 ) -> Result<HttpResponse, AppError> {
     let claims = middleware::auth_middleware(req, config).await?;
     middleware::require_permission(&claims, "financial:write")?;
     let church_id = middleware::get_church_id(&claims)?;
-    let campaign_id = path.into_inner();
+    let user_id = middleware::get_user_id(&claims)?;
+    let bank_account_id = path.into_inner();
 
     body.validate()
         .map_err(|e| AppError::validation(e.to_string()))?;
 
-    let campaign =
-        CampaignService::update(pool.get_ref(), church_id, campaign_id, &body).await?;
+    if !middleware::can_access_congregation(&claims, body.congregation_id) {
+        return Err(AppError::Forbidden(
+            "Sem permissão para gerar PIX neste escopo".into(),
+        ));
+    }
 
-    Ok(HttpResponse::Ok().json(ApiResponse::with_message(
-        campaign,
-        "Campanha atualizada com sucesso",
+    let charge =
+        PixService::create(pool.get_ref(), church_id, user_id, bank_account_id, &body).await?;
+
+    // Audit log
+    AuditService::log_action(
+        pool.get_ref(), church_id, Some(user_id), "create", "pix_charge", charge.id,
+    ).await.ok();
+
+    Ok(HttpResponse::Created().json(ApiResponse::with_message(
+        charge,
+        "QR Code PIX gerado com sucesso",
     )))
 }
 
-// ==========================================
-// Financial Entries
-// ==========================================
-
-/// List financial entries with filters
+/// List generated PIX codes
 #[utoipa::path(
     get,
-    path = "/api/v1/financial/entries",
+    path = "/api/v1/financial/pix-charges",
     params(
         ("page" = Option<i64>, Query, description = "Page number"),
         ("per_page" = Option<i64>, Query, description = "Items per page"),
-        ("search" = Option<String>, Query, description = "Search by description"),
-        ("type" = Option<String>, Query, description = "receita or despesa"),
-        ("status" = Option<String>, Query, description = "pendente, confirmado, cancelado"),
-        ("date_from" = Option<String>, Query, description = "Start date"),
-        ("date_to" = Option<String>, Query, description = "End date"),
+        ("bank_account_id" = Option<uuid::Uuid>, Query, description = "Codes of a bank account"),
+        ("campaign_id" = Option<uuid::Uuid>, Query, description = "Codes of a campaign"),
     ),
     responses(
-        (status = 200, description = "List of financial entries"),
+        (status = 200, description = "List of PIX codes"),
         (status = 401, description = "Not authenticated")
     ),
     security(("bearer_auth" = []))
 )]
-#[get("/api/v1/financial/entries")]
-pub async fn list_financial_entries(
+#[get("/api/v1/financial/pix-charges")]
+pub async fn list_pix_charges(
     req: HttpRequest,
     pool: web::Data<PgPool>,
     config: web::Data<AppConfig>,
     pagination: web::Query<PaginationParams>,
-    filter: web::Query<FinancialEntryFilter>,
+    filter: web::Query<PixChargeFilter>,
 ) -> Result<HttpResponse, AppError> {
     let claims = middleware::auth_middleware(req, config).await?;
     middleware::require_permission(&claims, "financial:read")?;
     let church_id = middleware::get_church_id(&claims)?;
 
-    let (entries, total) = FinancialEntryService::list(
+    let (charges, total) = PixService::list(
         pool.get_ref(),
         church_id,
         &filter,
-        &pagination.search,
         pagination.per_page(),
         pagination.offset(),
     )
     .await?;
 
     Ok(HttpResponse::Ok().json(ApiResponse::paginated(
-        entries,
+        charges,
         pagination.page(),
         pagination.per_page(),
         total,
     )))
 }
 
-/// Get financial entry by ID
+/// QR image of a PIX code, for bulletins and banners
 #[utoipa::path(
     get,
-    path = "/api/v1/financial/entries/{id}",
-    params(("id" = uuid::Uuid, Path, description = "Entry ID")),
+    path = "/api/v1/financial/pix-charges/{id}/qr",
+    params(
+        ("id" = uuid::Uuid, Path, description = "PIX code ID"),
+        ("format" = Option<String>, Query, description = "png (default) or svg"),
+        ("size" = Option<u32>, Query, description = "Minimum size in pixels (default 400)"),
+    ),
     responses(
-        (status = 200, description = "Financial entry details"),
+        (status = 200, description = "QR code image"),
         (status = 404, description = "Not found")
     ),
     security(("bearer_auth" = []))
 )]
-#[get("/api/v1/financial/entries/{id}")]
-pub async fn get_financial_entry(
+#[get("/api/v1/financial/pix-charges/{id}/qr")]
+pub async fn pix_charge_qr(
     req: HttpRequest,
     pool: web::Data<PgPool>,
     config: web::Data<AppConfig>,
     path: web::Path<uuid::Uuid>,
+    params: web::Query<PixQrParams>,
 ) -> Result<HttpResponse, AppError> {
     let claims = middleware::auth_middleware(req, config).await?;
     middleware::require_permission(&claims, "financial:read")?;
     let church_id = middleware::get_church_id(&claims)?;
-    let entry_id = path.into_inner();
 
-    let entry = FinancialEntryService::get_by_id(pool.get_ref(), church_id, entry_id).await?;
+    let format = params.format.as_deref().unwrap_or("png");
+    let content_type = match format {
+        "png" => "image/png",
+        "svg" => "image/svg+xml",
+        _ => return Err(AppError::validation("Formato inválido. Use png ou svg")),
+    };
 
-    Ok(HttpResponse::Ok().json(ApiResponse::ok(entry)))
+    let (charge, image) = PixService::qr_image(
+        pool.get_ref(),
+        church_id,
+        path.into_inner(),
+        format,
+        params.size.unwrap_or(400),
+    )
+    .await?;
+
+    Ok(HttpResponse::Ok()
+        .content_type(content_type)
+        .insert_header((
+            "Content-Disposition",
+            format!("attachment; filename=\"pix-{}.{format}\"", charge.txid),
+        ))
+        .body(image))
 }
 
-/// Create a new financial entry
+// ==========================================
+// Payment Providers
+// ==========================================
+
+/// Payment providers configured for the church (secrets are never returned)
 #[utoipa::path(
-    post,
-    path = "/api/v1/financial/entries",
-    request_body = CreateFinancialEntryRequest,
+    get,
+    path = "/api/v1/financial/payment-integrations",
     responses(
-        (status = 201, description = "Financial entry created"),
-        (status = 400, description = "Validation error")
+        (status = 200, description = "Configured payment providers"),
+        (status = 401, description = "Not authenticated")
     ),
     security(("bearer_auth" = []))
 )]
-#[post("/api/v1/financial/entries")]
-pub async fn create_financial_entry(
+#[get("/api/v1/financial/payment-integrations")]
+pub async fn list_payment_integrations(
     req: HttpRequest,
     pool: web::Data<PgPool>,
     config: web::Data<AppConfig>,
-    body: web::Json<CreateFinancialEntryRequest>,
 ) -> Result<HttpResponse, AppError> {
     let claims = middleware::auth_middleware(req, config).await?;
-    middleware::require_permission(&claims, "financial:write")?;
+    middleware::require_permission(&claims, "financial:close")?;
+    let church_id = middleware::get_church_id(&claims)?;
+
+    let integrations = PaymentWebhookService::list_integrations(pool.get_ref(), church_id).await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::ok(integrations)))
+}
+
+/// Configure the webhook secret of a payment provider
+#[utoipa::path(
+    put,
+    path = "/api/v1/financial/payment-integrations/{provider}",
+    params(("provider" = String, Path, description = "Provider name (pix, mock)")),
+    request_body = UpdatePaymentIntegrationRequest,
+    responses(
+        (status = 200, description = "Integration saved"),
+        (status = 403, description = "Forbidden")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[put("/api/v1/financial/payment-integrations/{provider}")]
+pub async fn update_payment_integration(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<String>,
+    body: web::Json<UpdatePaymentIntegrationRequest>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config.clone()).await?;
+    middleware::require_permission(&claims, "financial:close")?;
+    if !middleware::can_access_congregation(&claims, None) {
+        return Err(AppError::Forbidden(
+            "Sem permissão para configurar integrações de pagamento".into(),
+        ));
+    }
     let church_id = middleware::get_church_id(&claims)?;
     let user_id = middleware::get_user_id(&claims)?;
 
     body.validate()
         .map_err(|e| AppError::validation(e.to_string()))?;
 
-    let entry =
-        FinancialEntryService::create(pool.get_ref(), church_id, user_id, &body).await?;
+    let integration = PaymentWebhookService::save_integration(
+        pool.get_ref(),
+        church_id,
+        user_id,
+        &path.into_inner(),
+        config.payment_mock_provider,
+        &body,
+    )
+    .await?;
 
     // Audit log
     AuditService::log_action(
-        pool.get_ref(), church_id, Some(user_id), "create", "financial_entry", entry.id,
+        pool.get_ref(), church_id, Some(user_id), "update", "payment_integration", integration.id,
     ).await.ok();
 
-    Ok(HttpResponse::Created().json(ApiResponse::with_message(
-        entry,
-        "Lançamento criado com sucesso",
+    Ok(HttpResponse::Ok().json(ApiResponse::with_message(
+        integration,
+        "Integração de pagamento salva com sucesso",
     )))
 }
 
-/// Update a financial entry
+/// Payments received through provider webhooks (review queue: status=pendente_revisao)
 #[utoipa::path(
-    put,
-    path = "/api/v1/financial/entries/{id}",
-    params(("id" = uuid::Uuid, Path, description = "Entry ID")),
-    request_body = UpdateFinancialEntryRequest,
+    get,
+    path = "/api/v1/financial/payment-notifications",
+    params(
+        ("page" = Option<i64>, Query, description = "Page number"),
+        ("per_page" = Option<i64>, Query, description = "Items per page"),
+        ("status" = Option<String>, Query, description = "pendente_revisao, lancado or descartado"),
+        ("provider" = Option<String>, Query, description = "Provider name"),
+    ),
     responses(
-        (status = 200, description = "Financial entry updated"),
-        (status = 404, description = "Not found")
+        (status = 200, description = "List of received payments"),
+        (status = 401, description = "Not authenticated")
     ),
     security(("bearer_auth" = []))
 )]
-#[put("/api/v1/financial/entries/{id}")]
-pub async fn update_financial_entry(
+#[get("/api/v1/financial/payment-notifications")]
+pub async fn list_payment_notifications(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    pagination: web::Query<PaginationParams>,
+    filter: web::Query<PaymentNotificationFilter>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:read")?;
+    let church_id = middleware::get_church_id(&claims)?;
+
+    let (notifications, total) = PaymentWebhookService::list(
+        pool.get_ref(),
+        church_id,
+        &filter,
+        pagination.per_page(),
+        pagination.offset(),
+    )
+    .await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::paginated(
+        notifications,
+        pagination.page(),
+        pagination.per_page(),
+        total,
+    )))
+}
+
+/// Post a queued payment as a confirmed income entry
+#[utoipa::path(
+    post,
+    path = "/api/v1/financial/payment-notifications/{id}/entry",
+    params(("id" = uuid::Uuid, Path, description = "Received payment ID")),
+    request_body = ResolvePaymentNotificationRequest,
+    responses(
+        (status = 200, description = "Payment posted"),
+        (status = 409, description = "Payment already resolved")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[post("/api/v1/financial/payment-notifications/{id}/entry")]
+pub async fn resolve_payment_notification(
     req: HttpRequest,
     pool: web::Data<PgPool>,
     config: web::Data<AppConfig>,
+    cache: web::Data<CacheService>,
     path: web::Path<uuid::Uuid>,
-    body: web::Json<UpdateFinancialEntryRequest>,
+    body: web::Json<ResolvePaymentNotificationRequest>,
 ) -> Result<HttpResponse, AppError> {
     let claims = middleware::auth_middleware(req, config).await?;
     middleware::require_permission(&claims, "financial:write")?;
     let church_id = middleware::get_church_id(&claims)?;
-    let entry_id = path.into_inner();
+    let user_id = middleware::get_user_id(&claims)?;
+    let notification_id = path.into_inner();
 
     body.validate()
         .map_err(|e| AppError::validation(e.to_string()))?;
 
-    let entry =
-        FinancialEntryService::update(pool.get_ref(), church_id, entry_id, &body).await?;
+    if !middleware::can_access_congregation(&claims, body.congregation_id) {
+        return Err(AppError::Forbidden(
+            "Sem permissão para lançar neste escopo".into(),
+        ));
+    }
+
+    let notification = PaymentWebhookService::resolve(
+        pool.get_ref(),
+        church_id,
+        user_id,
+        notification_id,
+        &body,
+    )
+    .await?;
+
+    // Invalidate tither regularity cache
+    cache.del_pattern(&format!("tithes:*:{church_id}:*")).await;
 
     // Audit log
-    let user_id = middleware::get_user_id(&claims)?;
     AuditService::log_action(
-        pool.get_ref(), church_id, Some(user_id), "update", "financial_entry", entry_id,
+        pool.get_ref(), church_id, Some(user_id), "resolve", "payment_notification", notification_id,
     ).await.ok();
 
     Ok(HttpResponse::Ok().json(ApiResponse::with_message(
-        entry,
-        "Lançamento atualizado com sucesso",
+        notification,
+        "Pagamento lançado com sucesso",
     )))
 }
 
-/// Delete (cancel) a financial entry
+/// Discard a queued payment
 #[utoipa::path(
-    delete,
-    path = "/api/v1/financial/entries/{id}",
-    params(("id" = uuid::Uuid, Path, description = "Entry ID")),
+    post,
+    path = "/api/v1/financial/payment-notifications/{id}/discard",
+    params(("id" = uuid::Uuid, Path, description = "Received payment ID")),
+    request_body = DiscardPaymentNotificationRequest,
     responses(
-        (status = 200, description = "Financial entry deleted"),
-        (status = 404, description = "Not found")
+        (status = 200, description = "Payment discarded"),
+        (status = 409, description = "Payment already resolved")
     ),
     security(("bearer_auth" = []))
 )]
-#[delete("/api/v1/financial/entries/{id}")]
-pub async fn delete_financial_entry(
+#[post("/api/v1/financial/payment-notifications/{id}/discard")]
+pub async fn discard_payment_notification(
     req: HttpRequest,
     pool: web::Data<PgPool>,
     config: web::Data<AppConfig>,
     path: web::Path<uuid::Uuid>,
+    body: web::Json<DiscardPaymentNotificationRequest>,
 ) -> Result<HttpResponse, AppError> {
     let claims = middleware::auth_middleware(req, config).await?;
     middleware::require_permission(&claims, "financial:write")?;
     let church_id = middleware::get_church_id(&claims)?;
-    let entry_id = path.into_inner();
+    let user_id = middleware::get_user_id(&claims)?;
+    let notification_id = path.into_inner();
 
-    FinancialEntryService::delete(pool.get_ref(), church_id, entry_id).await?;
+    body.validate()
+        .map_err(|e| AppError::validation(e.to_string()))?;
+
+    let notification = PaymentWebhookService::discard(
+        pool.get_ref(),
+        church_id,
+        user_id,
+        notification_id,
+        &body.reason,
+    )
+    .await?;
 
     // Audit log
-    let user_id = middleware::get_user_id(&claims)?;
     AuditService::log_action(
-        pool.get_ref(), church_id, Some(user_id), "delete", "financial_entry", entry_id,
+        pool.get_ref(), church_id, Some(user_id), "discard", "payment_notification", notification_id,
     ).await.ok();
 
-    Ok(HttpResponse::Ok().json(ApiResponse::ok(serde_json::json!({
+    Ok(HttpResponse::Ok().json(ApiResponse::with_message(
+        notification,
+        "Pagamento descartado",
+    )))
+}
+
+// ==========================================
+// Campaigns
+// ==========================================
+
+#[derive(Debug, Deserialize)]
+pub struct CampaignFilter {
+    pub status: Option<String>,
+}
+
+/// List campaigns
+#[utoipa::path(
+    get,
+    path = "/api/v1/financial/campaigns",
+    params(
+        ("page" = Option<i64>, Query, description = "Page number"),
+        ("per_page" = Option<i64>, Query, description = "Items per page"),
+        ("search" = Option<String>, Query, description = "Search by name"),
+        ("status" = Option<String>, Query, description = "Filter by status"),
+    ),
+    responses(
+        (status = 200, description = "List of campaigns"),
+        (status = 401, description = "Not authenticated")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[get("/api/v1/financial/campaigns")]
+pub async fn list_campaigns(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    pagination: web::Query<PaginationParams>,
+    filter: web::Query<CampaignFilter>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:read")?;
+    let church_id = middleware::get_church_id(&claims)?;
+
+    let (campaigns, total) = CampaignService::list(
+        pool.get_ref(),
+        church_id,
+        filter.status.as_deref(),
+        &pagination.search,
+        pagination.per_page(),
+        pagination.offset(),
+    )
+    .await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::paginated(
+        campaigns,
+        pagination.page(),
+        pagination.per_page(),
+        total,
+    )))
+}
+
+/// Get campaign by ID
+#[utoipa::path(
+    get,
+    path = "/api/v1/financial/campaigns/{id}",
+    params(("id" = uuid::Uuid, Path, description = "Campaign ID")),
+    responses(
+        (status = 200, description = "Campaign details"),
+        (status = 404, description = "Not found")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[get("/api/v1/financial/campaigns/{id}")]
+pub async fn get_campaign(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<uuid::Uuid>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:read")?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let campaign_id = path.into_inner();
+
+    let campaign = CampaignService::get_by_id(pool.get_ref(), church_id, campaign_id).await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::ok(campaign)))
+}
+
+/// Create a new campaign
+#[utoipa::path(
+    post,
+    path = "/api/v1/financial/campaigns",
+    request_body = CreateCampaignRequest,
+    responses(
+        (status = 201, description = "Campaign created"),
+        (status = 400, description = "Validation error")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[post("/api/v1/financial/campaigns")]
+pub async fn create_campaign(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    body: web::Json<CreateCampaignRequest>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:write")?;
+    let church_id = middleware::get_church_id(&claims)?;
+
+    body.validate()
+        .map_err(|e| AppError::validation(e.to_string()))?;
+
+    let campaign = CampaignService::create(pool.get_ref(), church_id, &body).await?;
+
+    Ok(HttpResponse::Created().json(ApiResponse::with_message(
+        campaign,
+        "Campanha criada com sucesso",
+    )))
+}
+
+/// Update a campaign
+#[utoipa::path(
+    put,
+    path = "/api/v1/financial/campaigns/{id}",
+    params(("id" = uuid::Uuid, Path, description = "Campaign ID")),
+    request_body = UpdateCampaignRequest,
+    responses(
+        (status = 200, description = "Campaign updated"),
+        (status = 404, description = "Not found")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[put("/api/v1/financial/campaigns/{id}")]
+pub async fn update_campaign(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<uuid::Uuid>,
+    body: web::Json<UpdateCampaignRequest>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:write")?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let campaign_id = path.into_inner();
+
+    body.validate()
+        .map_err(|e| AppError::validation(e.to_string()))?;
+
+    let campaign =
+        CampaignService::update(pool.get_ref(), church_id, campaign_id, &body).await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::with_message(
+        campaign,
+        "Campanha atualizada com sucesso",
+    )))
+}
+
+/// List a campaign's member pledges with pledged vs paid progress
+#[utoipa::path(
+    get,
+    path = "/api/v1/financial/campaigns/{id}/pledges",
+    params(("id" = uuid::Uuid, Path, description = "Campaign ID")),
+    responses(
+        (status = 200, description = "Campaign pledges"),
+        (status = 404, description = "Not found")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[get("/api/v1/financial/campaigns/{id}/pledges")]
+pub async fn list_campaign_pledges(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<uuid::Uuid>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:read")?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let campaign_id = path.into_inner();
+
+    let pledges = CampaignService::list_pledges(pool.get_ref(), church_id, campaign_id).await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::ok(pledges)))
+}
+
+/// Register a member's pledge to a campaign
+#[utoipa::path(
+    post,
+    path = "/api/v1/financial/campaigns/{id}/pledges",
+    params(("id" = uuid::Uuid, Path, description = "Campaign ID")),
+    request_body = CreateCampaignPledgeRequest,
+    responses(
+        (status = 201, description = "Pledge created"),
+        (status = 400, description = "Validation error or campaign closed"),
+        (status = 409, description = "Member already pledged to this campaign")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[post("/api/v1/financial/campaigns/{id}/pledges")]
+pub async fn create_campaign_pledge(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<uuid::Uuid>,
+    body: web::Json<CreateCampaignPledgeRequest>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:write")?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let user_id = middleware::get_user_id(&claims)?;
+    let campaign_id = path.into_inner();
+
+    body.validate()
+        .map_err(|e| AppError::validation(e.to_string()))?;
+
+    let pledge =
+        CampaignService::create_pledge(pool.get_ref(), church_id, campaign_id, user_id, &body)
+            .await?;
+
+    // Audit log
+    AuditService::log_action(
+        pool.get_ref(), church_id, Some(user_id), "create", "campaign_pledge", pledge.id,
+    ).await.ok();
+
+    Ok(HttpResponse::Created().json(ApiResponse::with_message(
+        pledge,
+        "Promessa registrada com sucesso",
+    )))
+}
+
+/// Update a campaign pledge
+#[utoipa::path(
+    put,
+    path = "/api/v1/financial/campaigns/{id}/pledges/{pledge_id}",
+    params(
+        ("id" = uuid::Uuid, Path, description = "Campaign ID"),
+        ("pledge_id" = uuid::Uuid, Path, description = "Pledge ID"),
+    ),
+    request_body = UpdateCampaignPledgeRequest,
+    responses(
+        (status = 200, description = "Pledge updated"),
+        (status = 404, description = "Not found")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[put("/api/v1/financial/campaigns/{id}/pledges/{pledge_id}")]
+pub async fn update_campaign_pledge(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<(uuid::Uuid, uuid::Uuid)>,
+    body: web::Json<UpdateCampaignPledgeRequest>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:write")?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let (campaign_id, pledge_id) = path.into_inner();
+
+    body.validate()
+        .map_err(|e| AppError::validation(e.to_string()))?;
+
+    let pledge = CampaignService::update_pledge(
+        pool.get_ref(), church_id, campaign_id, pledge_id, &body,
+    )
+    .await?;
+
+    // Audit log
+    let user_id = middleware::get_user_id(&claims)?;
+    AuditService::log_action(
+        pool.get_ref(), church_id, Some(user_id), "update", "campaign_pledge", pledge_id,
+    ).await.ok();
+
+    Ok(HttpResponse::Ok().json(ApiResponse::with_message(
+        pledge,
+        "Promessa atualizada com sucesso",
+    )))
+}
+
+/// Delete a campaign pledge (soft delete)
+#[utoipa::path(
+    delete,
+    path = "/api/v1/financial/campaigns/{id}/pledges/{pledge_id}",
+    params(
+        ("id" = uuid::Uuid, Path, description = "Campaign ID"),
+        ("pledge_id" = uuid::Uuid, Path, description = "Pledge ID"),
+    ),
+    responses(
+        (status = 200, description = "Pledge deleted"),
+        (status = 404, description = "Not found")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[delete("/api/v1/financial/campaigns/{id}/pledges/{pledge_id}")]
+pub async fn delete_campaign_pledge(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<(uuid::Uuid, uuid::Uuid)>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:write")?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let (campaign_id, pledge_id) = path.into_inner();
+
+    CampaignService::delete_pledge(pool.get_ref(), church_id, campaign_id, pledge_id).await?;
+
+    // Audit log
+    let user_id = middleware::get_user_id(&claims)?;
+    AuditService::log_action(
+        pool.get_ref(), church_id, Some(user_id), "delete", "campaign_pledge", pledge_id,
+    ).await.ok();
+
+    Ok(HttpResponse::Ok().json(ApiResponse::ok(serde_json::json!({
+        "message": "Promessa excluída com sucesso"
+    }))))
+}
+
+/// Campaign report: goal, pledged, raised and percentages, with each pledge's progress
+#[utoipa::path(
+    get,
+    path = "/api/v1/financial/campaigns/{id}/report",
+    params(("id" = uuid::Uuid, Path, description = "Campaign ID")),
+    responses(
+        (status = 200, description = "Campaign report"),
+        (status = 404, description = "Not found")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[get("/api/v1/financial/campaigns/{id}/report")]
+pub async fn campaign_report(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<uuid::Uuid>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:read")?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let campaign_id = path.into_inner();
+
+    let report = CampaignService::report(pool.get_ref(), church_id, campaign_id).await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::ok(report)))
+}
+
+// ==========================================
+// Financial Entries
+// ==========================================
+
+/// List financial entries with filters
+#[utoipa::path(
+    get,
+    path = "/api/v1/financial/entries",
+    params(
+        ("page" = Option<i64>, Query, description = "Page number"),
+        ("per_page" = Option<i64>, Query, description = "Items per page"),
+        ("search" = Option<String>, Query, description = "Search by description"),
+        ("type" = Option<String>, Query, description = "receita or despesa"),
+        ("status" = Option<String>, Query, description = "pendente, confirmado, cancelado"),
+        ("date_from" = Option<String>, Query, description = "Start date"),
+        ("date_to" = Option<String>, Query, description = "End date"),
+    ),
+    responses(
+        (status = 200, description = "List of financial entries"),
+        (status = 401, description = "Not authenticated")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[get("/api/v1/financial/entries")]
+pub async fn list_financial_entries(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    pagination: web::Query<PaginationParams>,
+    filter: web::Query<FinancialEntryFilter>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:read")?;
+    let church_id = middleware::get_church_id(&claims)?;
+
+    let (entries, total) = FinancialEntryService::list(
+        pool.get_ref(),
+        church_id,
+        &filter,
+        &pagination.search,
+        pagination.per_page(),
+        pagination.offset(),
+    )
+    .await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::paginated(
+        entries,
+        pagination.page(),
+        pagination.per_page(),
+        total,
+    )))
+}
+
+/// Get financial entry by ID
+#[utoipa::path(
+    get,
+    path = "/api/v1/financial/entries/{id}",
+    params(("id" = uuid::Uuid, Path, description = "Entry ID")),
+    responses(
+        (status = 200, description = "Financial entry details"),
+        (status = 404, description = "Not found")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[get("/api/v1/financial/entries/{id}")]
+pub async fn get_financial_entry(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<uuid::Uuid>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:read")?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let entry_id = path.into_inner();
+
+    let entry = FinancialEntryService::get_by_id(pool.get_ref(), church_id, entry_id).await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::ok(entry)))
+}
+
+/// Allocation lines of a split entry (empty for single-line entries)
+#[utoipa::path(
+    get,
+    path = "/api/v1/financial/entries/{id}/allocations",
+    params(("id" = uuid::Uuid, Path, description = "Entry ID")),
+    responses(
+        (status = 200, description = "Category, campaign, member and amount of each line"),
+        (status = 404, description = "Not found")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[get("/api/v1/financial/entries/{id}/allocations")]
+pub async fn list_entry_allocations(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<uuid::Uuid>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:read")?;
+    let church_id = middleware::get_church_id(&claims)?;
+
+    let allocations =
+        FinancialEntryService::allocations(pool.get_ref(), church_id, path.into_inner()).await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::ok(allocations)))
+}
+
+/// Create a new financial entry
+#[utoipa::path(
+    post,
+    path = "/api/v1/financial/entries",
+    request_body = CreateFinancialEntryRequest,
+    responses(
+        (status = 201, description = "Financial entry created"),
+        (status = 400, description = "Validation error")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[post("/api/v1/financial/entries")]
+pub async fn create_financial_entry(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    cache: web::Data<CacheService>,
+    body: web::Json<CreateFinancialEntryRequest>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:write")?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let user_id = middleware::get_user_id(&claims)?;
+
+    body.validate()
+        .map_err(|e| AppError::validation(e.to_string()))?;
+
+    let entry =
+        FinancialEntryService::create(pool.get_ref(), church_id, user_id, &body).await?;
+
+    // Invalidate tither regularity cache
+    cache.del_pattern(&format!("tithes:*:{church_id}:*")).await;
+
+    // Audit log
+    AuditService::log_action(
+        pool.get_ref(), church_id, Some(user_id), "create", "financial_entry", entry.id,
+    ).await.ok();
+
+    let message = if entry.status == "aguardando_aprovacao" {
+        "Despesa registrada; aguardando aprovação"
+    } else {
+        "Lançamento criado com sucesso"
+    };
+
+    Ok(HttpResponse::Created().json(ApiResponse::with_message(entry, message)))
+}
+
+/// Update a financial entry
+#[utoipa::path(
+    put,
+    path = "/api/v1/financial/entries/{id}",
+    params(("id" = uuid::Uuid, Path, description = "Entry ID")),
+    request_body = UpdateFinancialEntryRequest,
+    responses(
+        (status = 200, description = "Financial entry updated"),
+        (status = 404, description = "Not found")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[put("/api/v1/financial/entries/{id}")]
+pub async fn update_financial_entry(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    cache: web::Data<CacheService>,
+    path: web::Path<uuid::Uuid>,
+    body: web::Json<UpdateFinancialEntryRequest>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:write")?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let entry_id = path.into_inner();
+
+    body.validate()
+        .map_err(|e| AppError::validation(e.to_string()))?;
+
+    let entry =
+        FinancialEntryService::update(pool.get_ref(), church_id, entry_id, &body).await?;
+
+    // Invalidate tither regularity cache
+    cache.del_pattern(&format!("tithes:*:{church_id}:*")).await;
+
+    // Audit log
+    let user_id = middleware::get_user_id(&claims)?;
+    AuditService::log_action(
+        pool.get_ref(), church_id, Some(user_id), "update", "financial_entry", entry_id,
+    ).await.ok();
+
+    Ok(HttpResponse::Ok().json(ApiResponse::with_message(
+        entry,
+        "Lançamento atualizado com sucesso",
+    )))
+}
+
+/// Delete (cancel) a financial entry
+#[utoipa::path(
+    delete,
+    path = "/api/v1/financial/entries/{id}",
+    params(("id" = uuid::Uuid, Path, description = "Entry ID")),
+    responses(
+        (status = 200, description = "Financial entry deleted"),
+        (status = 404, description = "Not found")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[delete("/api/v1/financial/entries/{id}")]
+pub async fn delete_financial_entry(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    cache: web::Data<CacheService>,
+    path: web::Path<uuid::Uuid>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:write")?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let entry_id = path.into_inner();
+
+    FinancialEntryService::delete(pool.get_ref(), church_id, entry_id).await?;
+
+    // Invalidate tither regularity cache
+    cache.del_pattern(&format!("tithes:*:{church_id}:*")).await;
+
+    // Audit log
+    let user_id = middleware::get_user_id(&claims)?;
+    AuditService::log_action(
+        pool.get_ref(), church_id, Some(user_id), "delete", "financial_entry", entry_id,
+    ).await.ok();
+
+    Ok(HttpResponse::Ok().json(ApiResponse::ok(serde_json::json!({
         "message": "Lançamento removido com sucesso"
     }))))
 }
 
+/// Reverse (estornar) a financial entry with a compensating entry
+#[utoipa::path(
+    post,
+    path = "/api/v1/financial/entries/{id}/reverse",
+    params(("id" = uuid::Uuid, Path, description = "Entry ID")),
+    request_body = ReverseFinancialEntryRequest,
+    responses(
+        (status = 201, description = "Entry reversed, compensating entry created"),
+        (status = 400, description = "Entry cannot be reversed"),
+        (status = 404, description = "Not found"),
+        (status = 409, description = "Entry already reversed")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[post("/api/v1/financial/entries/{id}/reverse")]
+pub async fn reverse_financial_entry(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    cache: web::Data<CacheService>,
+    path: web::Path<uuid::Uuid>,
+    body: web::Json<ReverseFinancialEntryRequest>,
+) -> Result<HttpResponse, AppError> {
+    let ip_address = req.peer_addr().map(|a| a.ip().to_string());
+    let user_agent = req
+        .headers()
+        .get("user-agent")
+        .and_then(|v| v.to_str().ok())
+        .map(String::from);
+
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:write")?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let user_id = middleware::get_user_id(&claims)?;
+    let entry_id = path.into_inner();
+
+    body.validate()
+        .map_err(|e| AppError::validation(e.to_string()))?;
+
+    let result =
+        FinancialEntryService::reverse(pool.get_ref(), church_id, user_id, entry_id, &body).await?;
+
+    // Invalidate tither regularity cache
+    cache.del_pattern(&format!("tithes:*:{church_id}:*")).await;
+
+    // Audit log
+    AuditService::log(
+        pool.get_ref(),
+        church_id,
+        Some(user_id),
+        "reverse",
+        "financial_entry",
+        entry_id,
+        Some(&serde_json::json!({ "status": "confirmado" })),
+        Some(&serde_json::json!({
+            "status": "estornado",
+            "reversal_entry_id": result.reversal.id,
+            "reason": body.reason,
+        })),
+        ip_address,
+        user_agent,
+    )
+    .await
+    .ok();
+
+    Ok(HttpResponse::Created().json(ApiResponse::with_message(
+        result,
+        "Lançamento estornado com sucesso",
+    )))
+}
+
+/// Pay a pending expense: sets the payment date, confirms it and updates the bank balance
+#[utoipa::path(
+    post,
+    path = "/api/v1/financial/entries/{id}/pay",
+    params(("id" = uuid::Uuid, Path, description = "Entry ID")),
+    request_body = PayFinancialEntryRequest,
+    responses(
+        (status = 200, description = "Expense paid"),
+        (status = 400, description = "Not an expense or month closed"),
+        (status = 404, description = "Not found"),
+        (status = 409, description = "Entry is not pending")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[post("/api/v1/financial/entries/{id}/pay")]
+pub async fn pay_financial_entry(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<uuid::Uuid>,
+    body: web::Json<PayFinancialEntryRequest>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:write")?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let user_id = middleware::get_user_id(&claims)?;
+    let entry_id = path.into_inner();
+
+    body.validate()
+        .map_err(|e| AppError::validation(e.to_string()))?;
+
+    let entry = FinancialEntryService::pay(pool.get_ref(), church_id, entry_id, &body).await?;
+
+    // Audit log
+    AuditService::log_action(
+        pool.get_ref(), church_id, Some(user_id), "pay", "financial_entry", entry_id,
+    ).await.ok();
+
+    let message = if entry.status == "aguardando_aprovacao" {
+        "Pagamento registrado; aguardando aprovação"
+    } else {
+        "Despesa paga com sucesso"
+    };
+
+    Ok(HttpResponse::Ok().json(ApiResponse::with_message(entry, message)))
+}
+
+/// Accounts payable: pending expenses overdue, due in 7 days and due in 30 days
+#[utoipa::path(
+    get,
+    path = "/api/v1/financial/payables",
+    params(
+        ("congregation_id" = Option<uuid::Uuid>, Query, description = "Expenses of a congregation"),
+    ),
+    responses(
+        (status = 200, description = "Pending expenses by aging bucket"),
+        (status = 401, description = "Not authenticated")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[get("/api/v1/financial/payables")]
+pub async fn accounts_payable(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    filter: web::Query<AccountsPayableFilter>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:read")?;
+    let church_id = middleware::get_church_id(&claims)?;
+
+    let mut congregation_filter = filter.congregation_id;
+
+    // Enforce congregation scope: if user has restricted access, use their first allowed congregation
+    if let Some(allowed) = middleware::get_allowed_congregations(&claims) {
+        match congregation_filter {
+            Some(cid) if !allowed.contains(&cid) => {
+                return Err(AppError::Forbidden(
+                    "Sem permissão para ver contas a pagar desta congregação".into(),
+                ));
+            }
+            None if !allowed.is_empty() => {
+                congregation_filter = Some(allowed[0]);
+            }
+            None => {
+                return Err(AppError::Forbidden(
+                    "Sem permissão para ver contas a pagar".into(),
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    let today = chrono::Local::now().date_naive();
+    let report =
+        PayablesService::aging(pool.get_ref(), church_id, congregation_filter, today).await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::ok(report)))
+}
+
+// ==========================================
+// Transfers
+// ==========================================
+
+/// List transfers between bank accounts
+#[utoipa::path(
+    get,
+    path = "/api/v1/financial/transfers",
+    params(
+        ("page" = Option<i64>, Query, description = "Page number"),
+        ("per_page" = Option<i64>, Query, description = "Items per page"),
+        ("bank_account_id" = Option<uuid::Uuid>, Query, description = "Source or destination account"),
+        ("date_from" = Option<String>, Query, description = "Start date"),
+        ("date_to" = Option<String>, Query, description = "End date"),
+    ),
+    responses(
+        (status = 200, description = "List of transfers"),
+        (status = 401, description = "Not authenticated")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[get("/api/v1/financial/transfers")]
+pub async fn list_transfers(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    pagination: web::Query<PaginationParams>,
+    filter: web::Query<TransferFilter>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:read")?;
+    let church_id = middleware::get_church_id(&claims)?;
+
+    let (transfers, total) = TransferService::list(
+        pool.get_ref(),
+        church_id,
+        &filter,
+        pagination.per_page(),
+        pagination.offset(),
+    )
+    .await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::paginated(
+        transfers,
+        pagination.page(),
+        pagination.per_page(),
+        total,
+    )))
+}
+
+/// Get a transfer with both legs
+#[utoipa::path(
+    get,
+    path = "/api/v1/financial/transfers/{id}",
+    params(("id" = uuid::Uuid, Path, description = "Transfer ID")),
+    responses(
+        (status = 200, description = "Transfer details"),
+        (status = 404, description = "Not found")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[get("/api/v1/financial/transfers/{id}")]
+pub async fn get_transfer(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<uuid::Uuid>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:read")?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let transfer_id = path.into_inner();
+
+    let transfer = TransferService::get_by_id(pool.get_ref(), church_id, transfer_id).await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::ok(transfer)))
+}
+
+/// Transfer an amount between two bank accounts
+#[utoipa::path(
+    post,
+    path = "/api/v1/financial/transfers",
+    request_body = CreateTransferRequest,
+    responses(
+        (status = 201, description = "Transfer created"),
+        (status = 400, description = "Validation error")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[post("/api/v1/financial/transfers")]
+pub async fn create_transfer(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    body: web::Json<CreateTransferRequest>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:write")?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let user_id = middleware::get_user_id(&claims)?;
+
+    body.validate()
+        .map_err(|e| AppError::validation(e.to_string()))?;
+
+    let transfer = TransferService::create(pool.get_ref(), church_id, user_id, &body).await?;
+
+    // Audit log
+    AuditService::log_action(
+        pool.get_ref(), church_id, Some(user_id), "create", "financial_transfer", transfer.transfer_id,
+    ).await.ok();
+
+    Ok(HttpResponse::Created().json(ApiResponse::with_message(
+        transfer,
+        "Transferência realizada com sucesso",
+    )))
+}
+
+/// Cancel a transfer (both legs)
+#[utoipa::path(
+    delete,
+    path = "/api/v1/financial/transfers/{id}",
+    params(("id" = uuid::Uuid, Path, description = "Transfer ID")),
+    responses(
+        (status = 200, description = "Transfer cancelled"),
+        (status = 404, description = "Not found")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[delete("/api/v1/financial/transfers/{id}")]
+pub async fn cancel_transfer(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<uuid::Uuid>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:write")?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let transfer_id = path.into_inner();
+
+    TransferService::cancel(pool.get_ref(), church_id, transfer_id).await?;
+
+    // Audit log
+    let user_id = middleware::get_user_id(&claims)?;
+    AuditService::log_action(
+        pool.get_ref(), church_id, Some(user_id), "delete", "financial_transfer", transfer_id,
+    ).await.ok();
+
+    Ok(HttpResponse::Ok().json(ApiResponse::ok(serde_json::json!({
+        "message": "Transferência cancelada com sucesso"
+    }))))
+}
+
+// ==========================================
+// Recurring Entries
+// ==========================================
+
+/// List recurrence templates
+#[utoipa::path(
+    get,
+    path = "/api/v1/financial/recurrences",
+    params(
+        ("page" = Option<i64>, Query, description = "Page number"),
+        ("per_page" = Option<i64>, Query, description = "Items per page"),
+        ("type" = Option<String>, Query, description = "receita or despesa"),
+        ("is_active" = Option<bool>, Query, description = "Filter by active status"),
+        ("congregation_id" = Option<uuid::Uuid>, Query, description = "Filter by congregation"),
+    ),
+    responses(
+        (status = 200, description = "List of recurrences"),
+        (status = 401, description = "Not authenticated")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[get("/api/v1/financial/recurrences")]
+pub async fn list_recurrences(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    pagination: web::Query<PaginationParams>,
+    filter: web::Query<RecurrenceFilter>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:read")?;
+    let church_id = middleware::get_church_id(&claims)?;
+
+    let (recurrences, total) = RecurrenceService::list(
+        pool.get_ref(),
+        church_id,
+        &filter,
+        pagination.per_page(),
+        pagination.offset(),
+    )
+    .await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::paginated(
+        recurrences,
+        pagination.page(),
+        pagination.per_page(),
+        total,
+    )))
+}
+
+/// Get a recurrence template
+#[utoipa::path(
+    get,
+    path = "/api/v1/financial/recurrences/{id}",
+    params(("id" = uuid::Uuid, Path, description = "Recurrence ID")),
+    responses(
+        (status = 200, description = "Recurrence details"),
+        (status = 404, description = "Not found")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[get("/api/v1/financial/recurrences/{id}")]
+pub async fn get_recurrence(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<uuid::Uuid>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:read")?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let recurrence_id = path.into_inner();
+
+    let recurrence = RecurrenceService::get_by_id(pool.get_ref(), church_id, recurrence_id).await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::ok(recurrence)))
+}
+
+/// Create a recurrence template (generates the occurrences already within the lead time)
+#[utoipa::path(
+    post,
+    path = "/api/v1/financial/recurrences",
+    request_body = CreateRecurrenceRequest,
+    responses(
+        (status = 201, description = "Recurrence created"),
+        (status = 400, description = "Validation error")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[post("/api/v1/financial/recurrences")]
+pub async fn create_recurrence(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    body: web::Json<CreateRecurrenceRequest>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:write")?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let user_id = middleware::get_user_id(&claims)?;
+
+    body.validate()
+        .map_err(|e| AppError::validation(e.to_string()))?;
+
+    let recurrence = RecurrenceService::create(pool.get_ref(), church_id, user_id, &body).await?;
+
+    // Audit log
+    AuditService::log_action(
+        pool.get_ref(), church_id, Some(user_id), "create", "financial_recurrence", recurrence.id,
+    ).await.ok();
+
+    Ok(HttpResponse::Created().json(ApiResponse::with_message(
+        recurrence,
+        "Recorrência criada com sucesso",
+    )))
+}
+
+/// Update a recurrence template and its pending occurrences from `apply_from` on
+#[utoipa::path(
+    put,
+    path = "/api/v1/financial/recurrences/{id}",
+    params(("id" = uuid::Uuid, Path, description = "Recurrence ID")),
+    request_body = UpdateRecurrenceRequest,
+    responses(
+        (status = 200, description = "Recurrence updated"),
+        (status = 404, description = "Not found")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[put("/api/v1/financial/recurrences/{id}")]
+pub async fn update_recurrence(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<uuid::Uuid>,
+    body: web::Json<UpdateRecurrenceRequest>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:write")?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let recurrence_id = path.into_inner();
+
+    body.validate()
+        .map_err(|e| AppError::validation(e.to_string()))?;
+
+    let recurrence =
+        RecurrenceService::update(pool.get_ref(), church_id, recurrence_id, &body).await?;
+
+    // Audit log
+    let user_id = middleware::get_user_id(&claims)?;
+    AuditService::log_action(
+        pool.get_ref(), church_id, Some(user_id), "update", "financial_recurrence", recurrence_id,
+    ).await.ok();
+
+    Ok(HttpResponse::Ok().json(ApiResponse::with_message(
+        recurrence,
+        "Recorrência atualizada com sucesso",
+    )))
+}
+
+/// Cancel a recurrence from a date on (pending occurrences are cancelled)
+#[utoipa::path(
+    delete,
+    path = "/api/v1/financial/recurrences/{id}",
+    params(
+        ("id" = uuid::Uuid, Path, description = "Recurrence ID"),
+        ("from" = Option<String>, Query, description = "First due date cancelled (default: today)"),
+    ),
+    responses(
+        (status = 200, description = "Recurrence cancelled"),
+        (status = 404, description = "Not found")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[delete("/api/v1/financial/recurrences/{id}")]
+pub async fn cancel_recurrence(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<uuid::Uuid>,
+    params: web::Query<CancelRecurrenceParams>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:write")?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let recurrence_id = path.into_inner();
+
+    let cancelled =
+        RecurrenceService::cancel(pool.get_ref(), church_id, recurrence_id, params.from).await?;
+
+    // Audit log
+    let user_id = middleware::get_user_id(&claims)?;
+    AuditService::log_action(
+        pool.get_ref(), church_id, Some(user_id), "delete", "financial_recurrence", recurrence_id,
+    ).await.ok();
+
+    Ok(HttpResponse::Ok().json(ApiResponse::ok(serde_json::json!({
+        "message": "Recorrência cancelada com sucesso",
+        "cancelled_entries": cancelled
+    }))))
+}
+
+/// Generate the church's due occurrences now (the scheduler also runs hourly)
+#[utoipa::path(
+    post,
+    path = "/api/v1/financial/recurrences/generate",
+    responses(
+        (status = 200, description = "Occurrences generated"),
+        (status = 401, description = "Not authenticated")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[post("/api/v1/financial/recurrences/generate")]
+pub async fn generate_recurrences(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:write")?;
+    let church_id = middleware::get_church_id(&claims)?;
+
+    let generated = RecurrenceService::generate_due(pool.get_ref(), Some(church_id)).await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::ok(serde_json::json!({
+        "generated_entries": generated
+    }))))
+}
+
+// ==========================================
+// Installment Plans
+// ==========================================
+
+/// List installment plans with their payment progress
+#[utoipa::path(
+    get,
+    path = "/api/v1/financial/installment-plans",
+    params(
+        ("page" = Option<i64>, Query, description = "Page number"),
+        ("per_page" = Option<i64>, Query, description = "Items per page"),
+        ("search" = Option<String>, Query, description = "Search by description"),
+        ("supplier_id" = Option<uuid::Uuid>, Query, description = "Filter by supplier"),
+        ("congregation_id" = Option<uuid::Uuid>, Query, description = "Filter by congregation"),
+        ("open" = Option<bool>, Query, description = "With (true) or without (false) open installments"),
+    ),
+    responses(
+        (status = 200, description = "List of installment plans"),
+        (status = 401, description = "Not authenticated")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[get("/api/v1/financial/installment-plans")]
+pub async fn list_installment_plans(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    pagination: web::Query<PaginationParams>,
+    filter: web::Query<InstallmentPlanFilter>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:read")?;
+    let church_id = middleware::get_church_id(&claims)?;
+
+    let (plans, total) = InstallmentService::list(
+        pool.get_ref(),
+        church_id,
+        &filter,
+        &pagination.search,
+        pagination.per_page(),
+        pagination.offset(),
+    )
+    .await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::paginated(
+        plans,
+        pagination.page(),
+        pagination.per_page(),
+        total,
+    )))
+}
+
+/// Get an installment plan with its installments
+#[utoipa::path(
+    get,
+    path = "/api/v1/financial/installment-plans/{id}",
+    params(("id" = uuid::Uuid, Path, description = "Installment plan ID")),
+    responses(
+        (status = 200, description = "Installment plan details"),
+        (status = 404, description = "Not found")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[get("/api/v1/financial/installment-plans/{id}")]
+pub async fn get_installment_plan(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<uuid::Uuid>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:read")?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let plan_id = path.into_inner();
+
+    let plan = InstallmentService::get_by_id(pool.get_ref(), church_id, plan_id).await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::ok(plan)))
+}
+
+/// Create an installment plan (generates one pending expense per installment)
+#[utoipa::path(
+    post,
+    path = "/api/v1/financial/installment-plans",
+    request_body = CreateInstallmentPlanRequest,
+    responses(
+        (status = 201, description = "Installment plan created"),
+        (status = 400, description = "Validation error")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[post("/api/v1/financial/installment-plans")]
+pub async fn create_installment_plan(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    body: web::Json<CreateInstallmentPlanRequest>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:write")?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let user_id = middleware::get_user_id(&claims)?;
+
+    body.validate()
+        .map_err(|e| AppError::validation(e.to_string()))?;
+
+    let plan = InstallmentService::create(pool.get_ref(), church_id, user_id, &body).await?;
+
+    // Audit log
+    AuditService::log_action(
+        pool.get_ref(), church_id, Some(user_id), "create", "installment_plan", plan.plan.id,
+    ).await.ok();
+
+    Ok(HttpResponse::Created().json(ApiResponse::with_message(
+        plan,
+        "Parcelamento criado com sucesso",
+    )))
+}
+
+/// Renegotiate the open installments of a plan (paid installments are kept)
+#[utoipa::path(
+    post,
+    path = "/api/v1/financial/installment-plans/{id}/renegotiate",
+    params(("id" = uuid::Uuid, Path, description = "Installment plan ID")),
+    request_body = RenegotiateInstallmentsRequest,
+    responses(
+        (status = 200, description = "Installments renegotiated"),
+        (status = 400, description = "No open installments or validation error"),
+        (status = 404, description = "Not found")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[post("/api/v1/financial/installment-plans/{id}/renegotiate")]
+pub async fn renegotiate_installment_plan(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<uuid::Uuid>,
+    body: web::Json<RenegotiateInstallmentsRequest>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:write")?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let user_id = middleware::get_user_id(&claims)?;
+    let plan_id = path.into_inner();
+
+    body.validate()
+        .map_err(|e| AppError::validation(e.to_string()))?;
+
+    let plan =
+        InstallmentService::renegotiate(pool.get_ref(), church_id, user_id, plan_id, &body).await?;
+
+    // Audit log
+    AuditService::log_action(
+        pool.get_ref(), church_id, Some(user_id), "renegotiate", "installment_plan", plan_id,
+    ).await.ok();
+
+    Ok(HttpResponse::Ok().json(ApiResponse::with_message(
+        plan,
+        "Parcelas renegociadas com sucesso",
+    )))
+}
+
+// ==========================================
+// Offering Counts
+// ==========================================
+
+/// List offering counting sheets
+#[utoipa::path(
+    get,
+    path = "/api/v1/financial/offering-counts",
+    params(
+        ("page" = Option<i64>, Query, description = "Page number"),
+        ("per_page" = Option<i64>, Query, description = "Items per page"),
+        ("congregation_id" = Option<uuid::Uuid>, Query, description = "Filter by congregation"),
+        ("status" = Option<String>, Query, description = "em_contagem or confirmada"),
+        ("date_from" = Option<String>, Query, description = "Service date from (YYYY-MM-DD)"),
+        ("date_to" = Option<String>, Query, description = "Service date to (YYYY-MM-DD)"),
+    ),
+    responses(
+        (status = 200, description = "List of counting sheets"),
+        (status = 401, description = "Not authenticated")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[get("/api/v1/financial/offering-counts")]
+pub async fn list_offering_counts(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    pagination: web::Query<PaginationParams>,
+    filter: web::Query<OfferingCountFilter>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    // Counters (deacons) work on the sheets without the rest of the financial module
+    middleware::require_permission(&claims, "financial:read")
+        .or_else(|_| middleware::require_permission(&claims, "financial:count"))?;
+    let church_id = middleware::get_church_id(&claims)?;
+
+    let mut filter = filter.into_inner();
+
+    // Enforce congregation scope: if user has restricted access, use their first allowed congregation
+    if let Some(allowed) = middleware::get_allowed_congregations(&claims) {
+        match filter.congregation_id {
+            Some(cid) if !allowed.contains(&cid) => {
+                return Err(AppError::Forbidden(
+                    "Sem permissão para ver as contagens desta congregação".into(),
+                ));
+            }
+            None if !allowed.is_empty() => {
+                filter.congregation_id = Some(allowed[0]);
+            }
+            None => {
+                return Err(AppError::Forbidden(
+                    "Sem permissão para ver as contagens de ofertas".into(),
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    let (counts, total) = OfferingCountService::list(
+        pool.get_ref(),
+        church_id,
+        &filter,
+        pagination.per_page(),
+        pagination.offset(),
+    )
+    .await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::paginated(
+        counts,
+        pagination.page(),
+        pagination.per_page(),
+        total,
+    )))
+}
+
+/// Get a counting sheet with bills and coins, envelopes and generated entries
+#[utoipa::path(
+    get,
+    path = "/api/v1/financial/offering-counts/{id}",
+    params(("id" = uuid::Uuid, Path, description = "Counting sheet ID")),
+    responses(
+        (status = 200, description = "Counting sheet details"),
+        (status = 404, description = "Not found")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[get("/api/v1/financial/offering-counts/{id}")]
+pub async fn get_offering_count(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<uuid::Uuid>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:read")
+        .or_else(|_| middleware::require_permission(&claims, "financial:count"))?;
+    let church_id = middleware::get_church_id(&claims)?;
+
+    let count = OfferingCountService::get_by_id(pool.get_ref(), church_id, path.into_inner()).await?;
+
+    if !middleware::can_access_congregation(&claims, count.count.congregation_id) {
+        return Err(AppError::Forbidden(
+            "Sem permissão para ver esta contagem".into(),
+        ));
+    }
+
+    Ok(HttpResponse::Ok().json(ApiResponse::ok(count)))
+}
+
+/// Open a counting sheet for a service
+#[utoipa::path(
+    post,
+    path = "/api/v1/financial/offering-counts",
+    request_body = CreateOfferingCountRequest,
+    responses(
+        (status = 201, description = "Counting sheet created"),
+        (status = 400, description = "Validation error")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[post("/api/v1/financial/offering-counts")]
+pub async fn create_offering_count(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    body: web::Json<CreateOfferingCountRequest>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:write")
+        .or_else(|_| middleware::require_permission(&claims, "financial:count"))?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let user_id = middleware::get_user_id(&claims)?;
+
+    body.validate()
+        .map_err(|e| AppError::validation(e.to_string()))?;
+
+    if !middleware::can_access_congregation(&claims, body.congregation_id) {
+        return Err(AppError::Forbidden(
+            "Sem permissão para registrar contagens neste escopo".into(),
+        ));
+    }
+
+    let count = OfferingCountService::create(pool.get_ref(), church_id, user_id, &body).await?;
+
+    // Audit log
+    AuditService::log_action(
+        pool.get_ref(), church_id, Some(user_id), "create", "offering_count", count.count.id,
+    ).await.ok();
+
+    Ok(HttpResponse::Created().json(ApiResponse::with_message(
+        count,
+        "Contagem registrada com sucesso",
+    )))
+}
+
+/// Update a counting sheet still being counted (clears a first confirmation)
+#[utoipa::path(
+    put,
+    path = "/api/v1/financial/offering-counts/{id}",
+    params(("id" = uuid::Uuid, Path, description = "Counting sheet ID")),
+    request_body = UpdateOfferingCountRequest,
+    responses(
+        (status = 200, description = "Counting sheet updated"),
+        (status = 400, description = "Sheet already confirmed or validation error"),
+        (status = 404, description = "Not found")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[put("/api/v1/financial/offering-counts/{id}")]
+pub async fn update_offering_count(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<uuid::Uuid>,
+    body: web::Json<UpdateOfferingCountRequest>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:write")
+        .or_else(|_| middleware::require_permission(&claims, "financial:count"))?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let user_id = middleware::get_user_id(&claims)?;
+    let count_id = path.into_inner();
+
+    body.validate()
+        .map_err(|e| AppError::validation(e.to_string()))?;
+
+    let existing = OfferingCountService::get_by_id(pool.get_ref(), church_id, count_id).await?;
+    if !middleware::can_access_congregation(&claims, existing.count.congregation_id) {
+        return Err(AppError::Forbidden(
+            "Sem permissão para alterar esta contagem".into(),
+        ));
+    }
+
+    let count = OfferingCountService::update(pool.get_ref(), church_id, count_id, &body).await?;
+
+    // Audit log
+    AuditService::log_action(
+        pool.get_ref(), church_id, Some(user_id), "update", "offering_count", count_id,
+    ).await.ok();
+
+    Ok(HttpResponse::Ok().json(ApiResponse::with_message(
+        count,
+        "Contagem atualizada com sucesso",
+    )))
+}
+
+/// Discard a counting sheet still being counted
+#[utoipa::path(
+    delete,
+    path = "/api/v1/financial/offering-counts/{id}",
+    params(("id" = uuid::Uuid, Path, description = "Counting sheet ID")),
+    responses(
+        (status = 200, description = "Counting sheet deleted"),
+        (status = 400, description = "Sheet already confirmed"),
+        (status = 404, description = "Not found")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[delete("/api/v1/financial/offering-counts/{id}")]
+pub async fn delete_offering_count(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<uuid::Uuid>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:write")
+        .or_else(|_| middleware::require_permission(&claims, "financial:count"))?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let user_id = middleware::get_user_id(&claims)?;
+    let count_id = path.into_inner();
+
+    let existing = OfferingCountService::get_by_id(pool.get_ref(), church_id, count_id).await?;
+    if !middleware::can_access_congregation(&claims, existing.count.congregation_id) {
+        return Err(AppError::Forbidden(
+            "Sem permissão para excluir esta contagem".into(),
+        ));
+    }
+
+    OfferingCountService::delete(pool.get_ref(), church_id, count_id).await?;
+
+    // Audit log
+    AuditService::log_action(
+        pool.get_ref(), church_id, Some(user_id), "delete", "offering_count", count_id,
+    ).await.ok();
+
+    Ok(HttpResponse::Ok().json(ApiResponse::ok(serde_json::json!({
+        "message": "Contagem excluída com sucesso"
+    }))))
+}
+
+/// Confirm a counting sheet as one of its two counters; the second confirmation,
+/// by a different user, generates the entries
+#[utoipa::path(
+    post,
+    path = "/api/v1/financial/offering-counts/{id}/confirm",
+    params(("id" = uuid::Uuid, Path, description = "Counting sheet ID")),
+    responses(
+        (status = 200, description = "Confirmation recorded"),
+        (status = 400, description = "Same counter, totals don't match or month closed"),
+        (status = 404, description = "Not found"),
+        (status = 409, description = "Sheet changed during confirmation")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[post("/api/v1/financial/offering-counts/{id}/confirm")]
+pub async fn confirm_offering_count(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    cache: web::Data<CacheService>,
+    path: web::Path<uuid::Uuid>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:write")
+        .or_else(|_| middleware::require_permission(&claims, "financial:count"))?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let user_id = middleware::get_user_id(&claims)?;
+    let count_id = path.into_inner();
+
+    let existing = OfferingCountService::get_by_id(pool.get_ref(), church_id, count_id).await?;
+    if !middleware::can_access_congregation(&claims, existing.count.congregation_id) {
+        return Err(AppError::Forbidden(
+            "Sem permissão para confirmar esta contagem".into(),
+        ));
+    }
+
+    let count =
+        OfferingCountService::confirm(pool.get_ref(), church_id, count_id, user_id).await?;
+
+    // Envelopes become tithe entries on the second confirmation
+    if count.count.status == "confirmada" {
+        // Invalidate tither regularity cache
+        cache.del_pattern(&format!("tithes:*:{church_id}:*")).await;
+    }
+
+    // Audit log
+    AuditService::log_action(
+        pool.get_ref(), church_id, Some(user_id), "confirm", "offering_count", count_id,
+    ).await.ok();
+
+    let message = if count.count.status == "confirmada" {
+        "Contagem confirmada e lançamentos gerados"
+    } else {
+        "Primeira confirmação registrada; aguardando o segundo contador"
+    };
+
+    Ok(HttpResponse::Ok().json(ApiResponse::with_message(count, message)))
+}
+
+// ==========================================
+// Bank Reconciliation
+// ==========================================
+
+/// Import a bank statement (OFX or CSV) for a bank account
+///
+/// Multipart form with `file` and an optional `statement_balance` (overrides the
+/// balance found in the file). Movements already imported are skipped.
+#[utoipa::path(
+    post,
+    path = "/api/v1/financial/bank-accounts/{id}/statements",
+    params(("id" = uuid::Uuid, Path, description = "Bank account ID")),
+    request_body(content_type = "multipart/form-data"),
+    responses(
+        (status = 201, description = "Statement imported"),
+        (status = 400, description = "Invalid file"),
+        (status = 409, description = "All movements already imported")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[post("/api/v1/financial/bank-accounts/{id}/statements")]
+pub async fn import_bank_statement(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<uuid::Uuid>,
+    mut payload: Multipart,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config.clone()).await?;
+    middleware::require_permission(&claims, "financial:write")?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let user_id = middleware::get_user_id(&claims)?;
+    let bank_account_id = path.into_inner();
+
+    let max_bytes = config.max_upload_size_mb * 1024 * 1024;
+    let mut file_bytes: Vec<u8> = Vec::new();
+    let mut file_name = None;
+    let mut statement_balance = None;
+
+    while let Some(item) = payload.next().await {
+        let mut field = item.map_err(|e| AppError::validation(format!("Erro no multipart: {e}")))?;
+
+        let field_name = field.name().map(|n| n.to_string()).unwrap_or_default();
+
+        let mut buf = Vec::new();
+        while let Some(chunk) = field.next().await {
+            let data = chunk.map_err(|e| {
+                AppError::validation(format!("Erro ao ler chunk: {e}"))
+            })?;
+            buf.extend_from_slice(&data);
+            if buf.len() > max_bytes {
+                return Err(AppError::validation(format!(
+                    "Arquivo muito grande. Máximo: {}MB",
+                    config.max_upload_size_mb
+                )));
+            }
+        }
+
+        match field_name.as_str() {
+            "file" => {
+                file_name = field
+                    .content_disposition()
+                    .and_then(|cd| cd.get_filename())
+                    .map(|name| name.to_string());
+                file_bytes = buf;
+            }
+            "statement_balance" => {
+                let value = String::from_utf8_lossy(&buf).to_string();
+                if !value.trim().is_empty() {
+                    statement_balance = Some(parse_amount(&value).ok_or_else(|| {
+                        AppError::validation("Saldo do extrato inválido")
+                    })?);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if file_bytes.is_empty() {
+        return Err(AppError::validation("Nenhum arquivo enviado"));
+    }
+
+    let result = BankStatementService::import(
+        pool.get_ref(),
+        church_id,
+        user_id,
+        bank_account_id,
+        file_name,
+        &file_bytes,
+        statement_balance,
+    )
+    .await?;
+
+    // Audit log
+    AuditService::log_action(
+        pool.get_ref(), church_id, Some(user_id), "create", "bank_statement", result.statement.id,
+    ).await.ok();
+
+    Ok(HttpResponse::Created().json(ApiResponse::with_message(
+        result,
+        "Extrato importado com sucesso",
+    )))
+}
+
+/// List statements imported for a bank account
+#[utoipa::path(
+    get,
+    path = "/api/v1/financial/bank-accounts/{id}/statements",
+    params(
+        ("id" = uuid::Uuid, Path, description = "Bank account ID"),
+        ("page" = Option<i64>, Query, description = "Page number"),
+        ("per_page" = Option<i64>, Query, description = "Items per page"),
+    ),
+    responses(
+        (status = 200, description = "List of statements"),
+        (status = 401, description = "Not authenticated")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[get("/api/v1/financial/bank-accounts/{id}/statements")]
+pub async fn list_bank_statements(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<uuid::Uuid>,
+    pagination: web::Query<PaginationParams>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:read")?;
+    let church_id = middleware::get_church_id(&claims)?;
+
+    let (statements, total) = BankStatementService::list(
+        pool.get_ref(),
+        church_id,
+        path.into_inner(),
+        pagination.per_page(),
+        pagination.offset(),
+    )
+    .await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::paginated(
+        statements,
+        pagination.page(),
+        pagination.per_page(),
+        total,
+    )))
+}
+
+/// Get a statement with its lines, match suggestions and balance comparison
+#[utoipa::path(
+    get,
+    path = "/api/v1/financial/bank-statements/{id}",
+    params(
+        ("id" = uuid::Uuid, Path, description = "Statement ID"),
+        ("days" = Option<i32>, Query, description = "Date window (± days) for suggestions, default 3"),
+    ),
+    responses(
+        (status = 200, description = "Statement details"),
+        (status = 404, description = "Not found")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[get("/api/v1/financial/bank-statements/{id}")]
+pub async fn get_bank_statement(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<uuid::Uuid>,
+    params: web::Query<ReconciliationParams>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:read")?;
+    let church_id = middleware::get_church_id(&claims)?;
+
+    let detail = BankStatementService::get_detail(
+        pool.get_ref(),
+        church_id,
+        path.into_inner(),
+        params.days.unwrap_or(3),
+    )
+    .await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::ok(detail)))
+}
+
+/// Reconcile a statement line with an existing entry
+#[utoipa::path(
+    post,
+    path = "/api/v1/financial/bank-statements/lines/{id}/match",
+    params(("id" = uuid::Uuid, Path, description = "Statement line ID")),
+    request_body = MatchStatementLineRequest,
+    responses(
+        (status = 200, description = "Line reconciled"),
+        (status = 400, description = "Entry does not match the line"),
+        (status = 409, description = "Already reconciled")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[post("/api/v1/financial/bank-statements/lines/{id}/match")]
+pub async fn match_statement_line(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<uuid::Uuid>,
+    body: web::Json<MatchStatementLineRequest>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:write")?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let user_id = middleware::get_user_id(&claims)?;
+
+    let line = BankStatementService::match_line(
+        pool.get_ref(),
+        church_id,
+        user_id,
+        path.into_inner(),
+        body.entry_id,
+    )
+    .await?;
+
+    // Audit log
+    AuditService::log_action(
+        pool.get_ref(), church_id, Some(user_id), "reconcile", "financial_entry", body.entry_id,
+    ).await.ok();
+
+    Ok(HttpResponse::Ok().json(ApiResponse::with_message(
+        line,
+        "Movimentação conciliada com sucesso",
+    )))
+}
+
+/// Undo the reconciliation of a statement line
+#[utoipa::path(
+    delete,
+    path = "/api/v1/financial/bank-statements/lines/{id}/match",
+    params(("id" = uuid::Uuid, Path, description = "Statement line ID")),
+    responses(
+        (status = 200, description = "Reconciliation undone"),
+        (status = 404, description = "Not found")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[delete("/api/v1/financial/bank-statements/lines/{id}/match")]
+pub async fn unmatch_statement_line(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<uuid::Uuid>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:write")?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let user_id = middleware::get_user_id(&claims)?;
+    let line_id = path.into_inner();
+
+    let line = BankStatementService::unmatch_line(pool.get_ref(), church_id, line_id).await?;
+
+    // Audit log
+    AuditService::log_action(
+        pool.get_ref(), church_id, Some(user_id), "unreconcile", "bank_statement", line.statement_id,
+    ).await.ok();
+
+    Ok(HttpResponse::Ok().json(ApiResponse::with_message(
+        line,
+        "Conciliação desfeita com sucesso",
+    )))
+}
+
+/// Create a confirmed entry from an unmatched statement line and reconcile it
+#[utoipa::path(
+    post,
+    path = "/api/v1/financial/bank-statements/lines/{id}/entry",
+    params(("id" = uuid::Uuid, Path, description = "Statement line ID")),
+    request_body = CreateEntryFromLineRequest,
+    responses(
+        (status = 201, description = "Entry created and reconciled"),
+        (status = 409, description = "Line already reconciled")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[post("/api/v1/financial/bank-statements/lines/{id}/entry")]
+pub async fn create_entry_from_statement_line(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    cache: web::Data<CacheService>,
+    path: web::Path<uuid::Uuid>,
+    body: web::Json<CreateEntryFromLineRequest>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:write")?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let user_id = middleware::get_user_id(&claims)?;
+
+    body.validate()
+        .map_err(|e| AppError::validation(e.to_string()))?;
+
+    let (entry, line) = BankStatementService::create_entry_from_line(
+        pool.get_ref(),
+        church_id,
+        user_id,
+        path.into_inner(),
+        &body,
+    )
+    .await?;
+
+    // Invalidate tither regularity cache
+    cache.del_pattern(&format!("tithes:*:{church_id}:*")).await;
+
+    // Audit log
+    AuditService::log_action(
+        pool.get_ref(), church_id, Some(user_id), "create", "financial_entry", entry.id,
+    ).await.ok();
+
+    Ok(HttpResponse::Created().json(ApiResponse::with_message(
+        serde_json::json!({ "entry": entry, "line": line }),
+        "Lançamento criado e conciliado com sucesso",
+    )))
+}
+
+// ==========================================
+// Tithes
+// ==========================================
+
+/// Annual tithe statement of a member (RN-FIN-005)
+///
+/// Requires `financial:tithes`, except for the member's own statement.
+#[utoipa::path(
+    get,
+    path = "/api/v1/financial/tithes/members/{member_id}/statement",
+    params(
+        ("member_id" = uuid::Uuid, Path, description = "Member ID"),
+        ("year" = Option<i32>, Query, description = "Statement year (default: current year)"),
+        ("format" = Option<String>, Query, description = "json (default) or pdf"),
+    ),
+    responses(
+        (status = 200, description = "Tithe statement (JSON or application/pdf)"),
+        (status = 403, description = "Insufficient permission"),
+        (status = 404, description = "Member not found")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[get("/api/v1/financial/tithes/members/{member_id}/statement")]
+pub async fn member_tithe_statement(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<uuid::Uuid>,
+    params: web::Query<TitheStatementParams>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    let member_id = path.into_inner();
+
+    // Members may always download their own statement
+    if middleware::get_member_id(&claims) != Some(member_id) {
+        middleware::require_permission(&claims, "financial:tithes")?;
+    }
+
+    let church_id = middleware::get_church_id(&claims)?;
+    let user_id = middleware::get_user_id(&claims)?;
+    let year = params.year.unwrap_or_else(|| chrono::Utc::now().year());
+
+    if !(2000..=2100).contains(&year) {
+        return Err(AppError::validation("Ano inválido"));
+    }
+
+    // Audit log (individual tithe amounts are confidential)
+    AuditService::log_action(
+        pool.get_ref(), church_id, Some(user_id), "export", "tithe_statement", member_id,
+    ).await.ok();
+
+    match params.format.as_deref() {
+        None | Some("json") => {
+            let statement =
+                TitheService::annual_statement(pool.get_ref(), church_id, member_id, year).await?;
+            Ok(HttpResponse::Ok().json(ApiResponse::ok(statement)))
+        }
+        Some("pdf") => {
+            let pdf =
+                TitheService::annual_statement_pdf(pool.get_ref(), church_id, member_id, year)
+                    .await?;
+            Ok(HttpResponse::Ok()
+                .content_type("application/pdf")
+                .insert_header((
+                    "Content-Disposition",
+                    format!("attachment; filename=\"declaracao-dizimos-{year}.pdf\""),
+                ))
+                .body(pdf))
+        }
+        Some(_) => Err(AppError::validation("Formato inválido. Use json ou pdf")),
+    }
+}
+
+/// Tither regularity (RN-FIN-006): regular, irregular and inactive members
+#[utoipa::path(
+    get,
+    path = "/api/v1/financial/tithes/regularity",
+    params(
+        ("congregation_id" = Option<uuid::Uuid>, Query, description = "Members of a congregation"),
+        ("status" = Option<String>, Query, description = "regular, irregular or inativo"),
+        ("reference_month" = Option<String>, Query, description = "Last month of the 12-month window (default: previous month)"),
+    ),
+    responses(
+        (status = 200, description = "Tither classification with monthly history"),
+        (status = 403, description = "Insufficient permission")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[get("/api/v1/financial/tithes/regularity")]
+pub async fn tither_regularity(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    cache: web::Data<CacheService>,
+    filter: web::Query<TitherRegularityFilter>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:tithes")?;
+    let church_id = middleware::get_church_id(&claims)?;
+
+    if let Some(status) = filter.status.as_deref() {
+        if !["regular", "irregular", "inativo"].contains(&status) {
+            return Err(AppError::validation(
+                "Status deve ser 'regular', 'irregular' ou 'inativo'",
+            ));
+        }
+    }
+
+    let mut congregation_filter = filter.congregation_id;
+
+    // Enforce congregation scope: if user has restricted access, use their first allowed congregation
+    if let Some(allowed) = middleware::get_allowed_congregations(&claims) {
+        match congregation_filter {
+            Some(cid) if !allowed.contains(&cid) => {
+                return Err(AppError::Forbidden(
+                    "Sem permissão para ver dizimistas desta congregação".into(),
+                ));
+            }
+            None if !allowed.is_empty() => {
+                congregation_filter = Some(allowed[0]);
+            }
+            None => {
+                return Err(AppError::Forbidden(
+                    "Sem permissão para ver dizimistas".into(),
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    let reference_month = filter.reference_month.unwrap_or_else(|| {
+        let today = chrono::Utc::now().date_naive();
+        today.with_day(1).unwrap_or(today) - chrono::Months::new(1)
+    });
+
+    // Try cache first (TTL 10 min)
+    let cache_suffix = congregation_filter
+        .map(|c| c.to_string())
+        .unwrap_or_else(|| "all".to_string());
+    let cache_key = format!(
+        "tithes:regularity:{church_id}:{cache_suffix}:{}",
+        reference_month.format("%Y-%m")
+    );
+
+    let mut report = match cache.get::<crate::domain::entities::TitherRegularityReport>(&cache_key).await {
+        Some(cached) => cached,
+        None => {
+            let report = TitheService::regularity(
+                pool.get_ref(),
+                church_id,
+                congregation_filter,
+                None,
+                reference_month,
+            )
+            .await?;
+            cache.set(&cache_key, &report, 600).await;
+            report
+        }
+    };
+
+    if let Some(status) = filter.status.as_deref() {
+        report.members.retain(|m| m.status == status);
+    }
+
+    Ok(HttpResponse::Ok().json(ApiResponse::ok(report)))
+}
+
+/// Tither regularity of a single member, with month-by-month history
+#[utoipa::path(
+    get,
+    path = "/api/v1/financial/tithes/regularity/members/{member_id}",
+    params(
+        ("member_id" = uuid::Uuid, Path, description = "Member ID"),
+        ("reference_month" = Option<String>, Query, description = "Last month of the 12-month window (default: previous month)"),
+    ),
+    responses(
+        (status = 200, description = "Member classification and history"),
+        (status = 403, description = "Insufficient permission"),
+        (status = 404, description = "Member not found")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[get("/api/v1/financial/tithes/regularity/members/{member_id}")]
+pub async fn member_tither_regularity(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<uuid::Uuid>,
+    filter: web::Query<TitherRegularityFilter>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:tithes")?;
+    let church_id = middleware::get_church_id(&claims)?;
+
+    let reference_month = filter.reference_month.unwrap_or_else(|| {
+        let today = chrono::Utc::now().date_naive();
+        today.with_day(1).unwrap_or(today) - chrono::Months::new(1)
+    });
+
+    let report = TitheService::regularity(
+        pool.get_ref(),
+        church_id,
+        None,
+        Some(path.into_inner()),
+        reference_month,
+    )
+    .await?;
+
+    let member = report
+        .members
+        .into_iter()
+        .next()
+        .ok_or_else(|| AppError::not_found("Membro"))?;
+
+    if !middleware::can_access_congregation(&claims, member.congregation_id) {
+        return Err(AppError::Forbidden(
+            "Sem permissão para ver dizimistas desta congregação".into(),
+        ));
+    }
+
+    Ok(HttpResponse::Ok().json(ApiResponse::ok(member)))
+}
+
+// ==========================================
+// Donation Receipts
+// ==========================================
+
+/// Issue a numbered donation receipt for a confirmed income entry
+#[utoipa::path(
+    post,
+    path = "/api/v1/financial/entries/{id}/receipt",
+    params(("id" = uuid::Uuid, Path, description = "Entry ID")),
+    request_body = IssueReceiptRequest,
+    responses(
+        (status = 201, description = "Receipt issued"),
+        (status = 400, description = "Entry cannot have a receipt"),
+        (status = 404, description = "Not found"),
+        (status = 409, description = "Entry already has a valid receipt")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[post("/api/v1/financial/entries/{id}/receipt")]
+pub async fn issue_receipt(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<uuid::Uuid>,
+    body: web::Json<IssueReceiptRequest>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:write")?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let user_id = middleware::get_user_id(&claims)?;
+    let entry_id = path.into_inner();
+
+    body.validate()
+        .map_err(|e| AppError::validation(e.to_string()))?;
+
+    let receipt =
+        DonationReceiptService::issue(pool.get_ref(), church_id, user_id, entry_id, &body).await?;
+
+    // Audit log
+    AuditService::log_action(
+        pool.get_ref(), church_id, Some(user_id), "issue", "donation_receipt", receipt.id,
+    ).await.ok();
+
+    let message = format!("Recibo nº {} emitido com sucesso", receipt.number);
+    Ok(HttpResponse::Created().json(ApiResponse::with_message(receipt, message)))
+}
+
+/// List donation receipts
+#[utoipa::path(
+    get,
+    path = "/api/v1/financial/receipts",
+    params(
+        ("page" = Option<i64>, Query, description = "Page number"),
+        ("per_page" = Option<i64>, Query, description = "Items per page"),
+        ("status" = Option<String>, Query, description = "emitido or cancelado"),
+        ("member_id" = Option<uuid::Uuid>, Query, description = "Receipts of a member"),
+        ("date_from" = Option<String>, Query, description = "Donation date from"),
+        ("date_to" = Option<String>, Query, description = "Donation date to"),
+    ),
+    responses(
+        (status = 200, description = "List of receipts"),
+        (status = 401, description = "Not authenticated")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[get("/api/v1/financial/receipts")]
+pub async fn list_receipts(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    pagination: web::Query<PaginationParams>,
+    filter: web::Query<ReceiptFilter>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:read")?;
+    let church_id = middleware::get_church_id(&claims)?;
+
+    let (receipts, total) = DonationReceiptService::list(
+        pool.get_ref(),
+        church_id,
+        &filter,
+        pagination.per_page(),
+        pagination.offset(),
+    )
+    .await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::paginated(
+        receipts,
+        pagination.page(),
+        pagination.per_page(),
+        total,
+    )))
+}
+
+/// Get a donation receipt
+#[utoipa::path(
+    get,
+    path = "/api/v1/financial/receipts/{id}",
+    params(("id" = uuid::Uuid, Path, description = "Receipt ID")),
+    responses(
+        (status = 200, description = "Receipt details"),
+        (status = 404, description = "Not found")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[get("/api/v1/financial/receipts/{id}")]
+pub async fn get_receipt(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<uuid::Uuid>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:read")?;
+    let church_id = middleware::get_church_id(&claims)?;
+
+    let receipt =
+        DonationReceiptService::get_by_id(pool.get_ref(), church_id, path.into_inner()).await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::ok(receipt)))
+}
+
+/// Donation receipt as PDF (voided receipts are printed marked as cancelled)
+#[utoipa::path(
+    get,
+    path = "/api/v1/financial/receipts/{id}/pdf",
+    params(("id" = uuid::Uuid, Path, description = "Receipt ID")),
+    responses(
+        (status = 200, description = "PDF receipt"),
+        (status = 404, description = "Not found")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[get("/api/v1/financial/receipts/{id}/pdf")]
+pub async fn receipt_pdf(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<uuid::Uuid>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:read")?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let receipt_id = path.into_inner();
+
+    let receipt = DonationReceiptService::get_by_id(pool.get_ref(), church_id, receipt_id).await?;
+    let pdf = DonationReceiptService::pdf(pool.get_ref(), church_id, receipt_id).await?;
+
+    Ok(HttpResponse::Ok()
+        .content_type("application/pdf")
+        .insert_header((
+            "Content-Disposition",
+            format!("attachment; filename=\"recibo-{:06}.pdf\"", receipt.number),
+        ))
+        .body(pdf))
+}
+
+// ==========================================
+// Budgets
+// ==========================================
+
+/// List budgets
+#[utoipa::path(
+    get,
+    path = "/api/v1/financial/budgets",
+    params(
+        ("page" = Option<i64>, Query, description = "Page number"),
+        ("per_page" = Option<i64>, Query, description = "Items per page"),
+        ("year" = Option<i16>, Query, description = "Budget year"),
+        ("account_plan_id" = Option<uuid::Uuid>, Query, description = "Account plan"),
+        ("congregation_id" = Option<uuid::Uuid>, Query, description = "Budgets of a congregation"),
+    ),
+    responses(
+        (status = 200, description = "List of budgets"),
+        (status = 401, description = "Not authenticated")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[get("/api/v1/financial/budgets")]
+pub async fn list_budgets(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    pagination: web::Query<PaginationParams>,
+    filter: web::Query<BudgetFilter>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:read")?;
+    let church_id = middleware::get_church_id(&claims)?;
+
+    let mut filter = filter.into_inner();
+
+    // Enforce congregation scope: if user has restricted access, use their first allowed congregation
+    if let Some(allowed) = middleware::get_allowed_congregations(&claims) {
+        match filter.congregation_id {
+            Some(cid) if !allowed.contains(&cid) => {
+                return Err(AppError::Forbidden(
+                    "Sem permissão para ver orçamentos desta congregação".into(),
+                ));
+            }
+            None if !allowed.is_empty() => {
+                filter.congregation_id = Some(allowed[0]);
+            }
+            None => {
+                return Err(AppError::Forbidden(
+                    "Sem permissão para ver orçamentos".into(),
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    let (budgets, total) = BudgetService::list(
+        pool.get_ref(),
+        church_id,
+        &filter,
+        pagination.per_page(),
+        pagination.offset(),
+    )
+    .await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::paginated(
+        budgets,
+        pagination.page(),
+        pagination.per_page(),
+        total,
+    )))
+}
+
+/// Create a yearly or monthly budget for an account plan
+#[utoipa::path(
+    post,
+    path = "/api/v1/financial/budgets",
+    request_body = CreateBudgetRequest,
+    responses(
+        (status = 201, description = "Budget created"),
+        (status = 409, description = "Budget already defined for the period")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[post("/api/v1/financial/budgets")]
+pub async fn create_budget(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    body: web::Json<CreateBudgetRequest>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:write")?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let user_id = middleware::get_user_id(&claims)?;
+
+    body.validate()
+        .map_err(|e| AppError::validation(e.to_string()))?;
+
+    if !middleware::can_access_congregation(&claims, body.congregation_id) {
+        return Err(AppError::Forbidden(
+            "Sem permissão para definir orçamento neste escopo".into(),
+        ));
+    }
+
+    let budget = BudgetService::create(pool.get_ref(), church_id, user_id, &body).await?;
+
+    // Audit log
+    AuditService::log_action(
+        pool.get_ref(), church_id, Some(user_id), "create", "budget", budget.id,
+    ).await.ok();
+
+    Ok(HttpResponse::Created().json(ApiResponse::with_message(
+        budget,
+        "Orçamento criado com sucesso",
+    )))
+}
+
+/// Update a budget
+#[utoipa::path(
+    put,
+    path = "/api/v1/financial/budgets/{id}",
+    params(("id" = uuid::Uuid, Path, description = "Budget ID")),
+    request_body = UpdateBudgetRequest,
+    responses(
+        (status = 200, description = "Budget updated"),
+        (status = 404, description = "Not found")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[put("/api/v1/financial/budgets/{id}")]
+pub async fn update_budget(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<uuid::Uuid>,
+    body: web::Json<UpdateBudgetRequest>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:write")?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let budget_id = path.into_inner();
+
+    body.validate()
+        .map_err(|e| AppError::validation(e.to_string()))?;
+
+    let existing = BudgetService::get_by_id(pool.get_ref(), church_id, budget_id).await?;
+    if !middleware::can_access_congregation(&claims, existing.congregation_id) {
+        return Err(AppError::Forbidden(
+            "Sem permissão para alterar orçamento neste escopo".into(),
+        ));
+    }
+
+    let budget = BudgetService::update(pool.get_ref(), church_id, budget_id, &body).await?;
+
+    // Audit log
+    let user_id = middleware::get_user_id(&claims)?;
+    AuditService::log_action(
+        pool.get_ref(), church_id, Some(user_id), "update", "budget", budget_id,
+    ).await.ok();
+
+    Ok(HttpResponse::Ok().json(ApiResponse::with_message(
+        budget,
+        "Orçamento atualizado com sucesso",
+    )))
+}
+
+/// Delete a budget
+#[utoipa::path(
+    delete,
+    path = "/api/v1/financial/budgets/{id}",
+    params(("id" = uuid::Uuid, Path, description = "Budget ID")),
+    responses(
+        (status = 200, description = "Budget deleted"),
+        (status = 404, description = "Not found")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[delete("/api/v1/financial/budgets/{id}")]
+pub async fn delete_budget(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<uuid::Uuid>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:write")?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let budget_id = path.into_inner();
+
+    let existing = BudgetService::get_by_id(pool.get_ref(), church_id, budget_id).await?;
+    if !middleware::can_access_congregation(&claims, existing.congregation_id) {
+        return Err(AppError::Forbidden(
+            "Sem permissão para excluir orçamento neste escopo".into(),
+        ));
+    }
+
+    BudgetService::delete(pool.get_ref(), church_id, budget_id).await?;
+
+    // Audit log
+    let user_id = middleware::get_user_id(&claims)?;
+    AuditService::log_action(
+        pool.get_ref(), church_id, Some(user_id), "delete", "budget", budget_id,
+    ).await.ok();
+
+    Ok(HttpResponse::Ok().json(ApiResponse::ok(serde_json::json!({
+        "message": "Orçamento excluído com sucesso"
+    }))))
+}
+
+/// Budget vs actual, rolled up the account plan tree, with overspending alerts
+#[utoipa::path(
+    get,
+    path = "/api/v1/financial/budgets/report",
+    params(
+        ("year" = i16, Query, description = "Budget year"),
+        ("month" = Option<i16>, Query, description = "Single month (1-12); omitted = whole year"),
+        ("congregation_id" = Option<uuid::Uuid>, Query, description = "Budgets and entries of a congregation"),
+    ),
+    responses(
+        (status = 200, description = "Budget vs actual report"),
+        (status = 401, description = "Not authenticated")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[get("/api/v1/financial/budgets/report")]
+pub async fn budget_report(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    filter: web::Query<BudgetReportFilter>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:read")?;
+    let church_id = middleware::get_church_id(&claims)?;
+
+    let mut filter = filter.into_inner();
+
+    // Enforce congregation scope: if user has restricted access, use their first allowed congregation
+    if let Some(allowed) = middleware::get_allowed_congregations(&claims) {
+        match filter.congregation_id {
+            Some(cid) if !allowed.contains(&cid) => {
+                return Err(AppError::Forbidden(
+                    "Sem permissão para ver orçamentos desta congregação".into(),
+                ));
+            }
+            None if !allowed.is_empty() => {
+                filter.congregation_id = Some(allowed[0]);
+            }
+            None => {
+                return Err(AppError::Forbidden(
+                    "Sem permissão para ver orçamentos".into(),
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    let report = BudgetService::report(pool.get_ref(), church_id, &filter).await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::ok(report)))
+}
+
+// ==========================================
+// Suppliers
+// ==========================================
+
+/// List suppliers
+#[utoipa::path(
+    get,
+    path = "/api/v1/financial/suppliers",
+    params(
+        ("page" = Option<i64>, Query, description = "Page number"),
+        ("per_page" = Option<i64>, Query, description = "Items per page"),
+        ("search" = Option<String>, Query, description = "Search by name or CPF/CNPJ"),
+        ("category" = Option<String>, Query, description = "Filter by category"),
+        ("is_active" = Option<bool>, Query, description = "Filter by active flag"),
+    ),
+    responses(
+        (status = 200, description = "List of suppliers"),
+        (status = 401, description = "Not authenticated")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[get("/api/v1/financial/suppliers")]
+pub async fn list_suppliers(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    pagination: web::Query<PaginationParams>,
+    filter: web::Query<SupplierFilter>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    // Maintenances link to suppliers too
+    middleware::require_permission(&claims, "financial:read")
+        .or_else(|_| middleware::require_permission(&claims, "assets:read"))?;
+    let church_id = middleware::get_church_id(&claims)?;
+
+    let (suppliers, total) = SupplierService::list(
+        pool.get_ref(),
+        church_id,
+        &filter,
+        &pagination.search,
+        pagination.per_page(),
+        pagination.offset(),
+    )
+    .await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::paginated(
+        suppliers,
+        pagination.page(),
+        pagination.per_page(),
+        total,
+    )))
+}
+
+/// Get supplier by ID
+#[utoipa::path(
+    get,
+    path = "/api/v1/financial/suppliers/{id}",
+    params(("id" = uuid::Uuid, Path, description = "Supplier ID")),
+    responses(
+        (status = 200, description = "Supplier details"),
+        (status = 404, description = "Not found")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[get("/api/v1/financial/suppliers/{id}")]
+pub async fn get_supplier(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<uuid::Uuid>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:read")
+        .or_else(|_| middleware::require_permission(&claims, "assets:read"))?;
+    let church_id = middleware::get_church_id(&claims)?;
+
+    let supplier = SupplierService::get_by_id(pool.get_ref(), church_id, path.into_inner()).await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::ok(supplier)))
+}
+
+/// Create a supplier
+#[utoipa::path(
+    post,
+    path = "/api/v1/financial/suppliers",
+    request_body = CreateSupplierRequest,
+    responses(
+        (status = 201, description = "Supplier created"),
+        (status = 400, description = "Invalid CPF/CNPJ"),
+        (status = 409, description = "CPF/CNPJ already registered")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[post("/api/v1/financial/suppliers")]
+pub async fn create_supplier(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    body: web::Json<CreateSupplierRequest>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:write")
+        .or_else(|_| middleware::require_permission(&claims, "assets:write"))?;
+    let church_id = middleware::get_church_id(&claims)?;
+
+    body.validate()
+        .map_err(|e| AppError::validation(e.to_string()))?;
+
+    let supplier = SupplierService::create(pool.get_ref(), church_id, &body).await?;
+
+    // Audit log
+    let user_id = middleware::get_user_id(&claims)?;
+    AuditService::log_action(
+        pool.get_ref(), church_id, Some(user_id), "create", "supplier", supplier.id,
+    ).await.ok();
+
+    Ok(HttpResponse::Created().json(ApiResponse::with_message(
+        supplier,
+        "Fornecedor cadastrado com sucesso",
+    )))
+}
+
+/// Update a supplier
+#[utoipa::path(
+    put,
+    path = "/api/v1/financial/suppliers/{id}",
+    params(("id" = uuid::Uuid, Path, description = "Supplier ID")),
+    request_body = UpdateSupplierRequest,
+    responses(
+        (status = 200, description = "Supplier updated"),
+        (status = 404, description = "Not found"),
+        (status = 409, description = "CPF/CNPJ already registered")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[put("/api/v1/financial/suppliers/{id}")]
+pub async fn update_supplier(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<uuid::Uuid>,
+    body: web::Json<UpdateSupplierRequest>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:write")
+        .or_else(|_| middleware::require_permission(&claims, "assets:write"))?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let supplier_id = path.into_inner();
+
+    body.validate()
+        .map_err(|e| AppError::validation(e.to_string()))?;
+
+    let supplier = SupplierService::update(pool.get_ref(), church_id, supplier_id, &body).await?;
+
+    // Audit log
+    let user_id = middleware::get_user_id(&claims)?;
+    AuditService::log_action(
+        pool.get_ref(), church_id, Some(user_id), "update", "supplier", supplier_id,
+    ).await.ok();
+
+    Ok(HttpResponse::Ok().json(ApiResponse::with_message(
+        supplier,
+        "Fornecedor atualizado com sucesso",
+    )))
+}
+
+/// Delete a supplier
+#[utoipa::path(
+    delete,
+    path = "/api/v1/financial/suppliers/{id}",
+    params(("id" = uuid::Uuid, Path, description = "Supplier ID")),
+    responses(
+        (status = 200, description = "Supplier deleted"),
+        (status = 404, description = "Not found")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[delete("/api/v1/financial/suppliers/{id}")]
+pub async fn delete_supplier(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<uuid::Uuid>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:write")?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let supplier_id = path.into_inner();
+
+    SupplierService::delete(pool.get_ref(), church_id, supplier_id).await?;
+
+    // Audit log
+    let user_id = middleware::get_user_id(&claims)?;
+    AuditService::log_action(
+        pool.get_ref(), church_id, Some(user_id), "delete", "supplier", supplier_id,
+    ).await.ok();
+
+    Ok(HttpResponse::Ok().json(ApiResponse::ok(serde_json::json!({
+        "message": "Fornecedor removido com sucesso"
+    }))))
+}
+
+/// Spending by supplier for the fiscal council (RN-FIN-021)
+#[utoipa::path(
+    get,
+    path = "/api/v1/financial/reports/suppliers",
+    params(
+        ("date_from" = String, Query, description = "First day of the period"),
+        ("date_to" = String, Query, description = "Last day of the period"),
+        ("congregation_id" = Option<uuid::Uuid>, Query, description = "Only expenses of a congregation"),
+        ("category" = Option<String>, Query, description = "Only suppliers of a category"),
+    ),
+    responses(
+        (status = 200, description = "Net spending by supplier, largest first"),
+        (status = 400, description = "Invalid period")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[get("/api/v1/financial/reports/suppliers")]
+pub async fn supplier_spending_report(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    filter: web::Query<SupplierSpendingFilter>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:read")?;
+    let church_id = middleware::get_church_id(&claims)?;
+
+    let mut congregation_filter = filter.congregation_id;
+
+    // Enforce congregation scope: if user has restricted access, use their first allowed congregation
+    if let Some(allowed) = middleware::get_allowed_congregations(&claims) {
+        match congregation_filter {
+            Some(cid) if !allowed.contains(&cid) => {
+                return Err(AppError::Forbidden(
+                    "Sem permissão para ver o relatório desta congregação".into(),
+                ));
+            }
+            None if !allowed.is_empty() => {
+                congregation_filter = Some(allowed[0]);
+            }
+            None => {
+                return Err(AppError::Forbidden(
+                    "Sem permissão para ver o relatório".into(),
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    let report = SupplierService::spending_report(
+        pool.get_ref(),
+        church_id,
+        congregation_filter,
+        &filter,
+    )
+    .await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::ok(report)))
+}
+
+// ==========================================
+// Expense Approvals
+// ==========================================
+
+/// List expense approval rules
+#[utoipa::path(
+    get,
+    path = "/api/v1/financial/approval-rules",
+    responses(
+        (status = 200, description = "Approval rules"),
+        (status = 401, description = "Not authenticated")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[get("/api/v1/financial/approval-rules")]
+pub async fn list_approval_rules(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:read")?;
+    let church_id = middleware::get_church_id(&claims)?;
+
+    let rules = ExpenseApprovalService::list_rules(pool.get_ref(), church_id).await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::ok(rules)))
+}
+
+/// Create an expense approval rule
+#[utoipa::path(
+    post,
+    path = "/api/v1/financial/approval-rules",
+    request_body = CreateExpenseApprovalRuleRequest,
+    responses(
+        (status = 201, description = "Approval rule created"),
+        (status = 400, description = "No condition given or category is not an expense")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[post("/api/v1/financial/approval-rules")]
+pub async fn create_approval_rule(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    body: web::Json<CreateExpenseApprovalRuleRequest>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:approve")?;
+    let church_id = middleware::get_church_id(&claims)?;
+
+    body.validate()
+        .map_err(|e| AppError::validation(e.to_string()))?;
+
+    let rule = ExpenseApprovalService::create_rule(pool.get_ref(), church_id, &body).await?;
+
+    // Audit log
+    let user_id = middleware::get_user_id(&claims)?;
+    AuditService::log_action(
+        pool.get_ref(), church_id, Some(user_id), "create", "expense_approval_rule", rule.id,
+    ).await.ok();
+
+    Ok(HttpResponse::Created().json(ApiResponse::with_message(
+        rule,
+        "Regra de aprovação criada com sucesso",
+    )))
+}
+
+/// Update an expense approval rule
+#[utoipa::path(
+    put,
+    path = "/api/v1/financial/approval-rules/{id}",
+    params(("id" = uuid::Uuid, Path, description = "Approval rule ID")),
+    request_body = UpdateExpenseApprovalRuleRequest,
+    responses(
+        (status = 200, description = "Approval rule updated"),
+        (status = 404, description = "Not found")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[put("/api/v1/financial/approval-rules/{id}")]
+pub async fn update_approval_rule(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<uuid::Uuid>,
+    body: web::Json<UpdateExpenseApprovalRuleRequest>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:approve")?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let rule_id = path.into_inner();
+
+    body.validate()
+        .map_err(|e| AppError::validation(e.to_string()))?;
+
+    let rule =
+        ExpenseApprovalService::update_rule(pool.get_ref(), church_id, rule_id, &body).await?;
+
+    // Audit log
+    let user_id = middleware::get_user_id(&claims)?;
+    AuditService::log_action(
+        pool.get_ref(), church_id, Some(user_id), "update", "expense_approval_rule", rule_id,
+    ).await.ok();
+
+    Ok(HttpResponse::Ok().json(ApiResponse::with_message(
+        rule,
+        "Regra de aprovação atualizada com sucesso",
+    )))
+}
+
+/// Delete an expense approval rule
+#[utoipa::path(
+    delete,
+    path = "/api/v1/financial/approval-rules/{id}",
+    params(("id" = uuid::Uuid, Path, description = "Approval rule ID")),
+    responses(
+        (status = 200, description = "Approval rule deleted"),
+        (status = 404, description = "Not found")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[delete("/api/v1/financial/approval-rules/{id}")]
+pub async fn delete_approval_rule(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<uuid::Uuid>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:approve")?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let rule_id = path.into_inner();
+
+    ExpenseApprovalService::delete_rule(pool.get_ref(), church_id, rule_id).await?;
+
+    // Audit log
+    let user_id = middleware::get_user_id(&claims)?;
+    AuditService::log_action(
+        pool.get_ref(), church_id, Some(user_id), "delete", "expense_approval_rule", rule_id,
+    ).await.ok();
+
+    Ok(HttpResponse::Ok().json(ApiResponse::ok(serde_json::json!({
+        "message": "Regra de aprovação excluída com sucesso"
+    }))))
+}
+
+/// Approve an expense awaiting approval (RN-FIN-020)
+#[utoipa::path(
+    post,
+    path = "/api/v1/financial/entries/{id}/approve",
+    params(("id" = uuid::Uuid, Path, description = "Entry ID")),
+    responses(
+        (status = 200, description = "Expense approved"),
+        (status = 403, description = "Approver registered the expense"),
+        (status = 404, description = "Not found"),
+        (status = 409, description = "Entry is not awaiting approval")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[post("/api/v1/financial/entries/{id}/approve")]
+pub async fn approve_financial_entry(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    cache: web::Data<CacheService>,
+    path: web::Path<uuid::Uuid>,
+) -> Result<HttpResponse, AppError> {
+    let ip_address = req.peer_addr().map(|a| a.ip().to_string());
+    let user_agent = req
+        .headers()
+        .get("user-agent")
+        .and_then(|v| v.to_str().ok())
+        .map(String::from);
+
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:approve")?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let user_id = middleware::get_user_id(&claims)?;
+    let entry_id = path.into_inner();
+
+    let existing = FinancialEntryService::get_by_id(pool.get_ref(), church_id, entry_id).await?;
+    if !middleware::can_access_congregation(&claims, existing.congregation_id) {
+        return Err(AppError::Forbidden(
+            "Sem permissão para aprovar despesas desta congregação".into(),
+        ));
+    }
+
+    let entry =
+        ExpenseApprovalService::approve(pool.get_ref(), church_id, user_id, entry_id).await?;
+
+    // Invalidate tither regularity cache
+    cache.del_pattern(&format!("tithes:*:{church_id}:*")).await;
+
+    // Audit log
+    AuditService::log(
+        pool.get_ref(),
+        church_id,
+        Some(user_id),
+        "approve",
+        "financial_entry",
+        entry_id,
+        Some(&serde_json::json!({ "status": existing.status, "amount": existing.amount })),
+        Some(&serde_json::json!({
+            "status": entry.status,
+            "registered_by": entry.registered_by,
+        })),
+        ip_address,
+        user_agent,
+    )
+    .await
+    .ok();
+
+    Ok(HttpResponse::Ok().json(ApiResponse::with_message(
+        entry,
+        "Despesa aprovada com sucesso",
+    )))
+}
+
+/// Reject an expense awaiting approval; it is cancelled (RN-FIN-020)
+#[utoipa::path(
+    post,
+    path = "/api/v1/financial/entries/{id}/reject",
+    params(("id" = uuid::Uuid, Path, description = "Entry ID")),
+    request_body = RejectExpenseRequest,
+    responses(
+        (status = 200, description = "Expense rejected"),
+        (status = 403, description = "Approver registered the expense"),
+        (status = 404, description = "Not found"),
+        (status = 409, description = "Entry is not awaiting approval")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[post("/api/v1/financial/entries/{id}/reject")]
+pub async fn reject_financial_entry(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<uuid::Uuid>,
+    body: web::Json<RejectExpenseRequest>,
+) -> Result<HttpResponse, AppError> {
+    let ip_address = req.peer_addr().map(|a| a.ip().to_string());
+    let user_agent = req
+        .headers()
+        .get("user-agent")
+        .and_then(|v| v.to_str().ok())
+        .map(String::from);
+
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:approve")?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let user_id = middleware::get_user_id(&claims)?;
+    let entry_id = path.into_inner();
+
+    body.validate()
+        .map_err(|e| AppError::validation(e.to_string()))?;
+
+    let existing = FinancialEntryService::get_by_id(pool.get_ref(), church_id, entry_id).await?;
+    if !middleware::can_access_congregation(&claims, existing.congregation_id) {
+        return Err(AppError::Forbidden(
+            "Sem permissão para rejeitar despesas desta congregação".into(),
+        ));
+    }
+
+    let entry = ExpenseApprovalService::reject(
+        pool.get_ref(),
+        church_id,
+        user_id,
+        entry_id,
+        &body.reason,
+    )
+    .await?;
+
+    // Audit log
+    AuditService::log(
+        pool.get_ref(),
+        church_id,
+        Some(user_id),
+        "reject",
+        "financial_entry",
+        entry_id,
+        Some(&serde_json::json!({ "status": existing.status, "amount": existing.amount })),
+        Some(&serde_json::json!({
+            "status": entry.status,
+            "registered_by": entry.registered_by,
+            "reason": body.reason,
+        })),
+        ip_address,
+        user_agent,
+    )
+    .await
+    .ok();
+
+    Ok(HttpResponse::Ok().json(ApiResponse::with_message(
+        entry,
+        "Despesa rejeitada",
+    )))
+}
+
+// ==========================================
+// Congregation Remittances
+// ==========================================
+
+/// Get the remittance rule of a congregation
+#[utoipa::path(
+    get,
+    path = "/api/v1/financial/congregations/{id}/remittance-rule",
+    params(("id" = uuid::Uuid, Path, description = "Congregation ID")),
+    responses(
+        (status = 200, description = "Remittance rule (null when not configured)"),
+        (status = 404, description = "Not found")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[get("/api/v1/financial/congregations/{id}/remittance-rule")]
+pub async fn get_remittance_rule(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<uuid::Uuid>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:read")?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let congregation_id = path.into_inner();
+
+    if !middleware::can_access_congregation(&claims, Some(congregation_id)) {
+        return Err(AppError::Forbidden(
+            "Sem permissão para ver o repasse desta congregação".into(),
+        ));
+    }
+
+    let rule = RemittanceService::get_rule(pool.get_ref(), church_id, congregation_id).await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::ok(rule)))
+}
+
+/// Set the percentage and base categories a congregation remits to the sede
+#[utoipa::path(
+    put,
+    path = "/api/v1/financial/congregations/{id}/remittance-rule",
+    params(("id" = uuid::Uuid, Path, description = "Congregation ID")),
+    request_body = UpdateRemittanceRuleRequest,
+    responses(
+        (status = 200, description = "Remittance rule saved"),
+        (status = 403, description = "Forbidden")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[put("/api/v1/financial/congregations/{id}/remittance-rule")]
+pub async fn update_remittance_rule(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<uuid::Uuid>,
+    body: web::Json<UpdateRemittanceRuleRequest>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:write")?;
+    if !middleware::can_access_congregation(&claims, None) {
+        return Err(AppError::Forbidden(
+            "Apenas a tesouraria da sede define regras de repasse".into(),
+        ));
+    }
+    let church_id = middleware::get_church_id(&claims)?;
+    let user_id = middleware::get_user_id(&claims)?;
+    let congregation_id = path.into_inner();
+
+    body.validate()
+        .map_err(|e| AppError::validation(e.to_string()))?;
+
+    let rule =
+        RemittanceService::set_rule(pool.get_ref(), church_id, congregation_id, &body).await?;
+
+    // Audit log
+    AuditService::log_action(
+        pool.get_ref(), church_id, Some(user_id), "update_remittance_rule", "congregation", congregation_id,
+    ).await.ok();
+
+    Ok(HttpResponse::Ok().json(ApiResponse::with_message(
+        rule,
+        "Regra de repasse salva com sucesso",
+    )))
+}
+
+/// Calculate the remittances of a month (pending ones are recalculated)
+#[utoipa::path(
+    post,
+    path = "/api/v1/financial/remittances/generate",
+    request_body = GenerateRemittancesRequest,
+    responses(
+        (status = 200, description = "Remittances calculated"),
+        (status = 403, description = "Forbidden")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[post("/api/v1/financial/remittances/generate")]
+pub async fn generate_remittances(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    body: web::Json<GenerateRemittancesRequest>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:write")?;
+    let church_id = middleware::get_church_id(&claims)?;
+
+    body.validate()
+        .map_err(|e| AppError::validation(e.to_string()))?;
+
+    if !middleware::can_access_congregation(&claims, body.congregation_id) {
+        return Err(AppError::Forbidden(
+            "Sem permissão para calcular repasses neste escopo".into(),
+        ));
+    }
+
+    let remittances = RemittanceService::generate(pool.get_ref(), church_id, &body).await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::with_message(
+        remittances,
+        "Repasses calculados com sucesso",
+    )))
+}
+
+/// List congregation remittances
+#[utoipa::path(
+    get,
+    path = "/api/v1/financial/remittances",
+    params(
+        ("page" = Option<i64>, Query, description = "Page number"),
+        ("per_page" = Option<i64>, Query, description = "Items per page"),
+        ("congregation_id" = Option<uuid::Uuid>, Query, description = "Remittances of a congregation"),
+        ("status" = Option<String>, Query, description = "pendente or pago"),
+    ),
+    responses(
+        (status = 200, description = "List of remittances"),
+        (status = 401, description = "Not authenticated")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[get("/api/v1/financial/remittances")]
+pub async fn list_remittances(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    pagination: web::Query<PaginationParams>,
+    filter: web::Query<RemittanceFilter>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:read")?;
+    let church_id = middleware::get_church_id(&claims)?;
+
+    let allowed = middleware::get_allowed_congregations(&claims);
+    if let (Some(allowed), Some(cid)) = (&allowed, filter.congregation_id) {
+        if !allowed.contains(&cid) {
+            return Err(AppError::Forbidden(
+                "Sem permissão para ver repasses desta congregação".into(),
+            ));
+        }
+    }
+
+    let (remittances, total) = RemittanceService::list(
+        pool.get_ref(),
+        church_id,
+        &filter,
+        allowed.as_deref(),
+        pagination.per_page(),
+        pagination.offset(),
+    )
+    .await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::paginated(
+        remittances,
+        pagination.page(),
+        pagination.per_page(),
+        total,
+    )))
+}
+
+/// Pending remittances per congregation
+#[utoipa::path(
+    get,
+    path = "/api/v1/financial/remittances/outstanding",
+    responses(
+        (status = 200, description = "Outstanding remittances per congregation"),
+        (status = 401, description = "Not authenticated")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[get("/api/v1/financial/remittances/outstanding")]
+pub async fn outstanding_remittances(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:read")?;
+    let church_id = middleware::get_church_id(&claims)?;
+
+    let allowed = middleware::get_allowed_congregations(&claims);
+    let report =
+        RemittanceService::outstanding(pool.get_ref(), church_id, allowed.as_deref()).await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::ok(report)))
+}
+
+/// Pay a remittance: expense on the congregation, income on the sede
+#[utoipa::path(
+    post,
+    path = "/api/v1/financial/remittances/{id}/pay",
+    params(("id" = uuid::Uuid, Path, description = "Remittance ID")),
+    request_body = PayRemittanceRequest,
+    responses(
+        (status = 200, description = "Remittance paid"),
+        (status = 409, description = "Remittance already paid")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[post("/api/v1/financial/remittances/{id}/pay")]
+pub async fn pay_remittance(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<uuid::Uuid>,
+    body: web::Json<PayRemittanceRequest>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:write")?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let user_id = middleware::get_user_id(&claims)?;
+    let remittance_id = path.into_inner();
+
+    body.validate()
+        .map_err(|e| AppError::validation(e.to_string()))?;
+
+    let existing = RemittanceService::get_by_id(pool.get_ref(), church_id, remittance_id).await?;
+    if !middleware::can_access_congregation(&claims, Some(existing.congregation_id)) {
+        return Err(AppError::Forbidden(
+            "Sem permissão para pagar repasse desta congregação".into(),
+        ));
+    }
+
+    let remittance =
+        RemittanceService::pay(pool.get_ref(), church_id, user_id, remittance_id, &body).await?;
+
+    // Audit log
+    AuditService::log_action(
+        pool.get_ref(), church_id, Some(user_id), "pay", "congregation_remittance", remittance_id,
+    ).await.ok();
+
+    Ok(HttpResponse::Ok().json(ApiResponse::with_message(
+        remittance,
+        "Repasse pago com sucesso",
+    )))
+}
+
 // ==========================================
 // Balance Report
 // ==========================================
@@ -658,6 +4284,203 @@ pub async fn balance_report(
     Ok(HttpResponse::Ok().json(ApiResponse::ok(report)))
 }
 
+/// Assembly accountability report (RN-FIN-011)
+#[utoipa::path(
+    get,
+    path = "/api/v1/financial/reports/assembly",
+    params(
+        ("month" = Option<String>, Query, description = "Single month (YYYY-MM-01)"),
+        ("month_from" = Option<String>, Query, description = "First month of the period"),
+        ("month_to" = Option<String>, Query, description = "Last month of the period"),
+        ("congregation_id" = Option<uuid::Uuid>, Query, description = "Only entries of a congregation"),
+        ("format" = Option<String>, Query, description = "json (default) or pdf"),
+    ),
+    responses(
+        (status = 200, description = "Previous balance, income and expense by category and final balance"),
+        (status = 400, description = "Invalid period")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[get("/api/v1/financial/reports/assembly")]
+pub async fn assembly_report(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    filter: web::Query<AssemblyReportFilter>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:read")?;
+    let church_id = middleware::get_church_id(&claims)?;
+
+    let (month_from, month_to) = match (filter.month, filter.month_from, filter.month_to) {
+        (Some(month), None, None) => (month, month),
+        (None, Some(from), Some(to)) => (from, to),
+        _ => {
+            return Err(AppError::validation(
+                "Informe 'month' ou o período 'month_from' e 'month_to'",
+            ))
+        }
+    };
+    let month_from = month_from.with_day(1).unwrap_or(month_from);
+    let month_to = month_to.with_day(1).unwrap_or(month_to);
+    if month_from > month_to {
+        return Err(AppError::validation(
+            "'month_from' deve ser anterior ou igual a 'month_to'",
+        ));
+    }
+
+    let mut congregation_filter = filter.congregation_id;
+
+    // Enforce congregation scope: if user has restricted access, use their first allowed congregation
+    if let Some(allowed) = middleware::get_allowed_congregations(&claims) {
+        match congregation_filter {
+            Some(cid) if !allowed.contains(&cid) => {
+                return Err(AppError::Forbidden(
+                    "Sem permissão para ver o relatório desta congregação".into(),
+                ));
+            }
+            None if !allowed.is_empty() => {
+                congregation_filter = Some(allowed[0]);
+            }
+            None => {
+                return Err(AppError::Forbidden(
+                    "Sem permissão para ver o relatório".into(),
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    match filter.format.as_deref() {
+        None | Some("json") => {
+            let report = FinancialReportService::assembly_report(
+                pool.get_ref(),
+                church_id,
+                congregation_filter,
+                month_from,
+                month_to,
+            )
+            .await?;
+            Ok(HttpResponse::Ok().json(ApiResponse::ok(report)))
+        }
+        Some("pdf") => {
+            let pdf = FinancialReportService::assembly_report_pdf(
+                pool.get_ref(),
+                church_id,
+                congregation_filter,
+                month_from,
+                month_to,
+            )
+            .await?;
+            Ok(HttpResponse::Ok()
+                .content_type("application/pdf")
+                .insert_header((
+                    "Content-Disposition",
+                    format!(
+                        "attachment; filename=\"prestacao-contas-{}.pdf\"",
+                        month_from.format("%Y-%m")
+                    ),
+                ))
+                .body(pdf))
+        }
+        Some(_) => Err(AppError::validation("Formato inválido. Use json ou pdf")),
+    }
+}
+
+/// Hierarchical income statement (DRE) over the account plan tree
+#[utoipa::path(
+    get,
+    path = "/api/v1/financial/reports/income-statement",
+    params(
+        ("date_from" = String, Query, description = "First day of the period"),
+        ("date_to" = String, Query, description = "Last day of the period"),
+        ("congregation_id" = Option<uuid::Uuid>, Query, description = "Only entries of a congregation"),
+        ("by_month" = Option<bool>, Query, description = "Side-by-side month columns (at most 12 months)"),
+        ("format" = Option<String>, Query, description = "json (default), csv or pdf"),
+    ),
+    responses(
+        (status = 200, description = "Income and expense rolled up by account plan code"),
+        (status = 400, description = "Invalid period")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[get("/api/v1/financial/reports/income-statement")]
+pub async fn income_statement_report(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    filter: web::Query<IncomeStatementFilter>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:read")?;
+    let church_id = middleware::get_church_id(&claims)?;
+
+    let mut congregation_filter = filter.congregation_id;
+
+    // Enforce congregation scope: if user has restricted access, use their first allowed congregation
+    if let Some(allowed) = middleware::get_allowed_congregations(&claims) {
+        match congregation_filter {
+            Some(cid) if !allowed.contains(&cid) => {
+                return Err(AppError::Forbidden(
+                    "Sem permissão para ver o relatório desta congregação".into(),
+                ));
+            }
+            None if !allowed.is_empty() => {
+                congregation_filter = Some(allowed[0]);
+            }
+            None => {
+                return Err(AppError::Forbidden(
+                    "Sem permissão para ver o relatório".into(),
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    let statement = FinancialReportService::income_statement(
+        pool.get_ref(),
+        church_id,
+        congregation_filter,
+        filter.date_from,
+        filter.date_to,
+        filter.by_month.unwrap_or(false),
+    )
+    .await?;
+
+    let filename = format!(
+        "dre-{}-{}",
+        filter.date_from.format("%Y-%m-%d"),
+        filter.date_to.format("%Y-%m-%d")
+    );
+
+    match filter.format.as_deref() {
+        None | Some("json") => Ok(HttpResponse::Ok().json(ApiResponse::ok(statement))),
+        Some("csv") => {
+            let csv = FinancialReportService::income_statement_csv(&statement)?;
+            Ok(HttpResponse::Ok()
+                .content_type("text/csv; charset=utf-8")
+                .insert_header((
+                    "Content-Disposition",
+                    format!("attachment; filename=\"{filename}.csv\""),
+                ))
+                .body(csv))
+        }
+        Some("pdf") => {
+            let pdf =
+                FinancialReportService::income_statement_pdf(pool.get_ref(), church_id, &statement)
+                    .await?;
+            Ok(HttpResponse::Ok()
+                .content_type("application/pdf")
+                .insert_header((
+                    "Content-Disposition",
+                    format!("attachment; filename=\"{filename}.pdf\""),
+                ))
+                .body(pdf))
+        }
+        Some(_) => Err(AppError::validation("Formato inválido. Use json, csv ou pdf")),
+    }
+}
+
 // ==========================================
 // Monthly Closings
 // ==========================================
@@ -669,6 +4492,7 @@ pub async fn balance_report(
     params(
         ("page" = Option<i64>, Query, description = "Page number"),
         ("per_page" = Option<i64>, Query, description = "Items per page"),
+        ("congregation_id" = Option<uuid::Uuid>, Query, description = "Closings of a congregation"),
     ),
     responses(
         (status = 200, description = "List of monthly closings"),
@@ -682,14 +4506,38 @@ pub async fn list_monthly_closings(
     pool: web::Data<PgPool>,
     config: web::Data<AppConfig>,
     pagination: web::Query<PaginationParams>,
+    filter: web::Query<MonthlyClosingFilter>,
 ) -> Result<HttpResponse, AppError> {
     let claims = middleware::auth_middleware(req, config).await?;
     middleware::require_permission(&claims, "financial:read")?;
     let church_id = middleware::get_church_id(&claims)?;
 
+    let mut congregation_filter = filter.congregation_id;
+
+    // Enforce congregation scope: if user has restricted access, use their first allowed congregation
+    if let Some(allowed) = middleware::get_allowed_congregations(&claims) {
+        match congregation_filter {
+            Some(cid) if !allowed.contains(&cid) => {
+                return Err(AppError::Forbidden(
+                    "Sem permissão para ver fechamentos desta congregação".into(),
+                ));
+            }
+            None if !allowed.is_empty() => {
+                congregation_filter = Some(allowed[0]);
+            }
+            None => {
+                return Err(AppError::Forbidden(
+                    "Sem permissão para ver fechamentos".into(),
+                ));
+            }
+            _ => {}
+        }
+    }
+
     let (closings, total) = MonthlyClosingService::list(
         pool.get_ref(),
         church_id,
+        congregation_filter,
         pagination.per_page(),
         pagination.offset(),
     )
@@ -703,13 +4551,48 @@ pub async fn list_monthly_closings(
     )))
 }
 
-/// Perform monthly closing
+/// Get a monthly closing with its bank account balances
+#[utoipa::path(
+    get,
+    path = "/api/v1/financial/monthly-closings/{id}",
+    params(("id" = uuid::Uuid, Path, description = "Monthly closing ID")),
+    responses(
+        (status = 200, description = "Monthly closing with bank balances"),
+        (status = 404, description = "Not found")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[get("/api/v1/financial/monthly-closings/{id}")]
+pub async fn get_monthly_closing(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<uuid::Uuid>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:read")?;
+    let church_id = middleware::get_church_id(&claims)?;
+
+    let closing =
+        MonthlyClosingService::get_by_id(pool.get_ref(), church_id, path.into_inner()).await?;
+
+    if !middleware::can_access_congregation(&claims, closing.closing.congregation_id) {
+        return Err(AppError::Forbidden(
+            "Sem permissão para ver fechamentos desta congregação".into(),
+        ));
+    }
+
+    Ok(HttpResponse::Ok().json(ApiResponse::ok(closing)))
+}
+
+/// Perform monthly closing of a congregation or of the whole church
 #[utoipa::path(
     post,
     path = "/api/v1/financial/monthly-closings",
     request_body = MonthlyClosingRequest,
     responses(
         (status = 201, description = "Month closed successfully"),
+        (status = 400, description = "Congregations still open (church-wide closing)"),
         (status = 409, description = "Month already closed")
     ),
     security(("bearer_auth" = []))
@@ -729,11 +4612,307 @@ pub async fn create_monthly_closing(
     body.validate()
         .map_err(|e| AppError::validation(e.to_string()))?;
 
+    // Congregation treasurers close only their own books; church-wide needs global scope
+    if !middleware::can_access_congregation(&claims, body.congregation_id) {
+        return Err(AppError::Forbidden(
+            "Sem permissão para fechar o mês neste escopo".into(),
+        ));
+    }
+
     let closing =
         MonthlyClosingService::close_month(pool.get_ref(), church_id, user_id, &body).await?;
 
+    // Audit log
+    AuditService::log_action(
+        pool.get_ref(), church_id, Some(user_id), "close", "monthly_closing", closing.closing.id,
+    ).await.ok();
+
     Ok(HttpResponse::Created().json(ApiResponse::with_message(
         closing,
         "Fechamento mensal realizado com sucesso",
     )))
 }
+
+/// Undo a monthly closing (super admin only, RN-FIN-007)
+#[utoipa::path(
+    post,
+    path = "/api/v1/financial/monthly-closings/{id}/reopen",
+    params(("id" = uuid::Uuid, Path, description = "Monthly closing ID")),
+    request_body = ReopenMonthlyClosingRequest,
+    responses(
+        (status = 200, description = "Month reopened; later closings recomputed"),
+        (status = 403, description = "Only super admin can undo a closing"),
+        (status = 404, description = "Not found")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[post("/api/v1/financial/monthly-closings/{id}/reopen")]
+pub async fn reopen_monthly_closing(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<uuid::Uuid>,
+    body: web::Json<ReopenMonthlyClosingRequest>,
+) -> Result<HttpResponse, AppError> {
+    let ip_address = req.peer_addr().map(|a| a.ip().to_string());
+    let user_agent = req
+        .headers()
+        .get("user-agent")
+        .and_then(|v| v.to_str().ok())
+        .map(String::from);
+
+    let claims = middleware::auth_middleware(req, config).await?;
+    if claims.role != "super_admin" {
+        return Err(AppError::Forbidden(
+            "Apenas o super admin pode desfazer um fechamento mensal".into(),
+        ));
+    }
+    let church_id = middleware::get_church_id(&claims)?;
+    let user_id = middleware::get_user_id(&claims)?;
+    let closing_id = path.into_inner();
+
+    body.validate()
+        .map_err(|e| AppError::validation(e.to_string()))?;
+
+    let closing = MonthlyClosingService::reopen(pool.get_ref(), church_id, closing_id).await?;
+
+    // Audit log
+    AuditService::log(
+        pool.get_ref(),
+        church_id,
+        Some(user_id),
+        "reopen",
+        "monthly_closing",
+        closing_id,
+        Some(&closing),
+        Some(&serde_json::json!({
+            "reference_month": closing.reference_month,
+            "reason": body.reason,
+        })),
+        ip_address,
+        user_agent,
+    )
+    .await
+    .ok();
+
+    Ok(HttpResponse::Ok().json(ApiResponse::with_message(
+        closing,
+        "Fechamento mensal desfeito com sucesso",
+    )))
+}
+
+// ==========================================
+// Accounting Export
+// ==========================================
+
+/// List accounting export layouts
+#[utoipa::path(
+    get,
+    path = "/api/v1/financial/export-layouts",
+    responses(
+        (status = 200, description = "Export layouts"),
+        (status = 401, description = "Not authenticated")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[get("/api/v1/financial/export-layouts")]
+pub async fn list_export_layouts(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:read")?;
+    let church_id = middleware::get_church_id(&claims)?;
+
+    let layouts = AccountingExportService::list_layouts(pool.get_ref(), church_id).await?;
+
+    Ok(HttpResponse::Ok().json(ApiResponse::ok(layouts)))
+}
+
+/// Create an accounting export layout
+#[utoipa::path(
+    post,
+    path = "/api/v1/financial/export-layouts",
+    request_body = CreateExportLayoutRequest,
+    responses(
+        (status = 201, description = "Export layout created"),
+        (status = 400, description = "Unknown column field or invalid delimiter"),
+        (status = 409, description = "Layout name already used")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[post("/api/v1/financial/export-layouts")]
+pub async fn create_export_layout(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    body: web::Json<CreateExportLayoutRequest>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:close")?;
+    let church_id = middleware::get_church_id(&claims)?;
+
+    body.validate()
+        .map_err(|e| AppError::validation(e.to_string()))?;
+
+    let layout = AccountingExportService::create_layout(pool.get_ref(), church_id, &body).await?;
+
+    // Audit log
+    let user_id = middleware::get_user_id(&claims)?;
+    AuditService::log_action(
+        pool.get_ref(), church_id, Some(user_id), "create", "accounting_export_layout", layout.id,
+    ).await.ok();
+
+    Ok(HttpResponse::Created().json(ApiResponse::with_message(
+        layout,
+        "Layout de exportação criado com sucesso",
+    )))
+}
+
+/// Update an accounting export layout
+#[utoipa::path(
+    put,
+    path = "/api/v1/financial/export-layouts/{id}",
+    params(("id" = uuid::Uuid, Path, description = "Export layout ID")),
+    request_body = UpdateExportLayoutRequest,
+    responses(
+        (status = 200, description = "Export layout updated"),
+        (status = 404, description = "Not found")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[put("/api/v1/financial/export-layouts/{id}")]
+pub async fn update_export_layout(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<uuid::Uuid>,
+    body: web::Json<UpdateExportLayoutRequest>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:close")?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let layout_id = path.into_inner();
+
+    body.validate()
+        .map_err(|e| AppError::validation(e.to_string()))?;
+
+    let layout =
+        AccountingExportService::update_layout(pool.get_ref(), church_id, layout_id, &body)
+            .await?;
+
+    // Audit log
+    let user_id = middleware::get_user_id(&claims)?;
+    AuditService::log_action(
+        pool.get_ref(), church_id, Some(user_id), "update", "accounting_export_layout", layout_id,
+    ).await.ok();
+
+    Ok(HttpResponse::Ok().json(ApiResponse::with_message(
+        layout,
+        "Layout de exportação atualizado com sucesso",
+    )))
+}
+
+/// Delete an accounting export layout
+#[utoipa::path(
+    delete,
+    path = "/api/v1/financial/export-layouts/{id}",
+    params(("id" = uuid::Uuid, Path, description = "Export layout ID")),
+    responses(
+        (status = 200, description = "Export layout deleted"),
+        (status = 404, description = "Not found")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[delete("/api/v1/financial/export-layouts/{id}")]
+pub async fn delete_export_layout(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<uuid::Uuid>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:close")?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let layout_id = path.into_inner();
+
+    AccountingExportService::delete_layout(pool.get_ref(), church_id, layout_id).await?;
+
+    // Audit log
+    let user_id = middleware::get_user_id(&claims)?;
+    AuditService::log_action(
+        pool.get_ref(), church_id, Some(user_id), "delete", "accounting_export_layout", layout_id,
+    ).await.ok();
+
+    Ok(HttpResponse::Ok().json(ApiResponse::ok(serde_json::json!({
+        "message": "Layout de exportação excluído com sucesso"
+    }))))
+}
+
+/// Export a closed month for the accountant (RN-FIN-019)
+#[utoipa::path(
+    get,
+    path = "/api/v1/financial/monthly-closings/{id}/accounting-export",
+    params(
+        ("id" = uuid::Uuid, Path, description = "Monthly closing ID"),
+        ("format" = Option<String>, Query, description = "csv (default) or xlsx"),
+        ("layout_id" = Option<uuid::Uuid>, Query, description = "Saved layout; omitted = general ledger journal"),
+    ),
+    responses(
+        (status = 200, description = "Export file"),
+        (status = 404, description = "Not found"),
+        (status = 409, description = "Entries no longer match the closing totals")
+    ),
+    security(("bearer_auth" = []))
+)]
+#[get("/api/v1/financial/monthly-closings/{id}/accounting-export")]
+pub async fn accounting_export(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    path: web::Path<uuid::Uuid>,
+    query: web::Query<AccountingExportParams>,
+) -> Result<HttpResponse, AppError> {
+    let claims = middleware::auth_middleware(req, config).await?;
+    middleware::require_permission(&claims, "financial:read")?;
+    let church_id = middleware::get_church_id(&claims)?;
+    let closing_id = path.into_inner();
+
+    let format = query.format.as_deref().unwrap_or("csv");
+    let content_type = match format {
+        "csv" => "text/csv; charset=utf-8",
+        "xlsx" => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        _ => return Err(AppError::validation("Formato inválido. Use csv ou xlsx")),
+    };
+
+    let closing = MonthlyClosingService::get_by_id(pool.get_ref(), church_id, closing_id).await?;
+    if !middleware::can_access_congregation(&claims, closing.closing.congregation_id) {
+        return Err(AppError::Forbidden(
+            "Sem permissão para exportar fechamentos desta congregação".into(),
+        ));
+    }
+
+    let (filename, bytes) = AccountingExportService::export(
+        pool.get_ref(),
+        church_id,
+        closing_id,
+        query.layout_id,
+        format,
+    )
+    .await?;
+
+    // Audit log
+    let user_id = middleware::get_user_id(&claims)?;
+    AuditService::log_action(
+        pool.get_ref(), church_id, Some(user_id), "export", "monthly_closing", closing_id,
+    ).await.ok();
+
+    Ok(HttpResponse::Ok()
+        .content_type(content_type)
+        .insert_header((
+            "Content-Disposition",
+            format!("attachment; filename=\"{filename}\""),
+        ))
+        .body(bytes))
+}
@@ -12,3 +12,4 @@ pub mod member_history_handler;
 pub mod ministry_handler;
 pub mod upload_handler;
 pub mod user_handler;
+pub mod webhook_handler;
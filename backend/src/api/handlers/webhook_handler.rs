@@ -0,0 +1,72 @@
+use actix_web::{post, web, HttpRequest, HttpResponse};
+use sqlx::PgPool;
+
+use crate::api::response::ApiResponse;
+use crate::application::services::PaymentWebhookService;
+use crate::config::AppConfig;
+use crate::errors::AppError;
+use crate::infrastructure::cache::CacheService;
+use crate::infrastructure::payment_provider;
+
+/// Payment notification from a provider (PIX or card).
+///
+/// Public endpoint: authenticated by the HMAC signature of the raw body with the
+/// secret configured in the church's payment integration.
+#[utoipa::path(
+    post,
+    path = "/api/v1/webhooks/payments/{provider}/{church_id}",
+    params(
+        ("provider" = String, Path, description = "Provider name (pix, mock)"),
+        ("church_id" = uuid::Uuid, Path, description = "Church ID"),
+    ),
+    request_body(content = String, description = "Provider payload, signed with HMAC-SHA256", content_type = "application/json"),
+    responses(
+        (status = 200, description = "Notification processed"),
+        (status = 401, description = "Invalid signature"),
+        (status = 404, description = "Provider not configured for the church")
+    )
+)]
+#[post("/api/v1/webhooks/payments/{provider}/{church_id}")]
+pub async fn payment_webhook(
+    req: HttpRequest,
+    pool: web::Data<PgPool>,
+    config: web::Data<AppConfig>,
+    cache: web::Data<CacheService>,
+    path: web::Path<(String, uuid::Uuid)>,
+    body: web::Bytes,
+) -> Result<HttpResponse, AppError> {
+    let (provider_name, church_id) = path.into_inner();
+
+    let provider = payment_provider::provider(&provider_name, config.payment_mock_provider)
+        .ok_or_else(|| AppError::not_found("Provedor de pagamento"))?;
+
+    let signature = req
+        .headers()
+        .get(provider.signature_header())
+        .and_then(|v| v.to_str().ok());
+
+    let result = PaymentWebhookService::ingest(
+        pool.get_ref(),
+        church_id,
+        provider.as_ref(),
+        signature,
+        &body,
+    )
+    .await?;
+
+    if result.created > 0 {
+        // Invalidate tither regularity cache
+        cache.del_pattern(&format!("tithes:*:{church_id}:*")).await;
+    }
+
+    if result.created + result.queued > 0 {
+        tracing::info!(
+            "Payment webhook ({provider_name}) for church {church_id}: {} created, {} queued, {} duplicates",
+            result.created,
+            result.queued,
+            result.duplicates
+        );
+    }
+
+    Ok(HttpResponse::Ok().json(ApiResponse::ok(result)))
+}
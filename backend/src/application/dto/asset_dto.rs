@@ -100,6 +100,8 @@ pub struct CreateMaintenanceRequest {
     pub maintenance_type: String,
     #[validate(length(min = 2, message = "Descrição é obrigatória"))]
     pub description: String,
+    /// Registered supplier; its name fills `supplier_name`
+    pub supplier_id: Option<Uuid>,
     pub supplier_name: Option<String>,
     pub cost: Option<Decimal>,
     pub scheduled_date: Option<NaiveDate>,
@@ -111,6 +113,7 @@ pub struct CreateMaintenanceRequest {
 #[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct UpdateMaintenanceRequest {
     pub description: Option<String>,
+    pub supplier_id: Option<Uuid>,
     pub supplier_name: Option<String>,
     pub cost: Option<Decimal>,
     pub scheduled_date: Option<NaiveDate>,
@@ -124,6 +127,7 @@ pub struct UpdateMaintenanceRequest {
 #[derive(Debug, Deserialize)]
 pub struct MaintenanceFilter {
     pub asset_id: Option<Uuid>,
+    pub supplier_id: Option<Uuid>,
     pub status: Option<String>,
     #[serde(rename = "type")]
     pub maintenance_type: Option<String>,
@@ -1,10 +1,13 @@
 use chrono::NaiveDate;
 use rust_decimal::Decimal;
 use serde::Deserialize;
+use std::collections::HashMap;
 use utoipa::ToSchema;
 use uuid::Uuid;
 use validator::Validate;
 
+use crate::domain::entities::ExportColumn;
+
 // ==========================================
 // Account Plans
 // ==========================================
@@ -21,6 +24,8 @@ pub struct CreateAccountPlanRequest {
     #[serde(rename = "type")]
     pub plan_type: String,
     pub level: Option<i16>,
+    /// Marks an income category as tithe (RN-FIN-006)
+    pub is_tithe: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, Validate, ToSchema)]
@@ -30,6 +35,7 @@ pub struct UpdateAccountPlanRequest {
     #[validate(length(min = 2, max = 100, message = "Nome deve ter entre 2 e 100 caracteres"))]
     pub name: Option<String>,
     pub is_active: Option<bool>,
+    pub is_tithe: Option<bool>,
 }
 
 // ==========================================
@@ -47,6 +53,9 @@ pub struct CreateBankAccountRequest {
     pub bank_name: Option<String>,
     pub agency: Option<String>,
     pub account_number: Option<String>,
+    /// "cpf", "cnpj", "email", "telefone" or "aleatoria"
+    pub pix_key_type: Option<String>,
+    pub pix_key: Option<String>,
     pub initial_balance: Option<Decimal>,
 }
 
@@ -57,9 +66,85 @@ pub struct UpdateBankAccountRequest {
     pub bank_name: Option<String>,
     pub agency: Option<String>,
     pub account_number: Option<String>,
+    pub pix_key_type: Option<String>,
+    /// Empty string removes the PIX key
+    pub pix_key: Option<String>,
+    pub is_active: Option<bool>,
+}
+
+/// Statement period of a bank account; defaults to the current month up to today
+#[derive(Debug, Deserialize)]
+pub struct BankAccountStatementParams {
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+    /// "json" (default), "csv" or "pdf"
+    pub format: Option<String>,
+}
+
+/// Static PIX BR Code for a bank account
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreatePixChargeRequest {
+    /// Omitted = the payer types the amount
+    pub amount: Option<Decimal>,
+    #[validate(length(max = 100, message = "Descrição deve ter no máximo 100 caracteres"))]
+    pub description: Option<String>,
+    /// Income category the credits belong to (e.g. Dízimos)
+    pub account_plan_id: Option<Uuid>,
+    pub campaign_id: Option<Uuid>,
+    pub congregation_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PixChargeFilter {
+    pub bank_account_id: Option<Uuid>,
+    pub campaign_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PixQrParams {
+    /// "png" (default) or "svg"
+    pub format: Option<String>,
+    /// Minimum size in pixels (default 400)
+    pub size: Option<u32>,
+}
+
+// ==========================================
+// Payment Providers
+// ==========================================
+
+/// Configure a payment provider webhook
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdatePaymentIntegrationRequest {
+    /// HMAC secret shared with the provider; required when creating
+    #[validate(length(min = 16, max = 200, message = "Segredo deve ter entre 16 e 200 caracteres"))]
+    pub webhook_secret: Option<String>,
     pub is_active: Option<bool>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct PaymentNotificationFilter {
+    /// "pendente_revisao", "lancado" or "descartado"
+    pub status: Option<String>,
+    pub provider: Option<String>,
+}
+
+/// Post a queued payment as a confirmed income entry
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct ResolvePaymentNotificationRequest {
+    pub account_plan_id: Uuid,
+    pub bank_account_id: Uuid,
+    pub campaign_id: Option<Uuid>,
+    pub member_id: Option<Uuid>,
+    pub congregation_id: Option<Uuid>,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct DiscardPaymentNotificationRequest {
+    #[validate(length(min = 3, max = 300, message = "Motivo deve ter entre 3 e 300 caracteres"))]
+    pub reason: String,
+}
+
 // ==========================================
 // Campaigns
 // ==========================================
@@ -85,6 +170,27 @@ pub struct UpdateCampaignRequest {
     pub status: Option<String>,
 }
 
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateCampaignPledgeRequest {
+    pub member_id: Uuid,
+    pub pledged_amount: Decimal,
+    /// Monthly installments (1–120, default 1)
+    #[validate(range(min = 1, max = 120, message = "Parcelas devem estar entre 1 e 120"))]
+    pub installments: Option<i16>,
+    /// First installment month (default: today)
+    pub start_date: Option<NaiveDate>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateCampaignPledgeRequest {
+    pub pledged_amount: Option<Decimal>,
+    #[validate(range(min = 1, max = 120, message = "Parcelas devem estar entre 1 e 120"))]
+    pub installments: Option<i16>,
+    pub start_date: Option<NaiveDate>,
+    pub notes: Option<String>,
+}
+
 // ==========================================
 // Financial Entries
 // ==========================================
@@ -95,10 +201,15 @@ pub struct CreateFinancialEntryRequest {
     #[validate(length(min = 1, message = "Tipo é obrigatório"))]
     #[serde(rename = "type")]
     pub entry_type: String,
-    pub account_plan_id: Uuid,
+    /// Required unless the entry is split into `allocations`
+    pub account_plan_id: Option<Uuid>,
     pub bank_account_id: Uuid,
     pub campaign_id: Option<Uuid>,
     pub amount: Decimal,
+    /// Split entry: two or more lines adding up to `amount`; category, campaign
+    /// and member then go on each line
+    #[validate(nested)]
+    pub allocations: Option<Vec<EntryAllocationRequest>>,
     pub entry_date: NaiveDate,
     pub due_date: Option<NaiveDate>,
     pub payment_date: Option<NaiveDate>,
@@ -107,6 +218,9 @@ pub struct CreateFinancialEntryRequest {
     /// "dinheiro", "pix", "transferencia", "cartao_debito", "cartao_credito", "cheque", "boleto", "outro"
     pub payment_method: Option<String>,
     pub member_id: Option<Uuid>,
+    /// Registered supplier; its name fills `supplier_name`
+    pub supplier_id: Option<Uuid>,
+    /// Free text; linked to the supplier with the same name when there is one
     pub supplier_name: Option<String>,
     pub receipt_url: Option<String>,
     /// "pendente", "confirmado"
@@ -127,11 +241,501 @@ pub struct UpdateFinancialEntryRequest {
     pub description: Option<String>,
     pub payment_method: Option<String>,
     pub member_id: Option<Uuid>,
+    pub supplier_id: Option<Uuid>,
     pub supplier_name: Option<String>,
     pub receipt_url: Option<String>,
     pub status: Option<String>,
     pub notes: Option<String>,
     pub congregation_id: Option<Option<Uuid>>,
+    /// Replaces the allocation lines; an empty list turns the entry back into
+    /// a single-line entry
+    #[validate(nested)]
+    pub allocations: Option<Vec<EntryAllocationRequest>>,
+}
+
+/// One allocation line of a split entry
+#[derive(Debug, Clone, Deserialize, Validate, ToSchema)]
+pub struct EntryAllocationRequest {
+    pub account_plan_id: Uuid,
+    pub campaign_id: Option<Uuid>,
+    pub member_id: Option<Uuid>,
+    pub amount: Decimal,
+    #[validate(length(max = 200, message = "Descrição da linha deve ter no máximo 200 caracteres"))]
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct ReverseFinancialEntryRequest {
+    /// Mandatory reason for the reversal (RN-FIN-010)
+    #[validate(length(min = 3, max = 500, message = "Motivo deve ter entre 3 e 500 caracteres"))]
+    pub reason: String,
+}
+
+/// Pays a pending expense: confirms it and moves the bank balance
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct PayFinancialEntryRequest {
+    /// Defaults to today
+    pub payment_date: Option<NaiveDate>,
+    pub payment_method: Option<String>,
+    /// Pay from another bank account than the one planned
+    pub bank_account_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AccountsPayableFilter {
+    pub congregation_id: Option<Uuid>,
+}
+
+// ==========================================
+// Transfers
+// ==========================================
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateTransferRequest {
+    pub from_bank_account_id: Uuid,
+    pub to_bank_account_id: Uuid,
+    pub amount: Decimal,
+    pub transfer_date: NaiveDate,
+    #[validate(length(max = 500, message = "Descrição deve ter no máximo 500 caracteres"))]
+    pub description: Option<String>,
+    /// "dinheiro", "pix", "transferencia", ...
+    pub payment_method: Option<String>,
+    pub notes: Option<String>,
+    pub congregation_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TransferFilter {
+    pub bank_account_id: Option<Uuid>,
+    pub date_from: Option<NaiveDate>,
+    pub date_to: Option<NaiveDate>,
+}
+
+// ==========================================
+// Recurring Entries
+// ==========================================
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateRecurrenceRequest {
+    /// "receita" or "despesa"
+    #[validate(length(min = 1, message = "Tipo é obrigatório"))]
+    #[serde(rename = "type")]
+    pub entry_type: String,
+    pub account_plan_id: Uuid,
+    pub bank_account_id: Uuid,
+    pub campaign_id: Option<Uuid>,
+    pub amount: Decimal,
+    #[validate(length(min = 1, message = "Descrição é obrigatória"))]
+    pub description: String,
+    pub payment_method: Option<String>,
+    pub member_id: Option<Uuid>,
+    pub supplier_name: Option<String>,
+    pub congregation_id: Option<Uuid>,
+    pub notes: Option<String>,
+    /// "mensal" (default), "bimestral", "trimestral", "semestral", "anual"
+    pub frequency: Option<String>,
+    /// Due day; clamped to the last day in shorter months
+    #[validate(range(min = 1, max = 31, message = "Dia do vencimento deve estar entre 1 e 31"))]
+    pub day_of_month: i16,
+    pub start_date: NaiveDate,
+    pub end_date: Option<NaiveDate>,
+    /// Days before the due date to generate the entry (default 30)
+    #[validate(range(min = 0, max = 365, message = "Antecedência deve estar entre 0 e 365 dias"))]
+    pub lead_days: Option<i16>,
+}
+
+/// Changes the template and every pending occurrence due on or after `apply_from`
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateRecurrenceRequest {
+    pub account_plan_id: Option<Uuid>,
+    pub bank_account_id: Option<Uuid>,
+    pub amount: Option<Decimal>,
+    #[validate(length(min = 1, message = "Descrição é obrigatória"))]
+    pub description: Option<String>,
+    pub payment_method: Option<String>,
+    pub supplier_name: Option<String>,
+    pub notes: Option<String>,
+    pub frequency: Option<String>,
+    #[validate(range(min = 1, max = 31, message = "Dia do vencimento deve estar entre 1 e 31"))]
+    pub day_of_month: Option<i16>,
+    pub end_date: Option<NaiveDate>,
+    /// Removes the end date (recurrence runs indefinitely)
+    pub no_end_date: Option<bool>,
+    #[validate(range(min = 0, max = 365, message = "Antecedência deve estar entre 0 e 365 dias"))]
+    pub lead_days: Option<i16>,
+    /// First due date affected (defaults to today). Use an occurrence's due_date
+    /// to change "this and all following" occurrences.
+    pub apply_from: Option<NaiveDate>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CancelRecurrenceParams {
+    /// First due date cancelled (defaults to today)
+    pub from: Option<NaiveDate>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RecurrenceFilter {
+    #[serde(rename = "type")]
+    pub entry_type: Option<String>,
+    pub is_active: Option<bool>,
+    pub congregation_id: Option<Uuid>,
+}
+
+// ==========================================
+// Installment Plans
+// ==========================================
+
+/// Expense paid in monthly installments; each installment becomes a pending entry
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateInstallmentPlanRequest {
+    pub account_plan_id: Uuid,
+    pub bank_account_id: Uuid,
+    #[validate(length(min = 1, message = "Descrição é obrigatória"))]
+    pub description: String,
+    /// Usually "boleto" or "cartao_credito"
+    pub payment_method: Option<String>,
+    pub supplier_id: Option<Uuid>,
+    pub supplier_name: Option<String>,
+    pub congregation_id: Option<Uuid>,
+    pub notes: Option<String>,
+    pub purchase_date: NaiveDate,
+    /// Split evenly; the cents left over go to the first installment
+    pub total_amount: Decimal,
+    #[validate(range(min = 2, max = 120, message = "Número de parcelas deve estar entre 2 e 120"))]
+    pub installment_count: i16,
+    /// The following installments fall due on the same day of the next months
+    pub first_due_date: NaiveDate,
+}
+
+/// Replaces the open installments with a new schedule; paid ones are kept
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct RenegotiateInstallmentsRequest {
+    #[validate(range(min = 1, max = 120, message = "Número de parcelas deve estar entre 1 e 120"))]
+    pub installment_count: i16,
+    pub first_due_date: NaiveDate,
+    /// New amount still owed, e.g. with interest or a discount (defaults to the open amount)
+    pub open_amount: Option<Decimal>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct InstallmentPlanFilter {
+    pub supplier_id: Option<Uuid>,
+    pub congregation_id: Option<Uuid>,
+    /// Plans with (true) or without (false) open installments
+    pub open: Option<bool>,
+}
+
+// ==========================================
+// Offering Counts
+// ==========================================
+
+/// Number of bills or coins of one value (R$ 0,01 up to R$ 200,00)
+#[derive(Debug, Clone, Deserialize, Validate, ToSchema)]
+pub struct OfferingDenominationRequest {
+    pub denomination: Decimal,
+    #[validate(range(min = 1, message = "Quantidade deve ser maior que zero"))]
+    pub quantity: i32,
+}
+
+/// Envelope found in the box: a member's tithe or a visitor's contribution
+#[derive(Debug, Clone, Deserialize, Validate, ToSchema)]
+pub struct OfferingEnvelopeRequest {
+    pub member_id: Option<Uuid>,
+    /// Required without `member_id`
+    #[validate(length(max = 200, message = "Nome deve ter no máximo 200 caracteres"))]
+    pub contributor_name: Option<String>,
+    /// Defaults to the sheet's `tithe_account_plan_id`
+    pub account_plan_id: Option<Uuid>,
+    pub amount: Decimal,
+    /// "dinheiro" (default, counted with the bills and coins) or "cheque"
+    pub payment_method: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateOfferingCountRequest {
+    pub congregation_id: Option<Uuid>,
+    pub service_date: NaiveDate,
+    #[validate(length(max = 100, message = "Nome do culto deve ter no máximo 100 caracteres"))]
+    pub service_name: Option<String>,
+    pub bank_account_id: Uuid,
+    /// Category of the loose offering
+    pub offering_account_plan_id: Uuid,
+    /// Default category of the envelopes
+    pub tithe_account_plan_id: Option<Uuid>,
+    pub notes: Option<String>,
+    /// Every bill and coin in the box, envelope cash included
+    #[validate(nested)]
+    pub denominations: Vec<OfferingDenominationRequest>,
+    #[validate(nested)]
+    pub envelopes: Option<Vec<OfferingEnvelopeRequest>>,
+}
+
+/// Changes a sheet still being counted; lists given replace the current ones.
+/// Any change clears a confirmation already made.
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateOfferingCountRequest {
+    pub service_date: Option<NaiveDate>,
+    #[validate(length(max = 100, message = "Nome do culto deve ter no máximo 100 caracteres"))]
+    pub service_name: Option<String>,
+    pub bank_account_id: Option<Uuid>,
+    pub offering_account_plan_id: Option<Uuid>,
+    pub tithe_account_plan_id: Option<Uuid>,
+    pub notes: Option<String>,
+    #[validate(nested)]
+    pub denominations: Option<Vec<OfferingDenominationRequest>>,
+    #[validate(nested)]
+    pub envelopes: Option<Vec<OfferingEnvelopeRequest>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OfferingCountFilter {
+    pub congregation_id: Option<Uuid>,
+    /// "em_contagem" or "confirmada"
+    pub status: Option<String>,
+    pub date_from: Option<NaiveDate>,
+    pub date_to: Option<NaiveDate>,
+}
+
+// ==========================================
+// Bank Reconciliation
+// ==========================================
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct MatchStatementLineRequest {
+    pub entry_id: Uuid,
+}
+
+/// Creates a confirmed entry from an unmatched statement line (amount, date and type
+/// come from the line) and reconciles it
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateEntryFromLineRequest {
+    /// Defaults to the category of the PIX code whose txid appears in the line
+    pub account_plan_id: Option<Uuid>,
+    /// Defaults to the line description
+    pub description: Option<String>,
+    pub campaign_id: Option<Uuid>,
+    pub payment_method: Option<String>,
+    pub member_id: Option<Uuid>,
+    pub supplier_id: Option<Uuid>,
+    pub supplier_name: Option<String>,
+    pub notes: Option<String>,
+    pub congregation_id: Option<Uuid>,
+    /// Split deposit (e.g. tithes, offerings and a campaign after the Sunday
+    /// service); the lines must add up to the line amount
+    #[validate(nested)]
+    pub allocations: Option<Vec<EntryAllocationRequest>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReconciliationParams {
+    /// Date window (± days) for match suggestions, default 3
+    pub days: Option<i32>,
+}
+
+// ==========================================
+// Tithes
+// ==========================================
+
+#[derive(Debug, Deserialize)]
+pub struct TitheStatementParams {
+    /// Defaults to the current year
+    pub year: Option<i32>,
+    /// "json" (default) or "pdf"
+    pub format: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TitherRegularityFilter {
+    pub congregation_id: Option<Uuid>,
+    /// "regular", "irregular" or "inativo"
+    pub status: Option<String>,
+    /// Last month of the 12-month window (default: previous month)
+    pub reference_month: Option<NaiveDate>,
+}
+
+// ==========================================
+// Donation Receipts
+// ==========================================
+
+/// Donor data for a receipt; defaults to the entry's member
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct IssueReceiptRequest {
+    #[validate(length(min = 2, max = 200, message = "Nome do doador deve ter entre 2 e 200 caracteres"))]
+    pub donor_name: Option<String>,
+    /// CPF or CNPJ printed on the receipt
+    #[validate(length(max = 20, message = "Documento deve ter no máximo 20 caracteres"))]
+    pub donor_document: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReceiptFilter {
+    /// "emitido" or "cancelado"
+    pub status: Option<String>,
+    pub member_id: Option<Uuid>,
+    pub date_from: Option<NaiveDate>,
+    pub date_to: Option<NaiveDate>,
+}
+
+// ==========================================
+// Budgets
+// ==========================================
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateBudgetRequest {
+    pub account_plan_id: Uuid,
+    /// Budget of a congregation; omitted = church-wide
+    pub congregation_id: Option<Uuid>,
+    #[validate(range(min = 2000, max = 2100, message = "Ano inválido"))]
+    pub year: i16,
+    /// 1-12 for a monthly budget; omitted = whole year
+    #[validate(range(min = 1, max = 12, message = "Mês deve estar entre 1 e 12"))]
+    pub month: Option<i16>,
+    pub amount: Decimal,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateBudgetRequest {
+    pub amount: Option<Decimal>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BudgetFilter {
+    pub year: Option<i16>,
+    pub account_plan_id: Option<Uuid>,
+    pub congregation_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BudgetReportFilter {
+    pub year: i16,
+    /// Single month (1-12); omitted = whole year
+    pub month: Option<i16>,
+    /// Budgets and entries of a congregation; omitted = church-wide budgets
+    pub congregation_id: Option<Uuid>,
+}
+
+// ==========================================
+// Suppliers
+// ==========================================
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateSupplierRequest {
+    #[validate(length(min = 2, max = 200, message = "Nome deve ter entre 2 e 200 caracteres"))]
+    pub name: String,
+    /// CPF or CNPJ, with or without punctuation
+    pub document: Option<String>,
+    /// e.g. "Material de construção", "Energia", "Som e iluminação"
+    #[validate(length(max = 100, message = "Categoria deve ter no máximo 100 caracteres"))]
+    pub category: Option<String>,
+    pub contact_name: Option<String>,
+    #[validate(length(max = 20, message = "Telefone deve ter no máximo 20 caracteres"))]
+    pub phone: Option<String>,
+    #[validate(email(message = "E-mail inválido"))]
+    pub email: Option<String>,
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateSupplierRequest {
+    #[validate(length(min = 2, max = 200, message = "Nome deve ter entre 2 e 200 caracteres"))]
+    pub name: Option<String>,
+    /// Empty string removes the document
+    pub document: Option<String>,
+    #[validate(length(max = 100, message = "Categoria deve ter no máximo 100 caracteres"))]
+    pub category: Option<String>,
+    pub contact_name: Option<String>,
+    #[validate(length(max = 20, message = "Telefone deve ter no máximo 20 caracteres"))]
+    pub phone: Option<String>,
+    #[validate(email(message = "E-mail inválido"))]
+    pub email: Option<String>,
+    pub notes: Option<String>,
+    pub is_active: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SupplierFilter {
+    pub category: Option<String>,
+    pub is_active: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SupplierSpendingFilter {
+    pub date_from: NaiveDate,
+    pub date_to: NaiveDate,
+    pub congregation_id: Option<Uuid>,
+    pub category: Option<String>,
+}
+
+// ==========================================
+// Expense Approvals
+// ==========================================
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateExpenseApprovalRuleRequest {
+    #[validate(length(min = 2, max = 100, message = "Nome deve ter entre 2 e 100 caracteres"))]
+    pub name: String,
+    /// Expenses strictly above this amount need approval
+    pub amount_above: Option<Decimal>,
+    /// Expenses in this category (or its subcategories) need approval
+    pub account_plan_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateExpenseApprovalRuleRequest {
+    #[validate(length(min = 2, max = 100, message = "Nome deve ter entre 2 e 100 caracteres"))]
+    pub name: Option<String>,
+    pub amount_above: Option<Decimal>,
+    pub account_plan_id: Option<Uuid>,
+    pub is_active: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct RejectExpenseRequest {
+    #[validate(length(min = 3, max = 500, message = "Motivo deve ter entre 3 e 500 caracteres"))]
+    pub reason: String,
+}
+
+// ==========================================
+// Congregation Remittances
+// ==========================================
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateRemittanceRuleRequest {
+    /// Share of the base sent to the sede (0–100)
+    pub percentage: Decimal,
+    /// Income account plans the percentage applies to (subcategories included)
+    #[validate(length(min = 1, message = "Informe ao menos uma categoria de receita"))]
+    pub account_plan_ids: Vec<Uuid>,
+    pub is_active: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct GenerateRemittancesRequest {
+    /// Any day of the month (normalized to day 1)
+    pub reference_month: NaiveDate,
+    /// Only this congregation; omitted = every congregation with an active rule
+    pub congregation_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct PayRemittanceRequest {
+    /// Defaults to today
+    pub payment_date: Option<NaiveDate>,
+    /// Congregation account the money leaves
+    pub from_bank_account_id: Uuid,
+    /// Sede account the money enters
+    pub to_bank_account_id: Uuid,
+    pub payment_method: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RemittanceFilter {
+    pub congregation_id: Option<Uuid>,
+    /// "pendente" or "pago"
+    pub status: Option<String>,
 }
 
 // ==========================================
@@ -142,9 +746,66 @@ pub struct UpdateFinancialEntryRequest {
 pub struct MonthlyClosingRequest {
     /// First day of the month to close (e.g., "2026-02-01")
     pub reference_month: NaiveDate,
+    /// Close only this congregation's books; omitted = church-wide closing,
+    /// allowed once every active congregation has closed the month
+    pub congregation_id: Option<Uuid>,
     pub notes: Option<String>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct MonthlyClosingFilter {
+    /// Closings of a congregation; omitted = all closings
+    pub congregation_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct ReopenMonthlyClosingRequest {
+    /// Mandatory reason for undoing the closing (RN-FIN-007)
+    #[validate(length(min = 3, max = 500, message = "Motivo deve ter entre 3 e 500 caracteres"))]
+    pub reason: String,
+}
+
+// ==========================================
+// Accounting Export
+// ==========================================
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct CreateExportLayoutRequest {
+    #[validate(length(min = 2, max = 100, message = "Nome deve ter entre 2 e 100 caracteres"))]
+    pub name: String,
+    #[validate(length(min = 1, message = "Informe ao menos uma coluna"))]
+    pub columns: Vec<ExportColumn>,
+    /// Account plan code → accountant's account
+    pub account_codes: Option<HashMap<String, String>>,
+    /// Bank account ID → accountant's account
+    pub bank_account_codes: Option<HashMap<Uuid, String>>,
+    /// CSV delimiter, default ";"
+    pub delimiter: Option<String>,
+    pub decimal_comma: Option<bool>,
+    pub include_totals: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct UpdateExportLayoutRequest {
+    #[validate(length(min = 2, max = 100, message = "Nome deve ter entre 2 e 100 caracteres"))]
+    pub name: Option<String>,
+    #[validate(length(min = 1, message = "Informe ao menos uma coluna"))]
+    pub columns: Option<Vec<ExportColumn>>,
+    pub account_codes: Option<HashMap<String, String>>,
+    pub bank_account_codes: Option<HashMap<Uuid, String>>,
+    pub delimiter: Option<String>,
+    pub decimal_comma: Option<bool>,
+    pub include_totals: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AccountingExportParams {
+    /// "csv" (default) or "xlsx"
+    pub format: Option<String>,
+    /// Saved layout; omitted = general ledger journal
+    pub layout_id: Option<Uuid>,
+}
+
 // ==========================================
 // Report Filters
 // ==========================================
@@ -161,8 +822,38 @@ pub struct FinancialEntryFilter {
     pub date_to: Option<NaiveDate>,
     pub member_id: Option<Uuid>,
     pub campaign_id: Option<Uuid>,
+    pub supplier_id: Option<Uuid>,
     pub payment_method: Option<String>,
     pub congregation_id: Option<Uuid>,
+    /// Occurrences generated from a recurrence template
+    pub recurring_id: Option<Uuid>,
+    /// Installments of an installment plan
+    pub installment_plan_id: Option<Uuid>,
+    /// Matched (true) or not (false) to a bank statement line
+    pub reconciled: Option<bool>,
+}
+
+/// Assembly report period: a single `month`, or `month_from`..`month_to`
+#[derive(Debug, Deserialize)]
+pub struct AssemblyReportFilter {
+    pub month: Option<NaiveDate>,
+    pub month_from: Option<NaiveDate>,
+    pub month_to: Option<NaiveDate>,
+    pub congregation_id: Option<Uuid>,
+    /// "json" (default) or "pdf"
+    pub format: Option<String>,
+}
+
+/// Hierarchical income statement (DRE) over the account plan tree
+#[derive(Debug, Deserialize)]
+pub struct IncomeStatementFilter {
+    pub date_from: NaiveDate,
+    pub date_to: NaiveDate,
+    pub congregation_id: Option<Uuid>,
+    /// Side-by-side month columns (at most 12 months)
+    pub by_month: Option<bool>,
+    /// "json" (default), "csv" or "pdf"
+    pub format: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -47,7 +47,7 @@ impl AccountPlanService {
         let query_sql = format!(
             r#"
             SELECT ap.id, ap.parent_id, ap.code, ap.name,
-                   ap.type, ap.level, ap.is_active,
+                   ap.type, ap.level, ap.is_active, ap.is_tithe,
                    p.name AS parent_name,
                    (SELECT COUNT(*) FROM account_plans c WHERE c.parent_id = ap.id) AS children_count,
                    ap.created_at
@@ -98,7 +98,7 @@ impl AccountPlanService {
         plan_id: Uuid,
     ) -> Result<AccountPlan, AppError> {
         sqlx::query_as::<_, AccountPlan>(
-            "SELECT id, church_id, parent_id, code, name, type, level, is_active, created_at, updated_at FROM account_plans WHERE id = $1 AND church_id = $2",
+            "SELECT id, church_id, parent_id, code, name, type, level, is_active, is_tithe, created_at, updated_at FROM account_plans WHERE id = $1 AND church_id = $2",
         )
         .bind(plan_id)
         .bind(church_id)
@@ -118,6 +118,13 @@ impl AccountPlanService {
             return Err(AppError::validation("Tipo deve ser 'receita' ou 'despesa'"));
         }
 
+        let is_tithe = req.is_tithe.unwrap_or(false);
+        if is_tithe && req.plan_type != "receita" {
+            return Err(AppError::validation(
+                "Apenas categorias de receita podem ser marcadas como dízimo",
+            ));
+        }
+
         // Check unique code per church
         let existing = sqlx::query_scalar::<_, Uuid>(
             "SELECT id FROM account_plans WHERE church_id = $1 AND code = $2",
@@ -141,9 +148,9 @@ impl AccountPlanService {
 
         let plan = sqlx::query_as::<_, AccountPlan>(
             r#"
-            INSERT INTO account_plans (church_id, parent_id, code, name, type, level)
-            VALUES ($1, $2, $3, $4, $5, $6)
-            RETURNING id, church_id, parent_id, code, name, type, level, is_active, created_at, updated_at
+            INSERT INTO account_plans (church_id, parent_id, code, name, type, level, is_tithe)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING id, church_id, parent_id, code, name, type, level, is_active, is_tithe, created_at, updated_at
             "#,
         )
         .bind(church_id)
@@ -152,6 +159,7 @@ impl AccountPlanService {
         .bind(&req.name)
         .bind(&req.plan_type)
         .bind(level)
+        .bind(is_tithe)
         .fetch_one(pool)
         .await?;
 
@@ -165,7 +173,7 @@ impl AccountPlanService {
         plan_id: Uuid,
         req: &UpdateAccountPlanRequest,
     ) -> Result<AccountPlan, AppError> {
-        let _existing = Self::get_by_id(pool, church_id, plan_id).await?;
+        let existing_plan = Self::get_by_id(pool, church_id, plan_id).await?;
 
         let mut set_clauses: Vec<String> = Vec::new();
         let mut args = sqlx::postgres::PgArguments::default();
@@ -206,6 +214,17 @@ impl AccountPlanService {
             param_index += 1;
         }
 
+        if let Some(is_tithe) = req.is_tithe {
+            if is_tithe && existing_plan.plan_type != "receita" {
+                return Err(AppError::validation(
+                    "Apenas categorias de receita podem ser marcadas como dízimo",
+                ));
+            }
+            set_clauses.push(format!("is_tithe = ${param_index}"));
+            sqlx::Arguments::add(&mut args, is_tithe).unwrap();
+            param_index += 1;
+        }
+
         let _ = param_index;
 
         if set_clauses.is_empty() {
@@ -213,7 +232,7 @@ impl AccountPlanService {
         }
 
         let sql = format!(
-            "UPDATE account_plans SET {} WHERE id = $1 AND church_id = $2 RETURNING id, church_id, parent_id, code, name, type, level, is_active, created_at, updated_at",
+            "UPDATE account_plans SET {} WHERE id = $1 AND church_id = $2 RETURNING id, church_id, parent_id, code, name, type, level, is_active, is_tithe, created_at, updated_at",
             set_clauses.join(", ")
         );
 
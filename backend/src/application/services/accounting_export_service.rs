@@ -0,0 +1,603 @@
+use crate::application::dto::{CreateExportLayoutRequest, UpdateExportLayoutRequest};
+use crate::application::services::MonthlyClosingService;
+use crate::domain::entities::{AccountingExportLayout, ExportColumn, MonthlyClosing};
+use crate::errors::AppError;
+use crate::infrastructure::pdf;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use rust_decimal::prelude::ToPrimitive;
+use rust_xlsxwriter::{Format, Workbook};
+use sqlx::PgPool;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Entry fields a layout column can show
+const EXPORT_FIELDS: &[&str] = &[
+    "date",
+    "document",
+    "entry_type",
+    "side",
+    "account_code",
+    "account_name",
+    "debit_account",
+    "credit_account",
+    "bank_account_code",
+    "bank_account_name",
+    "amount",
+    "debit",
+    "credit",
+    "history",
+    "description",
+    "payment_method",
+    "member",
+    "supplier",
+    "campaign",
+    "congregation",
+];
+
+/// Layout used when none is chosen: a general ledger journal
+const JOURNAL_COLUMNS: &[(&str, &str)] = &[
+    ("Data", "date"),
+    ("Documento", "document"),
+    ("Conta Débito", "debit_account"),
+    ("Conta Crédito", "credit_account"),
+    ("Valor", "amount"),
+    ("Histórico", "history"),
+];
+
+/// One movement of the month, as read for the export.
+/// Transfers come as a single row (the outgoing leg) with the destination account.
+#[derive(Debug, sqlx::FromRow)]
+struct ExportRow {
+    id: Uuid,
+    entry_date: NaiveDate,
+    entry_type: String,
+    amount: Decimal,
+    description: String,
+    payment_method: Option<String>,
+    account_plan_id: Uuid,
+    account_code: String,
+    account_name: String,
+    bank_account_id: Uuid,
+    bank_account_name: String,
+    member_name: Option<String>,
+    supplier_name: Option<String>,
+    campaign_name: Option<String>,
+    congregation_name: Option<String>,
+    transfer_id: Option<Uuid>,
+    transfer_to_id: Option<Uuid>,
+    transfer_to_name: Option<String>,
+    receipt_number: Option<i32>,
+}
+
+enum Cell {
+    Text(String),
+    Amount(Decimal),
+}
+
+/// Layout resolved for one export
+struct Layout {
+    columns: Vec<ExportColumn>,
+    account_codes: HashMap<String, String>,
+    bank_account_codes: HashMap<String, String>,
+    delimiter: u8,
+    decimal_comma: bool,
+    include_totals: bool,
+}
+
+pub struct AccountingExportService;
+
+impl AccountingExportService {
+    /// List export layouts
+    pub async fn list_layouts(
+        pool: &PgPool,
+        church_id: Uuid,
+    ) -> Result<Vec<AccountingExportLayout>, AppError> {
+        let layouts = sqlx::query_as::<_, AccountingExportLayout>(
+            "SELECT * FROM accounting_export_layouts WHERE church_id = $1 ORDER BY name",
+        )
+        .bind(church_id)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(layouts)
+    }
+
+    /// Get an export layout by ID
+    pub async fn get_layout(
+        pool: &PgPool,
+        church_id: Uuid,
+        layout_id: Uuid,
+    ) -> Result<AccountingExportLayout, AppError> {
+        sqlx::query_as::<_, AccountingExportLayout>(
+            "SELECT * FROM accounting_export_layouts WHERE id = $1 AND church_id = $2",
+        )
+        .bind(layout_id)
+        .bind(church_id)
+        .fetch_optional(pool)
+        .await?
+        .ok_or_else(|| AppError::not_found("Layout de exportação"))
+    }
+
+    /// Create an export layout
+    pub async fn create_layout(
+        pool: &PgPool,
+        church_id: Uuid,
+        req: &CreateExportLayoutRequest,
+    ) -> Result<AccountingExportLayout, AppError> {
+        Self::validate_columns(&req.columns)?;
+        let delimiter = req.delimiter.clone().unwrap_or_else(|| ";".to_string());
+        Self::validate_delimiter(&delimiter)?;
+
+        let layout = sqlx::query_as::<_, AccountingExportLayout>(
+            r#"
+            INSERT INTO accounting_export_layouts (church_id, name, columns, account_codes,
+                                                   bank_account_codes, delimiter, decimal_comma,
+                                                   include_totals)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING *
+            "#,
+        )
+        .bind(church_id)
+        .bind(&req.name)
+        .bind(serde_json::json!(req.columns))
+        .bind(serde_json::json!(req.account_codes.clone().unwrap_or_default()))
+        .bind(serde_json::json!(req.bank_account_codes.clone().unwrap_or_default()))
+        .bind(&delimiter)
+        .bind(req.decimal_comma.unwrap_or(true))
+        .bind(req.include_totals.unwrap_or(true))
+        .fetch_one(pool)
+        .await
+        .map_err(Self::map_unique_name)?;
+
+        Ok(layout)
+    }
+
+    /// Update an export layout
+    pub async fn update_layout(
+        pool: &PgPool,
+        church_id: Uuid,
+        layout_id: Uuid,
+        req: &UpdateExportLayoutRequest,
+    ) -> Result<AccountingExportLayout, AppError> {
+        Self::get_layout(pool, church_id, layout_id).await?;
+
+        if let Some(columns) = &req.columns {
+            Self::validate_columns(columns)?;
+        }
+        if let Some(delimiter) = &req.delimiter {
+            Self::validate_delimiter(delimiter)?;
+        }
+
+        let layout = sqlx::query_as::<_, AccountingExportLayout>(
+            r#"
+            UPDATE accounting_export_layouts SET
+                name = COALESCE($3, name),
+                columns = COALESCE($4, columns),
+                account_codes = COALESCE($5, account_codes),
+                bank_account_codes = COALESCE($6, bank_account_codes),
+                delimiter = COALESCE($7, delimiter),
+                decimal_comma = COALESCE($8, decimal_comma),
+                include_totals = COALESCE($9, include_totals)
+            WHERE id = $1 AND church_id = $2
+            RETURNING *
+            "#,
+        )
+        .bind(layout_id)
+        .bind(church_id)
+        .bind(&req.name)
+        .bind(req.columns.as_ref().map(|c| serde_json::json!(c)))
+        .bind(req.account_codes.as_ref().map(|c| serde_json::json!(c)))
+        .bind(req.bank_account_codes.as_ref().map(|c| serde_json::json!(c)))
+        .bind(&req.delimiter)
+        .bind(req.decimal_comma)
+        .bind(req.include_totals)
+        .fetch_one(pool)
+        .await
+        .map_err(Self::map_unique_name)?;
+
+        Ok(layout)
+    }
+
+    /// Delete an export layout
+    pub async fn delete_layout(
+        pool: &PgPool,
+        church_id: Uuid,
+        layout_id: Uuid,
+    ) -> Result<(), AppError> {
+        let result =
+            sqlx::query("DELETE FROM accounting_export_layouts WHERE id = $1 AND church_id = $2")
+                .bind(layout_id)
+                .bind(church_id)
+                .execute(pool)
+                .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::not_found("Layout de exportação"));
+        }
+        Ok(())
+    }
+
+    /// Export the movements of a closed month (RN-FIN-019) as CSV or XLSX.
+    /// Fails if the entries no longer add up to the totals of the closing record.
+    /// Returns the file name and its bytes.
+    pub async fn export(
+        pool: &PgPool,
+        church_id: Uuid,
+        closing_id: Uuid,
+        layout_id: Option<Uuid>,
+        format: &str,
+    ) -> Result<(String, Vec<u8>), AppError> {
+        let closing = MonthlyClosingService::get_by_id(pool, church_id, closing_id)
+            .await?
+            .closing;
+
+        let layout = match layout_id {
+            Some(id) => Self::resolve_layout(Self::get_layout(pool, church_id, id).await?)?,
+            None => Layout {
+                columns: JOURNAL_COLUMNS
+                    .iter()
+                    .map(|(header, field)| ExportColumn {
+                        header: header.to_string(),
+                        field: field.to_string(),
+                    })
+                    .collect(),
+                account_codes: HashMap::new(),
+                bank_account_codes: HashMap::new(),
+                delimiter: b';',
+                decimal_comma: true,
+                include_totals: true,
+            },
+        };
+
+        let rows = Self::rows(pool, church_id, &closing).await?;
+
+        let (total_income, total_expense) =
+            rows.iter()
+                .filter(|r| r.transfer_id.is_none())
+                .fold((Decimal::ZERO, Decimal::ZERO), |(income, expense), r| {
+                    if r.entry_type == "receita" {
+                        (income + r.amount, expense)
+                    } else {
+                        (income, expense + r.amount)
+                    }
+                });
+
+        if total_income != closing.total_income || total_expense != closing.total_expense {
+            return Err(AppError::conflict(format!(
+                "Os lançamentos do mês (receitas {}, despesas {}) não conferem com o fechamento \
+                 (receitas {}, despesas {}). Reabra e refaça o fechamento antes de exportar",
+                pdf::brl(total_income),
+                pdf::brl(total_expense),
+                pdf::brl(closing.total_income),
+                pdf::brl(closing.total_expense)
+            )));
+        }
+
+        // Account plan tree, to map subcategories through their nearest mapped ancestor
+        let plans: HashMap<Uuid, (Option<Uuid>, String)> =
+            sqlx::query_as::<_, (Uuid, Option<Uuid>, String)>(
+                "SELECT id, parent_id, code FROM account_plans WHERE church_id = $1",
+            )
+            .bind(church_id)
+            .fetch_all(pool)
+            .await?
+            .into_iter()
+            .map(|(id, parent_id, code)| (id, (parent_id, code)))
+            .collect();
+
+        let table: Vec<Vec<Cell>> = rows
+            .iter()
+            .map(|row| {
+                layout
+                    .columns
+                    .iter()
+                    .map(|c| Self::cell(&c.field, row, &layout, &plans))
+                    .collect()
+            })
+            .collect();
+
+        let totals = layout.include_totals.then(|| {
+            vec![
+                ("Total de receitas", closing.total_income),
+                ("Total de despesas", closing.total_expense),
+                ("Saldo do mês", closing.balance),
+            ]
+        });
+
+        let month = closing.reference_month.format("%Y-%m");
+        match format {
+            "xlsx" => {
+                let bytes = Self::xlsx(&layout, &table, totals.as_deref())?;
+                Ok((format!("contabil-{month}.xlsx"), bytes))
+            }
+            _ => {
+                let bytes = Self::csv(&layout, &table, totals.as_deref())?;
+                Ok((format!("contabil-{month}.csv"), bytes))
+            }
+        }
+    }
+
+    /// One row per allocation line, so split entries debit/credit each category
+    async fn rows(
+        pool: &PgPool,
+        church_id: Uuid,
+        closing: &MonthlyClosing,
+    ) -> Result<Vec<ExportRow>, AppError> {
+        let month_start = closing.reference_month;
+        let month_end = month_start + chrono::Months::new(1) - chrono::Duration::days(1);
+
+        let rows = sqlx::query_as::<_, ExportRow>(
+            r#"
+            SELECT fe.id, fe.entry_date, fe.type AS entry_type, l.amount, fe.description,
+                   fe.payment_method, l.account_plan_id, ap.code AS account_code,
+                   ap.name AS account_name, fe.bank_account_id, ba.name AS bank_account_name,
+                   m.full_name AS member_name, fe.supplier_name, c.name AS campaign_name,
+                   cg.name AS congregation_name, fe.transfer_id,
+                   tin.bank_account_id AS transfer_to_id, tba.name AS transfer_to_name,
+                   dr.number AS receipt_number
+            FROM financial_entries fe
+            JOIN financial_entry_lines l ON l.entry_id = fe.id
+            JOIN account_plans ap ON ap.id = l.account_plan_id
+            JOIN bank_accounts ba ON ba.id = fe.bank_account_id
+            LEFT JOIN members m ON m.id = l.member_id
+            LEFT JOIN campaigns c ON c.id = l.campaign_id
+            LEFT JOIN congregations cg ON cg.id = fe.congregation_id
+            LEFT JOIN financial_entries tin ON fe.transfer_id IS NOT NULL
+                 AND tin.transfer_id = fe.transfer_id AND tin.type = 'receita'
+                 AND tin.deleted_at IS NULL
+            LEFT JOIN bank_accounts tba ON tba.id = tin.bank_account_id
+            LEFT JOIN donation_receipts dr ON dr.financial_entry_id = fe.id
+                 AND dr.status = 'emitido'
+            WHERE fe.church_id = $1 AND fe.status IN ('confirmado', 'estornado')
+              AND fe.deleted_at IS NULL
+              AND fe.entry_date >= $2 AND fe.entry_date <= $3
+              AND ($4::uuid IS NULL OR fe.congregation_id = $4)
+              AND (fe.transfer_id IS NULL OR fe.type = 'despesa')
+            ORDER BY fe.entry_date, fe.created_at
+            "#,
+        )
+        .bind(church_id)
+        .bind(month_start)
+        .bind(month_end)
+        .bind(closing.congregation_id)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows)
+    }
+
+    fn cell(
+        field: &str,
+        row: &ExportRow,
+        layout: &Layout,
+        plans: &HashMap<Uuid, (Option<Uuid>, String)>,
+    ) -> Cell {
+        let bank_code = |id: Uuid, name: &str| {
+            layout
+                .bank_account_codes
+                .get(&id.to_string())
+                .cloned()
+                .unwrap_or_else(|| name.to_string())
+        };
+        let is_transfer = row.transfer_id.is_some();
+        let is_income = row.entry_type == "receita";
+        let account_code = if is_transfer {
+            String::new()
+        } else {
+            Self::account_code(row.account_plan_id, &row.account_code, layout, plans)
+        };
+        let bank = bank_code(row.bank_account_id, &row.bank_account_name);
+
+        let text = match field {
+            "date" => pdf::date_br(row.entry_date),
+            "document" => match row.receipt_number {
+                Some(number) => format!("REC-{number:06}"),
+                None => row.id.simple().to_string()[..8].to_uppercase(),
+            },
+            "entry_type" if is_transfer => "transferencia".to_string(),
+            "entry_type" => row.entry_type.clone(),
+            "side" if is_transfer => String::new(),
+            "side" => if is_income { "C" } else { "D" }.to_string(),
+            "account_code" => account_code,
+            "account_name" if is_transfer => String::new(),
+            "account_name" => row.account_name.clone(),
+            "debit_account" if is_transfer => row
+                .transfer_to_id
+                .map(|id| bank_code(id, row.transfer_to_name.as_deref().unwrap_or_default()))
+                .unwrap_or_default(),
+            "debit_account" => if is_income { bank } else { account_code },
+            "credit_account" if is_transfer => bank,
+            "credit_account" => if is_income { account_code } else { bank },
+            "bank_account_code" => bank,
+            "bank_account_name" => row.bank_account_name.clone(),
+            "amount" => return Cell::Amount(row.amount),
+            "debit" if is_transfer || !is_income => return Cell::Amount(row.amount),
+            "credit" if !is_transfer && is_income => return Cell::Amount(row.amount),
+            "debit" | "credit" => String::new(),
+            "history" => {
+                let mut history = row.description.clone();
+                if let Some(name) = row.member_name.as_ref().or(row.supplier_name.as_ref()) {
+                    history.push_str(" — ");
+                    history.push_str(name);
+                }
+                history
+            }
+            "description" => row.description.clone(),
+            "payment_method" => row.payment_method.clone().unwrap_or_default(),
+            "member" => row.member_name.clone().unwrap_or_default(),
+            "supplier" => row.supplier_name.clone().unwrap_or_default(),
+            "campaign" => row.campaign_name.clone().unwrap_or_default(),
+            "congregation" => row.congregation_name.clone().unwrap_or_default(),
+            _ => String::new(),
+        };
+
+        Cell::Text(text)
+    }
+
+    /// Accountant's code of a plan: its own mapping, else the nearest mapped
+    /// ancestor's, else the plan code itself
+    fn account_code(
+        plan_id: Uuid,
+        code: &str,
+        layout: &Layout,
+        plans: &HashMap<Uuid, (Option<Uuid>, String)>,
+    ) -> String {
+        let mut current = Some(plan_id);
+        // The depth bound protects against a cycle in bad data
+        for _ in 0..16 {
+            let Some((parent_id, plan_code)) = current.and_then(|id| plans.get(&id)) else {
+                break;
+            };
+            if let Some(mapped) = layout.account_codes.get(plan_code) {
+                return mapped.clone();
+            }
+            current = *parent_id;
+        }
+        code.to_string()
+    }
+
+    fn csv(
+        layout: &Layout,
+        table: &[Vec<Cell>],
+        totals: Option<&[(&str, Decimal)]>,
+    ) -> Result<Vec<u8>, AppError> {
+        let amount = |value: Decimal| {
+            let text = format!("{value:.2}");
+            if layout.decimal_comma { text.replace('.', ",") } else { text }
+        };
+        let csv_error = |e: csv::Error| AppError::Internal(format!("Erro ao gerar CSV: {e}"));
+
+        let mut writer = csv::WriterBuilder::new()
+            .delimiter(layout.delimiter)
+            .flexible(true)
+            .from_writer(Vec::new());
+
+        writer
+            .write_record(layout.columns.iter().map(|c| c.header.as_str()))
+            .map_err(csv_error)?;
+        for row in table {
+            writer
+                .write_record(row.iter().map(|cell| match cell {
+                    Cell::Text(text) => text.clone(),
+                    Cell::Amount(value) => amount(*value),
+                }))
+                .map_err(csv_error)?;
+        }
+        for (label, value) in totals.unwrap_or_default() {
+            writer
+                .write_record([label.to_string(), amount(*value)])
+                .map_err(csv_error)?;
+        }
+
+        let body = writer
+            .into_inner()
+            .map_err(|e| AppError::Internal(format!("Erro ao gerar CSV: {e}")))?;
+        let mut bytes = "\u{feff}".as_bytes().to_vec();
+        bytes.extend(body);
+        Ok(bytes)
+    }
+
+    fn xlsx(
+        layout: &Layout,
+        table: &[Vec<Cell>],
+        totals: Option<&[(&str, Decimal)]>,
+    ) -> Result<Vec<u8>, AppError> {
+        let xlsx_error =
+            |e: rust_xlsxwriter::XlsxError| AppError::Internal(format!("Erro ao gerar XLSX: {e}"));
+
+        let bold = Format::new().set_bold();
+        let money = Format::new().set_num_format("#,##0.00");
+        let bold_money = Format::new().set_bold().set_num_format("#,##0.00");
+
+        let mut workbook = Workbook::new();
+        let sheet = workbook.add_worksheet();
+        sheet.set_name("Lançamentos").map_err(xlsx_error)?;
+
+        for (col, column) in layout.columns.iter().enumerate() {
+            let col = col as u16;
+            sheet
+                .write_string_with_format(0, col, &column.header, &bold)
+                .map_err(xlsx_error)?;
+            let width = if column.field == "history" || column.field == "description" {
+                45
+            } else {
+                16
+            };
+            sheet.set_column_width(col, width).map_err(xlsx_error)?;
+        }
+
+        for (r, row) in table.iter().enumerate() {
+            let r = r as u32 + 1;
+            for (col, cell) in row.iter().enumerate() {
+                let col = col as u16;
+                match cell {
+                    Cell::Text(text) => sheet.write_string(r, col, text),
+                    Cell::Amount(value) => sheet.write_number_with_format(
+                        r,
+                        col,
+                        value.to_f64().unwrap_or_default(),
+                        &money,
+                    ),
+                }
+                .map_err(xlsx_error)?;
+            }
+        }
+
+        let first_total = table.len() as u32 + 2;
+        for (r, (label, value)) in (first_total..).zip(totals.unwrap_or_default()) {
+            sheet
+                .write_string_with_format(r, 0, *label, &bold)
+                .map_err(xlsx_error)?;
+            sheet
+                .write_number_with_format(r, 1, value.to_f64().unwrap_or_default(), &bold_money)
+                .map_err(xlsx_error)?;
+        }
+
+        workbook.save_to_buffer().map_err(xlsx_error)
+    }
+
+    fn resolve_layout(layout: AccountingExportLayout) -> Result<Layout, AppError> {
+        let invalid =
+            |e: serde_json::Error| AppError::Internal(format!("Layout de exportação inválido: {e}"));
+
+        Ok(Layout {
+            columns: serde_json::from_value(layout.columns).map_err(invalid)?,
+            account_codes: serde_json::from_value(layout.account_codes).map_err(invalid)?,
+            bank_account_codes: serde_json::from_value(layout.bank_account_codes)
+                .map_err(invalid)?,
+            delimiter: layout.delimiter.bytes().next().unwrap_or(b';'),
+            decimal_comma: layout.decimal_comma,
+            include_totals: layout.include_totals,
+        })
+    }
+
+    fn validate_columns(columns: &[ExportColumn]) -> Result<(), AppError> {
+        if let Some(column) = columns.iter().find(|c| !EXPORT_FIELDS.contains(&c.field.as_str())) {
+            return Err(AppError::validation(format!(
+                "Campo '{}' inválido. Use: {}",
+                column.field,
+                EXPORT_FIELDS.join(", ")
+            )));
+        }
+        Ok(())
+    }
+
+    fn validate_delimiter(delimiter: &str) -> Result<(), AppError> {
+        if ![";", ",", "|", "\t"].contains(&delimiter) {
+            return Err(AppError::validation(
+                "Separador deve ser ';', ',', '|' ou tabulação",
+            ));
+        }
+        Ok(())
+    }
+
+    fn map_unique_name(e: sqlx::Error) -> AppError {
+        match &e {
+            sqlx::Error::Database(db) if db.is_unique_violation() => {
+                AppError::conflict("Já existe um layout de exportação com este nome")
+            }
+            _ => AppError::from(e),
+        }
+    }
+}
@@ -1,14 +1,12 @@
 use crate::application::dto::{AuthUser, Claims, LoginRequest, LoginResponse};
 use crate::config::AppConfig;
 use crate::errors::AppError;
+use crate::infrastructure::email;
 use argon2::password_hash::rand_core::OsRng;
 use argon2::password_hash::SaltString;
 use argon2::{Argon2, PasswordHash, PasswordHasher, PasswordVerifier};
 use chrono::{DateTime, Utc};
 use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
-use lettre::message::header::ContentType;
-use lettre::transport::smtp::authentication::Credentials;
-use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
 use sqlx::{FromRow, PgPool};
 use uuid::Uuid;
 
@@ -121,44 +119,20 @@ impl AuthService {
         token: &str,
         config: &AppConfig,
     ) -> Result<(), AppError> {
-        let email = Message::builder()
-            .from(
-                config
-                    .smtp_from
-                    .parse()
-                    .map_err(|e| AppError::Internal(format!("Invalid SMTP from address: {e}")))?,
-            )
-            .to(to_email
-                .parse()
-                .map_err(|e| AppError::Internal(format!("Invalid recipient address: {e}")))?)
-            .subject("Igreja Manager — Redefinição de Senha")
-            .header(ContentType::TEXT_HTML)
-            .body(format!(
+        email::send_html(
+            config,
+            to_email,
+            "Igreja Manager — Redefinição de Senha",
+            format!(
                 r#"<h2>Redefinição de Senha</h2>
 <p>Você solicitou a redefinição de sua senha no <strong>Igreja Manager</strong>.</p>
 <p>Use o código abaixo para redefinir sua senha:</p>
 <h1 style="letter-spacing:8px;font-family:monospace;text-align:center;color:#D4A843;">{token}</h1>
 <p>Este código expira em <strong>30 minutos</strong>.</p>
 <p>Se você não solicitou esta redefinição, ignore este e-mail.</p>"#
-            ))
-            .map_err(|e| AppError::Internal(format!("Failed to build email: {e}")))?;
-
-        let creds = Credentials::new(
-            config.smtp_username.clone(),
-            config.smtp_password.clone(),
-        );
-
-        let mailer = AsyncSmtpTransport::<Tokio1Executor>::relay(&config.smtp_host)
-            .map_err(|e| AppError::Internal(format!("SMTP relay error: {e}")))?
-            .credentials(creds)
-            .build();
-
-        mailer
-            .send(email)
-            .await
-            .map_err(|e| AppError::Internal(format!("Failed to send email: {e}")))?;
-
-        Ok(())
+            ),
+        )
+        .await
     }
 
     #[allow(dead_code)]
@@ -1,6 +1,8 @@
 use crate::application::dto::{CreateBankAccountRequest, UpdateBankAccountRequest};
-use crate::domain::entities::BankAccount;
+use crate::application::services::FinancialEntryService;
+use crate::domain::entities::{BankAccount, BankBalanceCheck, BankBalanceVerification};
 use crate::errors::AppError;
+use crate::infrastructure::pix;
 use rust_decimal::Decimal;
 use sqlx::PgPool;
 use uuid::Uuid;
@@ -42,7 +44,8 @@ impl BankAccountService {
         let query_sql = format!(
             r#"
             SELECT id, church_id, name, type, bank_name, agency, account_number,
-                   initial_balance, current_balance, is_active, created_at, updated_at
+                   pix_key_type, pix_key, initial_balance, current_balance, is_active,
+                   created_at, updated_at
             FROM bank_accounts
             WHERE {where_clause}
             ORDER BY name ASC
@@ -84,7 +87,7 @@ impl BankAccountService {
         account_id: Uuid,
     ) -> Result<BankAccount, AppError> {
         sqlx::query_as::<_, BankAccount>(
-            "SELECT id, church_id, name, type, bank_name, agency, account_number, initial_balance, current_balance, is_active, created_at, updated_at FROM bank_accounts WHERE id = $1 AND church_id = $2",
+            "SELECT id, church_id, name, type, bank_name, agency, account_number, pix_key_type, pix_key, initial_balance, current_balance, is_active, created_at, updated_at FROM bank_accounts WHERE id = $1 AND church_id = $2",
         )
         .bind(account_id)
         .bind(church_id)
@@ -106,13 +109,23 @@ impl BankAccountService {
             ));
         }
 
+        let pix_key = match (&req.pix_key_type, &req.pix_key) {
+            (Some(key_type), Some(key)) => Some(pix::normalize_key(key_type, key)?),
+            (None, None) => None,
+            _ => {
+                return Err(AppError::validation(
+                    "Informe o tipo e a chave PIX juntos",
+                ));
+            }
+        };
+
         let initial_balance = req.initial_balance.unwrap_or(Decimal::ZERO);
 
         let account = sqlx::query_as::<_, BankAccount>(
             r#"
-            INSERT INTO bank_accounts (church_id, name, type, bank_name, agency, account_number, initial_balance, current_balance)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $7)
-            RETURNING id, church_id, name, type, bank_name, agency, account_number, initial_balance, current_balance, is_active, created_at, updated_at
+            INSERT INTO bank_accounts (church_id, name, type, bank_name, agency, account_number, initial_balance, current_balance, pix_key_type, pix_key)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $7, $8, $9)
+            RETURNING id, church_id, name, type, bank_name, agency, account_number, pix_key_type, pix_key, initial_balance, current_balance, is_active, created_at, updated_at
             "#,
         )
         .bind(church_id)
@@ -122,6 +135,8 @@ impl BankAccountService {
         .bind(&req.agency)
         .bind(&req.account_number)
         .bind(initial_balance)
+        .bind(&req.pix_key_type)
+        .bind(pix_key)
         .fetch_one(pool)
         .await?;
 
@@ -135,7 +150,7 @@ impl BankAccountService {
         account_id: Uuid,
         req: &UpdateBankAccountRequest,
     ) -> Result<BankAccount, AppError> {
-        let _existing = Self::get_by_id(pool, church_id, account_id).await?;
+        let existing = Self::get_by_id(pool, church_id, account_id).await?;
 
         let mut set_clauses: Vec<String> = Vec::new();
         let mut args = sqlx::postgres::PgArguments::default();
@@ -168,6 +183,32 @@ impl BankAccountService {
             param_index += 1;
         }
 
+        match (&req.pix_key, &req.pix_key_type) {
+            (Some(key), _) if key.trim().is_empty() => {
+                set_clauses.push("pix_key_type = NULL, pix_key = NULL".to_string());
+            }
+            (None, None) => {}
+            (key, key_type) => {
+                let key_type = key_type
+                    .clone()
+                    .or(existing.pix_key_type)
+                    .ok_or_else(|| AppError::validation("Informe o tipo da chave PIX"))?;
+                let key = key
+                    .clone()
+                    .or(existing.pix_key)
+                    .ok_or_else(|| AppError::validation("Informe a chave PIX"))?;
+                let key = pix::normalize_key(&key_type, &key)?;
+
+                set_clauses.push(format!(
+                    "pix_key_type = ${param_index}, pix_key = ${}",
+                    param_index + 1
+                ));
+                sqlx::Arguments::add(&mut args, key_type).unwrap();
+                sqlx::Arguments::add(&mut args, key).unwrap();
+                param_index += 2;
+            }
+        }
+
         if let Some(is_active) = req.is_active {
             set_clauses.push(format!("is_active = ${param_index}"));
             sqlx::Arguments::add(&mut args, is_active).unwrap();
@@ -181,7 +222,7 @@ impl BankAccountService {
         }
 
         let sql = format!(
-            "UPDATE bank_accounts SET {} WHERE id = $1 AND church_id = $2 RETURNING id, church_id, name, type, bank_name, agency, account_number, initial_balance, current_balance, is_active, created_at, updated_at",
+            "UPDATE bank_accounts SET {} WHERE id = $1 AND church_id = $2 RETURNING id, church_id, name, type, bank_name, agency, account_number, pix_key_type, pix_key, initial_balance, current_balance, is_active, created_at, updated_at",
             set_clauses.join(", ")
         );
 
@@ -191,4 +232,46 @@ impl BankAccountService {
 
         Ok(account)
     }
+
+    /// Compare each account's cached balance with its ledger (initial balance plus
+    /// confirmed and reversed entries). With `repair`, drifted balances are
+    /// recomputed from the ledger in one transaction.
+    pub async fn verify_balances(
+        pool: &PgPool,
+        church_id: Uuid,
+        repair: bool,
+    ) -> Result<BankBalanceVerification, AppError> {
+        let mut tx = pool.begin().await?;
+
+        let checks = sqlx::query_as::<_, BankBalanceCheck>(
+            r#"
+            SELECT id AS bank_account_id, name, initial_balance, current_balance,
+                   bank_account_ledger_balance(id) AS ledger_balance,
+                   current_balance - bank_account_ledger_balance(id) AS difference
+            FROM bank_accounts
+            WHERE church_id = $1
+            ORDER BY name
+            "#,
+        )
+        .bind(church_id)
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let checked_accounts = checks.len();
+        let drifted: Vec<BankBalanceCheck> =
+            checks.into_iter().filter(|c| !c.difference.is_zero()).collect();
+
+        if repair && !drifted.is_empty() {
+            let ids: Vec<Uuid> = drifted.iter().map(|c| c.bank_account_id).collect();
+            FinancialEntryService::sync_bank_balances(&mut tx, &ids).await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(BankBalanceVerification {
+            checked_accounts,
+            repaired: repair && !drifted.is_empty(),
+            drifted,
+        })
+    }
 }
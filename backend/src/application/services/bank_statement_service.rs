@@ -0,0 +1,577 @@
+use crate::application::dto::{CreateEntryFromLineRequest, CreateFinancialEntryRequest};
+use crate::application::services::{FinancialEntryService, PixService};
+use crate::domain::entities::{
+    BankStatement, BankStatementDetail, BankStatementImport, BankStatementLine,
+    BankStatementSummary, FinancialEntry, ReconciliationBalance, ReconciliationSuggestion,
+};
+use crate::errors::AppError;
+use crate::infrastructure::bank_statement;
+use rust_decimal::Decimal;
+use sqlx::{PgConnection, PgPool};
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+/// Suggestions kept per statement line
+const MAX_SUGGESTIONS_PER_LINE: usize = 3;
+
+pub struct BankStatementService;
+
+impl BankStatementService {
+    /// Import an OFX/CSV statement for a bank account.
+    /// Movements already imported (same FITID/row hash) are skipped, so overlapping
+    /// statements can be uploaded safely.
+    pub async fn import(
+        pool: &PgPool,
+        church_id: Uuid,
+        user_id: Uuid,
+        bank_account_id: Uuid,
+        file_name: Option<String>,
+        bytes: &[u8],
+        informed_balance: Option<Decimal>,
+    ) -> Result<BankStatementImport, AppError> {
+        sqlx::query_scalar::<_, Uuid>(
+            "SELECT id FROM bank_accounts WHERE id = $1 AND church_id = $2",
+        )
+        .bind(bank_account_id)
+        .bind(church_id)
+        .fetch_optional(pool)
+        .await?
+        .ok_or_else(|| AppError::not_found("Conta bancária"))?;
+
+        let parsed = bank_statement::parse(bytes)?;
+
+        // A balance typed from the paper statement wins over the one in the file
+        let (statement_balance, balance_date) = match informed_balance {
+            Some(balance) => (Some(balance), parsed.period_end),
+            None => (parsed.balance, parsed.balance_date.or(parsed.period_end)),
+        };
+
+        let mut tx = pool.begin().await?;
+
+        let statement = sqlx::query_as::<_, BankStatement>(
+            r#"
+            INSERT INTO bank_statements (church_id, bank_account_id, file_name, format,
+                                         period_start, period_end, statement_balance,
+                                         balance_date, imported_by)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            RETURNING *
+            "#,
+        )
+        .bind(church_id)
+        .bind(bank_account_id)
+        .bind(&file_name)
+        .bind(parsed.format)
+        .bind(parsed.period_start)
+        .bind(parsed.period_end)
+        .bind(statement_balance)
+        .bind(balance_date)
+        .bind(user_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let mut imported_lines = 0i64;
+        for line in &parsed.lines {
+            let result = sqlx::query(
+                r#"
+                INSERT INTO bank_statement_lines (statement_id, church_id, bank_account_id,
+                                                  line_date, amount, description, external_id)
+                VALUES ($1, $2, $3, $4, $5, $6, $7)
+                ON CONFLICT (bank_account_id, external_id) DO NOTHING
+                "#,
+            )
+            .bind(statement.id)
+            .bind(church_id)
+            .bind(bank_account_id)
+            .bind(line.date)
+            .bind(line.amount)
+            .bind(&line.description)
+            .bind(&line.external_id)
+            .execute(&mut *tx)
+            .await?;
+
+            imported_lines += result.rows_affected() as i64;
+        }
+
+        if imported_lines == 0 {
+            return Err(AppError::conflict(
+                "Todas as movimentações deste extrato já foram importadas",
+            ));
+        }
+
+        tx.commit().await?;
+
+        Ok(BankStatementImport {
+            statement,
+            imported_lines,
+            skipped_lines: parsed.lines.len() as i64 - imported_lines,
+        })
+    }
+
+    /// List statements imported for a bank account
+    pub async fn list(
+        pool: &PgPool,
+        church_id: Uuid,
+        bank_account_id: Uuid,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<BankStatementSummary>, i64), AppError> {
+        let total = sqlx::query_scalar::<_, i64>(
+            "SELECT COUNT(*) FROM bank_statements WHERE church_id = $1 AND bank_account_id = $2",
+        )
+        .bind(church_id)
+        .bind(bank_account_id)
+        .fetch_one(pool)
+        .await?;
+
+        let statements = sqlx::query_as::<_, BankStatementSummary>(
+            r#"
+            SELECT s.id, s.file_name, s.format, s.period_start, s.period_end,
+                   s.statement_balance,
+                   (SELECT COUNT(*) FROM bank_statement_lines l WHERE l.statement_id = s.id) AS total_lines,
+                   (SELECT COUNT(*) FROM bank_statement_lines l
+                    WHERE l.statement_id = s.id AND l.status = 'conciliado') AS reconciled_lines,
+                   s.created_at
+            FROM bank_statements s
+            WHERE s.church_id = $1 AND s.bank_account_id = $2
+            ORDER BY s.created_at DESC
+            LIMIT $3 OFFSET $4
+            "#,
+        )
+        .bind(church_id)
+        .bind(bank_account_id)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(pool)
+        .await?;
+
+        Ok((statements, total))
+    }
+
+    /// Statement with its lines, match suggestions for the pending ones and the
+    /// statement balance compared with the account's current balance
+    pub async fn get_detail(
+        pool: &PgPool,
+        church_id: Uuid,
+        statement_id: Uuid,
+        days: i32,
+    ) -> Result<BankStatementDetail, AppError> {
+        let statement = sqlx::query_as::<_, BankStatement>(
+            "SELECT * FROM bank_statements WHERE id = $1 AND church_id = $2",
+        )
+        .bind(statement_id)
+        .bind(church_id)
+        .fetch_optional(pool)
+        .await?
+        .ok_or_else(|| AppError::not_found("Extrato"))?;
+
+        let lines = sqlx::query_as::<_, BankStatementLine>(
+            r#"
+            SELECT l.id, l.statement_id, l.line_date, l.amount, l.description, l.external_id,
+                   l.status, l.matched_entry_id, fe.description AS matched_entry_description,
+                   l.matched_at
+            FROM bank_statement_lines l
+            LEFT JOIN financial_entries fe ON fe.id = l.matched_entry_id
+            WHERE l.statement_id = $1
+            ORDER BY l.line_date ASC, l.created_at ASC
+            "#,
+        )
+        .bind(statement_id)
+        .fetch_all(pool)
+        .await?;
+
+        let suggestions = Self::suggest(pool, statement_id, &lines, days.clamp(0, 30)).await?;
+
+        let current_balance = sqlx::query_scalar::<_, Decimal>(
+            "SELECT current_balance FROM bank_accounts WHERE id = $1",
+        )
+        .bind(statement.bank_account_id)
+        .fetch_one(pool)
+        .await?;
+
+        let unreconciled_entries = sqlx::query_scalar::<_, i64>(
+            r#"SELECT COUNT(*) FROM financial_entries
+               WHERE bank_account_id = $1 AND status = 'confirmado' AND deleted_at IS NULL
+               AND reconciled_at IS NULL
+               AND ($2::date IS NULL OR COALESCE(payment_date, entry_date) >= $2)
+               AND ($3::date IS NULL OR COALESCE(payment_date, entry_date) <= $3)"#,
+        )
+        .bind(statement.bank_account_id)
+        .bind(statement.period_start)
+        .bind(statement.period_end)
+        .fetch_one(pool)
+        .await?;
+
+        let reconciled_lines = lines.iter().filter(|l| l.status == "conciliado").count() as i64;
+
+        let balance = ReconciliationBalance {
+            statement_balance: statement.statement_balance,
+            balance_date: statement.balance_date,
+            current_balance,
+            difference: statement.statement_balance.map(|b| b - current_balance),
+            total_lines: lines.len() as i64,
+            reconciled_lines,
+            unreconciled_entries,
+        };
+
+        Ok(BankStatementDetail {
+            statement,
+            lines,
+            suggestions,
+            balance,
+        })
+    }
+
+    /// Confirm a match between a statement line and an entry.
+    /// A `pendente` entry is confirmed on the line date, since the bank shows it was paid;
+    /// an expense under an approval rule goes to `aguardando_aprovacao` instead.
+    pub async fn match_line(
+        pool: &PgPool,
+        church_id: Uuid,
+        user_id: Uuid,
+        line_id: Uuid,
+        entry_id: Uuid,
+    ) -> Result<BankStatementLine, AppError> {
+        let mut tx = pool.begin().await?;
+        Self::match_in(&mut tx, church_id, user_id, line_id, entry_id).await?;
+        tx.commit().await?;
+
+        Self::get_line(pool, church_id, line_id).await
+    }
+
+    /// `match_line` inside the caller's transaction
+    async fn match_in(
+        conn: &mut PgConnection,
+        church_id: Uuid,
+        user_id: Uuid,
+        line_id: Uuid,
+        entry_id: Uuid,
+    ) -> Result<(), AppError> {
+        let (bank_account_id, line_date, line_amount, line_status) =
+            sqlx::query_as::<_, (Uuid, chrono::NaiveDate, Decimal, String)>(
+                r#"SELECT bank_account_id, line_date, amount, status FROM bank_statement_lines
+                   WHERE id = $1 AND church_id = $2 FOR UPDATE"#,
+            )
+            .bind(line_id)
+            .bind(church_id)
+            .fetch_optional(&mut *conn)
+            .await?
+            .ok_or_else(|| AppError::not_found("Linha do extrato"))?;
+
+        if line_status != "pendente" {
+            return Err(AppError::conflict("Esta linha do extrato já foi conciliada"));
+        }
+
+        let entry = sqlx::query_as::<_, FinancialEntry>(&format!(
+            r#"SELECT {} FROM financial_entries
+               WHERE id = $1 AND church_id = $2 AND deleted_at IS NULL FOR UPDATE"#,
+            crate::application::services::financial_service::ENTRY_COLUMNS
+        ))
+        .bind(entry_id)
+        .bind(church_id)
+        .fetch_optional(&mut *conn)
+        .await?
+        .ok_or_else(|| AppError::not_found("Lançamento financeiro"))?;
+
+        let expected_type = if line_amount > Decimal::ZERO { "receita" } else { "despesa" };
+
+        if entry.reconciled_at.is_some() {
+            return Err(AppError::conflict("Este lançamento já foi conciliado"));
+        }
+        if entry.bank_account_id != bank_account_id {
+            return Err(AppError::validation(
+                "O lançamento pertence a outra conta bancária",
+            ));
+        }
+        if entry.entry_type != expected_type || entry.amount != line_amount.abs() {
+            return Err(AppError::validation(
+                "Tipo ou valor do lançamento não confere com a linha do extrato",
+            ));
+        }
+
+        match entry.status.as_str() {
+            "confirmado" => {}
+            "pendente" if !entry.is_closed => {
+                // Like a payment, an expense under an approval rule waits for the
+                // second signature before it moves the balance (RN-FIN-020)
+                let needs_approval =
+                    FinancialEntryService::payment_needs_approval(&mut *conn, church_id, &entry)
+                        .await?;
+
+                sqlx::query(
+                    r#"UPDATE financial_entries
+                       SET status = CASE WHEN $3 THEN 'aguardando_aprovacao' ELSE 'confirmado' END,
+                           requested_status = CASE WHEN $3 THEN 'confirmado' END,
+                           payment_date = COALESCE(payment_date, $2)
+                       WHERE id = $1"#,
+                )
+                .bind(entry.id)
+                .bind(line_date)
+                .bind(needs_approval)
+                .execute(&mut *conn)
+                .await?;
+
+                if !needs_approval {
+                    FinancialEntryService::sync_bank_balances(&mut *conn, &[entry.bank_account_id])
+                        .await?;
+                }
+            }
+            // Paid according to the bank: the approval confirms it
+            "aguardando_aprovacao" if !entry.is_closed => {
+                sqlx::query(
+                    r#"UPDATE financial_entries SET requested_status = 'confirmado',
+                       payment_date = COALESCE(payment_date, $2) WHERE id = $1"#,
+                )
+                .bind(entry.id)
+                .bind(line_date)
+                .execute(&mut *conn)
+                .await?;
+            }
+            _ => {
+                return Err(AppError::validation(
+                    "Apenas lançamentos confirmados, pendentes ou aguardando aprovação (de mês aberto) podem ser conciliados",
+                ))
+            }
+        }
+
+        sqlx::query("UPDATE financial_entries SET reconciled_at = NOW() WHERE id = $1")
+            .bind(entry.id)
+            .execute(&mut *conn)
+            .await?;
+
+        sqlx::query(
+            r#"UPDATE bank_statement_lines
+               SET status = 'conciliado', matched_entry_id = $2, matched_at = NOW(), matched_by = $3
+               WHERE id = $1"#,
+        )
+        .bind(line_id)
+        .bind(entry.id)
+        .bind(user_id)
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Undo a match. An entry confirmed by the match stays confirmed.
+    pub async fn unmatch_line(
+        pool: &PgPool,
+        church_id: Uuid,
+        line_id: Uuid,
+    ) -> Result<BankStatementLine, AppError> {
+        let mut tx = pool.begin().await?;
+
+        let matched_entry_id = sqlx::query_scalar::<_, Option<Uuid>>(
+            "SELECT matched_entry_id FROM bank_statement_lines WHERE id = $1 AND church_id = $2 FOR UPDATE",
+        )
+        .bind(line_id)
+        .bind(church_id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(|| AppError::not_found("Linha do extrato"))?
+        .ok_or_else(|| AppError::validation("Esta linha do extrato não está conciliada"))?;
+
+        sqlx::query("UPDATE financial_entries SET reconciled_at = NULL WHERE id = $1")
+            .bind(matched_entry_id)
+            .execute(&mut *tx)
+            .await?;
+
+        sqlx::query(
+            r#"UPDATE bank_statement_lines
+               SET status = 'pendente', matched_entry_id = NULL, matched_at = NULL, matched_by = NULL
+               WHERE id = $1"#,
+        )
+        .bind(line_id)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Self::get_line(pool, church_id, line_id).await
+    }
+
+    /// Create a confirmed entry from an unmatched line and reconcile it, in one transaction
+    pub async fn create_entry_from_line(
+        pool: &PgPool,
+        church_id: Uuid,
+        user_id: Uuid,
+        line_id: Uuid,
+        req: &CreateEntryFromLineRequest,
+    ) -> Result<(FinancialEntry, BankStatementLine), AppError> {
+        let line = Self::get_line(pool, church_id, line_id).await?;
+
+        let mut tx = pool.begin().await?;
+
+        // Locking the line first serializes concurrent requests: the second one
+        // finds it reconciled instead of creating a duplicate entry
+        let (bank_account_id, line_status) = sqlx::query_as::<_, (Uuid, String)>(
+            "SELECT bank_account_id, status FROM bank_statement_lines WHERE id = $1 FOR UPDATE",
+        )
+        .bind(line_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        if line_status != "pendente" {
+            return Err(AppError::conflict("Esta linha do extrato já foi conciliada"));
+        }
+
+        let description = req
+            .description
+            .clone()
+            .filter(|d| !d.trim().is_empty())
+            .or_else(|| Some(line.description.clone()).filter(|d| !d.trim().is_empty()))
+            .unwrap_or_else(|| "Movimentação do extrato bancário".to_string());
+
+        // PIX credits carrying the txid of one of our QR codes inherit its purpose
+        let pix_charge = if line.amount > Decimal::ZERO {
+            PixService::find_in_text(pool, church_id, &line.description).await?
+        } else {
+            None
+        };
+
+        // A split deposit carries its categories and campaigns on the lines
+        let pix_defaults = pix_charge.as_ref().filter(|_| req.allocations.is_none());
+        let account_plan_id = req
+            .account_plan_id
+            .or_else(|| pix_defaults.and_then(|c| c.account_plan_id));
+        if account_plan_id.is_none() && req.allocations.is_none() {
+            return Err(AppError::validation("Plano de contas é obrigatório"));
+        }
+
+        let entry_req = CreateFinancialEntryRequest {
+            entry_type: if line.amount > Decimal::ZERO { "receita" } else { "despesa" }.to_string(),
+            account_plan_id,
+            bank_account_id,
+            campaign_id: req
+                .campaign_id
+                .or_else(|| pix_defaults.and_then(|c| c.campaign_id)),
+            amount: line.amount.abs(),
+            allocations: req.allocations.clone(),
+            entry_date: line.line_date,
+            due_date: None,
+            payment_date: Some(line.line_date),
+            description,
+            payment_method: req.payment_method.clone(),
+            member_id: req.member_id,
+            supplier_id: req.supplier_id,
+            supplier_name: req.supplier_name.clone(),
+            receipt_url: None,
+            status: Some("confirmado".to_string()),
+            notes: req.notes.clone(),
+            congregation_id: req
+                .congregation_id
+                .or_else(|| pix_charge.as_ref().and_then(|c| c.congregation_id)),
+        };
+
+        let entry =
+            FinancialEntryService::create_in(pool, &mut tx, church_id, user_id, &entry_req).await?;
+        Self::match_in(&mut tx, church_id, user_id, line_id, entry.id).await?;
+
+        tx.commit().await?;
+
+        let line = Self::get_line(pool, church_id, line_id).await?;
+        let entry = FinancialEntryService::get_by_id(pool, church_id, entry.id).await?;
+
+        Ok((entry, line))
+    }
+
+    async fn get_line(
+        pool: &PgPool,
+        church_id: Uuid,
+        line_id: Uuid,
+    ) -> Result<BankStatementLine, AppError> {
+        sqlx::query_as::<_, BankStatementLine>(
+            r#"
+            SELECT l.id, l.statement_id, l.line_date, l.amount, l.description, l.external_id,
+                   l.status, l.matched_entry_id, fe.description AS matched_entry_description,
+                   l.matched_at
+            FROM bank_statement_lines l
+            LEFT JOIN financial_entries fe ON fe.id = l.matched_entry_id
+            WHERE l.id = $1 AND l.church_id = $2
+            "#,
+        )
+        .bind(line_id)
+        .bind(church_id)
+        .fetch_optional(pool)
+        .await?
+        .ok_or_else(|| AppError::not_found("Linha do extrato"))
+    }
+
+    /// Candidates for pending lines: same account, type and amount, not yet reconciled,
+    /// dated within ±`days`. Ranked by date distance and description similarity.
+    async fn suggest(
+        pool: &PgPool,
+        statement_id: Uuid,
+        lines: &[BankStatementLine],
+        days: i32,
+    ) -> Result<Vec<ReconciliationSuggestion>, AppError> {
+        let mut candidates = sqlx::query_as::<_, ReconciliationSuggestion>(
+            r#"
+            SELECT l.id AS line_id, fe.id AS entry_id, fe.entry_date, fe.description,
+                   fe.amount, fe.status,
+                   ABS(COALESCE(fe.payment_date, fe.entry_date) - l.line_date) AS day_difference
+            FROM bank_statement_lines l
+            JOIN financial_entries fe ON fe.bank_account_id = l.bank_account_id
+                 AND fe.church_id = l.church_id
+                 AND fe.deleted_at IS NULL AND fe.reconciled_at IS NULL
+                 AND fe.status IN ('confirmado', 'pendente')
+                 AND fe.amount = ABS(l.amount)
+                 AND fe.type = CASE WHEN l.amount > 0 THEN 'receita' ELSE 'despesa' END
+                 AND COALESCE(fe.payment_date, fe.entry_date) BETWEEN l.line_date - $2 AND l.line_date + $2
+            WHERE l.statement_id = $1 AND l.status = 'pendente'
+            "#,
+        )
+        .bind(statement_id)
+        .bind(days)
+        .fetch_all(pool)
+        .await?;
+
+        let line_words: HashMap<Uuid, HashSet<String>> = lines
+            .iter()
+            .map(|l| (l.id, words(&l.description)))
+            .collect();
+
+        for candidate in &mut candidates {
+            let date_score = (60 - candidate.day_difference * 15).max(0);
+            let text_score = line_words
+                .get(&candidate.line_id)
+                .map(|lw| similarity(lw, &words(&candidate.description)))
+                .unwrap_or(0);
+            candidate.score = date_score + text_score;
+        }
+
+        candidates.sort_by(|a, b| {
+            a.line_id
+                .cmp(&b.line_id)
+                .then(b.score.cmp(&a.score))
+                .then(a.day_difference.cmp(&b.day_difference))
+        });
+
+        let mut per_line: HashMap<Uuid, usize> = HashMap::new();
+        candidates.retain(|c| {
+            let count = per_line.entry(c.line_id).or_insert(0);
+            *count += 1;
+            *count <= MAX_SUGGESTIONS_PER_LINE
+        });
+
+        Ok(candidates)
+    }
+}
+
+/// Lowercase words with 3+ characters ("PIX RECEBIDO JOAO" → {pix, recebido, joao})
+fn words(text: &str) -> HashSet<String> {
+    text.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| w.chars().count() >= 3)
+        .map(str::to_string)
+        .collect()
+}
+
+/// 0-40 points for the share of common words
+fn similarity(a: &HashSet<String>, b: &HashSet<String>) -> i32 {
+    let smaller = a.len().min(b.len());
+    if smaller == 0 {
+        return 0;
+    }
+    let common = a.intersection(b).count();
+    (common * 40 / smaller) as i32
+}
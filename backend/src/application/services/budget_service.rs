@@ -0,0 +1,369 @@
+use crate::application::dto::{
+    BudgetFilter, BudgetReportFilter, CreateBudgetRequest, UpdateBudgetRequest,
+};
+use crate::domain::entities::{Budget, BudgetAlert, BudgetReport, BudgetReportLine, BudgetSummary};
+use crate::errors::AppError;
+use chrono::{Months, NaiveDate};
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+pub struct BudgetService;
+
+impl BudgetService {
+    /// List budgets
+    pub async fn list(
+        pool: &PgPool,
+        church_id: Uuid,
+        filter: &BudgetFilter,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<BudgetSummary>, i64), AppError> {
+        let where_clause = r#"
+            b.church_id = $1 AND b.deleted_at IS NULL
+            AND ($2::smallint IS NULL OR b.year = $2)
+            AND ($3::uuid IS NULL OR b.account_plan_id = $3)
+            AND ($4::uuid IS NULL OR b.congregation_id = $4)
+        "#;
+
+        let total = sqlx::query_scalar::<_, i64>(&format!(
+            "SELECT COUNT(*) FROM budgets b WHERE {where_clause}"
+        ))
+        .bind(church_id)
+        .bind(filter.year)
+        .bind(filter.account_plan_id)
+        .bind(filter.congregation_id)
+        .fetch_one(pool)
+        .await?;
+
+        let budgets = sqlx::query_as::<_, BudgetSummary>(&format!(
+            r#"
+            SELECT b.id, b.account_plan_id, ap.code AS account_plan_code,
+                   ap.name AS account_plan_name, ap.type,
+                   b.congregation_id, c.name AS congregation_name,
+                   b.year, b.month, b.amount, b.notes, b.created_at
+            FROM budgets b
+            JOIN account_plans ap ON ap.id = b.account_plan_id
+            LEFT JOIN congregations c ON c.id = b.congregation_id
+            WHERE {where_clause}
+            ORDER BY b.year DESC, ap.code, b.month NULLS FIRST
+            LIMIT $5 OFFSET $6
+            "#
+        ))
+        .bind(church_id)
+        .bind(filter.year)
+        .bind(filter.account_plan_id)
+        .bind(filter.congregation_id)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(pool)
+        .await?;
+
+        Ok((budgets, total))
+    }
+
+    /// Get budget by ID
+    pub async fn get_by_id(
+        pool: &PgPool,
+        church_id: Uuid,
+        budget_id: Uuid,
+    ) -> Result<Budget, AppError> {
+        sqlx::query_as::<_, Budget>(
+            "SELECT * FROM budgets WHERE id = $1 AND church_id = $2 AND deleted_at IS NULL",
+        )
+        .bind(budget_id)
+        .bind(church_id)
+        .fetch_optional(pool)
+        .await?
+        .ok_or_else(|| AppError::not_found("Orçamento"))
+    }
+
+    /// Create a yearly or monthly budget. A category uses either one yearly value
+    /// or monthly values in a given year and scope, never both.
+    pub async fn create(
+        pool: &PgPool,
+        church_id: Uuid,
+        user_id: Uuid,
+        req: &CreateBudgetRequest,
+    ) -> Result<Budget, AppError> {
+        if req.amount < Decimal::ZERO {
+            return Err(AppError::validation("Valor não pode ser negativo"));
+        }
+
+        let plan_exists = sqlx::query_scalar::<_, bool>(
+            "SELECT EXISTS(SELECT 1 FROM account_plans WHERE id = $1 AND church_id = $2)",
+        )
+        .bind(req.account_plan_id)
+        .bind(church_id)
+        .fetch_one(pool)
+        .await?;
+        if !plan_exists {
+            return Err(AppError::not_found("Plano de contas"));
+        }
+
+        if let Some(cid) = req.congregation_id {
+            let exists = sqlx::query_scalar::<_, bool>(
+                "SELECT EXISTS(SELECT 1 FROM congregations WHERE id = $1 AND church_id = $2)",
+            )
+            .bind(cid)
+            .bind(church_id)
+            .fetch_one(pool)
+            .await?;
+            if !exists {
+                return Err(AppError::not_found("Congregação"));
+            }
+        }
+
+        let existing = sqlx::query_as::<_, (Option<i16>,)>(
+            r#"SELECT month FROM budgets
+               WHERE church_id = $1 AND account_plan_id = $2 AND year = $3
+               AND congregation_id IS NOT DISTINCT FROM $4 AND deleted_at IS NULL"#,
+        )
+        .bind(church_id)
+        .bind(req.account_plan_id)
+        .bind(req.year)
+        .bind(req.congregation_id)
+        .fetch_all(pool)
+        .await?;
+
+        for (month,) in existing {
+            match (month, req.month) {
+                (a, b) if a == b => {
+                    return Err(AppError::Conflict(
+                        "Já existe orçamento desta categoria para o período".into(),
+                    ));
+                }
+                (None, Some(_)) | (Some(_), None) => {
+                    return Err(AppError::Conflict(
+                        "A categoria já possui orçamento neste ano com outra periodicidade (anual ou mensal)"
+                            .into(),
+                    ));
+                }
+                _ => {}
+            }
+        }
+
+        let budget = sqlx::query_as::<_, Budget>(
+            r#"
+            INSERT INTO budgets (church_id, account_plan_id, congregation_id, year, month,
+                                 amount, notes, created_by)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING *
+            "#,
+        )
+        .bind(church_id)
+        .bind(req.account_plan_id)
+        .bind(req.congregation_id)
+        .bind(req.year)
+        .bind(req.month)
+        .bind(req.amount)
+        .bind(&req.notes)
+        .bind(user_id)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(budget)
+    }
+
+    /// Update a budget's amount or notes
+    pub async fn update(
+        pool: &PgPool,
+        church_id: Uuid,
+        budget_id: Uuid,
+        req: &UpdateBudgetRequest,
+    ) -> Result<Budget, AppError> {
+        if req.amount.is_some_and(|a| a < Decimal::ZERO) {
+            return Err(AppError::validation("Valor não pode ser negativo"));
+        }
+
+        sqlx::query_as::<_, Budget>(
+            r#"
+            UPDATE budgets SET amount = COALESCE($3, amount), notes = COALESCE($4, notes)
+            WHERE id = $1 AND church_id = $2 AND deleted_at IS NULL
+            RETURNING *
+            "#,
+        )
+        .bind(budget_id)
+        .bind(church_id)
+        .bind(req.amount)
+        .bind(&req.notes)
+        .fetch_optional(pool)
+        .await?
+        .ok_or_else(|| AppError::not_found("Orçamento"))
+    }
+
+    /// Soft delete a budget
+    pub async fn delete(pool: &PgPool, church_id: Uuid, budget_id: Uuid) -> Result<(), AppError> {
+        let result = sqlx::query(
+            "UPDATE budgets SET deleted_at = NOW() WHERE id = $1 AND church_id = $2 AND deleted_at IS NULL",
+        )
+        .bind(budget_id)
+        .bind(church_id)
+        .execute(pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::not_found("Orçamento"));
+        }
+
+        Ok(())
+    }
+
+    /// Budget vs actual for a year or a month, rolled up the account plan tree.
+    /// Yearly budgets count 1/12 per month; actual follows the report rules
+    /// (confirmed and reversed entries, no transfers). Expense categories above
+    /// their budget are returned as alerts.
+    pub async fn report(
+        pool: &PgPool,
+        church_id: Uuid,
+        filter: &BudgetReportFilter,
+    ) -> Result<BudgetReport, AppError> {
+        let year_start = NaiveDate::from_ymd_opt(filter.year as i32, 1, 1)
+            .ok_or_else(|| AppError::validation("Ano inválido"))?;
+        let (period_start, months) = match filter.month {
+            Some(m) if (1..=12).contains(&m) => (year_start + Months::new(m as u32 - 1), 1),
+            Some(_) => return Err(AppError::validation("Mês deve estar entre 1 e 12")),
+            None => (year_start, 12),
+        };
+        let period_end = period_start + Months::new(months) - chrono::Days::new(1);
+
+        let plans = sqlx::query_as::<_, (Uuid, Option<Uuid>, String, String, String, i16)>(
+            "SELECT id, parent_id, code, name, type, level FROM account_plans WHERE church_id = $1 ORDER BY code",
+        )
+        .bind(church_id)
+        .fetch_all(pool)
+        .await?;
+
+        let budgeted = sqlx::query_as::<_, (Uuid, Decimal)>(
+            r#"
+            SELECT account_plan_id,
+                   SUM(CASE WHEN month IS NULL THEN ROUND(amount * $5 / 12, 2) ELSE amount END)
+            FROM budgets
+            WHERE church_id = $1 AND year = $2 AND deleted_at IS NULL
+              AND congregation_id IS NOT DISTINCT FROM $3
+              AND (month IS NULL OR $4::smallint IS NULL OR month = $4)
+            GROUP BY account_plan_id
+            "#,
+        )
+        .bind(church_id)
+        .bind(filter.year)
+        .bind(filter.congregation_id)
+        .bind(filter.month)
+        .bind(Decimal::from(months))
+        .fetch_all(pool)
+        .await?;
+
+        let actual = sqlx::query_as::<_, (Uuid, Decimal)>(
+            r#"
+            SELECT l.account_plan_id,
+                   -- A reversal has the opposite type of its category and offsets the original
+                   SUM(CASE WHEN fe.type = ap.type THEN l.amount ELSE -l.amount END)
+            FROM financial_entries fe
+            JOIN financial_entry_lines l ON l.entry_id = fe.id
+            JOIN account_plans ap ON ap.id = l.account_plan_id
+            WHERE fe.church_id = $1 AND fe.status IN ('confirmado', 'estornado')
+              AND fe.deleted_at IS NULL AND fe.transfer_id IS NULL
+              AND ($2::uuid IS NULL OR fe.congregation_id = $2)
+              AND fe.entry_date BETWEEN $3 AND $4
+            GROUP BY l.account_plan_id
+            "#,
+        )
+        .bind(church_id)
+        .bind(filter.congregation_id)
+        .bind(period_start)
+        .bind(period_end)
+        .fetch_all(pool)
+        .await?;
+
+        let parents: HashMap<Uuid, Option<Uuid>> =
+            plans.iter().map(|(id, parent, ..)| (*id, *parent)).collect();
+        let types: HashMap<Uuid, &str> =
+            plans.iter().map(|(id, _, _, _, t, _)| (*id, t.as_str())).collect();
+
+        let mut report = BudgetReport {
+            year: filter.year,
+            month: filter.month,
+            period_start,
+            period_end,
+            congregation_id: filter.congregation_id,
+            total_income_budgeted: Decimal::ZERO,
+            total_income_actual: Decimal::ZERO,
+            total_expense_budgeted: Decimal::ZERO,
+            total_expense_actual: Decimal::ZERO,
+            lines: Vec::new(),
+            alerts: Vec::new(),
+        };
+
+        // Add each category's own values to itself and to every ancestor
+        let mut totals: HashMap<Uuid, (Decimal, Decimal)> = HashMap::new();
+        let own = budgeted
+            .into_iter()
+            .map(|(id, amount)| (id, amount, Decimal::ZERO))
+            .chain(actual.into_iter().map(|(id, amount)| (id, Decimal::ZERO, amount)));
+        for (plan_id, budget, spent) in own {
+            match types.get(&plan_id) {
+                Some(&"receita") => {
+                    report.total_income_budgeted += budget;
+                    report.total_income_actual += spent;
+                }
+                Some(_) => {
+                    report.total_expense_budgeted += budget;
+                    report.total_expense_actual += spent;
+                }
+                None => continue,
+            }
+
+            let mut current = Some(plan_id);
+            let mut depth = 0;
+            while let Some(id) = current {
+                let entry = totals.entry(id).or_insert((Decimal::ZERO, Decimal::ZERO));
+                entry.0 += budget;
+                entry.1 += spent;
+                current = parents.get(&id).copied().flatten();
+                depth += 1;
+                if depth > plans.len() {
+                    break;
+                }
+            }
+        }
+
+        for (id, parent_id, code, name, plan_type, level) in plans {
+            let Some(&(budgeted, actual)) = totals.get(&id) else {
+                continue;
+            };
+            if budgeted.is_zero() && actual.is_zero() {
+                continue;
+            }
+
+            let over_budget = plan_type == "despesa" && budgeted > Decimal::ZERO && actual > budgeted;
+            if over_budget {
+                report.alerts.push(BudgetAlert {
+                    account_plan_id: id,
+                    code: code.clone(),
+                    name: name.clone(),
+                    budgeted,
+                    actual,
+                    exceeded_by: actual - budgeted,
+                });
+            }
+
+            report.lines.push(BudgetReportLine {
+                account_plan_id: id,
+                parent_id,
+                code,
+                name,
+                plan_type,
+                level,
+                budgeted,
+                actual,
+                variance: budgeted - actual,
+                percent_used: (budgeted > Decimal::ZERO)
+                    .then(|| (actual * Decimal::from(100) / budgeted).round_dp(1)),
+                over_budget,
+            });
+        }
+
+        Ok(report)
+    }
+}
@@ -1,6 +1,13 @@
-use crate::application::dto::{CreateCampaignRequest, UpdateCampaignRequest};
-use crate::domain::entities::{Campaign, CampaignSummary};
+use crate::application::dto::{
+    CreateCampaignPledgeRequest, CreateCampaignRequest, UpdateCampaignPledgeRequest,
+    UpdateCampaignRequest,
+};
+use crate::domain::entities::{
+    Campaign, CampaignPledge, CampaignPledgeSummary, CampaignReport, CampaignSummary,
+};
 use crate::errors::AppError;
+use chrono::{Local, NaiveDate};
+use rust_decimal::Decimal;
 use sqlx::PgPool;
 use uuid::Uuid;
 
@@ -42,8 +49,9 @@ impl CampaignService {
             r#"
             SELECT c.id, c.name, c.description, c.goal_amount, c.raised_amount,
                    c.start_date, c.end_date, c.status,
-                   (SELECT COUNT(*) FROM financial_entries fe 
-                    WHERE fe.campaign_id = c.id AND fe.deleted_at IS NULL AND fe.status != 'cancelado') AS entries_count,
+                   (SELECT COUNT(DISTINCT fe.id) FROM financial_entries fe
+                    JOIN financial_entry_lines l ON l.entry_id = fe.id
+                    WHERE l.campaign_id = c.id AND fe.deleted_at IS NULL AND fe.status != 'cancelado') AS entries_count,
                    c.created_at
             FROM campaigns c
             WHERE {where_clause}
@@ -95,6 +103,44 @@ impl CampaignService {
         .ok_or_else(|| AppError::not_found("Campanha"))
     }
 
+    /// Ensure a campaign can receive an entry dated `entry_date`: it must be
+    /// active and the date cannot be after its end date (RN-FIN-008)
+    pub async fn ensure_accepts_entries(
+        pool: &PgPool,
+        church_id: Uuid,
+        campaign_id: Uuid,
+        entry_date: NaiveDate,
+    ) -> Result<Campaign, AppError> {
+        let campaign = Self::get_by_id(pool, church_id, campaign_id)
+            .await
+            .map_err(|_| AppError::validation("Campanha não encontrada"))?;
+
+        match campaign.status.as_str() {
+            "encerrada" => {
+                return Err(AppError::validation(
+                    "Campanha encerrada: não aceita novos lançamentos",
+                ));
+            }
+            "cancelada" => {
+                return Err(AppError::validation(
+                    "Campanha cancelada: não aceita novos lançamentos",
+                ));
+            }
+            _ => {}
+        }
+
+        if let Some(end_date) = campaign.end_date {
+            if entry_date > end_date {
+                return Err(AppError::validation(format!(
+                    "Campanha terminou em {}: lançamentos devem ter data até o término",
+                    end_date.format("%d/%m/%Y")
+                )));
+            }
+        }
+
+        Ok(campaign)
+    }
+
     /// Create a new campaign
     pub async fn create(
         pool: &PgPool,
@@ -189,4 +235,246 @@ impl CampaignService {
 
         Ok(campaign)
     }
+
+    /// List a campaign's pledges with paid and expected-to-date amounts. Paid
+    /// counts the member's confirmed income entries linked to the campaign.
+    pub async fn list_pledges(
+        pool: &PgPool,
+        church_id: Uuid,
+        campaign_id: Uuid,
+    ) -> Result<Vec<CampaignPledgeSummary>, AppError> {
+        Self::get_by_id(pool, church_id, campaign_id).await?;
+
+        let pledges = sqlx::query_as::<_, CampaignPledgeSummary>(
+            r#"
+            SELECT p.id, p.member_id, m.full_name AS member_name, p.pledged_amount,
+                   p.installments, ROUND(p.pledged_amount / p.installments, 2) AS installment_amount,
+                   p.start_date,
+                   CASE WHEN d.due >= p.installments THEN p.pledged_amount
+                        ELSE ROUND(p.pledged_amount / p.installments, 2) * d.due
+                   END AS expected_to_date,
+                   pay.paid AS paid_amount,
+                   GREATEST(p.pledged_amount - pay.paid, 0) AS remaining,
+                   p.notes
+            FROM campaign_pledges p
+            JOIN members m ON m.id = p.member_id
+            CROSS JOIN LATERAL (
+                SELECT GREATEST(0, ((EXTRACT(YEAR FROM $3::date) - EXTRACT(YEAR FROM p.start_date)) * 12
+                       + EXTRACT(MONTH FROM $3::date) - EXTRACT(MONTH FROM p.start_date))::int + 1) AS due
+            ) d
+            CROSS JOIN LATERAL (
+                SELECT COALESCE(SUM(l.amount), 0) AS paid
+                FROM financial_entries fe
+                JOIN financial_entry_lines l ON l.entry_id = fe.id
+                WHERE l.campaign_id = p.campaign_id AND l.member_id = p.member_id
+                  AND fe.type = 'receita' AND fe.status = 'confirmado' AND fe.deleted_at IS NULL
+            ) pay
+            WHERE p.church_id = $1 AND p.campaign_id = $2 AND p.deleted_at IS NULL
+            ORDER BY m.full_name
+            "#,
+        )
+        .bind(church_id)
+        .bind(campaign_id)
+        .bind(Local::now().date_naive())
+        .fetch_all(pool)
+        .await?;
+
+        Ok(pledges)
+    }
+
+    /// Get a pledge by ID
+    async fn get_pledge(
+        pool: &PgPool,
+        church_id: Uuid,
+        campaign_id: Uuid,
+        pledge_id: Uuid,
+    ) -> Result<CampaignPledge, AppError> {
+        sqlx::query_as::<_, CampaignPledge>(
+            r#"SELECT * FROM campaign_pledges
+               WHERE id = $1 AND church_id = $2 AND campaign_id = $3 AND deleted_at IS NULL"#,
+        )
+        .bind(pledge_id)
+        .bind(church_id)
+        .bind(campaign_id)
+        .fetch_optional(pool)
+        .await?
+        .ok_or_else(|| AppError::not_found("Promessa"))
+    }
+
+    /// Register a member's pledge. Only open campaigns accept new pledges, and
+    /// a member has at most one pledge per campaign.
+    pub async fn create_pledge(
+        pool: &PgPool,
+        church_id: Uuid,
+        campaign_id: Uuid,
+        user_id: Uuid,
+        req: &CreateCampaignPledgeRequest,
+    ) -> Result<CampaignPledge, AppError> {
+        if req.pledged_amount <= Decimal::ZERO {
+            return Err(AppError::validation("Valor deve ser maior que zero"));
+        }
+
+        let start_date = req.start_date.unwrap_or_else(|| Local::now().date_naive());
+        Self::ensure_accepts_entries(pool, church_id, campaign_id, start_date).await?;
+
+        let member_exists = sqlx::query_scalar::<_, bool>(
+            "SELECT EXISTS(SELECT 1 FROM members WHERE id = $1 AND church_id = $2 AND deleted_at IS NULL)",
+        )
+        .bind(req.member_id)
+        .bind(church_id)
+        .fetch_one(pool)
+        .await?;
+        if !member_exists {
+            return Err(AppError::not_found("Membro"));
+        }
+
+        let duplicate = sqlx::query_scalar::<_, bool>(
+            r#"SELECT EXISTS(SELECT 1 FROM campaign_pledges
+               WHERE campaign_id = $1 AND member_id = $2 AND deleted_at IS NULL)"#,
+        )
+        .bind(campaign_id)
+        .bind(req.member_id)
+        .fetch_one(pool)
+        .await?;
+        if duplicate {
+            return Err(AppError::Conflict(
+                "Este membro já possui promessa nesta campanha".into(),
+            ));
+        }
+
+        let pledge = sqlx::query_as::<_, CampaignPledge>(
+            r#"
+            INSERT INTO campaign_pledges (church_id, campaign_id, member_id, pledged_amount,
+                                          installments, start_date, notes, created_by)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING *
+            "#,
+        )
+        .bind(church_id)
+        .bind(campaign_id)
+        .bind(req.member_id)
+        .bind(req.pledged_amount)
+        .bind(req.installments.unwrap_or(1))
+        .bind(start_date)
+        .bind(&req.notes)
+        .bind(user_id)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(pledge)
+    }
+
+    /// Update a pledge's amount, installments, start or notes
+    pub async fn update_pledge(
+        pool: &PgPool,
+        church_id: Uuid,
+        campaign_id: Uuid,
+        pledge_id: Uuid,
+        req: &UpdateCampaignPledgeRequest,
+    ) -> Result<CampaignPledge, AppError> {
+        if req.pledged_amount.is_some_and(|a| a <= Decimal::ZERO) {
+            return Err(AppError::validation("Valor deve ser maior que zero"));
+        }
+
+        Self::get_pledge(pool, church_id, campaign_id, pledge_id).await?;
+
+        let pledge = sqlx::query_as::<_, CampaignPledge>(
+            r#"
+            UPDATE campaign_pledges SET
+                pledged_amount = COALESCE($3, pledged_amount),
+                installments = COALESCE($4, installments),
+                start_date = COALESCE($5, start_date),
+                notes = COALESCE($6, notes)
+            WHERE id = $1 AND church_id = $2 AND deleted_at IS NULL
+            RETURNING *
+            "#,
+        )
+        .bind(pledge_id)
+        .bind(church_id)
+        .bind(req.pledged_amount)
+        .bind(req.installments)
+        .bind(req.start_date)
+        .bind(&req.notes)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(pledge)
+    }
+
+    /// Soft delete a pledge
+    pub async fn delete_pledge(
+        pool: &PgPool,
+        church_id: Uuid,
+        campaign_id: Uuid,
+        pledge_id: Uuid,
+    ) -> Result<(), AppError> {
+        let result = sqlx::query(
+            r#"UPDATE campaign_pledges SET deleted_at = NOW()
+               WHERE id = $1 AND church_id = $2 AND campaign_id = $3 AND deleted_at IS NULL"#,
+        )
+        .bind(pledge_id)
+        .bind(church_id)
+        .bind(campaign_id)
+        .execute(pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::not_found("Promessa"));
+        }
+
+        Ok(())
+    }
+
+    /// Goal, pledged and raised amounts of a campaign, with each pledge's progress.
+    /// Raised counts confirmed income entries linked to the campaign, including
+    /// the campaign lines of split entries.
+    pub async fn report(
+        pool: &PgPool,
+        church_id: Uuid,
+        campaign_id: Uuid,
+    ) -> Result<CampaignReport, AppError> {
+        let campaign = Self::get_by_id(pool, church_id, campaign_id).await?;
+        let pledges = Self::list_pledges(pool, church_id, campaign_id).await?;
+
+        let (raised_amount, entries_count, donors_count) =
+            sqlx::query_as::<_, (Decimal, i64, i64)>(
+                r#"
+                SELECT COALESCE(SUM(l.amount), 0), COUNT(DISTINCT fe.id), COUNT(DISTINCT l.member_id)
+                FROM financial_entries fe
+                JOIN financial_entry_lines l ON l.entry_id = fe.id
+                WHERE fe.church_id = $1 AND l.campaign_id = $2 AND fe.type = 'receita'
+                  AND fe.status = 'confirmado' AND fe.deleted_at IS NULL
+                "#,
+            )
+            .bind(church_id)
+            .bind(campaign_id)
+            .fetch_one(pool)
+            .await?;
+
+        let pledged_amount: Decimal = pledges.iter().map(|p| p.pledged_amount).sum();
+        let pledges_paid_amount: Decimal = pledges.iter().map(|p| p.paid_amount).sum();
+        let percent = |part: Decimal, whole: Decimal| {
+            (whole > Decimal::ZERO).then(|| (part * Decimal::from(100) / whole).round_dp(1))
+        };
+        let goal = campaign.goal_amount.unwrap_or(Decimal::ZERO);
+
+        Ok(CampaignReport {
+            campaign_id: campaign.id,
+            name: campaign.name,
+            status: campaign.status,
+            start_date: campaign.start_date,
+            end_date: campaign.end_date,
+            goal_amount: campaign.goal_amount,
+            pledged_amount,
+            raised_amount,
+            pledges_paid_amount,
+            percent_of_goal: percent(raised_amount, goal),
+            percent_pledged: percent(pledged_amount, goal),
+            percent_pledges_paid: percent(pledges_paid_amount, pledged_amount),
+            pledges_count: pledges.len() as i64,
+            donors_count,
+            entries_count,
+            pledges,
+        })
+    }
 }
@@ -403,7 +403,7 @@ impl CongregationService {
         // Financial stats (current month)
         let income: (Option<f64>,) = sqlx::query_as(
             r#"SELECT COALESCE(SUM(amount::float8), 0.0) FROM financial_entries 
-               WHERE congregation_id = $1 AND type = 'receita' AND status = 'confirmado' 
+               WHERE congregation_id = $1 AND type = 'receita' AND status IN ('confirmado', 'estornado') AND transfer_id IS NULL
                AND entry_date >= DATE_TRUNC('month', NOW()) AND deleted_at IS NULL"#,
         )
         .bind(congregation_id)
@@ -412,7 +412,7 @@ impl CongregationService {
 
         let expense: (Option<f64>,) = sqlx::query_as(
             r#"SELECT COALESCE(SUM(amount::float8), 0.0) FROM financial_entries 
-               WHERE congregation_id = $1 AND type = 'despesa' AND status = 'confirmado' 
+               WHERE congregation_id = $1 AND type = 'despesa' AND status IN ('confirmado', 'estornado') AND transfer_id IS NULL
                AND entry_date >= DATE_TRUNC('month', NOW()) AND deleted_at IS NULL"#,
         )
         .bind(congregation_id)
@@ -671,10 +671,10 @@ impl CongregationService {
             SELECT c.id, c.name, c.type,
                    (SELECT COUNT(*) FROM members m WHERE m.congregation_id = c.id AND m.status = 'ativo' AND m.deleted_at IS NULL) AS active_members,
                    (SELECT COALESCE(SUM(fe.amount::float8), 0.0) FROM financial_entries fe 
-                    WHERE fe.congregation_id = c.id AND fe.type = 'receita' AND fe.status = 'confirmado' 
+                    WHERE fe.congregation_id = c.id AND fe.type = 'receita' AND fe.status IN ('confirmado', 'estornado') AND fe.transfer_id IS NULL
                     AND fe.entry_date >= DATE_TRUNC('month', NOW()) AND fe.deleted_at IS NULL) AS income_month,
                    (SELECT COALESCE(SUM(fe.amount::float8), 0.0) FROM financial_entries fe 
-                    WHERE fe.congregation_id = c.id AND fe.type = 'despesa' AND fe.status = 'confirmado' 
+                    WHERE fe.congregation_id = c.id AND fe.type = 'despesa' AND fe.status IN ('confirmado', 'estornado') AND fe.transfer_id IS NULL
                     AND fe.entry_date >= DATE_TRUNC('month', NOW()) AND fe.deleted_at IS NULL) AS expense_month
             FROM congregations c
             WHERE c.church_id = $1 AND c.is_active = TRUE
@@ -834,13 +834,13 @@ impl CongregationService {
             r#"
             SELECT c.id, c.name, c.type,
                 (SELECT COALESCE(SUM(fe.amount::float8), 0.0) FROM financial_entries fe 
-                 WHERE fe.congregation_id = c.id AND fe.type = 'receita' AND fe.status = 'confirmado' 
+                 WHERE fe.congregation_id = c.id AND fe.type = 'receita' AND fe.status IN ('confirmado', 'estornado') AND fe.transfer_id IS NULL
                  {date_filter} AND fe.deleted_at IS NULL) AS value_1,
                 (SELECT COALESCE(SUM(fe.amount::float8), 0.0) FROM financial_entries fe 
-                 WHERE fe.congregation_id = c.id AND fe.type = 'despesa' AND fe.status = 'confirmado' 
+                 WHERE fe.congregation_id = c.id AND fe.type = 'despesa' AND fe.status IN ('confirmado', 'estornado') AND fe.transfer_id IS NULL
                  {date_filter} AND fe.deleted_at IS NULL) AS value_2,
                 (SELECT COALESCE(SUM(CASE WHEN fe.type = 'receita' THEN fe.amount ELSE -fe.amount END)::float8, 0.0) FROM financial_entries fe 
-                 WHERE fe.congregation_id = c.id AND fe.status = 'confirmado' 
+                 WHERE fe.congregation_id = c.id AND fe.status IN ('confirmado', 'estornado') AND fe.transfer_id IS NULL
                  {date_filter} AND fe.deleted_at IS NULL) AS value_3,
                 'Receitas'::text AS label_1,
                 'Despesas'::text AS label_2,
@@ -0,0 +1,251 @@
+use crate::application::dto::{IssueReceiptRequest, ReceiptFilter};
+use crate::application::services::financial_service::ENTRY_COLUMNS;
+use crate::application::services::ChurchService;
+use crate::domain::entities::{DonationReceipt, FinancialEntry};
+use crate::errors::AppError;
+use crate::infrastructure::pdf::{self, Letterhead, PdfReport};
+use chrono::{Datelike, Local};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+pub struct DonationReceiptService;
+
+impl DonationReceiptService {
+    /// List receipts, newest first
+    pub async fn list(
+        pool: &PgPool,
+        church_id: Uuid,
+        filter: &ReceiptFilter,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<DonationReceipt>, i64), AppError> {
+        let where_clause = r#"
+            church_id = $1
+            AND ($2::varchar IS NULL OR status = $2)
+            AND ($3::uuid IS NULL OR member_id = $3)
+            AND ($4::date IS NULL OR donation_date >= $4)
+            AND ($5::date IS NULL OR donation_date <= $5)
+        "#;
+
+        let total = sqlx::query_scalar::<_, i64>(&format!(
+            "SELECT COUNT(*) FROM donation_receipts WHERE {where_clause}"
+        ))
+        .bind(church_id)
+        .bind(&filter.status)
+        .bind(filter.member_id)
+        .bind(filter.date_from)
+        .bind(filter.date_to)
+        .fetch_one(pool)
+        .await?;
+
+        let receipts = sqlx::query_as::<_, DonationReceipt>(&format!(
+            r#"SELECT * FROM donation_receipts WHERE {where_clause}
+               ORDER BY number DESC LIMIT $6 OFFSET $7"#
+        ))
+        .bind(church_id)
+        .bind(&filter.status)
+        .bind(filter.member_id)
+        .bind(filter.date_from)
+        .bind(filter.date_to)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(pool)
+        .await?;
+
+        Ok((receipts, total))
+    }
+
+    /// Get receipt by ID
+    pub async fn get_by_id(
+        pool: &PgPool,
+        church_id: Uuid,
+        receipt_id: Uuid,
+    ) -> Result<DonationReceipt, AppError> {
+        sqlx::query_as::<_, DonationReceipt>(
+            "SELECT * FROM donation_receipts WHERE id = $1 AND church_id = $2",
+        )
+        .bind(receipt_id)
+        .bind(church_id)
+        .fetch_optional(pool)
+        .await?
+        .ok_or_else(|| AppError::not_found("Recibo"))
+    }
+
+    /// Issue a receipt for a confirmed income entry with the church's next number.
+    /// The donor defaults to the entry's member. Numbers come from a per-church
+    /// counter that only moves forward, so a voided receipt keeps its number.
+    pub async fn issue(
+        pool: &PgPool,
+        church_id: Uuid,
+        user_id: Uuid,
+        entry_id: Uuid,
+        req: &IssueReceiptRequest,
+    ) -> Result<DonationReceipt, AppError> {
+        let mut tx = pool.begin().await?;
+
+        let entry = sqlx::query_as::<_, FinancialEntry>(&format!(
+            r#"SELECT {ENTRY_COLUMNS} FROM financial_entries
+               WHERE id = $1 AND church_id = $2 AND deleted_at IS NULL
+               FOR UPDATE"#
+        ))
+        .bind(entry_id)
+        .bind(church_id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(|| AppError::not_found("Lançamento financeiro"))?;
+
+        if entry.entry_type != "receita"
+            || entry.transfer_id.is_some()
+            || entry.reversal_of.is_some()
+        {
+            return Err(AppError::validation(
+                "Recibos só podem ser emitidos para receitas (exceto transferências e estornos)",
+            ));
+        }
+        if entry.status != "confirmado" {
+            return Err(AppError::validation(
+                "Apenas receitas confirmadas podem ter recibo",
+            ));
+        }
+
+        let existing = sqlx::query_scalar::<_, i32>(
+            "SELECT number FROM donation_receipts WHERE financial_entry_id = $1 AND status = 'emitido'",
+        )
+        .bind(entry_id)
+        .fetch_optional(&mut *tx)
+        .await?;
+        if let Some(number) = existing {
+            return Err(AppError::Conflict(format!(
+                "Este lançamento já possui o recibo nº {number}"
+            )));
+        }
+
+        let member = match entry.member_id {
+            Some(member_id) => sqlx::query_as::<_, (String, Option<String>)>(
+                "SELECT full_name, cpf FROM members WHERE id = $1 AND church_id = $2",
+            )
+            .bind(member_id)
+            .bind(church_id)
+            .fetch_optional(&mut *tx)
+            .await?,
+            None => None,
+        };
+        let donor_name = req
+            .donor_name
+            .clone()
+            .or_else(|| member.as_ref().map(|(name, _)| name.clone()))
+            .ok_or_else(|| {
+                AppError::validation("Informe o nome do doador (lançamento sem membro vinculado)")
+            })?;
+        let donor_document = req
+            .donor_document
+            .clone()
+            .or_else(|| member.and_then(|(_, cpf)| cpf))
+            .filter(|d| !d.is_empty());
+
+        let number = sqlx::query_scalar::<_, i32>(
+            r#"INSERT INTO receipt_counters (church_id, last_number) VALUES ($1, 1)
+               ON CONFLICT (church_id) DO UPDATE SET last_number = receipt_counters.last_number + 1
+               RETURNING last_number"#,
+        )
+        .bind(church_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let receipt = sqlx::query_as::<_, DonationReceipt>(
+            r#"
+            INSERT INTO donation_receipts (church_id, number, financial_entry_id, member_id,
+                                           donor_name, donor_document, amount, donation_date,
+                                           description, issued_by)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, LEFT($9, 300), $10)
+            RETURNING *
+            "#,
+        )
+        .bind(church_id)
+        .bind(number)
+        .bind(entry.id)
+        .bind(entry.member_id)
+        .bind(&donor_name)
+        .bind(&donor_document)
+        .bind(entry.amount)
+        .bind(entry.payment_date.unwrap_or(entry.entry_date))
+        .bind(&entry.description)
+        .bind(user_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(receipt)
+    }
+
+    /// Receipt as a PDF with the church letterhead, the amount in words and the
+    /// issuing treasurer's signature. Voided receipts are marked as such.
+    pub async fn pdf(pool: &PgPool, church_id: Uuid, receipt_id: Uuid) -> Result<Vec<u8>, AppError> {
+        let receipt = Self::get_by_id(pool, church_id, receipt_id).await?;
+        let church = ChurchService::get_by_id(pool, church_id).await?;
+        let letterhead = Letterhead::for_church(&church).await;
+
+        let treasurer = sqlx::query_scalar::<_, String>(
+            r#"SELECT m.full_name FROM users u JOIN members m ON m.id = u.member_id
+               WHERE u.id = $1"#,
+        )
+        .bind(receipt.issued_by)
+        .fetch_optional(pool)
+        .await?;
+
+        let mut report = PdfReport::new(
+            letterhead,
+            &format!("Recibo de Doação Nº {:06}", receipt.number),
+        );
+
+        if receipt.status == "cancelado" {
+            let when = receipt
+                .voided_at
+                .map(|d| format!(" em {}", pdf::date_br(d.date_naive())))
+                .unwrap_or_default();
+            let reason = receipt
+                .void_reason
+                .as_deref()
+                .map(|r| format!(" — {r}"))
+                .unwrap_or_default();
+            report.paragraph(&format!(
+                "RECIBO CANCELADO{when}{reason}. Este documento não tem validade."
+            ));
+        }
+
+        let document = match receipt.donor_document.as_deref() {
+            Some(d) if d.chars().filter(char::is_ascii_digit).count() > 11 => format!(", CNPJ {d}"),
+            Some(d) => format!(", CPF {d}"),
+            None => String::new(),
+        };
+        report.paragraph(&format!(
+            "Recebemos de {}{document} a importância de {} ({}), referente a {}, em {}.",
+            receipt.donor_name,
+            pdf::brl(receipt.amount),
+            pdf::amount_in_words(receipt.amount),
+            receipt.description,
+            pdf::date_br(receipt.donation_date),
+        ));
+        report.paragraph("Para clareza, firmamos o presente recibo.");
+
+        report.key_value("Valor", &pdf::brl(receipt.amount), true);
+
+        let today = Local::now().date_naive();
+        let place = church.city.as_deref().map(|c| format!("{c}, ")).unwrap_or_default();
+        report.spacer(10.0);
+        report.paragraph(&format!(
+            "{place}{} de {} de {}.",
+            today.day(),
+            pdf::month_name(today.month()).to_lowercase(),
+            today.year()
+        ));
+
+        match treasurer.as_deref().filter(|t| !t.is_empty()) {
+            Some(name) => report.signature(name, "Tesouraria"),
+            None => report.signature("Tesouraria", &church.name),
+        }
+
+        Ok(report.finish())
+    }
+}
@@ -0,0 +1,304 @@
+use crate::application::dto::{CreateExpenseApprovalRuleRequest, UpdateExpenseApprovalRuleRequest};
+use crate::application::services::FinancialEntryService;
+use crate::application::services::financial_service::ENTRY_COLUMNS;
+use crate::domain::entities::{ExpenseApprovalRule, FinancialEntry};
+use crate::errors::AppError;
+use rust_decimal::Decimal;
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+pub struct ExpenseApprovalService;
+
+impl ExpenseApprovalService {
+    /// List approval rules
+    pub async fn list_rules(
+        pool: &PgPool,
+        church_id: Uuid,
+    ) -> Result<Vec<ExpenseApprovalRule>, AppError> {
+        let rules = sqlx::query_as::<_, ExpenseApprovalRule>(
+            "SELECT * FROM expense_approval_rules WHERE church_id = $1 ORDER BY name",
+        )
+        .bind(church_id)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rules)
+    }
+
+    /// Get an approval rule by ID
+    pub async fn get_rule(
+        pool: &PgPool,
+        church_id: Uuid,
+        rule_id: Uuid,
+    ) -> Result<ExpenseApprovalRule, AppError> {
+        sqlx::query_as::<_, ExpenseApprovalRule>(
+            "SELECT * FROM expense_approval_rules WHERE id = $1 AND church_id = $2",
+        )
+        .bind(rule_id)
+        .bind(church_id)
+        .fetch_optional(pool)
+        .await?
+        .ok_or_else(|| AppError::not_found("Regra de aprovação"))
+    }
+
+    /// Create an approval rule
+    pub async fn create_rule(
+        pool: &PgPool,
+        church_id: Uuid,
+        req: &CreateExpenseApprovalRuleRequest,
+    ) -> Result<ExpenseApprovalRule, AppError> {
+        Self::validate_conditions(pool, church_id, req.amount_above, req.account_plan_id).await?;
+
+        let rule = sqlx::query_as::<_, ExpenseApprovalRule>(
+            r#"
+            INSERT INTO expense_approval_rules (church_id, name, amount_above, account_plan_id)
+            VALUES ($1, $2, $3, $4)
+            RETURNING *
+            "#,
+        )
+        .bind(church_id)
+        .bind(&req.name)
+        .bind(req.amount_above)
+        .bind(req.account_plan_id)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(rule)
+    }
+
+    /// Update an approval rule
+    pub async fn update_rule(
+        pool: &PgPool,
+        church_id: Uuid,
+        rule_id: Uuid,
+        req: &UpdateExpenseApprovalRuleRequest,
+    ) -> Result<ExpenseApprovalRule, AppError> {
+        let existing = Self::get_rule(pool, church_id, rule_id).await?;
+
+        Self::validate_conditions(
+            pool,
+            church_id,
+            req.amount_above.or(existing.amount_above),
+            req.account_plan_id.or(existing.account_plan_id),
+        )
+        .await?;
+
+        let rule = sqlx::query_as::<_, ExpenseApprovalRule>(
+            r#"
+            UPDATE expense_approval_rules SET
+                name = COALESCE($3, name),
+                amount_above = COALESCE($4, amount_above),
+                account_plan_id = COALESCE($5, account_plan_id),
+                is_active = COALESCE($6, is_active)
+            WHERE id = $1 AND church_id = $2
+            RETURNING *
+            "#,
+        )
+        .bind(rule_id)
+        .bind(church_id)
+        .bind(&req.name)
+        .bind(req.amount_above)
+        .bind(req.account_plan_id)
+        .bind(req.is_active)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(rule)
+    }
+
+    /// Delete an approval rule. Expenses already awaiting approval keep waiting.
+    pub async fn delete_rule(
+        pool: &PgPool,
+        church_id: Uuid,
+        rule_id: Uuid,
+    ) -> Result<(), AppError> {
+        let result =
+            sqlx::query("DELETE FROM expense_approval_rules WHERE id = $1 AND church_id = $2")
+                .bind(rule_id)
+                .bind(church_id)
+                .execute(pool)
+                .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::not_found("Regra de aprovação"));
+        }
+        Ok(())
+    }
+
+    /// First active rule an expense of this amount and category falls under (RN-FIN-020).
+    /// A category rule also covers the subcategories below it.
+    pub async fn matching_rule(
+        conn: &mut PgConnection,
+        church_id: Uuid,
+        amount: Decimal,
+        account_plan_id: Uuid,
+    ) -> Result<Option<ExpenseApprovalRule>, AppError> {
+        let rule = sqlx::query_as::<_, ExpenseApprovalRule>(
+            r#"
+            WITH RECURSIVE lineage AS (
+                SELECT id, parent_id FROM account_plans WHERE id = $3 AND church_id = $1
+                UNION ALL
+                SELECT ap.id, ap.parent_id FROM account_plans ap
+                JOIN lineage l ON ap.id = l.parent_id
+            )
+            SELECT r.* FROM expense_approval_rules r
+            WHERE r.church_id = $1 AND r.is_active = TRUE
+              AND (r.amount_above IS NULL OR $2 > r.amount_above)
+              AND (r.account_plan_id IS NULL OR r.account_plan_id IN (SELECT id FROM lineage))
+            ORDER BY r.amount_above NULLS FIRST, r.name
+            LIMIT 1
+            "#,
+        )
+        .bind(church_id)
+        .bind(amount)
+        .bind(account_plan_id)
+        .fetch_optional(&mut *conn)
+        .await?;
+
+        Ok(rule)
+    }
+
+    /// Approve an expense awaiting approval: it takes the status requested at
+    /// registration and, if confirmed, moves the bank balance.
+    /// The approver must not be the user who registered the expense.
+    pub async fn approve(
+        pool: &PgPool,
+        church_id: Uuid,
+        user_id: Uuid,
+        entry_id: Uuid,
+    ) -> Result<FinancialEntry, AppError> {
+        let existing = Self::awaiting(pool, church_id, user_id, entry_id).await?;
+
+        if existing.is_closed {
+            return Err(AppError::validation(
+                "Lançamento pertence a um mês fechado",
+            ));
+        }
+
+        let mut tx = pool.begin().await?;
+
+        // Status guard protects against two concurrent decisions
+        let entry = sqlx::query_as::<_, FinancialEntry>(&format!(
+            r#"UPDATE financial_entries
+               SET status = COALESCE(requested_status, 'confirmado'), requested_status = NULL,
+                   approved_by = $3, approved_at = NOW()
+               WHERE id = $1 AND church_id = $2 AND status = 'aguardando_aprovacao'
+               AND deleted_at IS NULL
+               RETURNING {ENTRY_COLUMNS}"#
+        ))
+        .bind(entry_id)
+        .bind(church_id)
+        .bind(user_id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(|| AppError::conflict("Esta despesa não está aguardando aprovação"))?;
+
+        if entry.status == "confirmado" {
+            FinancialEntryService::sync_bank_balances(&mut tx, &[entry.bank_account_id]).await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(entry)
+    }
+
+    /// Reject an expense awaiting approval: it is cancelled with the reason, and a
+    /// statement line matched to it goes back to pending.
+    /// The approver must not be the user who registered the expense.
+    pub async fn reject(
+        pool: &PgPool,
+        church_id: Uuid,
+        user_id: Uuid,
+        entry_id: Uuid,
+        reason: &str,
+    ) -> Result<FinancialEntry, AppError> {
+        Self::awaiting(pool, church_id, user_id, entry_id).await?;
+
+        let mut tx = pool.begin().await?;
+
+        // A cancelled expense no longer explains a statement line it was matched to
+        let entry = sqlx::query_as::<_, FinancialEntry>(&format!(
+            r#"UPDATE financial_entries
+               SET status = 'cancelado', requested_status = NULL, rejection_reason = $3,
+                   reconciled_at = NULL
+               WHERE id = $1 AND church_id = $2 AND status = 'aguardando_aprovacao'
+               AND deleted_at IS NULL
+               RETURNING {ENTRY_COLUMNS}"#
+        ))
+        .bind(entry_id)
+        .bind(church_id)
+        .bind(reason)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(|| AppError::conflict("Esta despesa não está aguardando aprovação"))?;
+
+        sqlx::query(
+            r#"UPDATE bank_statement_lines
+               SET status = 'pendente', matched_entry_id = NULL, matched_at = NULL, matched_by = NULL
+               WHERE matched_entry_id = $1"#,
+        )
+        .bind(entry_id)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(entry)
+    }
+
+    /// Expense awaiting approval that `user_id` may decide on
+    async fn awaiting(
+        pool: &PgPool,
+        church_id: Uuid,
+        user_id: Uuid,
+        entry_id: Uuid,
+    ) -> Result<FinancialEntry, AppError> {
+        let entry = FinancialEntryService::get_by_id(pool, church_id, entry_id).await?;
+
+        if entry.status != "aguardando_aprovacao" {
+            return Err(AppError::conflict("Esta despesa não está aguardando aprovação"));
+        }
+        if entry.registered_by == user_id {
+            return Err(AppError::Forbidden(
+                "A aprovação deve ser feita por um usuário diferente de quem registrou a despesa"
+                    .into(),
+            ));
+        }
+
+        Ok(entry)
+    }
+
+    async fn validate_conditions(
+        pool: &PgPool,
+        church_id: Uuid,
+        amount_above: Option<Decimal>,
+        account_plan_id: Option<Uuid>,
+    ) -> Result<(), AppError> {
+        if amount_above.is_none() && account_plan_id.is_none() {
+            return Err(AppError::validation(
+                "Informe o valor, a categoria ou ambos",
+            ));
+        }
+        if amount_above.is_some_and(|a| a < Decimal::ZERO) {
+            return Err(AppError::validation("Valor não pode ser negativo"));
+        }
+
+        if let Some(plan_id) = account_plan_id {
+            let plan_type = sqlx::query_scalar::<_, String>(
+                "SELECT type FROM account_plans WHERE id = $1 AND church_id = $2",
+            )
+            .bind(plan_id)
+            .bind(church_id)
+            .fetch_optional(pool)
+            .await?
+            .ok_or_else(|| AppError::not_found("Plano de contas"))?;
+            if plan_type != "despesa" {
+                return Err(AppError::validation(
+                    "A categoria da regra deve ser de despesa",
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
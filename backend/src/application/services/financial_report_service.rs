@@ -0,0 +1,824 @@
+use crate::application::services::ChurchService;
+use crate::domain::entities::{
+    AssemblyReport, BankAccountStatement, IncomeStatement, IncomeStatementLine, ReportLine,
+    StatementMovement,
+};
+use crate::errors::AppError;
+use crate::infrastructure::pdf::{self, Column, Letterhead, PdfReport, Row};
+use chrono::{Datelike, Months, NaiveDate};
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+/// Entries that make up income/expense in reports: confirmed (and reversed, which are
+/// offset by their reversal entry), never transfers between accounts.
+const REPORTED_ENTRIES: &str = r#"
+    fe.church_id = $1 AND fe.status IN ('confirmado', 'estornado')
+    AND fe.deleted_at IS NULL AND fe.transfer_id IS NULL
+    AND ($2::uuid IS NULL OR fe.congregation_id = $2)
+"#;
+
+/// Month columns of the income statement are limited so the PDF stays readable
+const MAX_STATEMENT_MONTHS: usize = 12;
+
+/// Movements of one bank account ($2): every confirmed or reversed entry, transfers
+/// included, matching `bank_account_ledger_balance`
+const ACCOUNT_MOVEMENTS: &str = r#"
+    fe.church_id = $1 AND fe.bank_account_id = $2
+    AND fe.status IN ('confirmado', 'estornado') AND fe.deleted_at IS NULL
+"#;
+
+pub struct FinancialReportService;
+
+impl FinancialReportService {
+    /// Accountability report for the assembly (RN-FIN-011) over whole months.
+    /// The previous balance follows the monthly closings chain; months not yet
+    /// closed are added from the entries.
+    pub async fn assembly_report(
+        pool: &PgPool,
+        church_id: Uuid,
+        congregation_id: Option<Uuid>,
+        month_from: NaiveDate,
+        month_to: NaiveDate,
+    ) -> Result<AssemblyReport, AppError> {
+        let period_start = month_from;
+        let period_end = month_to + Months::new(1) - chrono::Days::new(1);
+
+        let congregation_name = Self::congregation_name(pool, church_id, congregation_id).await?;
+
+        let previous_balance =
+            Self::balance_before(pool, church_id, congregation_id, period_start).await?;
+
+        let income_by_category = Self::category_lines(
+            pool,
+            church_id,
+            congregation_id,
+            "receita",
+            period_start,
+            period_end,
+        )
+        .await?;
+        let expense_by_category = Self::category_lines(
+            pool,
+            church_id,
+            congregation_id,
+            "despesa",
+            period_start,
+            period_end,
+        )
+        .await?;
+
+        let closed_months = sqlx::query_scalar::<_, i64>(
+            r#"SELECT COUNT(*) FROM monthly_closings
+               WHERE church_id = $1 AND reference_month BETWEEN $2 AND $3
+               AND congregation_id IS NOT DISTINCT FROM $4"#,
+        )
+        .bind(church_id)
+        .bind(period_start)
+        .bind(month_to)
+        .bind(congregation_id)
+        .fetch_one(pool)
+        .await?;
+
+        let months = (0..)
+            .take_while(|i| period_start + Months::new(*i) <= month_to)
+            .count() as i64;
+
+        // Net of reversals, like the lines
+        let total_income: Decimal = income_by_category.iter().map(|l| l.amount).sum();
+        let total_expense: Decimal = expense_by_category.iter().map(|l| l.amount).sum();
+        let result = total_income - total_expense;
+
+        Ok(AssemblyReport {
+            period_start,
+            period_end,
+            congregation_id,
+            congregation_name,
+            is_closed: closed_months >= months,
+            previous_balance,
+            income_by_category,
+            expense_by_category,
+            total_income,
+            total_expense,
+            result,
+            final_balance: previous_balance + result,
+        })
+    }
+
+    /// Assembly report as a PDF with the church letterhead
+    pub async fn assembly_report_pdf(
+        pool: &PgPool,
+        church_id: Uuid,
+        congregation_id: Option<Uuid>,
+        month_from: NaiveDate,
+        month_to: NaiveDate,
+    ) -> Result<Vec<u8>, AppError> {
+        let report =
+            Self::assembly_report(pool, church_id, congregation_id, month_from, month_to).await?;
+        let church = ChurchService::get_by_id(pool, church_id).await?;
+        let letterhead = Letterhead::for_church(&church).await;
+
+        let mut pdf = PdfReport::new(letterhead, "Prestação de Contas");
+
+        let period = if month_from == month_to {
+            pdf::month_year(month_from)
+        } else {
+            format!("{} a {}", pdf::month_year(month_from), pdf::month_year(month_to))
+        };
+        let scope = report
+            .congregation_name
+            .as_deref()
+            .map(|name| format!(" — Congregação {name}"))
+            .unwrap_or_default();
+        pdf.paragraph(&format!("Período: {period}{scope}"));
+        if !report.is_closed {
+            pdf.paragraph(
+                "Relatório provisório: o período ainda possui meses sem fechamento mensal.",
+            );
+        }
+
+        let columns = || [Column::left("Categoria", 375.0), Column::right("Valor", 140.28)];
+        let rows = |lines: &[ReportLine], total: Decimal, label: &str| {
+            let mut rows: Vec<Row> = lines
+                .iter()
+                .map(|l| Row::new(vec![l.name.clone(), pdf::brl(l.amount)]))
+                .collect();
+            rows.push(Row::bold(vec![label.to_string(), pdf::brl(total)]));
+            rows
+        };
+
+        pdf.heading("Receitas");
+        pdf.table(
+            &columns(),
+            &rows(&report.income_by_category, report.total_income, "Total de receitas"),
+        );
+
+        pdf.heading("Despesas");
+        pdf.table(
+            &columns(),
+            &rows(&report.expense_by_category, report.total_expense, "Total de despesas"),
+        );
+
+        pdf.heading("Resumo");
+        pdf.key_value("Saldo anterior", &pdf::brl(report.previous_balance), false);
+        pdf.key_value("(+) Receitas", &pdf::brl(report.total_income), false);
+        pdf.key_value("(-) Despesas", &pdf::brl(report.total_expense), false);
+        pdf.key_value("Saldo final", &pdf::brl(report.final_balance), true);
+
+        pdf.signature("Tesouraria", &church.name);
+        match church.pastor_name.as_deref().filter(|p| !p.is_empty()) {
+            Some(pastor) => pdf.signature(pastor, "Pastor Presidente"),
+            None => pdf.signature("Pastor Presidente", &church.name),
+        }
+
+        Ok(pdf.finish())
+    }
+
+    /// Hierarchical income statement (DRE): each category of the account plan
+    /// carries its own entries plus those of every subcategory, in code order.
+    /// Reversals offset the category of the original entry. With `by_month` the
+    /// amounts are also broken down into month columns.
+    pub async fn income_statement(
+        pool: &PgPool,
+        church_id: Uuid,
+        congregation_id: Option<Uuid>,
+        date_from: NaiveDate,
+        date_to: NaiveDate,
+        by_month: bool,
+    ) -> Result<IncomeStatement, AppError> {
+        if date_from > date_to {
+            return Err(AppError::validation(
+                "'date_from' deve ser anterior ou igual a 'date_to'",
+            ));
+        }
+
+        let first_month = date_from.with_day(1).unwrap_or(date_from);
+        let months: Vec<NaiveDate> = (0..)
+            .map(|i| first_month + Months::new(i))
+            .take_while(|m| *m <= date_to)
+            .collect();
+        if by_month && months.len() > MAX_STATEMENT_MONTHS {
+            return Err(AppError::validation(format!(
+                "Colunas mensais aceitam no máximo {MAX_STATEMENT_MONTHS} meses"
+            )));
+        }
+
+        let congregation_name = Self::congregation_name(pool, church_id, congregation_id).await?;
+
+        let plans = sqlx::query_as::<_, (Uuid, Option<Uuid>, String, String, String)>(
+            "SELECT id, parent_id, code, name, type FROM account_plans WHERE church_id = $1",
+        )
+        .bind(church_id)
+        .fetch_all(pool)
+        .await?;
+
+        let amounts = sqlx::query_as::<_, (Uuid, NaiveDate, Decimal)>(&format!(
+            r#"
+            SELECT l.account_plan_id, date_trunc('month', fe.entry_date)::date,
+                   SUM(CASE WHEN fe.type = ap.type THEN l.amount ELSE -l.amount END)
+            FROM financial_entries fe
+            JOIN financial_entry_lines l ON l.entry_id = fe.id
+            JOIN account_plans ap ON ap.id = l.account_plan_id
+            WHERE {REPORTED_ENTRIES}
+              AND fe.entry_date BETWEEN $3 AND $4
+            GROUP BY 1, 2
+            "#
+        ))
+        .bind(church_id)
+        .bind(congregation_id)
+        .bind(date_from)
+        .bind(date_to)
+        .fetch_all(pool)
+        .await?;
+
+        let index: HashMap<Uuid, usize> =
+            plans.iter().enumerate().map(|(i, plan)| (plan.0, i)).collect();
+
+        // Own amounts per month, then rolled up into every ancestor
+        let mut totals = vec![vec![Decimal::ZERO; months.len()]; plans.len()];
+        for (plan_id, month, amount) in amounts {
+            let column = months.iter().position(|m| *m == month);
+            if let (Some(&i), Some(column)) = (index.get(&plan_id), column) {
+                totals[i][column] += amount;
+            }
+        }
+
+        // A parent of another type (misconfigured plan) does not absorb the child
+        let mut children: Vec<Vec<usize>> = vec![Vec::new(); plans.len()];
+        let mut roots = Vec::new();
+        for (i, plan) in plans.iter().enumerate() {
+            match plan.1.and_then(|parent| index.get(&parent)) {
+                Some(&p) if plans[p].4 == plan.4 => children[p].push(i),
+                _ => roots.push(i),
+            }
+        }
+        let by_code = |a: &usize, b: &usize| code_key(&plans[*a].2).cmp(&code_key(&plans[*b].2));
+        roots.sort_by(by_code);
+        for list in &mut children {
+            list.sort_by(by_code);
+        }
+
+        // Depth-first order: parents are listed before their subcategories
+        let mut order: Vec<(usize, i16)> = Vec::with_capacity(plans.len());
+        let mut stack: Vec<(usize, i16)> = roots.iter().rev().map(|&i| (i, 1)).collect();
+        while let Some((i, depth)) = stack.pop() {
+            order.push((i, depth));
+            stack.extend(children[i].iter().rev().map(|&c| (c, depth + 1)));
+        }
+        for &(i, _) in order.iter().rev() {
+            for &child in &children[i] {
+                let child_totals = totals[child].clone();
+                for (total, amount) in totals[i].iter_mut().zip(child_totals) {
+                    *total += amount;
+                }
+            }
+        }
+
+        let sum_roots = |plan_type: &str| -> Vec<Decimal> {
+            let mut sum = vec![Decimal::ZERO; months.len()];
+            for &i in roots.iter().filter(|&&i| plans[i].4 == plan_type) {
+                for (s, amount) in sum.iter_mut().zip(&totals[i]) {
+                    *s += *amount;
+                }
+            }
+            sum
+        };
+        let monthly_income = sum_roots("receita");
+        let monthly_expense = sum_roots("despesa");
+        let total_income: Decimal = monthly_income.iter().sum();
+        let total_expense: Decimal = monthly_expense.iter().sum();
+
+        let lines = |plan_type: &str| -> Vec<IncomeStatementLine> {
+            order
+                .iter()
+                .filter(|(i, _)| plans[*i].4 == plan_type)
+                .filter(|(i, _)| totals[*i].iter().any(|amount| !amount.is_zero()))
+                .map(|&(i, level)| {
+                    let (id, parent_id, code, name, _) = &plans[i];
+                    let amount: Decimal = totals[i].iter().sum();
+                    IncomeStatementLine {
+                        account_plan_id: *id,
+                        parent_id: *parent_id,
+                        code: code.clone(),
+                        name: name.clone(),
+                        level,
+                        is_group: !children[i].is_empty(),
+                        amount,
+                        percentage_of_income: if total_income.is_zero() {
+                            Decimal::ZERO
+                        } else {
+                            (amount * Decimal::from(100) / total_income).round_dp(2)
+                        },
+                        monthly: if by_month { totals[i].clone() } else { Vec::new() },
+                    }
+                })
+                .collect()
+        };
+        let income = lines("receita");
+        let expense = lines("despesa");
+
+        let (months, monthly_income, monthly_expense) = if by_month {
+            (months, monthly_income, monthly_expense)
+        } else {
+            (Vec::new(), Vec::new(), Vec::new())
+        };
+        let monthly_result = monthly_income
+            .iter()
+            .zip(&monthly_expense)
+            .map(|(income, expense)| income - expense)
+            .collect();
+
+        Ok(IncomeStatement {
+            date_from,
+            date_to,
+            congregation_id,
+            congregation_name,
+            months,
+            income,
+            expense,
+            total_income,
+            total_expense,
+            result: total_income - total_expense,
+            monthly_income,
+            monthly_expense,
+            monthly_result,
+        })
+    }
+
+    /// Income statement as CSV (";"-separated, decimal comma, UTF-8 BOM); the
+    /// names are indented by level so the tree shows in spreadsheet software
+    pub fn income_statement_csv(statement: &IncomeStatement) -> Result<Vec<u8>, AppError> {
+        let amount = |value: Decimal| format!("{value:.2}").replace('.', ",");
+        let csv_error = |e: csv::Error| AppError::Internal(format!("Erro ao gerar CSV: {e}"));
+
+        let mut writer = csv::WriterBuilder::new()
+            .delimiter(b';')
+            .flexible(true)
+            .from_writer(Vec::new());
+
+        let mut header = vec!["Código".to_string(), "Conta".to_string()];
+        header.extend(statement.months.iter().map(|m| pdf::month_short(*m)));
+        header.extend(["Total".to_string(), "% da receita".to_string()]);
+        writer.write_record(&header).map_err(csv_error)?;
+
+        let sections = [
+            ("Receitas", &statement.income, &statement.monthly_income, statement.total_income),
+            ("Despesas", &statement.expense, &statement.monthly_expense, statement.total_expense),
+        ];
+        for (title, lines, monthly, total) in sections {
+            writer.write_record([title.to_uppercase()]).map_err(csv_error)?;
+            for line in lines {
+                let mut record = vec![
+                    line.code.clone(),
+                    format!("{}{}", "  ".repeat(line.level as usize - 1), line.name),
+                ];
+                record.extend(line.monthly.iter().map(|v| amount(*v)));
+                record.extend([amount(line.amount), amount(line.percentage_of_income)]);
+                writer.write_record(&record).map_err(csv_error)?;
+            }
+            let mut record = vec![String::new(), format!("Total de {}", title.to_lowercase())];
+            record.extend(monthly.iter().map(|v| amount(*v)));
+            record.push(amount(total));
+            writer.write_record(&record).map_err(csv_error)?;
+        }
+
+        let mut record = vec![String::new(), "Resultado".to_string()];
+        record.extend(statement.monthly_result.iter().map(|v| amount(*v)));
+        record.push(amount(statement.result));
+        writer.write_record(&record).map_err(csv_error)?;
+
+        let body = writer
+            .into_inner()
+            .map_err(|e| AppError::Internal(format!("Erro ao gerar CSV: {e}")))?;
+        let mut bytes = "\u{feff}".as_bytes().to_vec();
+        bytes.extend(body);
+        Ok(bytes)
+    }
+
+    /// Income statement as PDF. Month columns do not fit an A4 page at once, so
+    /// they are printed four months per table before the totals table.
+    pub async fn income_statement_pdf(
+        pool: &PgPool,
+        church_id: Uuid,
+        statement: &IncomeStatement,
+    ) -> Result<Vec<u8>, AppError> {
+        let church = ChurchService::get_by_id(pool, church_id).await?;
+        let letterhead = Letterhead::for_church(&church).await;
+
+        let mut pdf = PdfReport::new(letterhead, "Demonstração do Resultado");
+
+        let scope = statement
+            .congregation_name
+            .as_deref()
+            .map(|name| format!(" — Congregação {name}"))
+            .unwrap_or_default();
+        pdf.paragraph(&format!(
+            "Período: {} a {}{scope}",
+            pdf::date_br(statement.date_from),
+            pdf::date_br(statement.date_to)
+        ));
+
+        let name = |line: &IncomeStatementLine| {
+            format!("{}{}", "    ".repeat(line.level as usize - 1), line.name)
+        };
+        let row = |line: &IncomeStatementLine, cells: Vec<String>| {
+            let mut all = vec![line.code.clone(), name(line)];
+            all.extend(cells);
+            if line.is_group { Row::bold(all) } else { Row::new(all) }
+        };
+        let percentage = |value: Decimal| format!("{value:.1}%").replace('.', ",");
+
+        let sections = [
+            ("Receitas", &statement.income, &statement.monthly_income, statement.total_income),
+            ("Despesas", &statement.expense, &statement.monthly_expense, statement.total_expense),
+        ];
+        for (title, lines, monthly, total) in sections {
+            pdf.heading(title);
+            let total_label = format!("Total de {}", title.to_lowercase());
+
+            for (chunk, months) in statement.months.chunks(4).enumerate() {
+                let first = chunk * 4;
+                let mut columns = vec![
+                    Column::left("Código", 55.0),
+                    Column::left("Conta", pdf::CONTENT_WIDTH - 55.0 - 75.0 * months.len() as f32),
+                ];
+                columns.extend(months.iter().map(|m| Column::right(pdf::month_short(*m), 75.0)));
+                let mut rows: Vec<Row> = lines
+                    .iter()
+                    .map(|line| {
+                        let cells = line.monthly[first..first + months.len()]
+                            .iter()
+                            .map(|v| pdf::brl(*v))
+                            .collect();
+                        row(line, cells)
+                    })
+                    .collect();
+                let mut cells = vec![String::new(), total_label.clone()];
+                cells.extend(monthly[first..first + months.len()].iter().map(|v| pdf::brl(*v)));
+                rows.push(Row::bold(cells));
+                pdf.table(&columns, &rows);
+            }
+
+            let columns = [
+                Column::left("Código", 60.0),
+                Column::left("Conta", 255.0),
+                Column::right("Total", 120.0),
+                Column::right("% receita", 80.28),
+            ];
+            let mut rows: Vec<Row> = lines
+                .iter()
+                .map(|line| {
+                    row(line, vec![pdf::brl(line.amount), percentage(line.percentage_of_income)])
+                })
+                .collect();
+            rows.push(Row::bold(vec![
+                String::new(),
+                total_label,
+                pdf::brl(total),
+                String::new(),
+            ]));
+            pdf.table(&columns, &rows);
+        }
+
+        pdf.heading("Resumo");
+        pdf.key_value("(+) Receitas", &pdf::brl(statement.total_income), false);
+        pdf.key_value("(-) Despesas", &pdf::brl(statement.total_expense), false);
+        pdf.key_value("Resultado do período", &pdf::brl(statement.result), true);
+
+        pdf.signature("Tesouraria", &church.name);
+
+        Ok(pdf.finish())
+    }
+
+    /// Statement of one bank account: opening balance, every confirmed movement
+    /// (transfers and reversals included) with the running balance, and the
+    /// closing balance. Follows the same ledger as the account's current balance.
+    pub async fn bank_account_statement(
+        pool: &PgPool,
+        church_id: Uuid,
+        bank_account_id: Uuid,
+        date_from: NaiveDate,
+        date_to: NaiveDate,
+    ) -> Result<BankAccountStatement, AppError> {
+        let account = sqlx::query_as::<
+            _,
+            (String, Option<String>, Option<String>, Option<String>, Option<Uuid>, Decimal),
+        >(
+            r#"SELECT name, bank_name, agency, account_number, congregation_id, initial_balance
+               FROM bank_accounts WHERE id = $1 AND church_id = $2"#,
+        )
+        .bind(bank_account_id)
+        .bind(church_id)
+        .fetch_optional(pool)
+        .await?
+        .ok_or_else(|| AppError::not_found("Conta bancária"))?;
+        let (name, bank_name, agency, account_number, congregation_id, initial_balance) = account;
+
+        let before = sqlx::query_scalar::<_, Decimal>(&format!(
+            r#"SELECT COALESCE(SUM(CASE WHEN fe.type = 'receita' THEN fe.amount ELSE -fe.amount END), 0)
+               FROM financial_entries fe
+               WHERE {ACCOUNT_MOVEMENTS} AND fe.entry_date < $3"#
+        ))
+        .bind(church_id)
+        .bind(bank_account_id)
+        .bind(date_from)
+        .fetch_one(pool)
+        .await?;
+        let opening_balance = initial_balance + before;
+
+        let movements = sqlx::query_as::<_, StatementMovement>(&format!(
+            r#"
+            SELECT fe.id AS entry_id, fe.entry_date, fe.description,
+                   ap.name AS account_plan_name, fe.type, fe.amount,
+                   m.signed_amount,
+                   $5 + SUM(m.signed_amount) OVER (ORDER BY fe.entry_date, fe.created_at, fe.id)
+                       AS balance,
+                   fe.transfer_id, fe.reversal_of,
+                   fe.reconciled_at IS NOT NULL AS reconciled
+            FROM financial_entries fe
+            LEFT JOIN account_plans ap ON ap.id = fe.account_plan_id
+            CROSS JOIN LATERAL (
+                SELECT CASE WHEN fe.type = 'receita' THEN fe.amount ELSE -fe.amount END AS signed_amount
+            ) m
+            WHERE {ACCOUNT_MOVEMENTS} AND fe.entry_date BETWEEN $3 AND $4
+            ORDER BY fe.entry_date, fe.created_at, fe.id
+            "#
+        ))
+        .bind(church_id)
+        .bind(bank_account_id)
+        .bind(date_from)
+        .bind(date_to)
+        .bind(opening_balance)
+        .fetch_all(pool)
+        .await?;
+
+        let total_credits: Decimal = movements
+            .iter()
+            .filter(|m| m.entry_type == "receita")
+            .map(|m| m.amount)
+            .sum();
+        let total_debits: Decimal = movements
+            .iter()
+            .filter(|m| m.entry_type == "despesa")
+            .map(|m| m.amount)
+            .sum();
+
+        Ok(BankAccountStatement {
+            bank_account_id,
+            bank_account_name: name,
+            bank_name,
+            agency,
+            account_number,
+            congregation_id,
+            date_from,
+            date_to,
+            opening_balance,
+            total_credits,
+            total_debits,
+            closing_balance: opening_balance + total_credits - total_debits,
+            movements,
+        })
+    }
+
+    /// Bank account statement as CSV (";"-separated, decimal comma, UTF-8 BOM) so it
+    /// opens directly in spreadsheet software configured for pt-BR
+    pub fn bank_account_statement_csv(statement: &BankAccountStatement) -> Result<Vec<u8>, AppError> {
+        let amount = |value: Decimal| format!("{value:.2}").replace('.', ",");
+        let csv_error = |e: csv::Error| AppError::Internal(format!("Erro ao gerar CSV: {e}"));
+
+        let mut writer = csv::WriterBuilder::new().delimiter(b';').from_writer(Vec::new());
+        writer
+            .write_record(["Data", "Descrição", "Categoria", "Entrada", "Saída", "Saldo"])
+            .map_err(csv_error)?;
+        writer
+            .write_record([
+                pdf::date_br(statement.date_from),
+                "Saldo anterior".to_string(),
+                String::new(),
+                String::new(),
+                String::new(),
+                amount(statement.opening_balance),
+            ])
+            .map_err(csv_error)?;
+        for m in &statement.movements {
+            let (credit, debit) = if m.entry_type == "receita" {
+                (amount(m.amount), String::new())
+            } else {
+                (String::new(), amount(m.amount))
+            };
+            writer
+                .write_record([
+                    pdf::date_br(m.entry_date),
+                    m.description.clone(),
+                    m.account_plan_name.clone().unwrap_or_default(),
+                    credit,
+                    debit,
+                    amount(m.balance),
+                ])
+                .map_err(csv_error)?;
+        }
+        writer
+            .write_record([
+                pdf::date_br(statement.date_to),
+                "Saldo final".to_string(),
+                String::new(),
+                amount(statement.total_credits),
+                amount(statement.total_debits),
+                amount(statement.closing_balance),
+            ])
+            .map_err(csv_error)?;
+
+        let body = writer
+            .into_inner()
+            .map_err(|e| AppError::Internal(format!("Erro ao gerar CSV: {e}")))?;
+        let mut bytes = "\u{feff}".as_bytes().to_vec();
+        bytes.extend(body);
+        Ok(bytes)
+    }
+
+    /// Bank account statement as PDF, for the fiscal council to compare with the
+    /// statement issued by the bank
+    pub async fn bank_account_statement_pdf(
+        pool: &PgPool,
+        church_id: Uuid,
+        statement: &BankAccountStatement,
+    ) -> Result<Vec<u8>, AppError> {
+        let church = ChurchService::get_by_id(pool, church_id).await?;
+        let letterhead = Letterhead::for_church(&church).await;
+
+        let mut pdf = PdfReport::new(letterhead, "Extrato da Conta");
+
+        let bank_details = [
+            statement.bank_name.as_deref().map(String::from),
+            statement.agency.as_deref().map(|a| format!("Agência {a}")),
+            statement.account_number.as_deref().map(|n| format!("Conta {n}")),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(" · ");
+        pdf.paragraph(&format!("Conta: {}", statement.bank_account_name));
+        if !bank_details.is_empty() {
+            pdf.paragraph(&bank_details);
+        }
+        pdf.paragraph(&format!(
+            "Período: {} a {}",
+            pdf::date_br(statement.date_from),
+            pdf::date_br(statement.date_to)
+        ));
+
+        let columns = [
+            Column::left("Data", 60.0),
+            Column::left("Descrição", 235.0),
+            Column::right("Entrada", 75.0),
+            Column::right("Saída", 75.0),
+            Column::right("Saldo", 70.28),
+        ];
+        let mut rows = vec![Row::bold(vec![
+            pdf::date_br(statement.date_from),
+            "Saldo anterior".to_string(),
+            String::new(),
+            String::new(),
+            pdf::brl(statement.opening_balance),
+        ])];
+        rows.extend(statement.movements.iter().map(|m| {
+            let (credit, debit) = if m.entry_type == "receita" {
+                (pdf::brl(m.amount), String::new())
+            } else {
+                (String::new(), pdf::brl(m.amount))
+            };
+            Row::new(vec![
+                pdf::date_br(m.entry_date),
+                m.description.clone(),
+                credit,
+                debit,
+                pdf::brl(m.balance),
+            ])
+        }));
+        rows.push(Row::bold(vec![
+            pdf::date_br(statement.date_to),
+            "Saldo final".to_string(),
+            pdf::brl(statement.total_credits),
+            pdf::brl(statement.total_debits),
+            pdf::brl(statement.closing_balance),
+        ]));
+        pdf.table(&columns, &rows);
+
+        pdf.heading("Resumo");
+        pdf.key_value("Saldo anterior", &pdf::brl(statement.opening_balance), false);
+        pdf.key_value("(+) Entradas", &pdf::brl(statement.total_credits), false);
+        pdf.key_value("(-) Saídas", &pdf::brl(statement.total_debits), false);
+        pdf.key_value("Saldo final", &pdf::brl(statement.closing_balance), true);
+
+        pdf.signature("Tesouraria", &church.name);
+
+        Ok(pdf.finish())
+    }
+
+    async fn congregation_name(
+        pool: &PgPool,
+        church_id: Uuid,
+        congregation_id: Option<Uuid>,
+    ) -> Result<Option<String>, AppError> {
+        let Some(cid) = congregation_id else {
+            return Ok(None);
+        };
+        let name = sqlx::query_scalar::<_, String>(
+            "SELECT name FROM congregations WHERE id = $1 AND church_id = $2",
+        )
+        .bind(cid)
+        .bind(church_id)
+        .fetch_optional(pool)
+        .await?
+        .ok_or_else(|| AppError::not_found("Congregação"))?;
+        Ok(Some(name))
+    }
+
+    /// Accumulated balance before `date`: last monthly closing of the same scope
+    /// (church-wide or the congregation) before it plus the entries of the months
+    /// not closed since.
+    async fn balance_before(
+        pool: &PgPool,
+        church_id: Uuid,
+        congregation_id: Option<Uuid>,
+        date: NaiveDate,
+    ) -> Result<Decimal, AppError> {
+        let last_closing = sqlx::query_as::<_, (NaiveDate, Decimal)>(
+            r#"SELECT reference_month, accumulated_balance FROM monthly_closings
+               WHERE church_id = $1 AND congregation_id IS NOT DISTINCT FROM $2
+               AND reference_month < $3
+               ORDER BY reference_month DESC LIMIT 1"#,
+        )
+        .bind(church_id)
+        .bind(congregation_id)
+        .bind(date)
+        .fetch_optional(pool)
+        .await?;
+
+        let (base, from) = match last_closing {
+            Some((month, accumulated)) => (accumulated, Some(month + Months::new(1))),
+            None => (Decimal::ZERO, None),
+        };
+
+        let net = sqlx::query_scalar::<_, Decimal>(&format!(
+            r#"SELECT COALESCE(SUM(CASE WHEN fe.type = 'receita' THEN fe.amount ELSE -fe.amount END), 0)
+               FROM financial_entries fe
+               WHERE {REPORTED_ENTRIES}
+                 AND ($3::date IS NULL OR fe.entry_date >= $3) AND fe.entry_date < $4"#
+        ))
+        .bind(church_id)
+        .bind(congregation_id)
+        .bind(from)
+        .bind(date)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(base + net)
+    }
+
+    /// Totals per account plan category; tithe categories collapse into one line
+    /// so individual contributions can never be inferred (RN-FIN-005).
+    /// Reversals are netted into the original's category, so a reversed tithe never
+    /// shows up on its own in the other section.
+    async fn category_lines(
+        pool: &PgPool,
+        church_id: Uuid,
+        congregation_id: Option<Uuid>,
+        entry_type: &str,
+        date_from: NaiveDate,
+        date_to: NaiveDate,
+    ) -> Result<Vec<ReportLine>, AppError> {
+        let lines = sqlx::query_as::<_, ReportLine>(&format!(
+            r#"
+            SELECT CASE WHEN ap.is_tithe THEN NULL ELSE ap.code END AS code,
+                   CASE WHEN ap.is_tithe THEN 'Dízimos' ELSE ap.name END AS name,
+                   SUM(CASE WHEN fe.type = ap.type THEN l.amount ELSE -l.amount END) AS amount
+            FROM financial_entries fe
+            JOIN financial_entry_lines l ON l.entry_id = fe.id
+            JOIN account_plans ap ON ap.id = l.account_plan_id
+            WHERE {REPORTED_ENTRIES}
+              AND ap.type = $3 AND fe.entry_date BETWEEN $4 AND $5
+            GROUP BY 1, 2
+            HAVING SUM(CASE WHEN fe.type = ap.type THEN l.amount ELSE -l.amount END) <> 0
+            ORDER BY 1 NULLS FIRST, 2
+            "#
+        ))
+        .bind(church_id)
+        .bind(congregation_id)
+        .bind(entry_type)
+        .bind(date_from)
+        .bind(date_to)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(lines)
+    }
+}
+
+/// Sort key for account plan codes: "1.10" comes after "1.9"
+fn code_key(code: &str) -> Vec<(u64, &str)> {
+    code.split('.')
+        .map(|part| (part.trim().parse().unwrap_or(u64::MAX), part))
+        .collect()
+}
@@ -1,16 +1,32 @@
 use crate::application::dto::{
-    BalanceReportFilter, CreateFinancialEntryRequest, FinancialEntryFilter,
-    MonthlyClosingRequest, UpdateFinancialEntryRequest,
+    BalanceReportFilter, CreateFinancialEntryRequest, EntryAllocationRequest,
+    FinancialEntryFilter, MonthlyClosingRequest, PayFinancialEntryRequest,
+    ReverseFinancialEntryRequest, UpdateFinancialEntryRequest,
 };
+use crate::application::services::{CampaignService, ExpenseApprovalService, SupplierService};
 use crate::domain::entities::{
-    CategoryAmount, FinancialBalance, FinancialEntry, FinancialEntrySummary,
-    MonthlyClosing, MonthlyClosingSummary,
+    CategoryAmount, FinancialBalance, FinancialEntry, FinancialEntryAllocation,
+    FinancialEntryReversal, FinancialEntrySummary, MonthlyClosing, MonthlyClosingBankBalance, MonthlyClosingDetail,
+    MonthlyClosingSummary,
 };
 use crate::errors::AppError;
 use rust_decimal::Decimal;
-use sqlx::PgPool;
+use sqlx::{PgConnection, PgPool};
 use uuid::Uuid;
 
+/// Columns selected/returned for a full `FinancialEntry` row
+pub(crate) const ENTRY_COLUMNS: &str = "id, church_id, type, account_plan_id, bank_account_id, campaign_id, \
+    amount, entry_date, due_date, payment_date, description, payment_method, \
+    member_id, supplier_name, supplier_id, receipt_url, status, is_recurring, recurring_id, \
+    is_closed, closed_at, closed_by, registered_by, notes, congregation_id, \
+    reversal_of, reversal_reason, transfer_id, reconciled_at, requested_status, approved_by, \
+    approved_at, rejection_reason, installment_plan_id, installment_number, \
+    offering_count_id, created_at, updated_at, deleted_at";
+
+/// Split entries carry category, campaign and member on each allocation line
+const SPLIT_FIELDS_ON_LINES: &str =
+    "Em lançamentos divididos, categoria, campanha e membro são informados em cada linha";
+
 pub struct FinancialEntryService;
 
 impl FinancialEntryService {
@@ -30,8 +46,11 @@ impl FinancialEntryService {
             conditions.push(format!("fe.type = ${param_idx}"));
             param_idx += 1;
         }
+        // Category, member and campaign match any allocation line of split entries
         if filter.account_plan_id.is_some() {
-            conditions.push(format!("fe.account_plan_id = ${param_idx}"));
+            conditions.push(format!(
+                "EXISTS (SELECT 1 FROM financial_entry_lines l WHERE l.entry_id = fe.id AND l.account_plan_id = ${param_idx})"
+            ));
             param_idx += 1;
         }
         if filter.bank_account_id.is_some() {
@@ -51,17 +70,25 @@ impl FinancialEntryService {
             param_idx += 1;
         }
         if filter.member_id.is_some() {
-            conditions.push(format!("fe.member_id = ${param_idx}"));
+            conditions.push(format!(
+                "EXISTS (SELECT 1 FROM financial_entry_lines l WHERE l.entry_id = fe.id AND l.member_id = ${param_idx})"
+            ));
             param_idx += 1;
         }
         if filter.campaign_id.is_some() {
-            conditions.push(format!("fe.campaign_id = ${param_idx}"));
+            conditions.push(format!(
+                "EXISTS (SELECT 1 FROM financial_entry_lines l WHERE l.entry_id = fe.id AND l.campaign_id = ${param_idx})"
+            ));
             param_idx += 1;
         }
         if filter.payment_method.is_some() {
             conditions.push(format!("fe.payment_method = ${param_idx}"));
             param_idx += 1;
         }
+        if filter.supplier_id.is_some() {
+            conditions.push(format!("fe.supplier_id = ${param_idx}"));
+            param_idx += 1;
+        }
         if search.is_some() {
             conditions.push(format!(
                 "unaccent(fe.description) ILIKE '%' || unaccent(${param_idx}) || '%'"
@@ -72,6 +99,19 @@ impl FinancialEntryService {
             conditions.push(format!("fe.congregation_id = ${param_idx}"));
             param_idx += 1;
         }
+        if filter.recurring_id.is_some() {
+            conditions.push(format!("fe.recurring_id = ${param_idx}"));
+            param_idx += 1;
+        }
+        if filter.installment_plan_id.is_some() {
+            conditions.push(format!("fe.installment_plan_id = ${param_idx}"));
+            param_idx += 1;
+        }
+        match filter.reconciled {
+            Some(true) => conditions.push("fe.reconciled_at IS NOT NULL".to_string()),
+            Some(false) => conditions.push("fe.reconciled_at IS NULL".to_string()),
+            None => {}
+        }
 
         let _ = param_idx;
         let where_clause = conditions.join(" AND ");
@@ -89,8 +129,15 @@ impl FinancialEntryService {
                    m.full_name AS member_name,
                    ca.name AS campaign_name,
                    fe.supplier_name,
+                   fe.supplier_id,
                    fe.congregation_id,
                    cg.name AS congregation_name,
+                   fe.transfer_id,
+                   fe.reconciled_at,
+                   EXISTS (SELECT 1 FROM financial_entry_allocations a WHERE a.entry_id = fe.id) AS is_split,
+                   fe.installment_plan_id,
+                   fe.installment_number,
+                   ip.installment_count,
                    fe.created_at
             FROM financial_entries fe
             LEFT JOIN account_plans ap ON ap.id = fe.account_plan_id
@@ -98,6 +145,7 @@ impl FinancialEntryService {
             LEFT JOIN members m ON m.id = fe.member_id
             LEFT JOIN campaigns ca ON ca.id = fe.campaign_id
             LEFT JOIN congregations cg ON cg.id = fe.congregation_id
+            LEFT JOIN installment_plans ip ON ip.id = fe.installment_plan_id
             WHERE {where_clause}
             ORDER BY fe.entry_date DESC, fe.created_at DESC
             LIMIT {limit} OFFSET {offset}
@@ -147,6 +195,10 @@ impl FinancialEntryService {
             sqlx::Arguments::add(&mut count_args, pm.as_str()).unwrap();
             sqlx::Arguments::add(&mut data_args, pm.as_str()).unwrap();
         }
+        if let Some(supplier_id) = filter.supplier_id {
+            sqlx::Arguments::add(&mut count_args, supplier_id).unwrap();
+            sqlx::Arguments::add(&mut data_args, supplier_id).unwrap();
+        }
         if let Some(term) = search {
             sqlx::Arguments::add(&mut count_args, term.as_str()).unwrap();
             sqlx::Arguments::add(&mut data_args, term.as_str()).unwrap();
@@ -155,6 +207,14 @@ impl FinancialEntryService {
             sqlx::Arguments::add(&mut count_args, congregation_id).unwrap();
             sqlx::Arguments::add(&mut data_args, congregation_id).unwrap();
         }
+        if let Some(recurring_id) = filter.recurring_id {
+            sqlx::Arguments::add(&mut count_args, recurring_id).unwrap();
+            sqlx::Arguments::add(&mut data_args, recurring_id).unwrap();
+        }
+        if let Some(plan_id) = filter.installment_plan_id {
+            sqlx::Arguments::add(&mut count_args, plan_id).unwrap();
+            sqlx::Arguments::add(&mut data_args, plan_id).unwrap();
+        }
 
         let total = sqlx::query_scalar_with::<_, i64, _>(&count_sql, count_args)
             .fetch_one(pool)
@@ -173,13 +233,9 @@ impl FinancialEntryService {
         church_id: Uuid,
         entry_id: Uuid,
     ) -> Result<FinancialEntry, AppError> {
-        sqlx::query_as::<_, FinancialEntry>(
-            r#"SELECT id, church_id, type, account_plan_id, bank_account_id, campaign_id,
-                      amount, entry_date, due_date, payment_date, description, payment_method,
-                      member_id, supplier_name, receipt_url, status, is_recurring, recurring_id,
-                      is_closed, closed_at, closed_by, registered_by, notes, congregation_id, created_at, updated_at, deleted_at
-               FROM financial_entries WHERE id = $1 AND church_id = $2 AND deleted_at IS NULL"#,
-        )
+        sqlx::query_as::<_, FinancialEntry>(&format!(
+            "SELECT {ENTRY_COLUMNS} FROM financial_entries WHERE id = $1 AND church_id = $2 AND deleted_at IS NULL"
+        ))
         .bind(entry_id)
         .bind(church_id)
         .fetch_optional(pool)
@@ -193,6 +249,22 @@ impl FinancialEntryService {
         church_id: Uuid,
         user_id: Uuid,
         req: &CreateFinancialEntryRequest,
+    ) -> Result<FinancialEntry, AppError> {
+        let mut tx = pool.begin().await?;
+        let entry = Self::create_in(pool, &mut tx, church_id, user_id, req).await?;
+        tx.commit().await?;
+
+        Ok(entry)
+    }
+
+    /// Create an entry inside the caller's transaction. Lookups run on `pool`;
+    /// every write goes through `conn`, so it commits together with the caller's changes.
+    pub(crate) async fn create_in(
+        pool: &PgPool,
+        conn: &mut PgConnection,
+        church_id: Uuid,
+        user_id: Uuid,
+        req: &CreateFinancialEntryRequest,
     ) -> Result<FinancialEntry, AppError> {
         // Validate entry type
         if req.entry_type != "receita" && req.entry_type != "despesa" {
@@ -204,15 +276,44 @@ impl FinancialEntryService {
             return Err(AppError::validation("Valor deve ser maior que zero"));
         }
 
-        // Verify account_plan exists and belongs to church
-        let _plan = sqlx::query_scalar::<_, Uuid>(
-            "SELECT id FROM account_plans WHERE id = $1 AND church_id = $2 AND is_active = TRUE",
-        )
-        .bind(req.account_plan_id)
-        .bind(church_id)
-        .fetch_optional(pool)
-        .await?
-        .ok_or_else(|| AppError::validation("Plano de contas não encontrado ou inativo"))?;
+        // A split entry takes category, campaign and member from its lines
+        let (account_plan_id, campaign_id, member_id) = match &req.allocations {
+            Some(lines) => {
+                if req.account_plan_id.is_some() || req.campaign_id.is_some() || req.member_id.is_some() {
+                    return Err(AppError::validation(SPLIT_FIELDS_ON_LINES));
+                }
+                let summary = Self::split_summary(lines, req.amount)?;
+                Self::check_allocations(pool, church_id, lines, req.entry_date).await?;
+                summary
+            }
+            None => {
+                let plan_id = req
+                    .account_plan_id
+                    .ok_or_else(|| AppError::validation("Plano de contas é obrigatório"))?;
+
+                // Verify account_plan exists and belongs to church
+                let _plan = sqlx::query_scalar::<_, Uuid>(
+                    "SELECT id FROM account_plans WHERE id = $1 AND church_id = $2 AND is_active = TRUE",
+                )
+                .bind(plan_id)
+                .bind(church_id)
+                .fetch_optional(pool)
+                .await?
+                .ok_or_else(|| AppError::validation("Plano de contas não encontrado ou inativo"))?;
+
+                // Verify campaign if provided and still open for entries
+                if let Some(campaign_id) = req.campaign_id {
+                    CampaignService::ensure_accepts_entries(pool, church_id, campaign_id, req.entry_date)
+                        .await?;
+                }
+
+                (plan_id, req.campaign_id, req.member_id)
+            }
+        };
+        let plan_ids = match &req.allocations {
+            Some(lines) => distinct_plans(lines),
+            None => vec![account_plan_id],
+        };
 
         // Verify bank_account exists and belongs to church
         let _account = sqlx::query_scalar::<_, Uuid>(
@@ -224,59 +325,71 @@ impl FinancialEntryService {
         .await?
         .ok_or_else(|| AppError::validation("Conta bancária não encontrada ou inativa"))?;
 
-        // Verify campaign if provided
-        if let Some(campaign_id) = req.campaign_id {
-            let _campaign = sqlx::query_scalar::<_, Uuid>(
-                "SELECT id FROM campaigns WHERE id = $1 AND church_id = $2",
-            )
-            .bind(campaign_id)
-            .bind(church_id)
-            .fetch_optional(pool)
-            .await?
-            .ok_or_else(|| AppError::validation("Campanha não encontrada"))?;
+        // A registered supplier's name goes to supplier_name (RN-FIN-021)
+        let supplier_name = match req.supplier_id {
+            Some(sid) => Some(SupplierService::name_for_link(pool, church_id, sid).await?),
+            None => req.supplier_name.clone(),
+        };
+
+        let mut status = req.status.as_deref().unwrap_or("confirmado");
+        if status == "aguardando_aprovacao" {
+            return Err(AppError::validation(
+                "O status 'aguardando_aprovacao' é definido pelas regras de aprovação",
+            ));
         }
 
-        let status = req.status.as_deref().unwrap_or("confirmado");
+        // Expenses under an approval rule wait for a second signature (RN-FIN-020)
+        let mut requested_status = None;
+        if req.entry_type == "despesa"
+            && (status == "pendente" || status == "confirmado")
+            && Self::approval_required(&mut *conn, church_id, req.amount, &plan_ids).await?
+        {
+            requested_status = Some(status);
+            status = "aguardando_aprovacao";
+        }
 
-        let entry = sqlx::query_as::<_, FinancialEntry>(
+        let entry = sqlx::query_as::<_, FinancialEntry>(&format!(
             r#"
             INSERT INTO financial_entries (
                 church_id, type, account_plan_id, bank_account_id, campaign_id,
                 amount, entry_date, due_date, payment_date, description,
                 payment_method, member_id, supplier_name, receipt_url,
-                status, registered_by, notes, congregation_id
+                status, registered_by, notes, congregation_id, requested_status, supplier_id
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18)
-            RETURNING id, church_id, type, account_plan_id, bank_account_id, campaign_id,
-                      amount, entry_date, due_date, payment_date, description, payment_method,
-                      member_id, supplier_name, receipt_url, status, is_recurring, recurring_id,
-                      is_closed, closed_at, closed_by, registered_by, notes, congregation_id, created_at, updated_at, deleted_at
-            "#,
-        )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20)
+            RETURNING {ENTRY_COLUMNS}
+            "#
+        ))
         .bind(church_id)
         .bind(&req.entry_type)
-        .bind(req.account_plan_id)
+        .bind(account_plan_id)
         .bind(req.bank_account_id)
-        .bind(req.campaign_id)
+        .bind(campaign_id)
         .bind(req.amount)
         .bind(req.entry_date)
         .bind(req.due_date)
         .bind(req.payment_date)
         .bind(&req.description)
         .bind(&req.payment_method)
-        .bind(req.member_id)
-        .bind(&req.supplier_name)
+        .bind(member_id)
+        .bind(&supplier_name)
         .bind(&req.receipt_url)
         .bind(status)
         .bind(user_id)
         .bind(&req.notes)
         .bind(req.congregation_id)
-        .fetch_one(pool)
+        .bind(requested_status)
+        .bind(req.supplier_id)
+        .fetch_one(&mut *conn)
         .await?;
 
+        if let Some(lines) = &req.allocations {
+            Self::save_allocations(&mut *conn, church_id, entry.id, lines).await?;
+        }
+
         // Update bank account balance if confirmed
         if status == "confirmado" {
-            Self::update_bank_balance(pool, req.bank_account_id, req.amount, &req.entry_type).await?;
+            Self::sync_bank_balances(&mut *conn, &[req.bank_account_id]).await?;
         }
 
         Ok(entry)
@@ -297,6 +410,108 @@ impl FinancialEntryService {
             ));
         }
 
+        if existing.status == "estornado" || existing.reversal_of.is_some() {
+            return Err(AppError::validation(
+                "Lançamentos estornados ou de estorno não podem ser alterados",
+            ));
+        }
+
+        if existing.transfer_id.is_some() {
+            return Err(AppError::validation(
+                "Lançamentos de transferência não podem ser alterados individualmente",
+            ));
+        }
+
+        // A reconciled entry mirrors a real bank movement
+        if existing.reconciled_at.is_some()
+            && (req.amount.is_some()
+                || req.bank_account_id.is_some()
+                || req.status.as_deref() == Some("cancelado"))
+        {
+            return Err(AppError::validation(
+                "Lançamento conciliado com o extrato: desfaça a conciliação antes de alterar valor, conta ou status",
+            ));
+        }
+
+        // Linking to a campaign, or moving a linked entry's date, follows RN-FIN-008
+        let campaign_id = req.campaign_id.or(existing.campaign_id);
+        let entry_date = req.entry_date.unwrap_or(existing.entry_date);
+        if let Some(cid) = campaign_id {
+            if req.campaign_id.is_some_and(|c| Some(c) != existing.campaign_id)
+                || entry_date != existing.entry_date
+            {
+                CampaignService::ensure_accepts_entries(pool, church_id, cid, entry_date).await?;
+            }
+        }
+
+        // New allocation lines must add up to the (new) amount; the lines of a split
+        // entry only change together with its amount, category, campaign or member
+        let current_lines = Self::allocation_lines(pool, entry_id).await?;
+        let new_amount = req.amount.unwrap_or(existing.amount);
+        let split = match &req.allocations {
+            Some(lines) if !lines.is_empty() => {
+                if req.account_plan_id.is_some() || req.campaign_id.is_some() || req.member_id.is_some() {
+                    return Err(AppError::validation(SPLIT_FIELDS_ON_LINES));
+                }
+                let summary = Self::split_summary(lines, new_amount)?;
+                Self::check_allocations(pool, church_id, lines, entry_date).await?;
+                Some(summary)
+            }
+            Some(_) => None,
+            None if !current_lines.is_empty() => {
+                if req.amount.is_some()
+                    || req.account_plan_id.is_some()
+                    || req.campaign_id.is_some()
+                    || req.member_id.is_some()
+                {
+                    return Err(AppError::validation(
+                        "Lançamento dividido: informe as novas linhas ('allocations') ao alterar valor, categoria, campanha ou membro",
+                    ));
+                }
+                if entry_date != existing.entry_date {
+                    Self::check_allocations(pool, church_id, &current_lines, entry_date).await?;
+                }
+                None
+            }
+            None => None,
+        };
+
+        if existing.status == "aguardando_aprovacao" && req.status.is_some() {
+            return Err(AppError::validation(
+                "Despesa aguardando aprovação: aprove ou rejeite antes de alterar o status",
+            ));
+        }
+
+        // Raising the amount, moving the category or activating an unapproved expense
+        // goes back through the approval rules (RN-FIN-020)
+        let new_status = req.status.clone().unwrap_or_else(|| existing.status.clone());
+        let current_plans = if current_lines.is_empty() {
+            vec![existing.account_plan_id]
+        } else {
+            distinct_plans(&current_lines)
+        };
+        let new_plans = match &req.allocations {
+            Some(lines) if !lines.is_empty() => distinct_plans(lines),
+            Some(_) => vec![req.account_plan_id.unwrap_or(existing.account_plan_id)],
+            None if !current_lines.is_empty() => current_plans.clone(),
+            None => vec![req.account_plan_id.unwrap_or(existing.account_plan_id)],
+        };
+        let needs_approval = existing.entry_type == "despesa"
+            && (new_status == "pendente" || new_status == "confirmado")
+            && (new_amount > existing.amount
+                || new_plans.iter().any(|p| !current_plans.contains(p))
+                || existing.status == "cancelado"
+                || (new_status != existing.status
+                    && new_status == "confirmado"
+                    && existing.approved_by.is_none()))
+            && Self::approval_required(
+                &mut *pool.acquire().await?,
+                church_id,
+                new_amount,
+                &new_plans,
+            )
+            .await?;
+
         let mut set_clauses: Vec<String> = Vec::new();
         let mut args = sqlx::postgres::PgArguments::default();
         let mut param_index = 3u32;
@@ -323,12 +538,6 @@ impl FinancialEntryService {
             if amount <= Decimal::ZERO {
                 return Err(AppError::validation("Valor deve ser maior que zero"));
             }
-            // Reverse old balance effect and apply new
-            if existing.status == "confirmado" {
-                Self::reverse_bank_balance(pool, existing.bank_account_id, existing.amount, &existing.entry_type).await?;
-                let target_bank = req.bank_account_id.unwrap_or(existing.bank_account_id);
-                Self::update_bank_balance(pool, target_bank, amount, &existing.entry_type).await?;
-            }
             set_clauses.push(format!("amount = ${param_index}"));
             sqlx::Arguments::add(&mut args, amount).unwrap();
             param_index += 1;
@@ -363,7 +572,16 @@ impl FinancialEntryService {
             sqlx::Arguments::add(&mut args, mid).unwrap();
             param_index += 1;
         }
-        if let Some(ref sn) = req.supplier_name {
+        if let Some(sid) = req.supplier_id {
+            let name = SupplierService::name_for_link(pool, church_id, sid).await?;
+            set_clauses.push(format!("supplier_id = ${param_index}"));
+            sqlx::Arguments::add(&mut args, sid).unwrap();
+            param_index += 1;
+            set_clauses.push(format!("supplier_name = ${param_index}"));
+            sqlx::Arguments::add(&mut args, name).unwrap();
+            param_index += 1;
+        } else if let Some(ref sn) = req.supplier_name {
+            // The database relinks the entry by the new name
             set_clauses.push(format!("supplier_name = ${param_index}"));
             sqlx::Arguments::add(&mut args, sn.as_str()).unwrap();
             param_index += 1;
@@ -374,21 +592,29 @@ impl FinancialEntryService {
             param_index += 1;
         }
         if let Some(ref status) = req.status {
-            let valid = ["pendente", "confirmado", "cancelado", "estornado"];
+            if status == "estornado" {
+                return Err(AppError::validation(
+                    "Para estornar um lançamento use POST /financial/entries/{id}/reverse",
+                ));
+            }
+            let valid = ["pendente", "confirmado", "cancelado"];
             if !valid.contains(&status.as_str()) {
                 return Err(AppError::validation(
-                    "Status deve ser: pendente, confirmado, cancelado ou estornado",
+                    "Status deve ser: pendente, confirmado ou cancelado",
                 ));
             }
-            // Handle balance changes on status transition
-            if existing.status == "confirmado" && (status == "cancelado" || status == "estornado") {
-                Self::reverse_bank_balance(pool, existing.bank_account_id, existing.amount, &existing.entry_type).await?;
-            } else if existing.status == "pendente" && status == "confirmado" {
-                Self::update_bank_balance(pool, existing.bank_account_id, existing.amount, &existing.entry_type).await?;
+            if !needs_approval {
+                set_clauses.push(format!("status = ${param_index}"));
+                sqlx::Arguments::add(&mut args, status.as_str()).unwrap();
+                param_index += 1;
             }
-            set_clauses.push(format!("status = ${param_index}"));
-            sqlx::Arguments::add(&mut args, status.as_str()).unwrap();
+        }
+        if needs_approval {
+            set_clauses.push("status = 'aguardando_aprovacao'".to_string());
+            set_clauses.push(format!("requested_status = ${param_index}"));
+            sqlx::Arguments::add(&mut args, new_status.as_str()).unwrap();
             param_index += 1;
+            set_clauses.push("approved_by = NULL, approved_at = NULL, rejection_reason = NULL".to_string());
         }
         if let Some(ref notes) = req.notes {
             set_clauses.push(format!("notes = ${param_index}"));
@@ -407,26 +633,61 @@ impl FinancialEntryService {
                 }
             }
         }
-
-        let _ = param_index;
+        if let Some((plan_id, campaign_id, member_id)) = split {
+            set_clauses.push(format!(
+                "account_plan_id = ${}, campaign_id = ${}, member_id = ${}",
+                param_index,
+                param_index + 1,
+                param_index + 2
+            ));
+            sqlx::Arguments::add(&mut args, plan_id).unwrap();
+            sqlx::Arguments::add(&mut args, campaign_id).unwrap();
+            sqlx::Arguments::add(&mut args, member_id).unwrap();
+            param_index += 3;
+        }
 
         if set_clauses.is_empty() {
-            return Self::get_by_id(pool, church_id, entry_id).await;
+            if req.allocations.is_none() {
+                return Self::get_by_id(pool, church_id, entry_id).await;
+            }
+            set_clauses.push("updated_at = NOW()".to_string());
         }
 
+        // Status guard: the checks above ran on `existing`, so a reversal, payment,
+        // approval, reconciliation or closing that got in first makes this update lose
+        sqlx::Arguments::add(&mut args, existing.status.as_str()).unwrap();
+        sqlx::Arguments::add(&mut args, existing.reconciled_at).unwrap();
         let sql = format!(
             r#"UPDATE financial_entries SET {}
                WHERE id = $1 AND church_id = $2 AND deleted_at IS NULL
-               RETURNING id, church_id, type, account_plan_id, bank_account_id, campaign_id,
-                         amount, entry_date, due_date, payment_date, description, payment_method,
-                         member_id, supplier_name, receipt_url, status, is_recurring, recurring_id,
-                         is_closed, closed_at, closed_by, registered_by, notes, congregation_id, created_at, updated_at, deleted_at"#,
-            set_clauses.join(", ")
+                 AND status = ${} AND is_closed = FALSE
+                 AND reconciled_at IS NOT DISTINCT FROM ${}
+               RETURNING {ENTRY_COLUMNS}"#,
+            set_clauses.join(", "),
+            param_index,
+            param_index + 1
         );
 
+        let mut tx = pool.begin().await?;
+
         let entry = sqlx::query_as_with::<_, FinancialEntry, _>(&sql, args)
-            .fetch_one(pool)
-            .await?;
+            .fetch_optional(&mut *tx)
+            .await?
+            .ok_or_else(|| {
+                AppError::conflict("O lançamento foi alterado por outra operação; recarregue e tente novamente")
+            })?;
+
+        if let Some(lines) = &req.allocations {
+            Self::save_allocations(&mut tx, church_id, entry_id, lines).await?;
+        }
+
+        // Amount, account or status changes move the balance of the old and new account
+        if existing.status == "confirmado" || entry.status == "confirmado" {
+            Self::sync_bank_balances(&mut tx, &[existing.bank_account_id, entry.bank_account_id])
+                .await?;
+        }
+
+        tx.commit().await?;
 
         Ok(entry)
     }
@@ -445,26 +706,244 @@ impl FinancialEntryService {
             ));
         }
 
-        // Reverse bank balance if was confirmed
-        if existing.status == "confirmado" {
-            Self::reverse_bank_balance(pool, existing.bank_account_id, existing.amount, &existing.entry_type).await?;
+        if existing.status == "estornado" || existing.reversal_of.is_some() {
+            return Err(AppError::validation(
+                "Lançamentos estornados ou de estorno não podem ser excluídos",
+            ));
+        }
+
+        if existing.transfer_id.is_some() {
+            return Err(AppError::validation(
+                "Lançamentos de transferência não podem ser excluídos individualmente",
+            ));
+        }
+
+        if existing.reconciled_at.is_some() {
+            return Err(AppError::validation(
+                "Lançamento conciliado com o extrato: desfaça a conciliação antes de excluir",
+            ));
         }
 
+        let mut tx = pool.begin().await?;
+
+        // Status guard: a reversal, payment, reconciliation or closing that got in
+        // first makes the delete lose
         let result = sqlx::query(
-            "UPDATE financial_entries SET deleted_at = NOW(), status = 'cancelado' WHERE id = $1 AND church_id = $2 AND deleted_at IS NULL",
+            r#"UPDATE financial_entries SET deleted_at = NOW(), status = 'cancelado'
+               WHERE id = $1 AND church_id = $2 AND deleted_at IS NULL
+                 AND status = $3 AND is_closed = FALSE AND reconciled_at IS NULL"#,
         )
         .bind(entry_id)
         .bind(church_id)
-        .execute(pool)
+        .bind(&existing.status)
+        .execute(&mut *tx)
         .await?;
 
         if result.rows_affected() == 0 {
-            return Err(AppError::not_found("Lançamento financeiro"));
+            return Err(AppError::conflict(
+                "O lançamento foi alterado por outra operação; recarregue e tente novamente",
+            ));
         }
 
+        // Recomputed from the ledger, so it holds whatever the status was
+        Self::sync_bank_balances(&mut tx, &[existing.bank_account_id]).await?;
+
+        tx.commit().await?;
+
         Ok(())
     }
 
+    /// Reverse (estornar) a confirmed entry, RN-FIN-010.
+    /// Inserts an opposite entry dated today that references the original, marks the
+    /// original as 'estornado' and moves the bank balance back, all in one transaction.
+    /// Works on closed entries too — it is the only way to correct a closed month.
+    pub async fn reverse(
+        pool: &PgPool,
+        church_id: Uuid,
+        user_id: Uuid,
+        entry_id: Uuid,
+        req: &ReverseFinancialEntryRequest,
+    ) -> Result<FinancialEntryReversal, AppError> {
+        let existing = Self::get_by_id(pool, church_id, entry_id).await?;
+
+        if existing.reversal_of.is_some() {
+            return Err(AppError::validation(
+                "Um lançamento de estorno não pode ser estornado",
+            ));
+        }
+        if existing.transfer_id.is_some() {
+            return Err(AppError::validation(
+                "Lançamentos de transferência não podem ser estornados individualmente",
+            ));
+        }
+        match existing.status.as_str() {
+            "confirmado" => {}
+            "estornado" => return Err(AppError::conflict("Este lançamento já foi estornado")),
+            _ => {
+                return Err(AppError::validation(
+                    "Apenas lançamentos confirmados podem ser estornados",
+                ))
+            }
+        }
+
+        // The compensating entry is dated today, so today's month must still be open
+        // for the church or the entry's congregation
+        let current_month_closed = sqlx::query_scalar::<_, bool>(
+            r#"SELECT EXISTS(SELECT 1 FROM monthly_closings
+               WHERE church_id = $1 AND reference_month = DATE_TRUNC('month', CURRENT_DATE)::date
+               AND (congregation_id IS NULL OR congregation_id = $2))"#,
+        )
+        .bind(church_id)
+        .bind(existing.congregation_id)
+        .fetch_one(pool)
+        .await?;
+
+        if current_month_closed {
+            return Err(AppError::validation(
+                "O mês atual já foi fechado; não é possível registrar o estorno",
+            ));
+        }
+
+        let reversal_type = if existing.entry_type == "receita" { "despesa" } else { "receita" };
+
+        let mut tx = pool.begin().await?;
+
+        // Status guard protects against two concurrent reversals of the same entry
+        let original = sqlx::query_as::<_, FinancialEntry>(&format!(
+            r#"UPDATE financial_entries SET status = 'estornado'
+               WHERE id = $1 AND church_id = $2 AND status = 'confirmado' AND deleted_at IS NULL
+               RETURNING {ENTRY_COLUMNS}"#
+        ))
+        .bind(entry_id)
+        .bind(church_id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(|| AppError::conflict("Este lançamento já foi estornado"))?;
+
+        let reversal = sqlx::query_as::<_, FinancialEntry>(&format!(
+            r#"
+            INSERT INTO financial_entries (
+                church_id, type, account_plan_id, bank_account_id,
+                amount, entry_date, payment_date, description,
+                payment_method, member_id, supplier_name, supplier_id,
+                status, registered_by, congregation_id, reversal_of, reversal_reason
+            )
+            VALUES ($1, $2, $3, $4, $5, CURRENT_DATE, CURRENT_DATE, $6, $7, $8, $9, $10, 'confirmado', $11, $12, $13, $14)
+            RETURNING {ENTRY_COLUMNS}
+            "#
+        ))
+        .bind(church_id)
+        .bind(reversal_type)
+        .bind(existing.account_plan_id)
+        .bind(existing.bank_account_id)
+        .bind(existing.amount)
+        .bind(format!("Estorno: {}", existing.description))
+        .bind(&existing.payment_method)
+        .bind(existing.member_id)
+        .bind(&existing.supplier_name)
+        .bind(existing.supplier_id)
+        .bind(user_id)
+        .bind(existing.congregation_id)
+        .bind(existing.id)
+        .bind(&req.reason)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        // The reversal offsets each allocation line of a split entry. Like a
+        // single-line reversal, it is not linked to the campaigns.
+        sqlx::query(
+            r#"INSERT INTO financial_entry_allocations (
+                   church_id, entry_id, account_plan_id, member_id,
+                   amount, description, sort_order
+               )
+               SELECT church_id, $2, account_plan_id, member_id,
+                      amount, description, sort_order
+               FROM financial_entry_allocations WHERE entry_id = $1"#,
+        )
+        .bind(existing.id)
+        .bind(reversal.id)
+        .execute(&mut *tx)
+        .await?;
+
+        Self::sync_bank_balances(&mut tx, &[existing.bank_account_id]).await?;
+
+        tx.commit().await?;
+
+        Ok(FinancialEntryReversal { original, reversal })
+    }
+
+    /// Pay a pending expense (RN-FIN-003): sets the payment date, confirms the entry
+    /// and moves the bank balance, all in one transaction
+    pub async fn pay(
+        pool: &PgPool,
+        church_id: Uuid,
+        entry_id: Uuid,
+        req: &PayFinancialEntryRequest,
+    ) -> Result<FinancialEntry, AppError> {
+        let existing = Self::get_by_id(pool, church_id, entry_id).await?;
+
+        if existing.entry_type != "despesa" {
+            return Err(AppError::validation("Apenas despesas podem ser pagas"));
+        }
+        if existing.status != "pendente" {
+            return Err(AppError::conflict("Apenas despesas pendentes podem ser pagas"));
+        }
+        if existing.is_closed {
+            return Err(AppError::validation(
+                "Lançamento pertence a um mês fechado",
+            ));
+        }
+
+        if let Some(ba_id) = req.bank_account_id {
+            let active = sqlx::query_scalar::<_, bool>(
+                "SELECT is_active FROM bank_accounts WHERE id = $1 AND church_id = $2",
+            )
+            .bind(ba_id)
+            .bind(church_id)
+            .fetch_optional(pool)
+            .await?
+            .ok_or_else(|| AppError::not_found("Conta bancária"))?;
+            if !active {
+                return Err(AppError::validation("Conta bancária inativa"));
+            }
+        }
+
+        let mut tx = pool.begin().await?;
+
+        // An unapproved expense under an approval rule waits with the payment recorded (RN-FIN-020)
+        let needs_approval = Self::payment_needs_approval(&mut tx, church_id, &existing).await?;
+
+        // Status guard protects against paying the same entry twice
+        let entry = sqlx::query_as::<_, FinancialEntry>(&format!(
+            r#"UPDATE financial_entries
+               SET status = CASE WHEN $6 THEN 'aguardando_aprovacao' ELSE 'confirmado' END,
+                   requested_status = CASE WHEN $6 THEN 'confirmado' END,
+                   payment_date = COALESCE($3, CURRENT_DATE),
+                   payment_method = COALESCE($4, payment_method),
+                   bank_account_id = COALESCE($5, bank_account_id)
+               WHERE id = $1 AND church_id = $2 AND status = 'pendente'
+               AND is_closed = FALSE AND deleted_at IS NULL
+               RETURNING {ENTRY_COLUMNS}"#
+        ))
+        .bind(entry_id)
+        .bind(church_id)
+        .bind(req.payment_date)
+        .bind(&req.payment_method)
+        .bind(req.bank_account_id)
+        .bind(needs_approval)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(|| AppError::conflict("Apenas despesas pendentes podem ser pagas"))?;
+
+        if entry.status == "confirmado" {
+            Self::sync_bank_balances(&mut tx, &[entry.bank_account_id]).await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(entry)
+    }
+
     /// Get financial balance report
     pub async fn balance_report(
         pool: &PgPool,
@@ -495,27 +974,29 @@ impl FinancialEntryService {
         // Total income
         let income_sql = format!(
             r#"SELECT COALESCE(SUM(fe.amount), 0) FROM financial_entries fe
-               WHERE fe.church_id = $1 AND fe.type = 'receita' AND fe.status = 'confirmado' AND fe.deleted_at IS NULL{date_conditions}"#
+               WHERE fe.church_id = $1 AND fe.type = 'receita' AND fe.status IN ('confirmado', 'estornado') AND fe.deleted_at IS NULL AND fe.transfer_id IS NULL{date_conditions}"#
         );
 
         let expense_sql = format!(
             r#"SELECT COALESCE(SUM(fe.amount), 0) FROM financial_entries fe
-               WHERE fe.church_id = $1 AND fe.type = 'despesa' AND fe.status = 'confirmado' AND fe.deleted_at IS NULL{date_conditions}"#
+               WHERE fe.church_id = $1 AND fe.type = 'despesa' AND fe.status IN ('confirmado', 'estornado') AND fe.deleted_at IS NULL AND fe.transfer_id IS NULL{date_conditions}"#
         );
 
         let income_cat_sql = format!(
-            r#"SELECT ap.name AS category_name, COALESCE(SUM(fe.amount), 0) AS amount, COUNT(*) AS count
+            r#"SELECT ap.name AS category_name, COALESCE(SUM(l.amount), 0) AS amount, COUNT(*) AS count
                FROM financial_entries fe
-               JOIN account_plans ap ON ap.id = fe.account_plan_id
-               WHERE fe.church_id = $1 AND fe.type = 'receita' AND fe.status = 'confirmado' AND fe.deleted_at IS NULL{date_conditions}
+               JOIN financial_entry_lines l ON l.entry_id = fe.id
+               JOIN account_plans ap ON ap.id = l.account_plan_id
+               WHERE fe.church_id = $1 AND fe.type = 'receita' AND fe.status IN ('confirmado', 'estornado') AND fe.deleted_at IS NULL AND fe.transfer_id IS NULL{date_conditions}
                GROUP BY ap.name ORDER BY amount DESC"#
         );
 
         let expense_cat_sql = format!(
-            r#"SELECT ap.name AS category_name, COALESCE(SUM(fe.amount), 0) AS amount, COUNT(*) AS count
+            r#"SELECT ap.name AS category_name, COALESCE(SUM(l.amount), 0) AS amount, COUNT(*) AS count
                FROM financial_entries fe
-               JOIN account_plans ap ON ap.id = fe.account_plan_id
-               WHERE fe.church_id = $1 AND fe.type = 'despesa' AND fe.status = 'confirmado' AND fe.deleted_at IS NULL{date_conditions}
+               JOIN financial_entry_lines l ON l.entry_id = fe.id
+               JOIN account_plans ap ON ap.id = l.account_plan_id
+               WHERE fe.church_id = $1 AND fe.type = 'despesa' AND fe.status IN ('confirmado', 'estornado') AND fe.deleted_at IS NULL AND fe.transfer_id IS NULL{date_conditions}
                GROUP BY ap.name ORDER BY amount DESC"#
         );
 
@@ -584,77 +1065,267 @@ impl FinancialEntryService {
         })
     }
 
-    /// Update bank account balance (add for receita, subtract for despesa)
-    async fn update_bank_balance(
+    /// Allocation lines of a split entry, in the order they were given
+    pub async fn allocations(
         pool: &PgPool,
-        bank_account_id: Uuid,
+        church_id: Uuid,
+        entry_id: Uuid,
+    ) -> Result<Vec<FinancialEntryAllocation>, AppError> {
+        Self::get_by_id(pool, church_id, entry_id).await?;
+
+        let allocations = sqlx::query_as::<_, FinancialEntryAllocation>(
+            r#"
+            SELECT a.id, a.entry_id, a.account_plan_id, ap.name AS account_plan_name,
+                   a.campaign_id, ca.name AS campaign_name,
+                   a.member_id, m.full_name AS member_name,
+                   a.amount, a.description
+            FROM financial_entry_allocations a
+            LEFT JOIN account_plans ap ON ap.id = a.account_plan_id
+            LEFT JOIN campaigns ca ON ca.id = a.campaign_id
+            LEFT JOIN members m ON m.id = a.member_id
+            WHERE a.entry_id = $1 AND a.church_id = $2
+            ORDER BY a.sort_order
+            "#,
+        )
+        .bind(entry_id)
+        .bind(church_id)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(allocations)
+    }
+
+    /// Validates the lines of a split entry and returns the entry's own category,
+    /// campaign and member: the largest line's category, and the campaign or
+    /// member only when every line shares it
+    fn split_summary(
+        lines: &[EntryAllocationRequest],
         amount: Decimal,
-        entry_type: &str,
-    ) -> Result<(), AppError> {
-        let adjustment = if entry_type == "receita" { amount } else { -amount };
+    ) -> Result<(Uuid, Option<Uuid>, Option<Uuid>), AppError> {
+        if lines.len() < 2 {
+            return Err(AppError::validation(
+                "Um lançamento dividido deve ter ao menos duas linhas",
+            ));
+        }
+        if lines.iter().any(|l| l.amount <= Decimal::ZERO) {
+            return Err(AppError::validation("Valor de cada linha deve ser maior que zero"));
+        }
+        let total: Decimal = lines.iter().map(|l| l.amount).sum();
+        if total != amount {
+            return Err(AppError::validation(format!(
+                "A soma das linhas ({total:.2}) difere do valor do lançamento ({amount:.2})"
+            )));
+        }
 
-        sqlx::query(
-            "UPDATE bank_accounts SET current_balance = current_balance + $1 WHERE id = $2",
+        let largest = lines
+            .iter()
+            .fold(&lines[0], |largest, l| if l.amount > largest.amount { l } else { largest });
+        let shared = |field: fn(&EntryAllocationRequest) -> Option<Uuid>| {
+            let first = field(&lines[0]);
+            if lines.iter().all(|l| field(l) == first) { first } else { None }
+        };
+
+        Ok((
+            largest.account_plan_id,
+            shared(|l| l.campaign_id),
+            shared(|l| l.member_id),
+        ))
+    }
+
+    /// Every line's category must be active and its campaign open on `entry_date` (RN-FIN-008)
+    async fn check_allocations(
+        pool: &PgPool,
+        church_id: Uuid,
+        lines: &[EntryAllocationRequest],
+        entry_date: chrono::NaiveDate,
+    ) -> Result<(), AppError> {
+        let plans = distinct_plans(lines);
+        let active = sqlx::query_scalar::<_, i64>(
+            "SELECT COUNT(*) FROM account_plans WHERE id = ANY($1) AND church_id = $2 AND is_active = TRUE",
         )
-        .bind(adjustment)
-        .bind(bank_account_id)
-        .execute(pool)
+        .bind(&plans)
+        .bind(church_id)
+        .fetch_one(pool)
         .await?;
+        if active != plans.len() as i64 {
+            return Err(AppError::validation("Plano de contas não encontrado ou inativo"));
+        }
+
+        let mut campaigns: Vec<Uuid> = lines.iter().filter_map(|l| l.campaign_id).collect();
+        campaigns.sort();
+        campaigns.dedup();
+        for campaign_id in campaigns {
+            CampaignService::ensure_accepts_entries(pool, church_id, campaign_id, entry_date).await?;
+        }
 
         Ok(())
     }
 
-    /// Reverse bank account balance
-    async fn reverse_bank_balance(
+    /// Current allocation lines of an entry (empty when it is not split)
+    async fn allocation_lines(
         pool: &PgPool,
-        bank_account_id: Uuid,
+        entry_id: Uuid,
+    ) -> Result<Vec<EntryAllocationRequest>, AppError> {
+        let rows = sqlx::query_as::<_, (Uuid, Option<Uuid>, Option<Uuid>, Decimal, Option<String>)>(
+            r#"SELECT account_plan_id, campaign_id, member_id, amount, description
+               FROM financial_entry_allocations WHERE entry_id = $1 ORDER BY sort_order"#,
+        )
+        .bind(entry_id)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(account_plan_id, campaign_id, member_id, amount, description)| {
+                EntryAllocationRequest { account_plan_id, campaign_id, member_id, amount, description }
+            })
+            .collect())
+    }
+
+    /// Replaces the allocation lines of an entry; an empty list leaves it single-line
+    async fn save_allocations(
+        conn: &mut PgConnection,
+        church_id: Uuid,
+        entry_id: Uuid,
+        lines: &[EntryAllocationRequest],
+    ) -> Result<(), AppError> {
+        sqlx::query("DELETE FROM financial_entry_allocations WHERE entry_id = $1")
+            .bind(entry_id)
+            .execute(&mut *conn)
+            .await?;
+
+        for (sort_order, line) in (0i16..).zip(lines) {
+            sqlx::query(
+                r#"INSERT INTO financial_entry_allocations (
+                       church_id, entry_id, account_plan_id, campaign_id, member_id,
+                       amount, description, sort_order
+                   )
+                   VALUES ($1, $2, $3, $4, $5, $6, $7, $8)"#,
+            )
+            .bind(church_id)
+            .bind(entry_id)
+            .bind(line.account_plan_id)
+            .bind(line.campaign_id)
+            .bind(line.member_id)
+            .bind(line.amount)
+            .bind(&line.description)
+            .bind(sort_order)
+            .execute(&mut *conn)
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    /// An expense needs approval when any of its categories falls under a rule (RN-FIN-020)
+    async fn approval_required(
+        conn: &mut PgConnection,
+        church_id: Uuid,
         amount: Decimal,
-        entry_type: &str,
+        plan_ids: &[Uuid],
+    ) -> Result<bool, AppError> {
+        for &plan_id in plan_ids {
+            if ExpenseApprovalService::matching_rule(&mut *conn, church_id, amount, plan_id)
+                .await?
+                .is_some()
+            {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Paying this expense needs a second signature: it is not approved yet and
+    /// one of its categories falls under a rule (RN-FIN-020)
+    pub(crate) async fn payment_needs_approval(
+        conn: &mut PgConnection,
+        church_id: Uuid,
+        entry: &FinancialEntry,
+    ) -> Result<bool, AppError> {
+        if entry.entry_type != "despesa" || entry.approved_by.is_some() {
+            return Ok(false);
+        }
+
+        let plan_ids = sqlx::query_scalar::<_, Uuid>(
+            "SELECT DISTINCT account_plan_id FROM financial_entry_lines WHERE entry_id = $1",
+        )
+        .bind(entry.id)
+        .fetch_all(&mut *conn)
+        .await?;
+
+        Self::approval_required(conn, church_id, entry.amount, &plan_ids).await
+    }
+
+    /// Recompute the cached `current_balance` of bank accounts from the ledger
+    /// (`bank_account_ledger_balance`: initial balance plus confirmed and reversed
+    /// entries). Call it in the transaction that changed the entries: the row
+    /// lock serializes concurrent writers, and the update then reads a snapshot
+    /// that includes every committed entry.
+    pub(crate) async fn sync_bank_balances(
+        conn: &mut PgConnection,
+        bank_account_ids: &[Uuid],
     ) -> Result<(), AppError> {
-        let adjustment = if entry_type == "receita" { -amount } else { amount };
+        // Lock in a fixed order so transfers in opposite directions cannot deadlock
+        sqlx::query("SELECT id FROM bank_accounts WHERE id = ANY($1) ORDER BY id FOR UPDATE")
+            .bind(bank_account_ids)
+            .execute(&mut *conn)
+            .await?;
 
         sqlx::query(
-            "UPDATE bank_accounts SET current_balance = current_balance + $1 WHERE id = $2",
+            "UPDATE bank_accounts SET current_balance = bank_account_ledger_balance(id) WHERE id = ANY($1)",
         )
-        .bind(adjustment)
-        .bind(bank_account_id)
-        .execute(pool)
+        .bind(bank_account_ids)
+        .execute(&mut *conn)
         .await?;
 
         Ok(())
     }
 }
 
+/// Categories of the allocation lines, without repetition
+fn distinct_plans(lines: &[EntryAllocationRequest]) -> Vec<Uuid> {
+    let mut plans: Vec<Uuid> = lines.iter().map(|l| l.account_plan_id).collect();
+    plans.sort();
+    plans.dedup();
+    plans
+}
+
 pub struct MonthlyClosingService;
 
 impl MonthlyClosingService {
-    /// List monthly closings
+    /// List monthly closings, optionally of a single congregation
     pub async fn list(
         pool: &PgPool,
         church_id: Uuid,
+        congregation_id: Option<Uuid>,
         limit: i64,
         offset: i64,
     ) -> Result<(Vec<MonthlyClosingSummary>, i64), AppError> {
         let total = sqlx::query_scalar::<_, i64>(
-            "SELECT COUNT(*) FROM monthly_closings WHERE church_id = $1",
+            r#"SELECT COUNT(*) FROM monthly_closings
+               WHERE church_id = $1 AND ($2::uuid IS NULL OR congregation_id = $2)"#,
         )
         .bind(church_id)
+        .bind(congregation_id)
         .fetch_one(pool)
         .await?;
 
         let closings = sqlx::query_as::<_, MonthlyClosingSummary>(
             r#"
-            SELECT mc.id, mc.reference_month, mc.total_income, mc.total_expense,
+            SELECT mc.id, mc.congregation_id, c.name AS congregation_name,
+                   mc.reference_month, mc.total_income, mc.total_expense,
                    mc.balance, mc.previous_balance, mc.accumulated_balance,
                    u.email AS closed_by_name, mc.notes, mc.created_at
             FROM monthly_closings mc
             LEFT JOIN users u ON u.id = mc.closed_by
-            WHERE mc.church_id = $1
-            ORDER BY mc.reference_month DESC
-            LIMIT $2 OFFSET $3
+            LEFT JOIN congregations c ON c.id = mc.congregation_id
+            WHERE mc.church_id = $1 AND ($2::uuid IS NULL OR mc.congregation_id = $2)
+            ORDER BY mc.reference_month DESC, mc.congregation_id NULLS FIRST, c.name
+            LIMIT $3 OFFSET $4
             "#,
         )
         .bind(church_id)
+        .bind(congregation_id)
         .bind(limit)
         .bind(offset)
         .fetch_all(pool)
@@ -663,20 +1334,68 @@ impl MonthlyClosingService {
         Ok((closings, total))
     }
 
-    /// Perform monthly closing
+    /// Get a monthly closing with its bank account balances
+    pub async fn get_by_id(
+        pool: &PgPool,
+        church_id: Uuid,
+        closing_id: Uuid,
+    ) -> Result<MonthlyClosingDetail, AppError> {
+        let closing = sqlx::query_as::<_, MonthlyClosing>(
+            "SELECT * FROM monthly_closings WHERE id = $1 AND church_id = $2",
+        )
+        .bind(closing_id)
+        .bind(church_id)
+        .fetch_optional(pool)
+        .await?
+        .ok_or_else(|| AppError::not_found("Fechamento mensal"))?;
+
+        let bank_balances = Self::bank_balances(pool, closing.id).await?;
+
+        Ok(MonthlyClosingDetail {
+            closing,
+            bank_balances,
+        })
+    }
+
+    /// Perform monthly closing of a congregation, or church-wide when
+    /// `congregation_id` is omitted. Each scope keeps its own accumulated balance
+    /// chain; the church-wide closing consolidates every entry and requires all
+    /// active congregations to be closed first.
     pub async fn close_month(
         pool: &PgPool,
         church_id: Uuid,
         user_id: Uuid,
         req: &MonthlyClosingRequest,
-    ) -> Result<MonthlyClosing, AppError> {
+    ) -> Result<MonthlyClosingDetail, AppError> {
+        let congregation_id = req.congregation_id;
+        let month_start = req.reference_month;
+        let month_end = month_start + chrono::Months::new(1) - chrono::Duration::days(1);
+
+        let mut tx = pool.begin().await?;
+
+        if let Some(cid) = congregation_id {
+            let exists = sqlx::query_scalar::<_, bool>(
+                "SELECT EXISTS(SELECT 1 FROM congregations WHERE id = $1 AND church_id = $2)",
+            )
+            .bind(cid)
+            .bind(church_id)
+            .fetch_one(&mut *tx)
+            .await?;
+            if !exists {
+                return Err(AppError::not_found("Congregação"));
+            }
+        }
+
         // Check if already closed
         let existing = sqlx::query_scalar::<_, Uuid>(
-            "SELECT id FROM monthly_closings WHERE church_id = $1 AND reference_month = $2",
+            r#"SELECT id FROM monthly_closings
+               WHERE church_id = $1 AND reference_month = $2
+               AND congregation_id IS NOT DISTINCT FROM $3"#,
         )
         .bind(church_id)
         .bind(req.reference_month)
-        .fetch_optional(pool)
+        .bind(congregation_id)
+        .fetch_optional(&mut *tx)
         .await?;
 
         if existing.is_some() {
@@ -685,71 +1404,137 @@ impl MonthlyClosingService {
             ));
         }
 
-        // Calculate totals for the month
-        let month_start = req.reference_month;
-        let month_end = month_start + chrono::Months::new(1) - chrono::Duration::days(1);
+        match congregation_id {
+            // A congregation cannot close a month already consolidated by the church
+            Some(_) => {
+                let church_closed = sqlx::query_scalar::<_, bool>(
+                    r#"SELECT EXISTS(SELECT 1 FROM monthly_closings
+                       WHERE church_id = $1 AND reference_month = $2 AND congregation_id IS NULL)"#,
+                )
+                .bind(church_id)
+                .bind(req.reference_month)
+                .fetch_one(&mut *tx)
+                .await?;
+                if church_closed {
+                    return Err(AppError::Conflict(
+                        "O fechamento geral deste mês já foi realizado".into(),
+                    ));
+                }
+            }
+            None => {
+                let pending = sqlx::query_scalar::<_, String>(
+                    r#"SELECT c.name FROM congregations c
+                       WHERE c.church_id = $1 AND c.is_active = TRUE
+                       AND NOT EXISTS (SELECT 1 FROM monthly_closings mc
+                           WHERE mc.congregation_id = c.id AND mc.reference_month = $2)
+                       ORDER BY c.name"#,
+                )
+                .bind(church_id)
+                .bind(req.reference_month)
+                .fetch_all(&mut *tx)
+                .await?;
+                if !pending.is_empty() {
+                    return Err(AppError::validation(format!(
+                        "Congregações sem fechamento neste mês: {}",
+                        pending.join(", ")
+                    )));
+                }
+            }
+        }
+
+        // Expenses awaiting approval would be left out of the closed month (RN-FIN-020)
+        let awaiting = sqlx::query_scalar::<_, i64>(
+            r#"SELECT COUNT(*) FROM financial_entries
+               WHERE church_id = $1 AND status = 'aguardando_aprovacao' AND deleted_at IS NULL
+               AND entry_date >= $2 AND entry_date <= $3
+               AND ($4::uuid IS NULL OR congregation_id = $4)"#,
+        )
+        .bind(church_id)
+        .bind(month_start)
+        .bind(month_end)
+        .bind(congregation_id)
+        .fetch_one(&mut *tx)
+        .await?;
+        if awaiting > 0 {
+            return Err(AppError::validation(format!(
+                "Há {awaiting} despesa(s) aguardando aprovação neste mês; aprove ou rejeite antes de fechar"
+            )));
+        }
 
+        // Calculate totals for the month
         let total_income = sqlx::query_scalar::<_, Decimal>(
             r#"SELECT COALESCE(SUM(amount), 0) FROM financial_entries
-               WHERE church_id = $1 AND type = 'receita' AND status = 'confirmado'
-               AND deleted_at IS NULL AND entry_date >= $2 AND entry_date <= $3"#,
+               WHERE church_id = $1 AND type = 'receita' AND status IN ('confirmado', 'estornado')
+               AND deleted_at IS NULL AND transfer_id IS NULL
+               AND entry_date >= $2 AND entry_date <= $3
+               AND ($4::uuid IS NULL OR congregation_id = $4)"#,
         )
         .bind(church_id)
         .bind(month_start)
         .bind(month_end)
-        .fetch_one(pool)
+        .bind(congregation_id)
+        .fetch_one(&mut *tx)
         .await?;
 
         let total_expense = sqlx::query_scalar::<_, Decimal>(
             r#"SELECT COALESCE(SUM(amount), 0) FROM financial_entries
-               WHERE church_id = $1 AND type = 'despesa' AND status = 'confirmado'
-               AND deleted_at IS NULL AND entry_date >= $2 AND entry_date <= $3"#,
+               WHERE church_id = $1 AND type = 'despesa' AND status IN ('confirmado', 'estornado')
+               AND deleted_at IS NULL AND transfer_id IS NULL
+               AND entry_date >= $2 AND entry_date <= $3
+               AND ($4::uuid IS NULL OR congregation_id = $4)"#,
         )
         .bind(church_id)
         .bind(month_start)
         .bind(month_end)
-        .fetch_one(pool)
+        .bind(congregation_id)
+        .fetch_one(&mut *tx)
         .await?;
 
         let balance = total_income - total_expense;
 
-        // Get previous month's accumulated balance
+        // Get previous month's accumulated balance in the same scope
         let previous_balance = sqlx::query_scalar::<_, Decimal>(
             r#"SELECT COALESCE(accumulated_balance, 0) FROM monthly_closings
                WHERE church_id = $1 AND reference_month < $2
+               AND congregation_id IS NOT DISTINCT FROM $3
                ORDER BY reference_month DESC LIMIT 1"#,
         )
         .bind(church_id)
         .bind(req.reference_month)
-        .fetch_optional(pool)
+        .bind(congregation_id)
+        .fetch_optional(&mut *tx)
         .await?
         .unwrap_or(Decimal::ZERO);
 
         let accumulated_balance = previous_balance + balance;
 
-        // Mark entries as closed
+        // Mark entries as closed (church-wide: whatever the congregations left open)
         sqlx::query(
             r#"UPDATE financial_entries SET is_closed = TRUE, closed_at = NOW(), closed_by = $1
                WHERE church_id = $2 AND entry_date >= $3 AND entry_date <= $4
+               AND ($5::uuid IS NULL OR congregation_id = $5)
                AND deleted_at IS NULL AND is_closed = FALSE"#,
         )
         .bind(user_id)
         .bind(church_id)
         .bind(month_start)
         .bind(month_end)
-        .execute(pool)
+        .bind(congregation_id)
+        .execute(&mut *tx)
         .await?;
 
         // Create closing record
         let closing = sqlx::query_as::<_, MonthlyClosing>(
             r#"
-            INSERT INTO monthly_closings (church_id, reference_month, total_income, total_expense,
-                                          balance, previous_balance, accumulated_balance, closed_by, notes)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            INSERT INTO monthly_closings (church_id, congregation_id, reference_month, total_income,
+                                          total_expense, balance, previous_balance, accumulated_balance,
+                                          closed_by, notes)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
             RETURNING *
             "#,
         )
         .bind(church_id)
+        .bind(congregation_id)
         .bind(req.reference_month)
         .bind(total_income)
         .bind(total_expense)
@@ -758,9 +1543,166 @@ impl MonthlyClosingService {
         .bind(accumulated_balance)
         .bind(user_id)
         .bind(&req.notes)
-        .fetch_one(pool)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        // Snapshot of the bank account balances at the end of the month
+        // (initial balance plus every movement, transfers included)
+        sqlx::query(
+            r#"
+            INSERT INTO monthly_closing_bank_balances (closing_id, bank_account_id, closing_balance)
+            SELECT $1, ba.id,
+                   ba.initial_balance + COALESCE(SUM(CASE WHEN fe.type = 'receita'
+                                                          THEN fe.amount ELSE -fe.amount END), 0)
+            FROM bank_accounts ba
+            LEFT JOIN financial_entries fe ON fe.bank_account_id = ba.id
+                 AND fe.status IN ('confirmado', 'estornado') AND fe.deleted_at IS NULL
+                 AND fe.entry_date <= $3
+            WHERE ba.church_id = $2 AND ba.is_active = TRUE
+              AND ($4::uuid IS NULL OR ba.congregation_id = $4)
+            GROUP BY ba.id, ba.initial_balance
+            "#,
+        )
+        .bind(closing.id)
+        .bind(church_id)
+        .bind(month_end)
+        .bind(congregation_id)
+        .execute(&mut *tx)
         .await?;
 
+        tx.commit().await?;
+
+        let bank_balances = Self::bank_balances(pool, closing.id).await?;
+
+        Ok(MonthlyClosingDetail {
+            closing,
+            bank_balances,
+        })
+    }
+
+    /// Undo a monthly closing (RN-FIN-007): the month's entries become editable
+    /// again, the closing row is removed and later closings of the same scope have
+    /// their previous and accumulated balances recomputed. Returns the removed closing.
+    pub async fn reopen(
+        pool: &PgPool,
+        church_id: Uuid,
+        closing_id: Uuid,
+    ) -> Result<MonthlyClosing, AppError> {
+        let mut tx = pool.begin().await?;
+
+        let closing = sqlx::query_as::<_, MonthlyClosing>(
+            "SELECT * FROM monthly_closings WHERE id = $1 AND church_id = $2 FOR UPDATE",
+        )
+        .bind(closing_id)
+        .bind(church_id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(|| AppError::not_found("Fechamento mensal"))?;
+
+        let month_start = closing.reference_month;
+        let month_end = month_start + chrono::Months::new(1) - chrono::Duration::days(1);
+
+        if closing.congregation_id.is_some() {
+            let church_closed = sqlx::query_scalar::<_, bool>(
+                r#"SELECT EXISTS(SELECT 1 FROM monthly_closings
+                   WHERE church_id = $1 AND reference_month = $2 AND congregation_id IS NULL)"#,
+            )
+            .bind(church_id)
+            .bind(month_start)
+            .fetch_one(&mut *tx)
+            .await?;
+            if church_closed {
+                return Err(AppError::Conflict(
+                    "Desfaça primeiro o fechamento geral deste mês".into(),
+                ));
+            }
+        }
+
+        // Reopen the month's entries; church-wide keeps the congregations still closed
+        sqlx::query(
+            r#"UPDATE financial_entries fe SET is_closed = FALSE, closed_at = NULL, closed_by = NULL
+               WHERE fe.church_id = $1 AND fe.entry_date >= $2 AND fe.entry_date <= $3
+               AND fe.is_closed = TRUE
+               AND CASE WHEN $4::uuid IS NULL
+                        THEN NOT EXISTS (SELECT 1 FROM monthly_closings mc
+                             WHERE mc.congregation_id = fe.congregation_id
+                             AND mc.reference_month = $2)
+                        ELSE fe.congregation_id = $4
+                   END"#,
+        )
+        .bind(church_id)
+        .bind(month_start)
+        .bind(month_end)
+        .bind(closing.congregation_id)
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query("DELETE FROM monthly_closings WHERE id = $1")
+            .bind(closing_id)
+            .execute(&mut *tx)
+            .await?;
+
+        // Rebuild the accumulated balance chain of the later closings
+        let mut previous_balance = sqlx::query_scalar::<_, Decimal>(
+            r#"SELECT accumulated_balance FROM monthly_closings
+               WHERE church_id = $1 AND reference_month < $2
+               AND congregation_id IS NOT DISTINCT FROM $3
+               ORDER BY reference_month DESC LIMIT 1"#,
+        )
+        .bind(church_id)
+        .bind(month_start)
+        .bind(closing.congregation_id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .unwrap_or(Decimal::ZERO);
+
+        let later = sqlx::query_as::<_, (Uuid, Decimal)>(
+            r#"SELECT id, balance FROM monthly_closings
+               WHERE church_id = $1 AND reference_month > $2
+               AND congregation_id IS NOT DISTINCT FROM $3
+               ORDER BY reference_month ASC"#,
+        )
+        .bind(church_id)
+        .bind(month_start)
+        .bind(closing.congregation_id)
+        .fetch_all(&mut *tx)
+        .await?;
+
+        for (id, balance) in later {
+            let accumulated_balance = previous_balance + balance;
+            sqlx::query(
+                "UPDATE monthly_closings SET previous_balance = $1, accumulated_balance = $2 WHERE id = $3",
+            )
+            .bind(previous_balance)
+            .bind(accumulated_balance)
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+            previous_balance = accumulated_balance;
+        }
+
+        tx.commit().await?;
+
         Ok(closing)
     }
+
+    async fn bank_balances(
+        pool: &PgPool,
+        closing_id: Uuid,
+    ) -> Result<Vec<MonthlyClosingBankBalance>, AppError> {
+        let balances = sqlx::query_as::<_, MonthlyClosingBankBalance>(
+            r#"
+            SELECT cb.bank_account_id, ba.name AS bank_account_name, cb.closing_balance
+            FROM monthly_closing_bank_balances cb
+            JOIN bank_accounts ba ON ba.id = cb.bank_account_id
+            WHERE cb.closing_id = $1
+            ORDER BY ba.name
+            "#,
+        )
+        .bind(closing_id)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(balances)
+    }
 }
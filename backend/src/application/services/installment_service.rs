@@ -0,0 +1,470 @@
+use crate::application::dto::{
+    CreateInstallmentPlanRequest, InstallmentPlanFilter, RenegotiateInstallmentsRequest,
+};
+use crate::application::services::financial_service::ENTRY_COLUMNS;
+use crate::application::services::{ExpenseApprovalService, SupplierService};
+use crate::domain::entities::{
+    FinancialEntry, InstallmentPlan, InstallmentPlanDetail, InstallmentPlanSummary,
+};
+use crate::errors::AppError;
+use chrono::{Months, NaiveDate};
+use rust_decimal::{Decimal, RoundingStrategy};
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+/// Installments still to be paid; only these are replaced on renegotiation
+const OPEN_STATUSES: &str = "('pendente', 'aguardando_aprovacao')";
+
+pub struct InstallmentService;
+
+impl InstallmentService {
+    /// List installment plans with their payment progress
+    pub async fn list(
+        pool: &PgPool,
+        church_id: Uuid,
+        filter: &InstallmentPlanFilter,
+        search: &Option<String>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<InstallmentPlanSummary>, i64), AppError> {
+        let mut conditions = vec![
+            "ip.church_id = $1".to_string(),
+            "ip.deleted_at IS NULL".to_string(),
+        ];
+        let mut param_idx = 2u32;
+
+        if filter.supplier_id.is_some() {
+            conditions.push(format!("ip.supplier_id = ${param_idx}"));
+            param_idx += 1;
+        }
+        if filter.congregation_id.is_some() {
+            conditions.push(format!("ip.congregation_id = ${param_idx}"));
+            param_idx += 1;
+        }
+        if search.is_some() {
+            conditions.push(format!(
+                "unaccent(ip.description) ILIKE '%' || unaccent(${param_idx}) || '%'"
+            ));
+            param_idx += 1;
+        }
+        let open_exists = format!(
+            "EXISTS (SELECT 1 FROM financial_entries fe WHERE fe.installment_plan_id = ip.id \
+             AND fe.status IN {OPEN_STATUSES} AND fe.deleted_at IS NULL)"
+        );
+        match filter.open {
+            Some(true) => conditions.push(open_exists),
+            Some(false) => conditions.push(format!("NOT {open_exists}")),
+            None => {}
+        }
+
+        let _ = param_idx;
+        let where_clause = conditions.join(" AND ");
+
+        let count_sql = format!("SELECT COUNT(*) FROM installment_plans ip WHERE {where_clause}");
+
+        let query_sql = format!(
+            r#"
+            SELECT ip.id, ip.description, ip.purchase_date, ip.total_amount,
+                   ip.installment_count, ip.payment_method, ip.supplier_name,
+                   ap.name AS account_plan_name,
+                   cg.name AS congregation_name,
+                   COALESCE(p.paid_count, 0) AS paid_count,
+                   COALESCE(p.paid_amount, 0) AS paid_amount,
+                   COALESCE(p.open_amount, 0) AS open_amount,
+                   p.next_due_date,
+                   ip.created_at
+            FROM installment_plans ip
+            LEFT JOIN account_plans ap ON ap.id = ip.account_plan_id
+            LEFT JOIN congregations cg ON cg.id = ip.congregation_id
+            LEFT JOIN LATERAL (
+                SELECT COUNT(*) FILTER (WHERE fe.status = 'confirmado') AS paid_count,
+                       SUM(fe.amount) FILTER (WHERE fe.status = 'confirmado') AS paid_amount,
+                       SUM(fe.amount) FILTER (WHERE fe.status IN {OPEN_STATUSES}) AS open_amount,
+                       MIN(fe.due_date) FILTER (WHERE fe.status IN {OPEN_STATUSES}) AS next_due_date
+                FROM financial_entries fe
+                WHERE fe.installment_plan_id = ip.id AND fe.deleted_at IS NULL
+            ) p ON TRUE
+            WHERE {where_clause}
+            ORDER BY p.next_due_date ASC NULLS LAST, ip.purchase_date DESC
+            LIMIT {limit} OFFSET {offset}
+            "#
+        );
+
+        let mut count_args = sqlx::postgres::PgArguments::default();
+        let mut data_args = sqlx::postgres::PgArguments::default();
+
+        sqlx::Arguments::add(&mut count_args, church_id).unwrap();
+        sqlx::Arguments::add(&mut data_args, church_id).unwrap();
+
+        if let Some(supplier_id) = filter.supplier_id {
+            sqlx::Arguments::add(&mut count_args, supplier_id).unwrap();
+            sqlx::Arguments::add(&mut data_args, supplier_id).unwrap();
+        }
+        if let Some(congregation_id) = filter.congregation_id {
+            sqlx::Arguments::add(&mut count_args, congregation_id).unwrap();
+            sqlx::Arguments::add(&mut data_args, congregation_id).unwrap();
+        }
+        if let Some(term) = search {
+            sqlx::Arguments::add(&mut count_args, term.as_str()).unwrap();
+            sqlx::Arguments::add(&mut data_args, term.as_str()).unwrap();
+        }
+
+        let total = sqlx::query_scalar_with::<_, i64, _>(&count_sql, count_args)
+            .fetch_one(pool)
+            .await?;
+
+        let plans = sqlx::query_as_with::<_, InstallmentPlanSummary, _>(&query_sql, data_args)
+            .fetch_all(pool)
+            .await?;
+
+        Ok((plans, total))
+    }
+
+    /// Get an installment plan with its installments
+    pub async fn get_by_id(
+        pool: &PgPool,
+        church_id: Uuid,
+        plan_id: Uuid,
+    ) -> Result<InstallmentPlanDetail, AppError> {
+        let plan = sqlx::query_as::<_, InstallmentPlan>(
+            "SELECT * FROM installment_plans WHERE id = $1 AND church_id = $2 AND deleted_at IS NULL",
+        )
+        .bind(plan_id)
+        .bind(church_id)
+        .fetch_optional(pool)
+        .await?
+        .ok_or_else(|| AppError::not_found("Parcelamento"))?;
+
+        let installments = sqlx::query_as::<_, FinancialEntry>(&format!(
+            r#"SELECT {ENTRY_COLUMNS} FROM financial_entries
+               WHERE installment_plan_id = $1 AND deleted_at IS NULL
+               ORDER BY installment_number"#
+        ))
+        .bind(plan_id)
+        .fetch_all(pool)
+        .await?;
+
+        let amount_in = |statuses: &[&str]| -> Decimal {
+            installments
+                .iter()
+                .filter(|e| statuses.contains(&e.status.as_str()))
+                .map(|e| e.amount)
+                .sum()
+        };
+        let paid_amount = amount_in(&["confirmado"]);
+        let open_amount = amount_in(&["pendente", "aguardando_aprovacao"]);
+
+        Ok(InstallmentPlanDetail {
+            plan,
+            paid_amount,
+            open_amount,
+            installments,
+        })
+    }
+
+    /// Create an installment plan and its installments as pending expenses,
+    /// one per month from the first due date (RN-FIN-024)
+    pub async fn create(
+        pool: &PgPool,
+        church_id: Uuid,
+        user_id: Uuid,
+        req: &CreateInstallmentPlanRequest,
+    ) -> Result<InstallmentPlanDetail, AppError> {
+        if req.first_due_date < req.purchase_date {
+            return Err(AppError::validation(
+                "O primeiro vencimento não pode ser anterior à data da compra",
+            ));
+        }
+
+        let amounts = split_amount(req.total_amount, req.installment_count)?;
+        let due_dates = monthly_due_dates(req.first_due_date, req.installment_count)?;
+
+        sqlx::query_scalar::<_, Uuid>(
+            "SELECT id FROM account_plans WHERE id = $1 AND church_id = $2 AND is_active = TRUE AND type = 'despesa'",
+        )
+        .bind(req.account_plan_id)
+        .bind(church_id)
+        .fetch_optional(pool)
+        .await?
+        .ok_or_else(|| AppError::validation("Plano de contas de despesa não encontrado ou inativo"))?;
+
+        sqlx::query_scalar::<_, Uuid>(
+            "SELECT id FROM bank_accounts WHERE id = $1 AND church_id = $2 AND is_active = TRUE",
+        )
+        .bind(req.bank_account_id)
+        .bind(church_id)
+        .fetch_optional(pool)
+        .await?
+        .ok_or_else(|| AppError::validation("Conta bancária não encontrada ou inativa"))?;
+
+        // A registered supplier's name goes to supplier_name (RN-FIN-021)
+        let supplier_name = match req.supplier_id {
+            Some(sid) => Some(SupplierService::name_for_link(pool, church_id, sid).await?),
+            None => req.supplier_name.clone(),
+        };
+
+        let mut tx = pool.begin().await?;
+
+        Self::ensure_open_months(&mut tx, church_id, req.congregation_id, &due_dates).await?;
+
+        let plan = sqlx::query_as::<_, InstallmentPlan>(
+            r#"
+            INSERT INTO installment_plans (
+                church_id, account_plan_id, bank_account_id, description, payment_method,
+                supplier_id, supplier_name, congregation_id, notes, purchase_date,
+                total_amount, installment_count, first_due_date, created_by
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+            RETURNING *
+            "#,
+        )
+        .bind(church_id)
+        .bind(req.account_plan_id)
+        .bind(req.bank_account_id)
+        .bind(&req.description)
+        .bind(&req.payment_method)
+        .bind(req.supplier_id)
+        .bind(&supplier_name)
+        .bind(req.congregation_id)
+        .bind(&req.notes)
+        .bind(req.purchase_date)
+        .bind(req.total_amount)
+        .bind(req.installment_count)
+        .bind(req.first_due_date)
+        .bind(user_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        Self::insert_installments(&mut tx, &plan, user_id, 1, &amounts, &due_dates).await?;
+
+        tx.commit().await?;
+
+        Self::get_by_id(pool, church_id, plan.id).await
+    }
+
+    /// Replace the open installments with a new schedule. Paid installments are
+    /// kept and numbered first; the new ones follow them (RN-FIN-024).
+    pub async fn renegotiate(
+        pool: &PgPool,
+        church_id: Uuid,
+        user_id: Uuid,
+        plan_id: Uuid,
+        req: &RenegotiateInstallmentsRequest,
+    ) -> Result<InstallmentPlanDetail, AppError> {
+        let mut tx = pool.begin().await?;
+
+        let plan = sqlx::query_as::<_, InstallmentPlan>(
+            "SELECT * FROM installment_plans WHERE id = $1 AND church_id = $2 AND deleted_at IS NULL FOR UPDATE",
+        )
+        .bind(plan_id)
+        .bind(church_id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(|| AppError::not_found("Parcelamento"))?;
+
+        let open = sqlx::query_as::<_, FinancialEntry>(&format!(
+            r#"SELECT {ENTRY_COLUMNS} FROM financial_entries
+               WHERE installment_plan_id = $1 AND status IN {OPEN_STATUSES} AND deleted_at IS NULL
+               ORDER BY installment_number"#
+        ))
+        .bind(plan_id)
+        .fetch_all(&mut *tx)
+        .await?;
+
+        if open.is_empty() {
+            return Err(AppError::validation(
+                "Não há parcelas em aberto para renegociar",
+            ));
+        }
+        if open.iter().any(|e| e.is_closed || e.reconciled_at.is_some()) {
+            return Err(AppError::validation(
+                "Há parcelas em aberto em mês fechado ou conciliadas com o extrato",
+            ));
+        }
+
+        let open_amount = match req.open_amount {
+            Some(amount) => amount,
+            None => open.iter().map(|e| e.amount).sum(),
+        };
+        let amounts = split_amount(open_amount, req.installment_count)?;
+        let due_dates = monthly_due_dates(req.first_due_date, req.installment_count)?;
+
+        Self::ensure_open_months(&mut tx, church_id, plan.congregation_id, &due_dates).await?;
+
+        let open_ids: Vec<Uuid> = open.iter().map(|e| e.id).collect();
+        sqlx::query(
+            "UPDATE financial_entries SET deleted_at = NOW(), status = 'cancelado' WHERE id = ANY($1)",
+        )
+        .bind(&open_ids)
+        .execute(&mut *tx)
+        .await?;
+
+        // The kept installments (paid, cancelled, reversed) are numbered 1..k in order
+        let (kept_count, kept_amount) = sqlx::query_as::<_, (i64, Decimal)>(
+            r#"
+            WITH kept AS (
+                SELECT id, ROW_NUMBER() OVER (ORDER BY installment_number, due_date) AS n
+                FROM financial_entries
+                WHERE installment_plan_id = $1 AND deleted_at IS NULL
+            ), renumbered AS (
+                UPDATE financial_entries fe SET installment_number = kept.n
+                FROM kept WHERE fe.id = kept.id
+                RETURNING fe.status, fe.amount
+            )
+            SELECT COUNT(*),
+                   COALESCE(SUM(amount) FILTER (WHERE status = 'confirmado'), 0)
+            FROM renumbered
+            "#,
+        )
+        .bind(plan_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let installment_count = i16::try_from(kept_count)
+            .ok()
+            .and_then(|kept| kept.checked_add(req.installment_count))
+            .filter(|count| *count <= 120)
+            .ok_or_else(|| AppError::validation("Um parcelamento pode ter no máximo 120 parcelas"))?;
+
+        let plan = sqlx::query_as::<_, InstallmentPlan>(
+            r#"UPDATE installment_plans
+               SET total_amount = $2, installment_count = $3, renegotiated_at = NOW()
+               WHERE id = $1
+               RETURNING *"#,
+        )
+        .bind(plan_id)
+        .bind(kept_amount + open_amount)
+        .bind(installment_count)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        Self::insert_installments(
+            &mut tx,
+            &plan,
+            user_id,
+            installment_count - req.installment_count + 1,
+            &amounts,
+            &due_dates,
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Self::get_by_id(pool, church_id, plan_id).await
+    }
+
+    /// Insert installments numbered from `first_number` as pending expenses.
+    /// The approval rules look at the amount being committed, not at each
+    /// installment, so splitting a purchase does not skip a second signature.
+    async fn insert_installments(
+        conn: &mut PgConnection,
+        plan: &InstallmentPlan,
+        user_id: Uuid,
+        first_number: i16,
+        amounts: &[Decimal],
+        due_dates: &[NaiveDate],
+    ) -> Result<(), AppError> {
+        let committed: Decimal = amounts.iter().sum();
+        let (status, requested_status) = match ExpenseApprovalService::matching_rule(
+            &mut *conn,
+            plan.church_id,
+            committed,
+            plan.account_plan_id,
+        )
+        .await?
+        {
+            Some(_) => ("aguardando_aprovacao", Some("pendente")),
+            None => ("pendente", None),
+        };
+
+        for (i, (amount, due_date)) in amounts.iter().zip(due_dates).enumerate() {
+            sqlx::query(
+                r#"
+                INSERT INTO financial_entries (
+                    church_id, type, account_plan_id, bank_account_id, amount,
+                    entry_date, due_date, description, payment_method, supplier_id,
+                    supplier_name, status, requested_status, registered_by, notes,
+                    congregation_id, installment_plan_id, installment_number
+                )
+                VALUES ($1, 'despesa', $2, $3, $4, $5, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16)
+                "#,
+            )
+            .bind(plan.church_id)
+            .bind(plan.account_plan_id)
+            .bind(plan.bank_account_id)
+            .bind(amount)
+            .bind(due_date)
+            .bind(&plan.description)
+            .bind(&plan.payment_method)
+            .bind(plan.supplier_id)
+            .bind(&plan.supplier_name)
+            .bind(status)
+            .bind(requested_status)
+            .bind(user_id)
+            .bind(&plan.notes)
+            .bind(plan.congregation_id)
+            .bind(plan.id)
+            .bind(first_number + i as i16)
+            .execute(&mut *conn)
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Installments cannot fall due in a month already closed
+    async fn ensure_open_months(
+        conn: &mut PgConnection,
+        church_id: Uuid,
+        congregation_id: Option<Uuid>,
+        due_dates: &[NaiveDate],
+    ) -> Result<(), AppError> {
+        let closed = sqlx::query_scalar::<_, bool>(
+            r#"SELECT EXISTS(SELECT 1 FROM monthly_closings
+                      WHERE church_id = $1
+                      AND reference_month IN (SELECT DATE_TRUNC('month', d)::date FROM UNNEST($2::date[]) d)
+                      AND (congregation_id IS NULL OR congregation_id = $3))"#,
+        )
+        .bind(church_id)
+        .bind(due_dates)
+        .bind(congregation_id)
+        .fetch_one(&mut *conn)
+        .await?;
+
+        if closed {
+            return Err(AppError::validation(
+                "Há vencimentos em mês já fechado",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Even installments to the cent; the remainder goes to the first one
+fn split_amount(total: Decimal, count: i16) -> Result<Vec<Decimal>, AppError> {
+    if count < 1 {
+        return Err(AppError::validation("Número de parcelas deve ser maior que zero"));
+    }
+    let installment = (total / Decimal::from(count))
+        .round_dp_with_strategy(2, RoundingStrategy::ToZero);
+    if installment <= Decimal::ZERO {
+        return Err(AppError::validation(
+            "Valor insuficiente para o número de parcelas",
+        ));
+    }
+
+    let mut amounts = vec![installment; count as usize];
+    amounts[0] = total - installment * Decimal::from(count - 1);
+    Ok(amounts)
+}
+
+/// Same day of the month as the first due date, clamped in shorter months
+fn monthly_due_dates(first: NaiveDate, count: i16) -> Result<Vec<NaiveDate>, AppError> {
+    (0..count as u32)
+        .map(|i| {
+            first
+                .checked_add_months(Months::new(i))
+                .ok_or_else(|| AppError::validation("Data de vencimento inválida"))
+        })
+        .collect()
+}
@@ -1,5 +1,6 @@
 use crate::application::dto::{CreateMaintenanceRequest, MaintenanceFilter, UpdateMaintenanceRequest};
 use crate::domain::entities::{Maintenance, MaintenanceSummary};
+use crate::application::services::SupplierService;
 use crate::errors::AppError;
 use sqlx::PgPool;
 use uuid::Uuid;
@@ -24,6 +25,11 @@ impl MaintenanceService {
             param_idx += 1;
         }
 
+        if filter.supplier_id.is_some() {
+            conditions.push(format!("m.supplier_id = ${param_idx}"));
+            param_idx += 1;
+        }
+
         if filter.status.is_some() {
             conditions.push(format!("m.status = ${param_idx}"));
             param_idx += 1;
@@ -51,7 +57,7 @@ impl MaintenanceService {
         let query_sql = format!(
             r#"
             SELECT m.id, m.asset_id, a.asset_code, a.description AS asset_description,
-                   m.type, m.description, m.supplier_name, m.cost,
+                   m.type, m.description, m.supplier_name, m.supplier_id, m.cost,
                    m.scheduled_date, m.execution_date, m.status, m.created_at
             FROM maintenances m
             LEFT JOIN assets a ON a.id = m.asset_id
@@ -72,6 +78,11 @@ impl MaintenanceService {
             sqlx::Arguments::add(&mut data_args, asset_id).unwrap();
         }
 
+        if let Some(supplier_id) = filter.supplier_id {
+            sqlx::Arguments::add(&mut count_args, supplier_id).unwrap();
+            sqlx::Arguments::add(&mut data_args, supplier_id).unwrap();
+        }
+
         if let Some(ref status) = filter.status {
             sqlx::Arguments::add(&mut count_args, status.as_str()).unwrap();
             sqlx::Arguments::add(&mut data_args, status.as_str()).unwrap();
@@ -128,6 +139,12 @@ impl MaintenanceService {
             ));
         }
 
+        // A registered supplier's name goes to supplier_name (RN-FIN-021)
+        let supplier_name = match req.supplier_id {
+            Some(sid) => Some(SupplierService::name_for_link(pool, church_id, sid).await?),
+            None => req.supplier_name.clone(),
+        };
+
         // Optionally update asset status to em_manutencao
         if req.execution_date.is_none() || req.execution_date == req.scheduled_date {
             sqlx::query(
@@ -143,9 +160,9 @@ impl MaintenanceService {
             r#"
             INSERT INTO maintenances (
                 church_id, asset_id, type, description, supplier_name, cost,
-                scheduled_date, execution_date, next_maintenance_date, notes
+                scheduled_date, execution_date, next_maintenance_date, notes, supplier_id
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
             RETURNING *
             "#,
         )
@@ -153,12 +170,13 @@ impl MaintenanceService {
         .bind(req.asset_id)
         .bind(&req.maintenance_type)
         .bind(&req.description)
-        .bind(&req.supplier_name)
+        .bind(&supplier_name)
         .bind(req.cost)
         .bind(req.scheduled_date)
         .bind(req.execution_date)
         .bind(req.next_maintenance_date)
         .bind(&req.notes)
+        .bind(req.supplier_id)
         .fetch_one(pool)
         .await?;
 
@@ -186,7 +204,15 @@ impl MaintenanceService {
             sqlx::Arguments::add(&mut args, v.as_str()).unwrap();
             pi += 1;
         }
-        if let Some(ref v) = req.supplier_name {
+        if let Some(sid) = req.supplier_id {
+            let name = SupplierService::name_for_link(pool, church_id, sid).await?;
+            set_clauses.push(format!("supplier_id = ${pi}"));
+            sqlx::Arguments::add(&mut args, sid).unwrap();
+            pi += 1;
+            set_clauses.push(format!("supplier_name = ${pi}"));
+            sqlx::Arguments::add(&mut args, name).unwrap();
+            pi += 1;
+        } else if let Some(ref v) = req.supplier_name {
             set_clauses.push(format!("supplier_name = ${pi}"));
             sqlx::Arguments::add(&mut args, v.as_str()).unwrap();
             pi += 1;
@@ -1,4 +1,5 @@
 pub mod account_plan_service;
+pub mod accounting_export_service;
 pub mod asset_category_service;
 pub mod asset_loan_service;
 pub mod asset_service;
@@ -17,18 +18,34 @@ pub mod ebd_student_service;
 pub mod ebd_report_service;
 pub mod ebd_term_service;
 pub mod bank_account_service;
+pub mod bank_statement_service;
+pub mod budget_service;
 pub mod campaign_service;
 pub mod church_role_service;
+pub mod donation_receipt_service;
+pub mod expense_approval_service;
 pub mod family_service;
+pub mod financial_report_service;
 pub mod financial_service;
+pub mod installment_service;
 pub mod inventory_service;
 pub mod maintenance_service;
 pub mod member_history_service;
 pub mod member_service;
 pub mod ministry_service;
+pub mod offering_count_service;
+pub mod payables_service;
+pub mod payment_webhook_service;
+pub mod pix_service;
+pub mod recurrence_service;
+pub mod remittance_service;
+pub mod supplier_service;
+pub mod tithe_service;
+pub mod transfer_service;
 pub mod user_service;
 
 pub use account_plan_service::AccountPlanService;
+pub use accounting_export_service::AccountingExportService;
 pub use asset_category_service::AssetCategoryService;
 pub use asset_loan_service::AssetLoanService;
 pub use asset_service::AssetService;
@@ -47,13 +64,28 @@ pub use ebd_student_service::EbdStudentService;
 pub use ebd_report_service::EbdReportService;
 pub use ebd_term_service::EbdTermService;
 pub use bank_account_service::BankAccountService;
+pub use bank_statement_service::BankStatementService;
+pub use budget_service::BudgetService;
 pub use campaign_service::CampaignService;
 pub use church_role_service::ChurchRoleService;
+pub use donation_receipt_service::DonationReceiptService;
+pub use expense_approval_service::ExpenseApprovalService;
 pub use family_service::FamilyService;
+pub use financial_report_service::FinancialReportService;
 pub use financial_service::{FinancialEntryService, MonthlyClosingService};
+pub use installment_service::InstallmentService;
 pub use inventory_service::InventoryService;
 pub use maintenance_service::MaintenanceService;
 pub use member_history_service::MemberHistoryService;
 pub use member_service::MemberService;
 pub use ministry_service::MinistryService;
+pub use offering_count_service::OfferingCountService;
+pub use payables_service::PayablesService;
+pub use payment_webhook_service::PaymentWebhookService;
+pub use pix_service::PixService;
+pub use recurrence_service::RecurrenceService;
+pub use remittance_service::RemittanceService;
+pub use supplier_service::SupplierService;
+pub use tithe_service::TitheService;
+pub use transfer_service::TransferService;
 pub use user_service::UserService;
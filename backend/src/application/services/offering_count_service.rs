@@ -0,0 +1,654 @@
+use crate::application::dto::{
+    CreateOfferingCountRequest, OfferingCountFilter, OfferingDenominationRequest,
+    OfferingEnvelopeRequest, UpdateOfferingCountRequest,
+};
+use crate::application::services::FinancialEntryService;
+use crate::application::services::financial_service::ENTRY_COLUMNS;
+use crate::domain::entities::{
+    FinancialEntry, OfferingCount, OfferingCountDenomination, OfferingCountDetail,
+    OfferingCountEnvelope, OfferingCountSummary,
+};
+use crate::errors::AppError;
+use rust_decimal::Decimal;
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+/// Brazilian bills and coins, in cents
+const DENOMINATION_CENTS: [i64; 13] =
+    [1, 5, 10, 25, 50, 100, 200, 500, 1000, 2000, 5000, 10000, 20000];
+
+const NOT_EDITABLE: &str = "A folha já foi confirmada pelos dois contadores e não pode ser alterada";
+
+pub struct OfferingCountService;
+
+impl OfferingCountService {
+    /// List counting sheets with their totals
+    pub async fn list(
+        pool: &PgPool,
+        church_id: Uuid,
+        filter: &OfferingCountFilter,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<OfferingCountSummary>, i64), AppError> {
+        let mut conditions = vec![
+            "oc.church_id = $1".to_string(),
+            "oc.deleted_at IS NULL".to_string(),
+        ];
+        let mut param_idx = 2u32;
+
+        if filter.congregation_id.is_some() {
+            conditions.push(format!("oc.congregation_id = ${param_idx}"));
+            param_idx += 1;
+        }
+        if filter.status.is_some() {
+            conditions.push(format!("oc.status = ${param_idx}"));
+            param_idx += 1;
+        }
+        if filter.date_from.is_some() {
+            conditions.push(format!("oc.service_date >= ${param_idx}"));
+            param_idx += 1;
+        }
+        if filter.date_to.is_some() {
+            conditions.push(format!("oc.service_date <= ${param_idx}"));
+            param_idx += 1;
+        }
+
+        let _ = param_idx;
+        let where_clause = conditions.join(" AND ");
+
+        let count_sql = format!("SELECT COUNT(*) FROM offering_counts oc WHERE {where_clause}");
+
+        let query_sql = format!(
+            r#"
+            SELECT oc.id, oc.service_date, oc.service_name, oc.status,
+                   cg.name AS congregation_name,
+                   ba.name AS bank_account_name,
+                   COALESCE((SELECT SUM(d.denomination * d.quantity) FROM offering_count_denominations d
+                             WHERE d.count_id = oc.id), 0) AS cash_total,
+                   (SELECT COUNT(*) FROM offering_count_envelopes e
+                    WHERE e.count_id = oc.id) AS envelope_count,
+                   COALESCE((SELECT SUM(e.amount) FROM offering_count_envelopes e
+                             WHERE e.count_id = oc.id), 0) AS envelope_total,
+                   u1.email AS first_counter_name,
+                   u2.email AS second_counter_name,
+                   oc.created_at
+            FROM offering_counts oc
+            LEFT JOIN congregations cg ON cg.id = oc.congregation_id
+            LEFT JOIN bank_accounts ba ON ba.id = oc.bank_account_id
+            LEFT JOIN users u1 ON u1.id = oc.first_counter_id
+            LEFT JOIN users u2 ON u2.id = oc.second_counter_id
+            WHERE {where_clause}
+            ORDER BY oc.service_date DESC, oc.created_at DESC
+            LIMIT {limit} OFFSET {offset}
+            "#
+        );
+
+        let mut count_args = sqlx::postgres::PgArguments::default();
+        let mut data_args = sqlx::postgres::PgArguments::default();
+
+        sqlx::Arguments::add(&mut count_args, church_id).unwrap();
+        sqlx::Arguments::add(&mut data_args, church_id).unwrap();
+
+        if let Some(congregation_id) = filter.congregation_id {
+            sqlx::Arguments::add(&mut count_args, congregation_id).unwrap();
+            sqlx::Arguments::add(&mut data_args, congregation_id).unwrap();
+        }
+        if let Some(ref status) = filter.status {
+            sqlx::Arguments::add(&mut count_args, status.as_str()).unwrap();
+            sqlx::Arguments::add(&mut data_args, status.as_str()).unwrap();
+        }
+        if let Some(date_from) = filter.date_from {
+            sqlx::Arguments::add(&mut count_args, date_from).unwrap();
+            sqlx::Arguments::add(&mut data_args, date_from).unwrap();
+        }
+        if let Some(date_to) = filter.date_to {
+            sqlx::Arguments::add(&mut count_args, date_to).unwrap();
+            sqlx::Arguments::add(&mut data_args, date_to).unwrap();
+        }
+
+        let total = sqlx::query_scalar_with::<_, i64, _>(&count_sql, count_args)
+            .fetch_one(pool)
+            .await?;
+
+        let counts = sqlx::query_as_with::<_, OfferingCountSummary, _>(&query_sql, data_args)
+            .fetch_all(pool)
+            .await?;
+
+        Ok((counts, total))
+    }
+
+    /// Get a counting sheet with its bills and coins, envelopes and generated entries
+    pub async fn get_by_id(
+        pool: &PgPool,
+        church_id: Uuid,
+        count_id: Uuid,
+    ) -> Result<OfferingCountDetail, AppError> {
+        let count = sqlx::query_as::<_, OfferingCount>(
+            "SELECT * FROM offering_counts WHERE id = $1 AND church_id = $2 AND deleted_at IS NULL",
+        )
+        .bind(count_id)
+        .bind(church_id)
+        .fetch_optional(pool)
+        .await?
+        .ok_or_else(|| AppError::not_found("Contagem de ofertas"))?;
+
+        let denominations = sqlx::query_as::<_, OfferingCountDenomination>(
+            r#"SELECT denomination, quantity, denomination * quantity AS subtotal
+               FROM offering_count_denominations WHERE count_id = $1
+               ORDER BY denomination DESC"#,
+        )
+        .bind(count_id)
+        .fetch_all(pool)
+        .await?;
+
+        let envelopes = sqlx::query_as::<_, OfferingCountEnvelope>(
+            r#"
+            SELECT e.id, e.member_id, m.full_name AS member_name, e.contributor_name,
+                   e.account_plan_id, ap.name AS account_plan_name,
+                   e.amount, e.payment_method
+            FROM offering_count_envelopes e
+            LEFT JOIN members m ON m.id = e.member_id
+            LEFT JOIN account_plans ap ON ap.id = e.account_plan_id
+            WHERE e.count_id = $1
+            ORDER BY e.sort_order
+            "#,
+        )
+        .bind(count_id)
+        .fetch_all(pool)
+        .await?;
+
+        let entries = sqlx::query_as::<_, FinancialEntry>(&format!(
+            r#"SELECT {ENTRY_COLUMNS} FROM financial_entries
+               WHERE offering_count_id = $1 AND deleted_at IS NULL
+               ORDER BY member_id NULLS LAST, created_at"#
+        ))
+        .bind(count_id)
+        .fetch_all(pool)
+        .await?;
+
+        let cash_total: Decimal = denominations.iter().map(|d| d.subtotal).sum();
+        let envelope_total: Decimal = envelopes.iter().map(|e| e.amount).sum();
+        let envelope_cash: Decimal = envelopes
+            .iter()
+            .filter(|e| e.payment_method == "dinheiro")
+            .map(|e| e.amount)
+            .sum();
+        let loose_offering = cash_total - envelope_cash;
+
+        Ok(OfferingCountDetail {
+            count,
+            denominations,
+            envelopes,
+            cash_total,
+            envelope_total,
+            loose_offering,
+            total: loose_offering + envelope_total,
+            entries,
+        })
+    }
+
+    /// Open a counting sheet for a service
+    pub async fn create(
+        pool: &PgPool,
+        church_id: Uuid,
+        user_id: Uuid,
+        req: &CreateOfferingCountRequest,
+    ) -> Result<OfferingCountDetail, AppError> {
+        let envelopes = req.envelopes.as_deref().unwrap_or_default();
+        check_lines(&req.denominations, envelopes)?;
+        Self::check_references(
+            pool,
+            church_id,
+            req.bank_account_id,
+            &[Some(req.offering_account_plan_id), req.tithe_account_plan_id],
+            envelopes,
+        )
+        .await?;
+
+        let mut tx = pool.begin().await?;
+
+        let count_id = sqlx::query_scalar::<_, Uuid>(
+            r#"
+            INSERT INTO offering_counts (
+                church_id, congregation_id, service_date, service_name, bank_account_id,
+                offering_account_plan_id, tithe_account_plan_id, notes, created_by
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            RETURNING id
+            "#,
+        )
+        .bind(church_id)
+        .bind(req.congregation_id)
+        .bind(req.service_date)
+        .bind(&req.service_name)
+        .bind(req.bank_account_id)
+        .bind(req.offering_account_plan_id)
+        .bind(req.tithe_account_plan_id)
+        .bind(&req.notes)
+        .bind(user_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        save_denominations(&mut tx, count_id, &req.denominations).await?;
+        save_envelopes(&mut tx, count_id, envelopes).await?;
+
+        tx.commit().await?;
+
+        Self::get_by_id(pool, church_id, count_id).await
+    }
+
+    /// Change a sheet still being counted. A confirmation already given no
+    /// longer holds for the changed sheet and is cleared.
+    pub async fn update(
+        pool: &PgPool,
+        church_id: Uuid,
+        count_id: Uuid,
+        req: &UpdateOfferingCountRequest,
+    ) -> Result<OfferingCountDetail, AppError> {
+        let existing = Self::get_by_id(pool, church_id, count_id).await?.count;
+        if existing.status != "em_contagem" {
+            return Err(AppError::validation(NOT_EDITABLE));
+        }
+
+        check_lines(
+            req.denominations.as_deref().unwrap_or_default(),
+            req.envelopes.as_deref().unwrap_or_default(),
+        )?;
+        Self::check_references(
+            pool,
+            church_id,
+            req.bank_account_id.unwrap_or(existing.bank_account_id),
+            &[req.offering_account_plan_id, req.tithe_account_plan_id],
+            req.envelopes.as_deref().unwrap_or_default(),
+        )
+        .await?;
+
+        let mut tx = pool.begin().await?;
+
+        let updated = sqlx::query(
+            r#"
+            UPDATE offering_counts SET
+                service_date = COALESCE($3, service_date),
+                service_name = COALESCE($4, service_name),
+                bank_account_id = COALESCE($5, bank_account_id),
+                offering_account_plan_id = COALESCE($6, offering_account_plan_id),
+                tithe_account_plan_id = COALESCE($7, tithe_account_plan_id),
+                notes = COALESCE($8, notes),
+                first_counter_id = NULL,
+                first_confirmed_at = NULL
+            WHERE id = $1 AND church_id = $2 AND status = 'em_contagem' AND deleted_at IS NULL
+            "#,
+        )
+        .bind(count_id)
+        .bind(church_id)
+        .bind(req.service_date)
+        .bind(&req.service_name)
+        .bind(req.bank_account_id)
+        .bind(req.offering_account_plan_id)
+        .bind(req.tithe_account_plan_id)
+        .bind(&req.notes)
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+
+        // Confirmed in the meantime
+        if updated == 0 {
+            return Err(AppError::validation(NOT_EDITABLE));
+        }
+
+        if let Some(denominations) = &req.denominations {
+            save_denominations(&mut tx, count_id, denominations).await?;
+        }
+        if let Some(envelopes) = &req.envelopes {
+            save_envelopes(&mut tx, count_id, envelopes).await?;
+        }
+
+        tx.commit().await?;
+
+        Self::get_by_id(pool, church_id, count_id).await
+    }
+
+    /// Discard a sheet still being counted
+    pub async fn delete(pool: &PgPool, church_id: Uuid, count_id: Uuid) -> Result<(), AppError> {
+        let existing = Self::get_by_id(pool, church_id, count_id).await?.count;
+        if existing.status != "em_contagem" {
+            return Err(AppError::validation(
+                "Folha confirmada não pode ser excluída: estorne os lançamentos gerados",
+            ));
+        }
+
+        sqlx::query("UPDATE offering_counts SET deleted_at = NOW() WHERE id = $1 AND church_id = $2")
+            .bind(count_id)
+            .bind(church_id)
+            .execute(pool)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Confirm the sheet as one of its counters (RN-FIN-025). The first
+    /// confirmation is recorded; the second, by a different user, closes the
+    /// sheet and generates its entries in the same transaction.
+    pub async fn confirm(
+        pool: &PgPool,
+        church_id: Uuid,
+        count_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<OfferingCountDetail, AppError> {
+        let detail = Self::get_by_id(pool, church_id, count_id).await?;
+        if detail.count.status != "em_contagem" {
+            return Err(AppError::validation("A folha já foi confirmada pelos dois contadores"));
+        }
+        if detail.total <= Decimal::ZERO {
+            return Err(AppError::validation("A folha não tem valores contados"));
+        }
+        if detail.loose_offering < Decimal::ZERO {
+            return Err(AppError::validation(format!(
+                "Os envelopes em dinheiro somam mais que as cédulas e moedas contadas ({:.2})",
+                detail.cash_total
+            )));
+        }
+        if detail.count.tithe_account_plan_id.is_none()
+            && detail.envelopes.iter().any(|e| e.account_plan_id.is_none())
+        {
+            return Err(AppError::validation(
+                "Informe a categoria de dízimo da folha ou a categoria de cada envelope",
+            ));
+        }
+
+        let mut tx = pool.begin().await?;
+
+        // Lock the sheet: concurrent confirmations (or edits) wait for this one
+        let count = sqlx::query_as::<_, OfferingCount>(
+            "SELECT * FROM offering_counts WHERE id = $1 AND church_id = $2 AND deleted_at IS NULL FOR UPDATE",
+        )
+        .bind(count_id)
+        .bind(church_id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(|| AppError::not_found("Contagem de ofertas"))?;
+
+        if count.status != "em_contagem" || count.updated_at != detail.count.updated_at {
+            return Err(AppError::conflict(
+                "A folha foi alterada durante a confirmação; confira-a novamente",
+            ));
+        }
+
+        match count.first_counter_id {
+            None => {
+                sqlx::query(
+                    "UPDATE offering_counts SET first_counter_id = $2, first_confirmed_at = NOW() WHERE id = $1",
+                )
+                .bind(count_id)
+                .bind(user_id)
+                .execute(&mut *tx)
+                .await?;
+            }
+            Some(first) if first == user_id => {
+                return Err(AppError::validation(
+                    "A segunda confirmação deve ser feita por outro contador",
+                ));
+            }
+            Some(_) => {
+                Self::ensure_open_month(&mut tx, &count).await?;
+
+                sqlx::query(
+                    r#"UPDATE offering_counts
+                       SET second_counter_id = $2, second_confirmed_at = NOW(), status = 'confirmada'
+                       WHERE id = $1"#,
+                )
+                .bind(count_id)
+                .bind(user_id)
+                .execute(&mut *tx)
+                .await?;
+
+                Self::generate_entries(&mut tx, &count, user_id, detail.loose_offering).await?;
+                FinancialEntryService::sync_bank_balances(&mut tx, &[count.bank_account_id])
+                    .await?;
+            }
+        }
+
+        tx.commit().await?;
+
+        Self::get_by_id(pool, church_id, count_id).await
+    }
+
+    /// One confirmed income per envelope, with the member, plus one for the
+    /// loose offering, all dated on the service
+    async fn generate_entries(
+        conn: &mut PgConnection,
+        count: &OfferingCount,
+        user_id: Uuid,
+        loose_offering: Decimal,
+    ) -> Result<(), AppError> {
+        let service = format!(
+            "{} {}",
+            count.service_name.as_deref().unwrap_or("Culto"),
+            count.service_date.format("%d/%m/%Y")
+        );
+
+        sqlx::query(
+            r#"
+            INSERT INTO financial_entries (
+                church_id, type, account_plan_id, bank_account_id, amount,
+                entry_date, payment_date, description, payment_method, member_id,
+                status, registered_by, congregation_id, offering_count_id
+            )
+            SELECT oc.church_id, 'receita', ap.id, oc.bank_account_id, e.amount,
+                   oc.service_date, oc.service_date,
+                   ap.name || ' — ' || COALESCE(m.full_name, e.contributor_name) || ' — ' || $2,
+                   e.payment_method, e.member_id,
+                   'confirmado', $3, oc.congregation_id, oc.id
+            FROM offering_count_envelopes e
+            JOIN offering_counts oc ON oc.id = e.count_id
+            JOIN account_plans ap ON ap.id = COALESCE(e.account_plan_id, oc.tithe_account_plan_id)
+            LEFT JOIN members m ON m.id = e.member_id
+            WHERE e.count_id = $1
+            ORDER BY e.sort_order
+            "#,
+        )
+        .bind(count.id)
+        .bind(&service)
+        .bind(user_id)
+        .execute(&mut *conn)
+        .await?;
+
+        if loose_offering > Decimal::ZERO {
+            sqlx::query(
+                r#"
+                INSERT INTO financial_entries (
+                    church_id, type, account_plan_id, bank_account_id, amount,
+                    entry_date, payment_date, description, payment_method,
+                    status, registered_by, congregation_id, offering_count_id
+                )
+                VALUES ($1, 'receita', $2, $3, $4, $5, $5, $6, 'dinheiro', 'confirmado', $7, $8, $9)
+                "#,
+            )
+            .bind(count.church_id)
+            .bind(count.offering_account_plan_id)
+            .bind(count.bank_account_id)
+            .bind(loose_offering)
+            .bind(count.service_date)
+            .bind(format!("Ofertas — {service}"))
+            .bind(user_id)
+            .bind(count.congregation_id)
+            .bind(count.id)
+            .execute(&mut *conn)
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    /// The generated entries cannot fall in a month already closed
+    async fn ensure_open_month(conn: &mut PgConnection, count: &OfferingCount) -> Result<(), AppError> {
+        let closed = sqlx::query_scalar::<_, bool>(
+            r#"SELECT EXISTS(SELECT 1 FROM monthly_closings
+                      WHERE church_id = $1 AND reference_month = DATE_TRUNC('month', $2::date)::date
+                      AND (congregation_id IS NULL OR congregation_id = $3))"#,
+        )
+        .bind(count.church_id)
+        .bind(count.service_date)
+        .bind(count.congregation_id)
+        .fetch_one(&mut *conn)
+        .await?;
+
+        if closed {
+            return Err(AppError::validation(
+                "O mês do culto já foi fechado",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Bank account, categories (income only) and envelope members must belong to the church
+    async fn check_references(
+        pool: &PgPool,
+        church_id: Uuid,
+        bank_account_id: Uuid,
+        sheet_plans: &[Option<Uuid>],
+        envelopes: &[OfferingEnvelopeRequest],
+    ) -> Result<(), AppError> {
+        sqlx::query_scalar::<_, Uuid>(
+            "SELECT id FROM bank_accounts WHERE id = $1 AND church_id = $2 AND is_active = TRUE",
+        )
+        .bind(bank_account_id)
+        .bind(church_id)
+        .fetch_optional(pool)
+        .await?
+        .ok_or_else(|| AppError::validation("Conta bancária não encontrada ou inativa"))?;
+
+        let mut plans: Vec<Uuid> = sheet_plans
+            .iter()
+            .flatten()
+            .copied()
+            .chain(envelopes.iter().filter_map(|e| e.account_plan_id))
+            .collect();
+        plans.sort();
+        plans.dedup();
+        let active = sqlx::query_scalar::<_, i64>(
+            r#"SELECT COUNT(*) FROM account_plans
+               WHERE id = ANY($1) AND church_id = $2 AND is_active = TRUE AND type = 'receita'"#,
+        )
+        .bind(&plans)
+        .bind(church_id)
+        .fetch_one(pool)
+        .await?;
+        if active != plans.len() as i64 {
+            return Err(AppError::validation(
+                "Plano de contas de receita não encontrado ou inativo",
+            ));
+        }
+
+        let mut members: Vec<Uuid> = envelopes.iter().filter_map(|e| e.member_id).collect();
+        members.sort();
+        members.dedup();
+        let found = sqlx::query_scalar::<_, i64>(
+            "SELECT COUNT(*) FROM members WHERE id = ANY($1) AND church_id = $2 AND deleted_at IS NULL",
+        )
+        .bind(&members)
+        .bind(church_id)
+        .fetch_one(pool)
+        .await?;
+        if found != members.len() as i64 {
+            return Err(AppError::validation("Membro do envelope não encontrado"));
+        }
+
+        Ok(())
+    }
+}
+
+/// Known bills and coins, each value once; envelopes with an amount and a contributor
+fn check_lines(
+    denominations: &[OfferingDenominationRequest],
+    envelopes: &[OfferingEnvelopeRequest],
+) -> Result<(), AppError> {
+    for (i, d) in denominations.iter().enumerate() {
+        if !DENOMINATION_CENTS.iter().any(|&cents| Decimal::new(cents, 2) == d.denomination) {
+            return Err(AppError::validation(format!(
+                "Cédula ou moeda inválida: {}",
+                d.denomination
+            )));
+        }
+        if denominations[..i].iter().any(|p| p.denomination == d.denomination) {
+            return Err(AppError::validation(format!(
+                "Cédula ou moeda repetida: {}",
+                d.denomination
+            )));
+        }
+    }
+
+    for e in envelopes {
+        if e.amount <= Decimal::ZERO {
+            return Err(AppError::validation("Valor de cada envelope deve ser maior que zero"));
+        }
+        if e.member_id.is_none() && e.contributor_name.as_deref().is_none_or(|n| n.trim().is_empty()) {
+            return Err(AppError::validation(
+                "Informe o membro ou o nome de quem entregou o envelope",
+            ));
+        }
+        if !matches!(e.payment_method.as_deref(), None | Some("dinheiro") | Some("cheque")) {
+            return Err(AppError::validation(
+                "Forma de pagamento do envelope deve ser 'dinheiro' ou 'cheque'",
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+async fn save_denominations(
+    conn: &mut PgConnection,
+    count_id: Uuid,
+    denominations: &[OfferingDenominationRequest],
+) -> Result<(), AppError> {
+    sqlx::query("DELETE FROM offering_count_denominations WHERE count_id = $1")
+        .bind(count_id)
+        .execute(&mut *conn)
+        .await?;
+
+    for d in denominations {
+        sqlx::query(
+            "INSERT INTO offering_count_denominations (count_id, denomination, quantity) VALUES ($1, $2, $3)",
+        )
+        .bind(count_id)
+        .bind(d.denomination)
+        .bind(d.quantity)
+        .execute(&mut *conn)
+        .await?;
+    }
+
+    Ok(())
+}
+
+async fn save_envelopes(
+    conn: &mut PgConnection,
+    count_id: Uuid,
+    envelopes: &[OfferingEnvelopeRequest],
+) -> Result<(), AppError> {
+    sqlx::query("DELETE FROM offering_count_envelopes WHERE count_id = $1")
+        .bind(count_id)
+        .execute(&mut *conn)
+        .await?;
+
+    for (sort_order, e) in (0i16..).zip(envelopes) {
+        sqlx::query(
+            r#"INSERT INTO offering_count_envelopes (
+                   count_id, member_id, contributor_name, account_plan_id,
+                   amount, payment_method, sort_order
+               )
+               VALUES ($1, $2, $3, $4, $5, $6, $7)"#,
+        )
+        .bind(count_id)
+        .bind(e.member_id)
+        .bind(&e.contributor_name)
+        .bind(e.account_plan_id)
+        .bind(e.amount)
+        .bind(e.payment_method.as_deref().unwrap_or("dinheiro"))
+        .bind(sort_order)
+        .execute(&mut *conn)
+        .await?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,191 @@
+use crate::config::AppConfig;
+use crate::domain::entities::{AccountsPayable, PayableEntry, PayablesBucket};
+use crate::errors::AppError;
+use crate::infrastructure::{email, pdf};
+use chrono::{Local, NaiveDate, Timelike};
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Aging buckets, in days from today
+const DUE_SOON_DAYS: i32 = 7;
+const DUE_LATER_DAYS: i32 = 30;
+
+/// Local hour from which the daily digest is sent
+const DIGEST_HOUR: u32 = 7;
+
+pub struct PayablesService;
+
+impl PayablesService {
+    /// Pending expenses overdue or due within 30 days, grouped by aging bucket
+    pub async fn aging(
+        pool: &PgPool,
+        church_id: Uuid,
+        congregation_id: Option<Uuid>,
+        as_of: NaiveDate,
+    ) -> Result<AccountsPayable, AppError> {
+        let entries = sqlx::query_as::<_, PayableEntry>(
+            r#"
+            SELECT fe.id, fe.description, fe.amount, fe.due_date,
+                   (fe.due_date - $3::date) AS days_to_due,
+                   ap.name AS account_plan_name, ba.name AS bank_account_name,
+                   fe.supplier_name, cg.name AS congregation_name
+            FROM financial_entries fe
+            LEFT JOIN account_plans ap ON ap.id = fe.account_plan_id
+            LEFT JOIN bank_accounts ba ON ba.id = fe.bank_account_id
+            LEFT JOIN congregations cg ON cg.id = fe.congregation_id
+            WHERE fe.church_id = $1 AND fe.type = 'despesa' AND fe.status = 'pendente'
+              AND fe.deleted_at IS NULL AND fe.due_date IS NOT NULL
+              AND fe.due_date <= $3::date + $4::int
+              AND ($2::uuid IS NULL OR fe.congregation_id = $2)
+            ORDER BY fe.due_date ASC, fe.amount DESC
+            "#,
+        )
+        .bind(church_id)
+        .bind(congregation_id)
+        .bind(as_of)
+        .bind(DUE_LATER_DAYS)
+        .fetch_all(pool)
+        .await?;
+
+        let mut report = AccountsPayable {
+            as_of,
+            congregation_id,
+            overdue: PayablesBucket::default(),
+            due_7_days: PayablesBucket::default(),
+            due_30_days: PayablesBucket::default(),
+            total: Decimal::ZERO,
+        };
+
+        for entry in entries {
+            let bucket = match entry.days_to_due {
+                d if d < 0 => &mut report.overdue,
+                d if d <= DUE_SOON_DAYS => &mut report.due_7_days,
+                _ => &mut report.due_30_days,
+            };
+            bucket.count += 1;
+            bucket.total += entry.amount;
+            report.total += entry.amount;
+            bucket.entries.push(entry);
+        }
+
+        Ok(report)
+    }
+
+    /// Email treasurers (roles with `financial:*`) the overdue and due-in-7-days
+    /// expenses, once a day per church. Returns the number of churches notified.
+    pub async fn send_daily_digests(pool: &PgPool, config: &AppConfig) -> Result<usize, AppError> {
+        if config.smtp_host.is_empty() {
+            return Ok(0);
+        }
+
+        let now = Local::now();
+        if now.hour() < DIGEST_HOUR {
+            return Ok(0);
+        }
+        let today = now.date_naive();
+
+        let churches = sqlx::query_as::<_, (Uuid, String)>(
+            r#"
+            SELECT c.id, c.name FROM churches c
+            WHERE c.is_active = TRUE
+              AND EXISTS (SELECT 1 FROM financial_entries fe
+                          WHERE fe.church_id = c.id AND fe.type = 'despesa'
+                          AND fe.status = 'pendente' AND fe.deleted_at IS NULL
+                          AND fe.due_date <= $1::date + $2::int)
+              AND NOT EXISTS (SELECT 1 FROM payables_digests d
+                              WHERE d.church_id = c.id AND d.digest_date = $1)
+            "#,
+        )
+        .bind(today)
+        .bind(DUE_SOON_DAYS)
+        .fetch_all(pool)
+        .await?;
+
+        let mut notified = 0;
+        for (church_id, church_name) in churches {
+            let recipients = sqlx::query_scalar::<_, String>(
+                r#"SELECT u.email FROM users u
+                   JOIN roles r ON r.id = u.role_id
+                   WHERE u.church_id = $1 AND u.is_active = TRUE
+                   AND r.permissions ? 'financial:*'"#,
+            )
+            .bind(church_id)
+            .fetch_all(pool)
+            .await?;
+            if recipients.is_empty() {
+                continue;
+            }
+
+            // Claim the day first so a concurrent or repeated run never sends twice
+            let claimed = sqlx::query(
+                r#"INSERT INTO payables_digests (church_id, digest_date, recipients)
+                   VALUES ($1, $2, $3) ON CONFLICT DO NOTHING"#,
+            )
+            .bind(church_id)
+            .bind(today)
+            .bind(recipients.len() as i32)
+            .execute(pool)
+            .await?;
+            if claimed.rows_affected() == 0 {
+                continue;
+            }
+
+            let report = Self::aging(pool, church_id, None, today).await?;
+            let html = Self::digest_html(&church_name, &report);
+            let subject = format!("{church_name} — Contas a pagar de {}", pdf::date_br(today));
+
+            for to in &recipients {
+                if let Err(e) = email::send_html(config, to, &subject, html.clone()).await {
+                    tracing::warn!("Payables digest to {to} failed: {e}");
+                }
+            }
+            notified += 1;
+        }
+
+        Ok(notified)
+    }
+
+    fn digest_html(church_name: &str, report: &AccountsPayable) -> String {
+        let section = |title: &str, bucket: &PayablesBucket| {
+            if bucket.entries.is_empty() {
+                return String::new();
+            }
+            let rows: String = bucket
+                .entries
+                .iter()
+                .map(|e| {
+                    format!(
+                        "<tr><td>{}</td><td>{}</td><td>{}</td><td style=\"text-align:right\">{}</td></tr>",
+                        pdf::date_br(e.due_date),
+                        html_escape(&e.description),
+                        html_escape(e.supplier_name.as_deref().unwrap_or("")),
+                        pdf::brl(e.amount)
+                    )
+                })
+                .collect();
+            format!(
+                r#"<h3>{title} — {} ({} lançamentos)</h3>
+<table cellpadding="4" style="border-collapse:collapse">
+<tr><th>Vencimento</th><th>Descrição</th><th>Fornecedor</th><th>Valor</th></tr>
+{rows}</table>"#,
+                pdf::brl(bucket.total),
+                bucket.count
+            )
+        };
+
+        format!(
+            r#"<h2>Contas a pagar — {}</h2>
+<p>Resumo diário das despesas pendentes de <strong>{}</strong>.</p>
+{}{}"#,
+            pdf::date_br(report.as_of),
+            html_escape(church_name),
+            section("Vencidas", &report.overdue),
+            section("Vencem em até 7 dias", &report.due_7_days),
+        )
+    }
+}
+
+fn html_escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
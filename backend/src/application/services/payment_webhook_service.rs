@@ -0,0 +1,655 @@
+use crate::application::dto::{
+    CreateFinancialEntryRequest, PaymentNotificationFilter, ResolvePaymentNotificationRequest,
+    UpdatePaymentIntegrationRequest,
+};
+use crate::application::services::{FinancialEntryService, PixService};
+use crate::domain::entities::{
+    FinancialEntry, PaymentIntegration, PaymentNotification, PixCharge, WebhookIngestResult,
+};
+use crate::errors::AppError;
+use crate::infrastructure::documents;
+use crate::infrastructure::payment_provider::{self, PaymentProvider, ProviderPayment};
+use chrono::{DateTime, FixedOffset, NaiveDate, Utc};
+use sqlx::{Connection, PgConnection, PgPool};
+use uuid::Uuid;
+
+/// Brasília time (no daylight saving since 2019): payments are dated in local time
+const BRT_OFFSET_SECS: i32 = -3 * 3600;
+
+pub struct PaymentWebhookService;
+
+impl PaymentWebhookService {
+    /// Payment providers configured for the church
+    pub async fn list_integrations(
+        pool: &PgPool,
+        church_id: Uuid,
+    ) -> Result<Vec<PaymentIntegration>, AppError> {
+        let integrations = sqlx::query_as::<_, PaymentIntegration>(
+            "SELECT * FROM payment_integrations WHERE church_id = $1 ORDER BY provider",
+        )
+        .bind(church_id)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(integrations)
+    }
+
+    /// Create or update the integration of a provider. The user saving it becomes
+    /// the author of the entries the webhook creates.
+    pub async fn save_integration(
+        pool: &PgPool,
+        church_id: Uuid,
+        user_id: Uuid,
+        provider: &str,
+        allow_mock: bool,
+        req: &UpdatePaymentIntegrationRequest,
+    ) -> Result<PaymentIntegration, AppError> {
+        if payment_provider::provider(provider, allow_mock).is_none() {
+            return Err(AppError::validation(format!(
+                "Provedor de pagamento desconhecido: {provider}"
+            )));
+        }
+
+        let integration = sqlx::query_as::<_, PaymentIntegration>(
+            r#"
+            UPDATE payment_integrations
+            SET webhook_secret = COALESCE($3, webhook_secret),
+                is_active = COALESCE($4, is_active),
+                registered_by = $5
+            WHERE church_id = $1 AND provider = $2
+            RETURNING *
+            "#,
+        )
+        .bind(church_id)
+        .bind(provider)
+        .bind(&req.webhook_secret)
+        .bind(req.is_active)
+        .bind(user_id)
+        .fetch_optional(pool)
+        .await?;
+
+        if let Some(integration) = integration {
+            return Ok(integration);
+        }
+
+        let secret = req
+            .webhook_secret
+            .as_deref()
+            .ok_or_else(|| AppError::validation("Informe o segredo do webhook"))?;
+
+        let integration = sqlx::query_as::<_, PaymentIntegration>(
+            r#"
+            INSERT INTO payment_integrations (church_id, provider, webhook_secret, registered_by, is_active)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING *
+            "#,
+        )
+        .bind(church_id)
+        .bind(provider)
+        .bind(secret)
+        .bind(user_id)
+        .bind(req.is_active.unwrap_or(true))
+        .fetch_one(pool)
+        .await?;
+
+        Ok(integration)
+    }
+
+    /// Process a webhook delivery (RN-FIN-018): authenticate it, drop payments already
+    /// received, post the ones whose txid matches one of our PIX codes and queue the
+    /// rest for review.
+    pub async fn ingest(
+        pool: &PgPool,
+        church_id: Uuid,
+        provider: &dyn PaymentProvider,
+        signature: Option<&str>,
+        body: &[u8],
+    ) -> Result<WebhookIngestResult, AppError> {
+        let integration = sqlx::query_as::<_, PaymentIntegration>(
+            r#"SELECT * FROM payment_integrations
+               WHERE church_id = $1 AND provider = $2 AND is_active = TRUE"#,
+        )
+        .bind(church_id)
+        .bind(provider.name())
+        .fetch_optional(pool)
+        .await?
+        .ok_or_else(|| AppError::not_found("Integração de pagamento"))?;
+
+        let authentic = signature
+            .is_some_and(|s| provider.verify_signature(&integration.webhook_secret, body, s));
+        if !authentic {
+            return Err(AppError::Unauthorized("Assinatura do webhook inválida".into()));
+        }
+
+        let payments = provider.parse(body)?;
+        let mut result = WebhookIngestResult {
+            received: payments.len(),
+            created: 0,
+            queued: 0,
+            duplicates: 0,
+        };
+
+        // One transaction per payment: the notification is never left without the
+        // entry it produced, and a redelivery waits for it instead of posting twice
+        for payment in payments {
+            let mut tx = pool.begin().await?;
+
+            let Some(notification_id) =
+                Self::record(&mut tx, church_id, provider.name(), &payment).await?
+            else {
+                result.duplicates += 1;
+                continue;
+            };
+
+            match Self::auto_post(pool, &mut tx, church_id, &integration, &payment).await? {
+                Ok((entry, charge_id)) => {
+                    sqlx::query(
+                        r#"UPDATE payment_notifications
+                           SET status = 'lancado', entry_id = $2, pix_charge_id = $3, resolved_at = NOW()
+                           WHERE id = $1"#,
+                    )
+                    .bind(notification_id)
+                    .bind(entry.id)
+                    .bind(charge_id)
+                    .execute(&mut *tx)
+                    .await?;
+                    result.created += 1;
+                }
+                Err((reason, charge_id)) => {
+                    sqlx::query(
+                        r#"UPDATE payment_notifications SET review_reason = $2, pix_charge_id = $3
+                           WHERE id = $1"#,
+                    )
+                    .bind(notification_id)
+                    .bind(reason)
+                    .bind(charge_id)
+                    .execute(&mut *tx)
+                    .await?;
+                    result.queued += 1;
+                }
+            }
+
+            tx.commit().await?;
+        }
+
+        Ok(result)
+    }
+
+    /// List received payments (the review queue is `status = pendente_revisao`)
+    pub async fn list(
+        pool: &PgPool,
+        church_id: Uuid,
+        filter: &PaymentNotificationFilter,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<PaymentNotification>, i64), AppError> {
+        let where_clause = r#"
+            church_id = $1
+            AND ($2::varchar IS NULL OR status = $2)
+            AND ($3::varchar IS NULL OR provider = $3)
+        "#;
+
+        let total = sqlx::query_scalar::<_, i64>(&format!(
+            "SELECT COUNT(*) FROM payment_notifications WHERE {where_clause}"
+        ))
+        .bind(church_id)
+        .bind(&filter.status)
+        .bind(&filter.provider)
+        .fetch_one(pool)
+        .await?;
+
+        let notifications = sqlx::query_as::<_, PaymentNotification>(&format!(
+            r#"SELECT * FROM payment_notifications WHERE {where_clause}
+               ORDER BY paid_at DESC LIMIT {limit} OFFSET {offset}"#
+        ))
+        .bind(church_id)
+        .bind(&filter.status)
+        .bind(&filter.provider)
+        .fetch_all(pool)
+        .await?;
+
+        Ok((notifications, total))
+    }
+
+    /// Get a received payment by ID
+    pub async fn get_by_id(
+        pool: &PgPool,
+        church_id: Uuid,
+        notification_id: Uuid,
+    ) -> Result<PaymentNotification, AppError> {
+        sqlx::query_as::<_, PaymentNotification>(
+            "SELECT * FROM payment_notifications WHERE id = $1 AND church_id = $2",
+        )
+        .bind(notification_id)
+        .bind(church_id)
+        .fetch_optional(pool)
+        .await?
+        .ok_or_else(|| AppError::not_found("Pagamento recebido"))
+    }
+
+    /// Post a queued payment with the category and account chosen by the treasurer
+    pub async fn resolve(
+        pool: &PgPool,
+        church_id: Uuid,
+        user_id: Uuid,
+        notification_id: Uuid,
+        req: &ResolvePaymentNotificationRequest,
+    ) -> Result<PaymentNotification, AppError> {
+        let mut tx = pool.begin().await?;
+
+        // The lock makes a concurrent resolution wait and then find it resolved
+        let notification = sqlx::query_as::<_, PaymentNotification>(
+            "SELECT * FROM payment_notifications WHERE id = $1 AND church_id = $2 FOR UPDATE",
+        )
+        .bind(notification_id)
+        .bind(church_id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(|| AppError::not_found("Pagamento recebido"))?;
+
+        if notification.status != "pendente_revisao" {
+            return Err(AppError::conflict("Pagamento já foi resolvido"));
+        }
+
+        let entry_req = CreateFinancialEntryRequest {
+            entry_type: "receita".to_string(),
+            account_plan_id: Some(req.account_plan_id),
+            bank_account_id: req.bank_account_id,
+            campaign_id: req.campaign_id,
+            amount: notification.amount,
+            allocations: None,
+            entry_date: local_date(notification.paid_at),
+            due_date: None,
+            payment_date: Some(local_date(notification.paid_at)),
+            description: req.description.clone().unwrap_or_else(|| {
+                entry_description(&notification.method, None, notification.payer_name.as_deref())
+            }),
+            payment_method: Some(entry_method(&notification.method).to_string()),
+            member_id: req.member_id,
+            supplier_id: None,
+            supplier_name: None,
+            receipt_url: None,
+            status: Some("confirmado".to_string()),
+            notes: Some(provider_note(&notification.provider, &notification.provider_transaction_id)),
+            congregation_id: req.congregation_id,
+        };
+
+        let entry =
+            FinancialEntryService::create_in(pool, &mut tx, church_id, user_id, &entry_req).await?;
+
+        let resolved = sqlx::query_as::<_, PaymentNotification>(
+            r#"UPDATE payment_notifications
+               SET status = 'lancado', entry_id = $3, resolved_by = $4, resolved_at = NOW()
+               WHERE id = $1 AND church_id = $2
+               RETURNING *"#,
+        )
+        .bind(notification_id)
+        .bind(church_id)
+        .bind(entry.id)
+        .bind(user_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(resolved)
+    }
+
+    /// Discard a queued payment (e.g. a test payment or one already posted by hand)
+    pub async fn discard(
+        pool: &PgPool,
+        church_id: Uuid,
+        user_id: Uuid,
+        notification_id: Uuid,
+        reason: &str,
+    ) -> Result<PaymentNotification, AppError> {
+        Self::get_by_id(pool, church_id, notification_id).await?;
+
+        sqlx::query_as::<_, PaymentNotification>(
+            r#"UPDATE payment_notifications
+               SET status = 'descartado', review_reason = $3, resolved_by = $4, resolved_at = NOW()
+               WHERE id = $1 AND church_id = $2 AND status = 'pendente_revisao'
+               RETURNING *"#,
+        )
+        .bind(notification_id)
+        .bind(church_id)
+        .bind(reason)
+        .bind(user_id)
+        .fetch_optional(pool)
+        .await?
+        .ok_or_else(|| AppError::conflict("Pagamento já foi resolvido"))
+    }
+
+    /// Store the payment in the review queue; None when the provider already sent it
+    async fn record(
+        conn: &mut PgConnection,
+        church_id: Uuid,
+        provider: &str,
+        payment: &ProviderPayment,
+    ) -> Result<Option<Uuid>, AppError> {
+        let id = sqlx::query_scalar::<_, Uuid>(
+            r#"
+            INSERT INTO payment_notifications (
+                church_id, provider, provider_transaction_id, method, amount, paid_at,
+                txid, payer_name, payer_document, raw_payload
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            ON CONFLICT (church_id, provider, provider_transaction_id) DO NOTHING
+            RETURNING id
+            "#,
+        )
+        .bind(church_id)
+        .bind(provider)
+        .bind(&payment.transaction_id)
+        .bind(payment.method)
+        .bind(payment.amount)
+        .bind(payment.paid_at)
+        .bind(&payment.txid)
+        .bind(&payment.payer_name)
+        .bind(payment.payer_document.as_deref().map(documents::only_digits))
+        .bind(&payment.raw)
+        .fetch_optional(&mut *conn)
+        .await?;
+
+        Ok(id)
+    }
+
+    /// Post the payment when its txid identifies one of our PIX codes.
+    /// Err carries the reason it must be reviewed by hand.
+    async fn auto_post(
+        pool: &PgPool,
+        conn: &mut PgConnection,
+        church_id: Uuid,
+        integration: &PaymentIntegration,
+        payment: &ProviderPayment,
+    ) -> Result<Result<(FinancialEntry, Uuid), (String, Option<Uuid>)>, AppError> {
+        let charge: Option<PixCharge> = match payment.txid.as_deref() {
+            Some(txid) if !txid.is_empty() && txid != "***" => {
+                PixService::find_by_txid(pool, church_id, txid).await?
+            }
+            _ => None,
+        };
+
+        let Some(charge) = charge else {
+            return Ok(Err((
+                "Pagamento sem QR Code PIX correspondente (txid)".to_string(),
+                None,
+            )));
+        };
+
+        let Some(account_plan_id) = charge.account_plan_id else {
+            return Ok(Err((
+                "QR Code PIX sem categoria de receita".to_string(),
+                Some(charge.id),
+            )));
+        };
+
+        if charge.amount.is_some_and(|amount| amount != payment.amount) {
+            return Ok(Err((
+                "Valor pago diferente do valor do QR Code".to_string(),
+                Some(charge.id),
+            )));
+        }
+
+        // Payer's CPF links tithes and offerings to the member
+        let member_id = match payment.payer_document.as_deref().map(documents::only_digits) {
+            Some(document) if document.len() == 11 => {
+                sqlx::query_scalar::<_, Uuid>(
+                    r#"SELECT id FROM members
+                       WHERE church_id = $1 AND deleted_at IS NULL
+                         AND regexp_replace(cpf, '\D', '', 'g') = $2
+                       LIMIT 1"#,
+                )
+                .bind(church_id)
+                .bind(document)
+                .fetch_optional(pool)
+                .await?
+            }
+            _ => None,
+        };
+
+        let paid_on = local_date(payment.paid_at);
+
+        let entry_req = CreateFinancialEntryRequest {
+            entry_type: "receita".to_string(),
+            account_plan_id: Some(account_plan_id),
+            bank_account_id: charge.bank_account_id,
+            campaign_id: charge.campaign_id,
+            amount: payment.amount,
+            allocations: None,
+            entry_date: paid_on,
+            due_date: None,
+            payment_date: Some(paid_on),
+            description: entry_description(
+                payment.method,
+                charge.description.as_deref(),
+                payment.payer_name.as_deref(),
+            ),
+            payment_method: Some(entry_method(payment.method).to_string()),
+            member_id,
+            supplier_id: None,
+            supplier_name: None,
+            receipt_url: None,
+            status: Some("confirmado".to_string()),
+            notes: Some(provider_note(&integration.provider, &payment.transaction_id)),
+            congregation_id: charge.congregation_id,
+        };
+
+        // Business-rule failures (e.g. campaign closed meanwhile) go to review;
+        // the savepoint discards whatever the failed attempt wrote
+        let mut savepoint = conn.begin().await?;
+        match FinancialEntryService::create_in(
+            pool,
+            &mut savepoint,
+            church_id,
+            integration.registered_by,
+            &entry_req,
+        )
+        .await
+        {
+            Ok(entry) => {
+                savepoint.commit().await?;
+                Ok(Ok((entry, charge.id)))
+            }
+            Err(AppError::Validation { message, .. }) => Ok(Err((message, Some(charge.id)))),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+fn local_date(at: DateTime<Utc>) -> NaiveDate {
+    at.with_timezone(&FixedOffset::east_opt(BRT_OFFSET_SECS).expect("valid offset"))
+        .date_naive()
+}
+
+fn entry_method(method: &str) -> &'static str {
+    if method == "cartao" { "cartao_credito" } else { "pix" }
+}
+
+fn entry_description(method: &str, purpose: Option<&str>, payer: Option<&str>) -> String {
+    let mut description = if method == "cartao" {
+        "Pagamento com cartão".to_string()
+    } else {
+        "PIX recebido".to_string()
+    };
+    for part in [purpose, payer].into_iter().flatten() {
+        description.push_str(" — ");
+        description.push_str(part);
+    }
+    description
+}
+
+fn provider_note(provider: &str, transaction_id: &str) -> String {
+    format!("Recebido via webhook ({provider}), transação {transaction_id}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::payment_provider::MockProvider;
+    use hmac::{Hmac, Mac};
+    use sha2::Sha256;
+
+    const SECRET: &str = "segredo-do-webhook";
+
+    /// Database with every migration applied (e.g. a local development database).
+    /// Tests that need it are skipped when `TEST_DATABASE_URL` is unset.
+    async fn test_pool() -> Option<PgPool> {
+        let url = std::env::var("TEST_DATABASE_URL").ok()?;
+        Some(PgPool::connect(&url).await.expect("TEST_DATABASE_URL inacessível"))
+    }
+
+    /// New church with a user, an account, a category, a PIX code and the `mock` integration
+    async fn seed(pool: &PgPool) -> (Uuid, Uuid, Uuid, Uuid) {
+        let church_id = sqlx::query_scalar::<_, Uuid>(
+            "INSERT INTO churches (name) VALUES ('Igreja de Teste') RETURNING id",
+        )
+        .fetch_one(pool)
+        .await
+        .unwrap();
+
+        let user_id = sqlx::query_scalar::<_, Uuid>(
+            r#"INSERT INTO users (church_id, email, password_hash, role_id)
+               SELECT $1, $2, 'x', id FROM roles LIMIT 1 RETURNING id"#,
+        )
+        .bind(church_id)
+        .bind(format!("tesouraria-{church_id}@teste.com"))
+        .fetch_one(pool)
+        .await
+        .unwrap();
+
+        let bank_account_id = sqlx::query_scalar::<_, Uuid>(
+            "INSERT INTO bank_accounts (church_id, name, type) VALUES ($1, 'Conta PIX', 'digital') RETURNING id",
+        )
+        .bind(church_id)
+        .fetch_one(pool)
+        .await
+        .unwrap();
+
+        let account_plan_id = sqlx::query_scalar::<_, Uuid>(
+            "INSERT INTO account_plans (church_id, code, name, type) VALUES ($1, '1.1', 'Ofertas', 'receita') RETURNING id",
+        )
+        .bind(church_id)
+        .fetch_one(pool)
+        .await
+        .unwrap();
+
+        sqlx::query(
+            r#"INSERT INTO pix_charges (church_id, bank_account_id, txid, amount, account_plan_id, payload)
+               VALUES ($1, $2, 'TXIDTESTE', 50.00, $3, 'payload')"#,
+        )
+        .bind(church_id)
+        .bind(bank_account_id)
+        .bind(account_plan_id)
+        .execute(pool)
+        .await
+        .unwrap();
+
+        sqlx::query(
+            r#"INSERT INTO payment_integrations (church_id, provider, webhook_secret, registered_by)
+               VALUES ($1, 'mock', $2, $3)"#,
+        )
+        .bind(church_id)
+        .bind(SECRET)
+        .bind(user_id)
+        .execute(pool)
+        .await
+        .unwrap();
+
+        (church_id, user_id, bank_account_id, account_plan_id)
+    }
+
+    fn sign(body: &[u8]) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(SECRET.as_bytes()).unwrap();
+        mac.update(body);
+        mac.finalize()
+            .into_bytes()
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect()
+    }
+
+    async fn notification(pool: &PgPool, church_id: Uuid, transaction_id: &str) -> PaymentNotification {
+        sqlx::query_as::<_, PaymentNotification>(
+            "SELECT * FROM payment_notifications WHERE church_id = $1 AND provider_transaction_id = $2",
+        )
+        .bind(church_id)
+        .bind(transaction_id)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn ingest_posts_matches_queues_the_rest_and_drops_redeliveries() {
+        let Some(pool) = test_pool().await else {
+            return;
+        };
+        let (church_id, user_id, bank_account_id, account_plan_id) = seed(&pool).await;
+
+        let paid_at = Utc::now().to_rfc3339();
+        let body = serde_json::to_vec(&serde_json::json!({
+            "payments": [
+                { "transaction_id": "t-1", "method": "pix", "amount": "50.00",
+                  "paid_at": paid_at, "reference": "TXIDTESTE" },
+                { "transaction_id": "t-2", "method": "cartao", "amount": "30.00",
+                  "paid_at": paid_at, "payer_name": "Maria Souza" }
+            ]
+        }))
+        .unwrap();
+
+        let rejected =
+            PaymentWebhookService::ingest(&pool, church_id, &MockProvider, Some("00"), &body).await;
+        assert!(matches!(rejected, Err(AppError::Unauthorized(_))));
+
+        let signature = sign(&body);
+        let first =
+            PaymentWebhookService::ingest(&pool, church_id, &MockProvider, Some(&signature), &body)
+                .await
+                .unwrap();
+        assert_eq!((first.received, first.created, first.queued, first.duplicates), (2, 1, 1, 0));
+
+        let posted = notification(&pool, church_id, "t-1").await;
+        assert_eq!(posted.status, "lancado");
+        assert!(posted.entry_id.is_some());
+
+        let queued = notification(&pool, church_id, "t-2").await;
+        assert_eq!(queued.status, "pendente_revisao");
+        assert!(queued.entry_id.is_none());
+        assert!(queued.review_reason.is_some());
+
+        // The provider redelivers the same payments
+        let again =
+            PaymentWebhookService::ingest(&pool, church_id, &MockProvider, Some(&signature), &body)
+                .await
+                .unwrap();
+        assert_eq!((again.created, again.queued, again.duplicates), (0, 0, 2));
+
+        // The treasurer posts the queued card payment
+        let req = ResolvePaymentNotificationRequest {
+            account_plan_id,
+            bank_account_id,
+            campaign_id: None,
+            member_id: None,
+            congregation_id: None,
+            description: None,
+        };
+        let resolved = PaymentWebhookService::resolve(&pool, church_id, user_id, queued.id, &req)
+            .await
+            .unwrap();
+        assert_eq!(resolved.status, "lancado");
+        assert!(resolved.entry_id.is_some());
+
+        let twice = PaymentWebhookService::resolve(&pool, church_id, user_id, queued.id, &req).await;
+        assert!(matches!(twice, Err(AppError::Conflict(_))));
+
+        let entries = sqlx::query_scalar::<_, i64>(
+            "SELECT COUNT(*) FROM financial_entries WHERE church_id = $1 AND deleted_at IS NULL",
+        )
+        .bind(church_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(entries, 2);
+    }
+}
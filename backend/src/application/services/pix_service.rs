@@ -0,0 +1,256 @@
+use crate::application::dto::{CreatePixChargeRequest, PixChargeFilter};
+use crate::application::services::{BankAccountService, CampaignService};
+use crate::domain::entities::{PixCharge, PixChargeSummary};
+use crate::errors::AppError;
+use crate::infrastructure::pix::{self, StaticCharge};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Characters used in generated txids (no 0/O or 1/I, which get confused on print)
+const TXID_ALPHABET: &[u8; 32] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+const TXID_RANDOM_LEN: usize = 16;
+
+pub struct PixService;
+
+impl PixService {
+    /// List generated PIX charges
+    pub async fn list(
+        pool: &PgPool,
+        church_id: Uuid,
+        filter: &PixChargeFilter,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<PixChargeSummary>, i64), AppError> {
+        let where_clause = r#"
+            pc.church_id = $1
+            AND ($2::uuid IS NULL OR pc.bank_account_id = $2)
+            AND ($3::uuid IS NULL OR pc.campaign_id = $3)
+        "#;
+
+        let total = sqlx::query_scalar::<_, i64>(&format!(
+            "SELECT COUNT(*) FROM pix_charges pc WHERE {where_clause}"
+        ))
+        .bind(church_id)
+        .bind(filter.bank_account_id)
+        .bind(filter.campaign_id)
+        .fetch_one(pool)
+        .await?;
+
+        let charges = sqlx::query_as::<_, PixChargeSummary>(&format!(
+            r#"
+            SELECT pc.id, pc.bank_account_id, ba.name AS bank_account_name, pc.txid,
+                   pc.amount, pc.description, ap.name AS account_plan_name,
+                   c.name AS campaign_name, pc.payload, pc.created_at
+            FROM pix_charges pc
+            JOIN bank_accounts ba ON ba.id = pc.bank_account_id
+            LEFT JOIN account_plans ap ON ap.id = pc.account_plan_id
+            LEFT JOIN campaigns c ON c.id = pc.campaign_id
+            WHERE {where_clause}
+            ORDER BY pc.created_at DESC
+            LIMIT {limit} OFFSET {offset}
+            "#
+        ))
+        .bind(church_id)
+        .bind(filter.bank_account_id)
+        .bind(filter.campaign_id)
+        .fetch_all(pool)
+        .await?;
+
+        Ok((charges, total))
+    }
+
+    /// Get a PIX charge by ID
+    pub async fn get_by_id(
+        pool: &PgPool,
+        church_id: Uuid,
+        charge_id: Uuid,
+    ) -> Result<PixCharge, AppError> {
+        sqlx::query_as::<_, PixCharge>(
+            "SELECT * FROM pix_charges WHERE id = $1 AND church_id = $2",
+        )
+        .bind(charge_id)
+        .bind(church_id)
+        .fetch_optional(pool)
+        .await?
+        .ok_or_else(|| AppError::not_found("Cobrança PIX"))
+    }
+
+    /// Find the charge a received payment refers to, by the txid it carried
+    pub async fn find_by_txid(
+        pool: &PgPool,
+        church_id: Uuid,
+        txid: &str,
+    ) -> Result<Option<PixCharge>, AppError> {
+        let charge = sqlx::query_as::<_, PixCharge>(
+            "SELECT * FROM pix_charges WHERE church_id = $1 AND txid = $2",
+        )
+        .bind(church_id)
+        .bind(txid)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(charge)
+    }
+
+    /// Find the charge whose txid appears in a bank statement description.
+    /// Banks usually echo the txid of PIX credits in the statement line.
+    pub async fn find_in_text(
+        pool: &PgPool,
+        church_id: Uuid,
+        text: &str,
+    ) -> Result<Option<PixCharge>, AppError> {
+        let charge = sqlx::query_as::<_, PixCharge>(
+            r#"SELECT * FROM pix_charges
+               WHERE church_id = $1 AND length(txid) >= 10 AND strpos(upper($2), txid) > 0
+               ORDER BY created_at DESC LIMIT 1"#,
+        )
+        .bind(church_id)
+        .bind(text)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(charge)
+    }
+
+    /// Generate a static BR Code for a bank account (RN-FIN-017).
+    /// The txid is prefixed by purpose (CP = campaign, CT = category, IG = general)
+    /// and stored with the campaign/category so the credit can be matched later.
+    pub async fn create(
+        pool: &PgPool,
+        church_id: Uuid,
+        user_id: Uuid,
+        bank_account_id: Uuid,
+        req: &CreatePixChargeRequest,
+    ) -> Result<PixCharge, AppError> {
+        let account = BankAccountService::get_by_id(pool, church_id, bank_account_id).await?;
+        if !account.is_active {
+            return Err(AppError::validation("Conta bancária inativa"));
+        }
+        let key = account
+            .pix_key
+            .ok_or_else(|| AppError::validation("Conta bancária sem chave PIX cadastrada"))?;
+
+        if let Some(plan_id) = req.account_plan_id {
+            let plan_type = sqlx::query_scalar::<_, String>(
+                "SELECT type FROM account_plans WHERE id = $1 AND church_id = $2 AND is_active = TRUE",
+            )
+            .bind(plan_id)
+            .bind(church_id)
+            .fetch_optional(pool)
+            .await?
+            .ok_or_else(|| AppError::validation("Plano de contas não encontrado"))?;
+
+            if plan_type != "receita" {
+                return Err(AppError::validation(
+                    "QR Code PIX só pode ser vinculado a categoria de receita",
+                ));
+            }
+        }
+
+        let campaign_name = match req.campaign_id {
+            Some(campaign_id) => {
+                let today = chrono::Utc::now().date_naive();
+                let campaign =
+                    CampaignService::ensure_accepts_entries(pool, church_id, campaign_id, today)
+                        .await?;
+                Some(campaign.name)
+            }
+            None => None,
+        };
+
+        // Receiver shown by the payer's bank: church name, congregation (or church) city
+        let (church_name, church_city) = sqlx::query_as::<_, (String, Option<String>)>(
+            "SELECT name, city FROM churches WHERE id = $1",
+        )
+        .bind(church_id)
+        .fetch_one(pool)
+        .await?;
+
+        let congregation_city = match req.congregation_id {
+            Some(congregation_id) => sqlx::query_scalar::<_, Option<String>>(
+                "SELECT city FROM congregations WHERE id = $1 AND church_id = $2",
+            )
+            .bind(congregation_id)
+            .bind(church_id)
+            .fetch_optional(pool)
+            .await?
+            .ok_or_else(|| AppError::validation("Congregação não encontrada"))?,
+            None => None,
+        };
+
+        let city = congregation_city
+            .or(church_city)
+            .ok_or_else(|| AppError::validation("Cadastre a cidade da igreja para gerar o PIX"))?;
+
+        let description = req.description.clone().or(campaign_name);
+
+        let prefix = if req.campaign_id.is_some() {
+            "CP"
+        } else if req.account_plan_id.is_some() {
+            "CT"
+        } else {
+            "IG"
+        };
+        let txid = format!("{prefix}{}", Self::random_code());
+
+        let payload = pix::static_payload(&StaticCharge {
+            key: &key,
+            merchant_name: &church_name,
+            merchant_city: &city,
+            amount: req.amount,
+            txid: Some(&txid),
+            description: description.as_deref(),
+        })?;
+
+        let charge = sqlx::query_as::<_, PixCharge>(
+            r#"
+            INSERT INTO pix_charges (church_id, bank_account_id, txid, amount, description,
+                                     account_plan_id, campaign_id, congregation_id, payload, created_by)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            RETURNING *
+            "#,
+        )
+        .bind(church_id)
+        .bind(bank_account_id)
+        .bind(&txid)
+        .bind(req.amount.map(|a| a.round_dp(2)))
+        .bind(&description)
+        .bind(req.account_plan_id)
+        .bind(req.campaign_id)
+        .bind(req.congregation_id)
+        .bind(&payload)
+        .bind(user_id)
+        .fetch_one(pool)
+        .await?;
+
+        Ok(charge)
+    }
+
+    /// QR image of a charge: PNG or SVG
+    pub async fn qr_image(
+        pool: &PgPool,
+        church_id: Uuid,
+        charge_id: Uuid,
+        format: &str,
+        size: u32,
+    ) -> Result<(PixCharge, Vec<u8>), AppError> {
+        let charge = Self::get_by_id(pool, church_id, charge_id).await?;
+        let size = size.clamp(100, 2000);
+
+        let image = match format {
+            "svg" => pix::qr_svg(&charge.payload, size)?.into_bytes(),
+            _ => pix::qr_png(&charge.payload, size)?,
+        };
+
+        Ok((charge, image))
+    }
+
+    fn random_code() -> String {
+        let mut bytes = [0u8; TXID_RANDOM_LEN];
+        rand::fill(&mut bytes);
+        bytes
+            .iter()
+            .map(|b| TXID_ALPHABET[(b % 32) as usize] as char)
+            .collect()
+    }
+}
@@ -0,0 +1,652 @@
+use crate::application::dto::{CreateRecurrenceRequest, RecurrenceFilter, UpdateRecurrenceRequest};
+use crate::application::services::CampaignService;
+use crate::domain::entities::{FinancialRecurrence, FinancialRecurrenceSummary};
+use crate::errors::AppError;
+use chrono::{Datelike, Duration, Months, NaiveDate};
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+const FREQUENCIES: [&str; 5] = ["mensal", "bimestral", "trimestral", "semestral", "anual"];
+
+pub struct RecurrenceService;
+
+impl RecurrenceService {
+    /// List recurrence templates
+    pub async fn list(
+        pool: &PgPool,
+        church_id: Uuid,
+        filter: &RecurrenceFilter,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<FinancialRecurrenceSummary>, i64), AppError> {
+        let mut conditions = vec![
+            "r.church_id = $1".to_string(),
+            "r.deleted_at IS NULL".to_string(),
+        ];
+        let mut param_idx = 2u32;
+
+        if filter.entry_type.is_some() {
+            conditions.push(format!("r.type = ${param_idx}"));
+            param_idx += 1;
+        }
+        if filter.is_active.is_some() {
+            conditions.push(format!("r.is_active = ${param_idx}"));
+            param_idx += 1;
+        }
+        if filter.congregation_id.is_some() {
+            conditions.push(format!("r.congregation_id = ${param_idx}"));
+            param_idx += 1;
+        }
+
+        let _ = param_idx;
+        let where_clause = conditions.join(" AND ");
+
+        let count_sql = format!("SELECT COUNT(*) FROM financial_recurrences r WHERE {where_clause}");
+
+        let query_sql = format!(
+            r#"
+            SELECT r.id, r.type, r.amount, r.description, r.frequency, r.day_of_month,
+                   r.start_date, r.end_date, r.next_due_date, r.is_active,
+                   ap.name AS account_plan_name,
+                   ba.name AS bank_account_name,
+                   r.supplier_name,
+                   cg.name AS congregation_name,
+                   (SELECT COUNT(*) FROM financial_entries fe
+                    WHERE fe.recurring_id = r.id AND fe.status = 'pendente'
+                    AND fe.deleted_at IS NULL) AS pending_count,
+                   r.created_at
+            FROM financial_recurrences r
+            LEFT JOIN account_plans ap ON ap.id = r.account_plan_id
+            LEFT JOIN bank_accounts ba ON ba.id = r.bank_account_id
+            LEFT JOIN congregations cg ON cg.id = r.congregation_id
+            WHERE {where_clause}
+            ORDER BY r.is_active DESC, r.next_due_date ASC
+            LIMIT {limit} OFFSET {offset}
+            "#
+        );
+
+        let mut count_args = sqlx::postgres::PgArguments::default();
+        let mut data_args = sqlx::postgres::PgArguments::default();
+
+        sqlx::Arguments::add(&mut count_args, church_id).unwrap();
+        sqlx::Arguments::add(&mut data_args, church_id).unwrap();
+
+        if let Some(ref et) = filter.entry_type {
+            sqlx::Arguments::add(&mut count_args, et.as_str()).unwrap();
+            sqlx::Arguments::add(&mut data_args, et.as_str()).unwrap();
+        }
+        if let Some(is_active) = filter.is_active {
+            sqlx::Arguments::add(&mut count_args, is_active).unwrap();
+            sqlx::Arguments::add(&mut data_args, is_active).unwrap();
+        }
+        if let Some(congregation_id) = filter.congregation_id {
+            sqlx::Arguments::add(&mut count_args, congregation_id).unwrap();
+            sqlx::Arguments::add(&mut data_args, congregation_id).unwrap();
+        }
+
+        let total = sqlx::query_scalar_with::<_, i64, _>(&count_sql, count_args)
+            .fetch_one(pool)
+            .await?;
+
+        let recurrences =
+            sqlx::query_as_with::<_, FinancialRecurrenceSummary, _>(&query_sql, data_args)
+                .fetch_all(pool)
+                .await?;
+
+        Ok((recurrences, total))
+    }
+
+    /// Get recurrence template by ID
+    pub async fn get_by_id(
+        pool: &PgPool,
+        church_id: Uuid,
+        recurrence_id: Uuid,
+    ) -> Result<FinancialRecurrence, AppError> {
+        sqlx::query_as::<_, FinancialRecurrence>(
+            "SELECT * FROM financial_recurrences WHERE id = $1 AND church_id = $2 AND deleted_at IS NULL",
+        )
+        .bind(recurrence_id)
+        .bind(church_id)
+        .fetch_optional(pool)
+        .await?
+        .ok_or_else(|| AppError::not_found("Recorrência"))
+    }
+
+    /// Create a recurrence template and generate the occurrences already within its lead time
+    pub async fn create(
+        pool: &PgPool,
+        church_id: Uuid,
+        user_id: Uuid,
+        req: &CreateRecurrenceRequest,
+    ) -> Result<FinancialRecurrence, AppError> {
+        if req.entry_type != "receita" && req.entry_type != "despesa" {
+            return Err(AppError::validation("Tipo deve ser 'receita' ou 'despesa'"));
+        }
+
+        if req.amount <= Decimal::ZERO {
+            return Err(AppError::validation("Valor deve ser maior que zero"));
+        }
+
+        let frequency = req.frequency.as_deref().unwrap_or("mensal");
+        Self::validate_frequency(frequency)?;
+
+        if let Some(end_date) = req.end_date {
+            if end_date < req.start_date {
+                return Err(AppError::validation(
+                    "Data final deve ser igual ou posterior à data inicial",
+                ));
+            }
+        }
+
+        Self::validate_accounts(
+            pool,
+            church_id,
+            Some(req.account_plan_id),
+            Some(req.bank_account_id),
+        )
+        .await?;
+
+        if let Some(campaign_id) = req.campaign_id {
+            CampaignService::ensure_accepts_entries(pool, church_id, campaign_id, req.start_date)
+                .await?;
+        }
+
+        let next_due_date = first_due_on_or_after(
+            req.start_date,
+            req.day_of_month,
+            frequency_months(frequency),
+            req.start_date,
+        );
+
+        let recurrence = sqlx::query_as::<_, FinancialRecurrence>(
+            r#"
+            INSERT INTO financial_recurrences (
+                church_id, type, account_plan_id, bank_account_id, campaign_id,
+                amount, description, payment_method, member_id, supplier_name,
+                congregation_id, notes, frequency, day_of_month, start_date,
+                end_date, lead_days, next_due_date, created_by
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19)
+            RETURNING *
+            "#,
+        )
+        .bind(church_id)
+        .bind(&req.entry_type)
+        .bind(req.account_plan_id)
+        .bind(req.bank_account_id)
+        .bind(req.campaign_id)
+        .bind(req.amount)
+        .bind(&req.description)
+        .bind(&req.payment_method)
+        .bind(req.member_id)
+        .bind(&req.supplier_name)
+        .bind(req.congregation_id)
+        .bind(&req.notes)
+        .bind(frequency)
+        .bind(req.day_of_month)
+        .bind(req.start_date)
+        .bind(req.end_date)
+        .bind(req.lead_days.unwrap_or(30))
+        .bind(next_due_date)
+        .bind(user_id)
+        .fetch_one(pool)
+        .await?;
+
+        Self::generate_one(pool, recurrence.id).await?;
+
+        Self::get_by_id(pool, church_id, recurrence.id).await
+    }
+
+    /// Update a template and propagate the change to pending occurrences due on or
+    /// after `apply_from` ("this and all following"). Editing a single occurrence is a
+    /// regular entry update. Schedule changes (frequency/day) cancel those pending
+    /// occurrences and regenerate them on the new schedule.
+    pub async fn update(
+        pool: &PgPool,
+        church_id: Uuid,
+        recurrence_id: Uuid,
+        req: &UpdateRecurrenceRequest,
+    ) -> Result<FinancialRecurrence, AppError> {
+        let existing = Self::get_by_id(pool, church_id, recurrence_id).await?;
+
+        let today = sqlx::query_scalar::<_, NaiveDate>("SELECT CURRENT_DATE")
+            .fetch_one(pool)
+            .await?;
+        let apply_from = req.apply_from.unwrap_or(today);
+
+        Self::validate_accounts(pool, church_id, req.account_plan_id, req.bank_account_id).await?;
+
+        // Template and pending occurrences share these columns
+        let mut rec_sets: Vec<String> = Vec::new();
+        let mut entry_sets: Vec<String> = Vec::new();
+        let mut rec_args = sqlx::postgres::PgArguments::default();
+        let mut entry_args = sqlx::postgres::PgArguments::default();
+        let mut rec_idx = 2u32;
+        let mut entry_idx = 3u32;
+
+        sqlx::Arguments::add(&mut rec_args, recurrence_id).unwrap();
+        sqlx::Arguments::add(&mut entry_args, recurrence_id).unwrap();
+        sqlx::Arguments::add(&mut entry_args, apply_from).unwrap();
+
+        if let Some(ap_id) = req.account_plan_id {
+            rec_sets.push(format!("account_plan_id = ${rec_idx}"));
+            sqlx::Arguments::add(&mut rec_args, ap_id).unwrap();
+            rec_idx += 1;
+            entry_sets.push(format!("account_plan_id = ${entry_idx}"));
+            sqlx::Arguments::add(&mut entry_args, ap_id).unwrap();
+            entry_idx += 1;
+        }
+        if let Some(ba_id) = req.bank_account_id {
+            rec_sets.push(format!("bank_account_id = ${rec_idx}"));
+            sqlx::Arguments::add(&mut rec_args, ba_id).unwrap();
+            rec_idx += 1;
+            entry_sets.push(format!("bank_account_id = ${entry_idx}"));
+            sqlx::Arguments::add(&mut entry_args, ba_id).unwrap();
+            entry_idx += 1;
+        }
+        if let Some(amount) = req.amount {
+            if amount <= Decimal::ZERO {
+                return Err(AppError::validation("Valor deve ser maior que zero"));
+            }
+            rec_sets.push(format!("amount = ${rec_idx}"));
+            sqlx::Arguments::add(&mut rec_args, amount).unwrap();
+            rec_idx += 1;
+            entry_sets.push(format!("amount = ${entry_idx}"));
+            sqlx::Arguments::add(&mut entry_args, amount).unwrap();
+            entry_idx += 1;
+        }
+        if let Some(ref desc) = req.description {
+            rec_sets.push(format!("description = ${rec_idx}"));
+            sqlx::Arguments::add(&mut rec_args, desc.as_str()).unwrap();
+            rec_idx += 1;
+            entry_sets.push(format!("description = ${entry_idx}"));
+            sqlx::Arguments::add(&mut entry_args, desc.as_str()).unwrap();
+            entry_idx += 1;
+        }
+        if let Some(ref pm) = req.payment_method {
+            rec_sets.push(format!("payment_method = ${rec_idx}"));
+            sqlx::Arguments::add(&mut rec_args, pm.as_str()).unwrap();
+            rec_idx += 1;
+            entry_sets.push(format!("payment_method = ${entry_idx}"));
+            sqlx::Arguments::add(&mut entry_args, pm.as_str()).unwrap();
+            entry_idx += 1;
+        }
+        if let Some(ref sn) = req.supplier_name {
+            rec_sets.push(format!("supplier_name = ${rec_idx}"));
+            sqlx::Arguments::add(&mut rec_args, sn.as_str()).unwrap();
+            rec_idx += 1;
+            entry_sets.push(format!("supplier_name = ${entry_idx}"));
+            sqlx::Arguments::add(&mut entry_args, sn.as_str()).unwrap();
+            entry_idx += 1;
+        }
+        if let Some(ref notes) = req.notes {
+            rec_sets.push(format!("notes = ${rec_idx}"));
+            sqlx::Arguments::add(&mut rec_args, notes.as_str()).unwrap();
+            rec_idx += 1;
+            entry_sets.push(format!("notes = ${entry_idx}"));
+            sqlx::Arguments::add(&mut entry_args, notes.as_str()).unwrap();
+            entry_idx += 1;
+        }
+
+        let frequency = req.frequency.as_deref().unwrap_or(&existing.frequency);
+        Self::validate_frequency(frequency)?;
+        let day_of_month = req.day_of_month.unwrap_or(existing.day_of_month);
+        let schedule_changed =
+            frequency != existing.frequency || day_of_month != existing.day_of_month;
+
+        let end_date = if req.no_end_date == Some(true) {
+            None
+        } else {
+            req.end_date.or(existing.end_date)
+        };
+        if let Some(end) = end_date {
+            if end < existing.start_date {
+                return Err(AppError::validation(
+                    "Data final deve ser igual ou posterior à data inicial",
+                ));
+            }
+        }
+
+        if schedule_changed {
+            let next_due_date = first_due_on_or_after(
+                existing.start_date,
+                day_of_month,
+                frequency_months(frequency),
+                apply_from.max(existing.start_date),
+            );
+            rec_sets.push(format!("frequency = ${rec_idx}"));
+            sqlx::Arguments::add(&mut rec_args, frequency).unwrap();
+            rec_idx += 1;
+            rec_sets.push(format!("day_of_month = ${rec_idx}"));
+            sqlx::Arguments::add(&mut rec_args, day_of_month).unwrap();
+            rec_idx += 1;
+            rec_sets.push(format!("next_due_date = ${rec_idx}"));
+            sqlx::Arguments::add(&mut rec_args, next_due_date).unwrap();
+            rec_idx += 1;
+        }
+        if end_date != existing.end_date {
+            rec_sets.push(format!("end_date = ${rec_idx}"));
+            sqlx::Arguments::add(&mut rec_args, end_date).unwrap();
+            rec_idx += 1;
+        }
+        if let Some(lead_days) = req.lead_days {
+            rec_sets.push(format!("lead_days = ${rec_idx}"));
+            sqlx::Arguments::add(&mut rec_args, lead_days).unwrap();
+            rec_idx += 1;
+        }
+
+        let _ = (rec_idx, entry_idx);
+
+        if rec_sets.is_empty() {
+            return Ok(existing);
+        }
+
+        let mut tx = pool.begin().await?;
+
+        sqlx::query_with(
+            &format!(
+                "UPDATE financial_recurrences SET {} WHERE id = $1",
+                rec_sets.join(", ")
+            ),
+            rec_args,
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        if !entry_sets.is_empty() {
+            sqlx::query_with(
+                &format!(
+                    r#"UPDATE financial_entries SET {}
+                       WHERE recurring_id = $1 AND due_date >= $2 AND status = 'pendente'
+                       AND is_closed = FALSE AND deleted_at IS NULL"#,
+                    entry_sets.join(", ")
+                ),
+                entry_args,
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        // Occurrences that no longer fit the schedule are cancelled (and regenerated below)
+        if schedule_changed || end_date.is_some() {
+            let cancel_from = if schedule_changed { Some(apply_from) } else { None };
+            sqlx::query(
+                r#"UPDATE financial_entries SET deleted_at = NOW(), status = 'cancelado'
+                   WHERE recurring_id = $1 AND status = 'pendente'
+                   AND is_closed = FALSE AND deleted_at IS NULL
+                   AND (due_date >= $2 OR due_date > $3)"#,
+            )
+            .bind(recurrence_id)
+            .bind(cancel_from)
+            .bind(end_date)
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        Self::generate_one(pool, recurrence_id).await?;
+
+        Self::get_by_id(pool, church_id, recurrence_id).await
+    }
+
+    /// Cancel pending occurrences due on or after `from` and end the recurrence there.
+    /// Cancelling from the start date deactivates the template entirely.
+    /// Returns the number of cancelled occurrences.
+    pub async fn cancel(
+        pool: &PgPool,
+        church_id: Uuid,
+        recurrence_id: Uuid,
+        from: Option<NaiveDate>,
+    ) -> Result<u64, AppError> {
+        let existing = Self::get_by_id(pool, church_id, recurrence_id).await?;
+
+        let from = match from {
+            Some(date) => date,
+            None => {
+                sqlx::query_scalar::<_, NaiveDate>("SELECT CURRENT_DATE")
+                    .fetch_one(pool)
+                    .await?
+            }
+        };
+
+        let mut tx = pool.begin().await?;
+
+        let cancelled = sqlx::query(
+            r#"UPDATE financial_entries SET deleted_at = NOW(), status = 'cancelado'
+               WHERE recurring_id = $1 AND church_id = $2 AND due_date >= $3
+               AND status = 'pendente' AND is_closed = FALSE AND deleted_at IS NULL"#,
+        )
+        .bind(recurrence_id)
+        .bind(church_id)
+        .bind(from)
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+
+        if from <= existing.start_date {
+            sqlx::query("UPDATE financial_recurrences SET is_active = FALSE WHERE id = $1")
+                .bind(recurrence_id)
+                .execute(&mut *tx)
+                .await?;
+        } else {
+            let last_day = from - Duration::days(1);
+            let end_date = existing.end_date.map_or(last_day, |end| end.min(last_day));
+            sqlx::query("UPDATE financial_recurrences SET end_date = $2 WHERE id = $1")
+                .bind(recurrence_id)
+                .bind(end_date)
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(cancelled)
+    }
+
+    /// Materialize every occurrence whose due date is within its template's lead time.
+    /// Runs from the background scheduler (all churches) or on demand (one church).
+    /// Returns the number of entries created.
+    pub async fn generate_due(pool: &PgPool, church_id: Option<Uuid>) -> Result<u64, AppError> {
+        let recurrence_ids = sqlx::query_scalar::<_, Uuid>(
+            r#"SELECT id FROM financial_recurrences
+               WHERE is_active = TRUE AND deleted_at IS NULL
+               AND next_due_date <= CURRENT_DATE + lead_days
+               AND (end_date IS NULL OR next_due_date <= end_date)
+               AND ($1::uuid IS NULL OR church_id = $1)"#,
+        )
+        .bind(church_id)
+        .fetch_all(pool)
+        .await?;
+
+        let mut generated = 0;
+        for recurrence_id in recurrence_ids {
+            match Self::generate_one(pool, recurrence_id).await {
+                Ok(count) => generated += count,
+                Err(e) => tracing::warn!("Recurrence {recurrence_id} generation failed: {e}"),
+            }
+        }
+
+        Ok(generated)
+    }
+
+    /// Generate pending entries for one template, advancing its `next_due_date` cursor.
+    /// The row lock plus the unique (recurring_id, due_date) index make it safe to run
+    /// from several instances at once.
+    async fn generate_one(pool: &PgPool, recurrence_id: Uuid) -> Result<u64, AppError> {
+        let mut tx = pool.begin().await?;
+
+        let Some(rec) = sqlx::query_as::<_, FinancialRecurrence>(
+            r#"SELECT * FROM financial_recurrences
+               WHERE id = $1 AND is_active = TRUE AND deleted_at IS NULL
+               FOR UPDATE SKIP LOCKED"#,
+        )
+        .bind(recurrence_id)
+        .fetch_optional(&mut *tx)
+        .await?
+        else {
+            return Ok(0);
+        };
+
+        let horizon = sqlx::query_scalar::<_, NaiveDate>("SELECT CURRENT_DATE + $1::int")
+            .bind(i32::from(rec.lead_days))
+            .fetch_one(&mut *tx)
+            .await?;
+
+        let step = frequency_months(&rec.frequency);
+        let mut due = rec.next_due_date;
+        let mut generated = 0;
+
+        while due <= horizon && rec.end_date.is_none_or(|end| due <= end) {
+            // Skip closed months and months that already have an occurrence
+            // (e.g. paid before the due day was changed)
+            let skip = sqlx::query_scalar::<_, bool>(
+                r#"SELECT EXISTS(SELECT 1 FROM monthly_closings
+                          WHERE church_id = $1 AND reference_month = DATE_TRUNC('month', $2::date)::date
+                          AND (congregation_id IS NULL OR congregation_id = $4))
+                       OR EXISTS(SELECT 1 FROM financial_entries
+                          WHERE recurring_id = $3 AND deleted_at IS NULL
+                          AND DATE_TRUNC('month', due_date) = DATE_TRUNC('month', $2::date))"#,
+            )
+            .bind(rec.church_id)
+            .bind(due)
+            .bind(rec.id)
+            .bind(rec.congregation_id)
+            .fetch_one(&mut *tx)
+            .await?;
+
+            if !skip {
+                let result = sqlx::query(
+                    r#"
+                    INSERT INTO financial_entries (
+                        church_id, type, account_plan_id, bank_account_id, campaign_id,
+                        amount, entry_date, due_date, description, payment_method,
+                        member_id, supplier_name, status, is_recurring, recurring_id,
+                        registered_by, notes, congregation_id
+                    )
+                    VALUES ($1, $2, $3, $4,
+                            -- Only link while the campaign still accepts entries (RN-FIN-008)
+                            (SELECT id FROM campaigns WHERE id = $5 AND status = 'ativa'
+                             AND (end_date IS NULL OR end_date >= $7)),
+                            $6, $7, $7, $8, $9, $10, $11, 'pendente', TRUE, $12, $13, $14, $15)
+                    ON CONFLICT (recurring_id, due_date)
+                        WHERE recurring_id IS NOT NULL AND deleted_at IS NULL
+                    DO NOTHING
+                    "#,
+                )
+                .bind(rec.church_id)
+                .bind(&rec.entry_type)
+                .bind(rec.account_plan_id)
+                .bind(rec.bank_account_id)
+                .bind(rec.campaign_id)
+                .bind(rec.amount)
+                .bind(due)
+                .bind(&rec.description)
+                .bind(&rec.payment_method)
+                .bind(rec.member_id)
+                .bind(&rec.supplier_name)
+                .bind(rec.id)
+                .bind(rec.created_by)
+                .bind(&rec.notes)
+                .bind(rec.congregation_id)
+                .execute(&mut *tx)
+                .await?;
+
+                generated += result.rows_affected();
+            }
+
+            due = next_due(due, rec.day_of_month, step);
+        }
+
+        if due != rec.next_due_date {
+            sqlx::query("UPDATE financial_recurrences SET next_due_date = $2 WHERE id = $1")
+                .bind(rec.id)
+                .bind(due)
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(generated)
+    }
+
+    fn validate_frequency(frequency: &str) -> Result<(), AppError> {
+        if !FREQUENCIES.contains(&frequency) {
+            return Err(AppError::validation(
+                "Frequência deve ser: mensal, bimestral, trimestral, semestral ou anual",
+            ));
+        }
+        Ok(())
+    }
+
+    async fn validate_accounts(
+        pool: &PgPool,
+        church_id: Uuid,
+        account_plan_id: Option<Uuid>,
+        bank_account_id: Option<Uuid>,
+    ) -> Result<(), AppError> {
+        if let Some(ap_id) = account_plan_id {
+            sqlx::query_scalar::<_, Uuid>(
+                "SELECT id FROM account_plans WHERE id = $1 AND church_id = $2 AND is_active = TRUE",
+            )
+            .bind(ap_id)
+            .bind(church_id)
+            .fetch_optional(pool)
+            .await?
+            .ok_or_else(|| AppError::validation("Plano de contas não encontrado ou inativo"))?;
+        }
+
+        if let Some(ba_id) = bank_account_id {
+            sqlx::query_scalar::<_, Uuid>(
+                "SELECT id FROM bank_accounts WHERE id = $1 AND church_id = $2 AND is_active = TRUE",
+            )
+            .bind(ba_id)
+            .bind(church_id)
+            .fetch_optional(pool)
+            .await?
+            .ok_or_else(|| AppError::validation("Conta bancária não encontrada ou inativa"))?;
+        }
+
+        Ok(())
+    }
+}
+
+fn frequency_months(frequency: &str) -> u32 {
+    match frequency {
+        "bimestral" => 2,
+        "trimestral" => 3,
+        "semestral" => 6,
+        "anual" => 12,
+        _ => 1,
+    }
+}
+
+/// Due date within the month of `date`, clamped to the month's last day (e.g. 31 → 28/02)
+fn due_in_month(date: NaiveDate, day_of_month: i16) -> NaiveDate {
+    let first = date.with_day(1).unwrap();
+    let last = first + Months::new(1) - Duration::days(1);
+    first.with_day(day_of_month as u32).unwrap_or(last)
+}
+
+fn next_due(due: NaiveDate, day_of_month: i16, step_months: u32) -> NaiveDate {
+    due_in_month(due.with_day(1).unwrap() + Months::new(step_months), day_of_month)
+}
+
+/// First due date of a schedule anchored at `start_date` that falls on or after `from`
+fn first_due_on_or_after(
+    start_date: NaiveDate,
+    day_of_month: i16,
+    step_months: u32,
+    from: NaiveDate,
+) -> NaiveDate {
+    let mut due = due_in_month(start_date, day_of_month);
+    if due < start_date {
+        due = next_due(due, day_of_month, step_months);
+    }
+    while due < from {
+        due = next_due(due, day_of_month, step_months);
+    }
+    due
+}
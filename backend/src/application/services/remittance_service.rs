@@ -0,0 +1,523 @@
+use crate::application::dto::{
+    GenerateRemittancesRequest, PayRemittanceRequest, RemittanceFilter,
+    UpdateRemittanceRuleRequest,
+};
+use crate::application::services::FinancialEntryService;
+use crate::domain::entities::{
+    CongregationRemittance, CongregationRemittanceSummary, OutstandingRemittance,
+    RemittanceOutstandingReport, RemittanceRule,
+};
+use crate::errors::AppError;
+use chrono::{Datelike, NaiveDate};
+use rust_decimal::Decimal;
+use sqlx::{PgPool, Postgres, Transaction};
+use uuid::Uuid;
+
+/// Account plan codes reserved for the two legs of a remittance payment
+const REMITTANCE_OUT_PLAN_CODE: &str = "REP-S";
+const REMITTANCE_IN_PLAN_CODE: &str = "REP-E";
+
+/// Base amount of one congregation for one month: net income of the base plans
+/// (and their subcategories), reversals included, transfers excluded.
+const BASE_AMOUNT_SQL: &str = r#"
+    WITH RECURSIVE base_plans AS (
+        SELECT id FROM account_plans WHERE church_id = $1 AND id = ANY($3)
+        UNION
+        SELECT ap.id FROM account_plans ap JOIN base_plans bp ON ap.parent_id = bp.id
+        WHERE ap.church_id = $1
+    )
+    SELECT COALESCE(SUM(CASE WHEN fe.type = 'receita' THEN l.amount ELSE -l.amount END), 0)
+    FROM financial_entries fe
+    JOIN financial_entry_lines l ON l.entry_id = fe.id
+    WHERE fe.church_id = $1 AND fe.congregation_id = $2
+      AND l.account_plan_id IN (SELECT id FROM base_plans)
+      AND fe.status IN ('confirmado', 'estornado') AND fe.transfer_id IS NULL
+      AND fe.deleted_at IS NULL
+      AND fe.entry_date >= $4 AND fe.entry_date < ($4::date + INTERVAL '1 month')
+"#;
+
+pub struct RemittanceService;
+
+impl RemittanceService {
+    /// Remittance rule of a congregation (None when never configured)
+    pub async fn get_rule(
+        pool: &PgPool,
+        church_id: Uuid,
+        congregation_id: Uuid,
+    ) -> Result<Option<RemittanceRule>, AppError> {
+        let rule = sqlx::query_scalar::<_, Option<serde_json::Value>>(
+            r#"SELECT settings->'remittance' FROM congregations
+               WHERE id = $1 AND church_id = $2"#,
+        )
+        .bind(congregation_id)
+        .bind(church_id)
+        .fetch_optional(pool)
+        .await?
+        .ok_or_else(|| AppError::not_found("Congregação"))?;
+
+        rule.map(serde_json::from_value)
+            .transpose()
+            .map_err(|e| AppError::Internal(format!("Regra de repasse inválida: {e}")))
+    }
+
+    /// Set the remittance rule of a congregation (RN-FIN-016)
+    pub async fn set_rule(
+        pool: &PgPool,
+        church_id: Uuid,
+        congregation_id: Uuid,
+        req: &UpdateRemittanceRuleRequest,
+    ) -> Result<RemittanceRule, AppError> {
+        if req.percentage <= Decimal::ZERO || req.percentage > Decimal::from(100) {
+            return Err(AppError::validation(
+                "Percentual de repasse deve ser maior que 0 e no máximo 100",
+            ));
+        }
+
+        let congregation_type = sqlx::query_scalar::<_, String>(
+            "SELECT type FROM congregations WHERE id = $1 AND church_id = $2",
+        )
+        .bind(congregation_id)
+        .bind(church_id)
+        .fetch_optional(pool)
+        .await?
+        .ok_or_else(|| AppError::not_found("Congregação"))?;
+
+        if congregation_type == "sede" {
+            return Err(AppError::validation("A sede não faz repasse para si mesma"));
+        }
+
+        let mut account_plan_ids = req.account_plan_ids.clone();
+        account_plan_ids.sort();
+        account_plan_ids.dedup();
+
+        let valid_plans = sqlx::query_scalar::<_, i64>(
+            r#"SELECT COUNT(*) FROM account_plans
+               WHERE church_id = $1 AND id = ANY($2) AND type = 'receita'"#,
+        )
+        .bind(church_id)
+        .bind(&account_plan_ids)
+        .fetch_one(pool)
+        .await?;
+
+        if valid_plans != account_plan_ids.len() as i64 {
+            return Err(AppError::validation(
+                "Categorias-base devem ser planos de contas de receita da igreja",
+            ));
+        }
+
+        let rule = RemittanceRule {
+            percentage: req.percentage.round_dp(2),
+            account_plan_ids,
+            is_active: req.is_active.unwrap_or(true),
+        };
+        let value = serde_json::to_value(&rule)
+            .map_err(|e| AppError::Internal(format!("Falha ao serializar regra: {e}")))?;
+
+        sqlx::query(
+            r#"UPDATE congregations
+               SET settings = COALESCE(settings, '{}'::jsonb) || jsonb_build_object('remittance', $3::jsonb)
+               WHERE id = $1 AND church_id = $2"#,
+        )
+        .bind(congregation_id)
+        .bind(church_id)
+        .bind(value)
+        .execute(pool)
+        .await?;
+
+        Ok(rule)
+    }
+
+    /// Calculate the remittance of every congregation with an active rule for a month.
+    /// Pending remittances are recalculated; paid ones are left untouched.
+    pub async fn generate(
+        pool: &PgPool,
+        church_id: Uuid,
+        req: &GenerateRemittancesRequest,
+    ) -> Result<Vec<CongregationRemittance>, AppError> {
+        let month = first_day_of_month(req.reference_month);
+
+        let congregations = sqlx::query_as::<_, (Uuid, serde_json::Value)>(
+            r#"SELECT id, settings->'remittance' FROM congregations
+               WHERE church_id = $1 AND is_active = TRUE
+                 AND type <> 'sede' AND settings ? 'remittance'
+                 AND ($2::uuid IS NULL OR id = $2)
+               ORDER BY name"#,
+        )
+        .bind(church_id)
+        .bind(req.congregation_id)
+        .fetch_all(pool)
+        .await?;
+
+        let mut remittances = Vec::with_capacity(congregations.len());
+        for (congregation_id, value) in congregations {
+            let Ok(rule) = serde_json::from_value::<RemittanceRule>(value) else {
+                continue;
+            };
+            if !rule.is_active {
+                continue;
+            }
+
+            let base_amount = sqlx::query_scalar::<_, Decimal>(BASE_AMOUNT_SQL)
+                .bind(church_id)
+                .bind(congregation_id)
+                .bind(&rule.account_plan_ids)
+                .bind(month)
+                .fetch_one(pool)
+                .await?;
+
+            let amount_due = (base_amount * rule.percentage / Decimal::from(100))
+                .round_dp(2)
+                .max(Decimal::ZERO);
+
+            let remittance = sqlx::query_as::<_, CongregationRemittance>(
+                r#"
+                INSERT INTO congregation_remittances
+                    (church_id, congregation_id, reference_month, base_amount, percentage, amount_due)
+                VALUES ($1, $2, $3, $4, $5, $6)
+                ON CONFLICT (congregation_id, reference_month) DO UPDATE
+                    SET base_amount = EXCLUDED.base_amount,
+                        percentage = EXCLUDED.percentage,
+                        amount_due = EXCLUDED.amount_due
+                    WHERE congregation_remittances.status = 'pendente'
+                RETURNING *
+                "#,
+            )
+            .bind(church_id)
+            .bind(congregation_id)
+            .bind(month)
+            .bind(base_amount)
+            .bind(rule.percentage)
+            .bind(amount_due)
+            .fetch_optional(pool)
+            .await?;
+
+            if let Some(remittance) = remittance {
+                remittances.push(remittance);
+            }
+        }
+
+        Ok(remittances)
+    }
+
+    /// Calculate last month's remittances for every church (background job).
+    /// Only creates missing rows, so values adjusted by a manual run are kept.
+    pub async fn generate_due(pool: &PgPool) -> Result<u64, AppError> {
+        let today = chrono::Utc::now().date_naive();
+        let previous_month = first_day_of_month(today)
+            .pred_opt()
+            .map(first_day_of_month)
+            .unwrap_or(today);
+
+        let pending = sqlx::query_as::<_, (Uuid, Uuid)>(
+            r#"SELECT c.church_id, c.id FROM congregations c
+               WHERE c.is_active = TRUE AND c.type <> 'sede'
+                 AND COALESCE((c.settings->'remittance'->>'is_active')::boolean, FALSE)
+                 AND NOT EXISTS (SELECT 1 FROM congregation_remittances cr
+                                 WHERE cr.congregation_id = c.id AND cr.reference_month = $1)"#,
+        )
+        .bind(previous_month)
+        .fetch_all(pool)
+        .await?;
+
+        let mut generated = 0u64;
+        for (church_id, congregation_id) in pending {
+            let req = GenerateRemittancesRequest {
+                reference_month: previous_month,
+                congregation_id: Some(congregation_id),
+            };
+            generated += Self::generate(pool, church_id, &req).await?.len() as u64;
+        }
+
+        Ok(generated)
+    }
+
+    /// List remittances
+    pub async fn list(
+        pool: &PgPool,
+        church_id: Uuid,
+        filter: &RemittanceFilter,
+        allowed_congregations: Option<&[Uuid]>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<CongregationRemittanceSummary>, i64), AppError> {
+        let where_clause = r#"
+            cr.church_id = $1
+            AND ($2::uuid IS NULL OR cr.congregation_id = $2)
+            AND ($3::varchar IS NULL OR cr.status = $3)
+            AND ($4::uuid[] IS NULL OR cr.congregation_id = ANY($4))
+        "#;
+
+        let total = sqlx::query_scalar::<_, i64>(&format!(
+            "SELECT COUNT(*) FROM congregation_remittances cr WHERE {where_clause}"
+        ))
+        .bind(church_id)
+        .bind(filter.congregation_id)
+        .bind(&filter.status)
+        .bind(allowed_congregations)
+        .fetch_one(pool)
+        .await?;
+
+        let remittances = sqlx::query_as::<_, CongregationRemittanceSummary>(&format!(
+            r#"
+            SELECT cr.id, cr.congregation_id, c.name AS congregation_name, cr.reference_month,
+                   cr.base_amount, cr.percentage, cr.amount_due, cr.status, cr.paid_at
+            FROM congregation_remittances cr
+            JOIN congregations c ON c.id = cr.congregation_id
+            WHERE {where_clause}
+            ORDER BY cr.reference_month DESC, c.name
+            LIMIT {limit} OFFSET {offset}
+            "#
+        ))
+        .bind(church_id)
+        .bind(filter.congregation_id)
+        .bind(&filter.status)
+        .bind(allowed_congregations)
+        .fetch_all(pool)
+        .await?;
+
+        Ok((remittances, total))
+    }
+
+    /// Get a remittance by ID
+    pub async fn get_by_id(
+        pool: &PgPool,
+        church_id: Uuid,
+        remittance_id: Uuid,
+    ) -> Result<CongregationRemittance, AppError> {
+        sqlx::query_as::<_, CongregationRemittance>(
+            "SELECT * FROM congregation_remittances WHERE id = $1 AND church_id = $2",
+        )
+        .bind(remittance_id)
+        .bind(church_id)
+        .fetch_optional(pool)
+        .await?
+        .ok_or_else(|| AppError::not_found("Repasse"))
+    }
+
+    /// Pay a remittance: writes a confirmed "despesa" on the congregation and the
+    /// matching "receita" on the sede, moves both balances and marks it as paid,
+    /// all in a single transaction.
+    pub async fn pay(
+        pool: &PgPool,
+        church_id: Uuid,
+        user_id: Uuid,
+        remittance_id: Uuid,
+        req: &PayRemittanceRequest,
+    ) -> Result<CongregationRemittance, AppError> {
+        if req.from_bank_account_id == req.to_bank_account_id {
+            return Err(AppError::validation(
+                "Conta de origem e destino devem ser diferentes",
+            ));
+        }
+
+        let mut tx = pool.begin().await?;
+
+        let remittance = sqlx::query_as::<_, CongregationRemittance>(
+            "SELECT * FROM congregation_remittances WHERE id = $1 AND church_id = $2 FOR UPDATE",
+        )
+        .bind(remittance_id)
+        .bind(church_id)
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or_else(|| AppError::not_found("Repasse"))?;
+
+        if remittance.status != "pendente" {
+            return Err(AppError::conflict("Repasse já foi pago"));
+        }
+        if remittance.amount_due <= Decimal::ZERO {
+            return Err(AppError::validation("Repasse sem valor a pagar"));
+        }
+
+        let active_accounts = sqlx::query_scalar::<_, i64>(
+            r#"SELECT COUNT(*) FROM bank_accounts
+               WHERE church_id = $1 AND id = ANY($2) AND is_active = TRUE"#,
+        )
+        .bind(church_id)
+        .bind([req.from_bank_account_id, req.to_bank_account_id])
+        .fetch_one(&mut *tx)
+        .await?;
+
+        if active_accounts != 2 {
+            return Err(AppError::validation(
+                "Conta de origem ou destino não encontrada ou inativa",
+            ));
+        }
+
+        let congregation_name = sqlx::query_scalar::<_, String>(
+            "SELECT name FROM congregations WHERE id = $1",
+        )
+        .bind(remittance.congregation_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let sede_id = sqlx::query_scalar::<_, Uuid>(
+            r#"SELECT id FROM congregations
+               WHERE church_id = $1 AND type = 'sede' AND is_active = TRUE
+               ORDER BY sort_order, created_at LIMIT 1"#,
+        )
+        .bind(church_id)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let out_plan_id = Self::remittance_plan_id(&mut tx, church_id, "despesa").await?;
+        let in_plan_id = Self::remittance_plan_id(&mut tx, church_id, "receita").await?;
+
+        let payment_date = req
+            .payment_date
+            .unwrap_or_else(|| chrono::Utc::now().date_naive());
+
+        // Both entries land in the payment month, on the congregation and on the sede
+        let month_closed = sqlx::query_scalar::<_, bool>(
+            r#"SELECT EXISTS(SELECT 1 FROM monthly_closings
+               WHERE church_id = $1 AND reference_month = DATE_TRUNC('month', $2::date)::date
+               AND (congregation_id IS NULL OR congregation_id IN ($3, $4)))"#,
+        )
+        .bind(church_id)
+        .bind(payment_date)
+        .bind(remittance.congregation_id)
+        .bind(sede_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        if month_closed {
+            return Err(AppError::validation(
+                "O mês da data de pagamento já foi fechado",
+            ));
+        }
+
+        let description = format!(
+            "Repasse à sede {:02}/{} — {congregation_name}",
+            remittance.reference_month.month(),
+            remittance.reference_month.year()
+        );
+
+        let mut entry_ids = Vec::with_capacity(2);
+        for (entry_type, plan_id, bank_account_id, congregation_id) in [
+            ("despesa", out_plan_id, req.from_bank_account_id, Some(remittance.congregation_id)),
+            ("receita", in_plan_id, req.to_bank_account_id, sede_id),
+        ] {
+            let entry_id = sqlx::query_scalar::<_, Uuid>(
+                r#"
+                INSERT INTO financial_entries (
+                    church_id, type, account_plan_id, bank_account_id,
+                    amount, entry_date, payment_date, description, payment_method,
+                    status, registered_by, congregation_id
+                )
+                VALUES ($1, $2, $3, $4, $5, $6, $6, $7, $8, 'confirmado', $9, $10)
+                RETURNING id
+                "#,
+            )
+            .bind(church_id)
+            .bind(entry_type)
+            .bind(plan_id)
+            .bind(bank_account_id)
+            .bind(remittance.amount_due)
+            .bind(payment_date)
+            .bind(&description)
+            .bind(&req.payment_method)
+            .bind(user_id)
+            .bind(congregation_id)
+            .fetch_one(&mut *tx)
+            .await?;
+
+            entry_ids.push(entry_id);
+        }
+
+        FinancialEntryService::sync_bank_balances(
+            &mut tx,
+            &[req.from_bank_account_id, req.to_bank_account_id],
+        )
+        .await?;
+
+        let remittance = sqlx::query_as::<_, CongregationRemittance>(
+            r#"UPDATE congregation_remittances
+               SET status = 'pago', expense_entry_id = $2, income_entry_id = $3,
+                   paid_at = NOW(), paid_by = $4
+               WHERE id = $1
+               RETURNING *"#,
+        )
+        .bind(remittance_id)
+        .bind(entry_ids[0])
+        .bind(entry_ids[1])
+        .bind(user_id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(remittance)
+    }
+
+    /// Pending remittances per congregation
+    pub async fn outstanding(
+        pool: &PgPool,
+        church_id: Uuid,
+        allowed_congregations: Option<&[Uuid]>,
+    ) -> Result<RemittanceOutstandingReport, AppError> {
+        let congregations = sqlx::query_as::<_, OutstandingRemittance>(
+            r#"
+            SELECT cr.congregation_id, c.name AS congregation_name,
+                   COUNT(*) AS pending_months,
+                   MIN(cr.reference_month) AS oldest_month,
+                   SUM(cr.amount_due) AS outstanding_amount
+            FROM congregation_remittances cr
+            JOIN congregations c ON c.id = cr.congregation_id
+            WHERE cr.church_id = $1 AND cr.status = 'pendente' AND cr.amount_due > 0
+              AND ($2::uuid[] IS NULL OR cr.congregation_id = ANY($2))
+            GROUP BY cr.congregation_id, c.name
+            ORDER BY outstanding_amount DESC
+            "#,
+        )
+        .bind(church_id)
+        .bind(allowed_congregations)
+        .fetch_all(pool)
+        .await?;
+
+        let total = congregations.iter().map(|c| c.outstanding_amount).sum();
+
+        Ok(RemittanceOutstandingReport {
+            congregations,
+            total,
+        })
+    }
+
+    /// Get (or lazily create) the church's reserved account plan for a remittance leg
+    async fn remittance_plan_id(
+        tx: &mut Transaction<'_, Postgres>,
+        church_id: Uuid,
+        plan_type: &str,
+    ) -> Result<Uuid, AppError> {
+        let (code, name) = if plan_type == "receita" {
+            (REMITTANCE_IN_PLAN_CODE, "Repasse recebido das congregações")
+        } else {
+            (REMITTANCE_OUT_PLAN_CODE, "Repasse à sede")
+        };
+
+        let (plan_id, existing_type) = sqlx::query_as::<_, (Uuid, String)>(
+            r#"
+            INSERT INTO account_plans (church_id, code, name, type, level)
+            VALUES ($1, $2, $3, $4, 1)
+            ON CONFLICT (church_id, code) DO UPDATE SET code = EXCLUDED.code
+            RETURNING id, type
+            "#,
+        )
+        .bind(church_id)
+        .bind(code)
+        .bind(name)
+        .bind(plan_type)
+        .fetch_one(&mut **tx)
+        .await?;
+
+        if existing_type != plan_type {
+            return Err(AppError::conflict(format!(
+                "O código de plano de contas '{code}' é reservado para repasses"
+            )));
+        }
+
+        Ok(plan_id)
+    }
+}
+
+fn first_day_of_month(date: NaiveDate) -> NaiveDate {
+    date.with_day(1).unwrap_or(date)
+}
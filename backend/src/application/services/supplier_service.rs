@@ -0,0 +1,298 @@
+use crate::application::dto::{
+    CreateSupplierRequest, SupplierFilter, SupplierSpendingFilter, UpdateSupplierRequest,
+};
+use crate::domain::entities::{Supplier, SupplierSpendingLine, SupplierSpendingReport};
+use crate::errors::AppError;
+use crate::infrastructure::documents;
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Spending expenses, net of their reversals (the reversal of an expense is a receita)
+const SPENDING_CONDITIONS: &str = r#"fe.church_id = $1 AND fe.status IN ('confirmado', 'estornado')
+    AND fe.deleted_at IS NULL AND fe.transfer_id IS NULL
+    AND ((fe.type = 'despesa' AND fe.reversal_of IS NULL)
+         OR (fe.type = 'receita' AND fe.reversal_of IS NOT NULL))
+    AND fe.entry_date >= $2 AND fe.entry_date <= $3
+    AND ($4::uuid IS NULL OR fe.congregation_id = $4)"#;
+
+pub struct SupplierService;
+
+impl SupplierService {
+    /// List suppliers with filters
+    pub async fn list(
+        pool: &PgPool,
+        church_id: Uuid,
+        filter: &SupplierFilter,
+        search: &Option<String>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<Supplier>, i64), AppError> {
+        let where_clause = r#"church_id = $1 AND deleted_at IS NULL
+            AND ($2::text IS NULL OR category = $2)
+            AND ($3::bool IS NULL OR is_active = $3)
+            AND ($4::text IS NULL OR unaccent(name) ILIKE '%' || unaccent($4) || '%'
+                 OR document LIKE '%' || $4 || '%')"#;
+
+        let total = sqlx::query_scalar::<_, i64>(&format!(
+            "SELECT COUNT(*) FROM suppliers WHERE {where_clause}"
+        ))
+        .bind(church_id)
+        .bind(&filter.category)
+        .bind(filter.is_active)
+        .bind(search)
+        .fetch_one(pool)
+        .await?;
+
+        let suppliers = sqlx::query_as::<_, Supplier>(&format!(
+            "SELECT * FROM suppliers WHERE {where_clause} ORDER BY name LIMIT {limit} OFFSET {offset}"
+        ))
+        .bind(church_id)
+        .bind(&filter.category)
+        .bind(filter.is_active)
+        .bind(search)
+        .fetch_all(pool)
+        .await?;
+
+        Ok((suppliers, total))
+    }
+
+    /// Get a supplier by ID
+    pub async fn get_by_id(
+        pool: &PgPool,
+        church_id: Uuid,
+        supplier_id: Uuid,
+    ) -> Result<Supplier, AppError> {
+        sqlx::query_as::<_, Supplier>(
+            "SELECT * FROM suppliers WHERE id = $1 AND church_id = $2 AND deleted_at IS NULL",
+        )
+        .bind(supplier_id)
+        .bind(church_id)
+        .fetch_optional(pool)
+        .await?
+        .ok_or_else(|| AppError::not_found("Fornecedor"))
+    }
+
+    /// Create a supplier
+    pub async fn create(
+        pool: &PgPool,
+        church_id: Uuid,
+        req: &CreateSupplierRequest,
+    ) -> Result<Supplier, AppError> {
+        let document = req.document.as_deref().map(Self::normalize_document).transpose()?.flatten();
+
+        let supplier = sqlx::query_as::<_, Supplier>(
+            r#"
+            INSERT INTO suppliers (church_id, name, document, category, contact_name, phone,
+                                   email, notes)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING *
+            "#,
+        )
+        .bind(church_id)
+        .bind(req.name.trim())
+        .bind(document)
+        .bind(&req.category)
+        .bind(&req.contact_name)
+        .bind(&req.phone)
+        .bind(&req.email)
+        .bind(&req.notes)
+        .fetch_one(pool)
+        .await
+        .map_err(Self::map_unique_document)?;
+
+        Ok(supplier)
+    }
+
+    /// Update a supplier. Linked entries keep the name they were registered with.
+    pub async fn update(
+        pool: &PgPool,
+        church_id: Uuid,
+        supplier_id: Uuid,
+        req: &UpdateSupplierRequest,
+    ) -> Result<Supplier, AppError> {
+        Self::get_by_id(pool, church_id, supplier_id).await?;
+
+        // None = keep, Some(None) = remove, Some(Some(_)) = replace
+        let document = req.document.as_deref().map(Self::normalize_document).transpose()?;
+
+        let supplier = sqlx::query_as::<_, Supplier>(
+            r#"
+            UPDATE suppliers SET
+                name = COALESCE($3, name),
+                document = CASE WHEN $4 THEN $5 ELSE document END,
+                category = COALESCE($6, category),
+                contact_name = COALESCE($7, contact_name),
+                phone = COALESCE($8, phone),
+                email = COALESCE($9, email),
+                notes = COALESCE($10, notes),
+                is_active = COALESCE($11, is_active)
+            WHERE id = $1 AND church_id = $2 AND deleted_at IS NULL
+            RETURNING *
+            "#,
+        )
+        .bind(supplier_id)
+        .bind(church_id)
+        .bind(req.name.as_deref().map(str::trim))
+        .bind(document.is_some())
+        .bind(document.flatten())
+        .bind(&req.category)
+        .bind(&req.contact_name)
+        .bind(&req.phone)
+        .bind(&req.email)
+        .bind(&req.notes)
+        .bind(req.is_active)
+        .fetch_one(pool)
+        .await
+        .map_err(Self::map_unique_document)?;
+
+        Ok(supplier)
+    }
+
+    /// Soft delete a supplier; linked entries and maintenances keep the link
+    pub async fn delete(
+        pool: &PgPool,
+        church_id: Uuid,
+        supplier_id: Uuid,
+    ) -> Result<(), AppError> {
+        let result = sqlx::query(
+            "UPDATE suppliers SET deleted_at = NOW() WHERE id = $1 AND church_id = $2 AND deleted_at IS NULL",
+        )
+        .bind(supplier_id)
+        .bind(church_id)
+        .execute(pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::not_found("Fornecedor"));
+        }
+        Ok(())
+    }
+
+    /// Name to store on an expense or maintenance linked to an active supplier
+    pub async fn name_for_link(
+        pool: &PgPool,
+        church_id: Uuid,
+        supplier_id: Uuid,
+    ) -> Result<String, AppError> {
+        let (name, is_active) = sqlx::query_as::<_, (String, bool)>(
+            "SELECT name, is_active FROM suppliers WHERE id = $1 AND church_id = $2 AND deleted_at IS NULL",
+        )
+        .bind(supplier_id)
+        .bind(church_id)
+        .fetch_optional(pool)
+        .await?
+        .ok_or_else(|| AppError::not_found("Fornecedor"))?;
+
+        if !is_active {
+            return Err(AppError::validation("Fornecedor inativo"));
+        }
+        Ok(name)
+    }
+
+    /// Net spending by supplier in the period, largest first (RN-FIN-021)
+    pub async fn spending_report(
+        pool: &PgPool,
+        church_id: Uuid,
+        congregation_id: Option<Uuid>,
+        filter: &SupplierSpendingFilter,
+    ) -> Result<SupplierSpendingReport, AppError> {
+        if filter.date_from > filter.date_to {
+            return Err(AppError::validation(
+                "'date_from' deve ser anterior ou igual a 'date_to'",
+            ));
+        }
+
+        let rows = sqlx::query_as::<
+            _,
+            (Uuid, String, Option<String>, Option<String>, i64, Decimal, Option<NaiveDate>),
+        >(&format!(
+            r#"
+            SELECT s.id, s.name, s.document, s.category,
+                   COUNT(*) FILTER (WHERE fe.type = 'despesa'),
+                   SUM(CASE WHEN fe.type = 'despesa' THEN fe.amount ELSE -fe.amount END),
+                   MAX(fe.entry_date) FILTER (WHERE fe.type = 'despesa')
+            FROM financial_entries fe
+            JOIN suppliers s ON s.id = fe.supplier_id
+            WHERE {SPENDING_CONDITIONS}
+              AND ($5::text IS NULL OR s.category = $5)
+            GROUP BY s.id, s.name, s.document, s.category
+            HAVING SUM(CASE WHEN fe.type = 'despesa' THEN fe.amount ELSE -fe.amount END) <> 0
+            ORDER BY 6 DESC, s.name
+            "#
+        ))
+        .bind(church_id)
+        .bind(filter.date_from)
+        .bind(filter.date_to)
+        .bind(congregation_id)
+        .bind(&filter.category)
+        .fetch_all(pool)
+        .await?;
+
+        let unlinked_total = sqlx::query_scalar::<_, Decimal>(&format!(
+            r#"SELECT COALESCE(SUM(CASE WHEN fe.type = 'despesa' THEN fe.amount ELSE -fe.amount END), 0)
+               FROM financial_entries fe
+               WHERE {SPENDING_CONDITIONS} AND fe.supplier_id IS NULL"#
+        ))
+        .bind(church_id)
+        .bind(filter.date_from)
+        .bind(filter.date_to)
+        .bind(congregation_id)
+        .fetch_one(pool)
+        .await?;
+
+        let total: Decimal = rows.iter().map(|r| r.5).sum();
+        let suppliers = rows
+            .into_iter()
+            .map(
+                |(supplier_id, supplier_name, document, category, entry_count, amount, last)| {
+                    SupplierSpendingLine {
+                        supplier_id,
+                        supplier_name,
+                        document,
+                        category,
+                        entry_count,
+                        total: amount,
+                        percentage: if total.is_zero() {
+                            Decimal::ZERO
+                        } else {
+                            (amount * Decimal::from(100) / total).round_dp(2)
+                        },
+                        last_entry_date: last,
+                    }
+                },
+            )
+            .collect();
+
+        Ok(SupplierSpendingReport {
+            date_from: filter.date_from,
+            date_to: filter.date_to,
+            congregation_id,
+            category: filter.category.clone(),
+            suppliers,
+            total,
+            unlinked_total,
+        })
+    }
+
+    /// Digits of a valid CPF or CNPJ; an empty value means no document
+    fn normalize_document(value: &str) -> Result<Option<String>, AppError> {
+        let digits = documents::only_digits(value);
+        match digits.len() {
+            0 if value.trim().is_empty() => Ok(None),
+            11 if documents::is_valid_cpf(&digits) => Ok(Some(digits)),
+            14 if documents::is_valid_cnpj(&digits) => Ok(Some(digits)),
+            _ => Err(AppError::validation("CPF ou CNPJ inválido")),
+        }
+    }
+
+    fn map_unique_document(e: sqlx::Error) -> AppError {
+        match &e {
+            sqlx::Error::Database(db) if db.is_unique_violation() => {
+                AppError::conflict("Já existe um fornecedor com este CPF/CNPJ")
+            }
+            _ => AppError::from(e),
+        }
+    }
+}
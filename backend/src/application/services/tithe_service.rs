@@ -0,0 +1,357 @@
+use crate::application::services::ChurchService;
+use crate::domain::entities::{
+    TitheStatement, TitheStatementCategory, TitheStatementEntry, TitheStatementMonth,
+    TitherMonth, TitherRegularity, TitherRegularityReport,
+};
+use crate::errors::AppError;
+use crate::infrastructure::pdf::{self, Column, Letterhead, PdfReport, Row};
+use chrono::{Datelike, Local, Months, NaiveDate};
+use std::collections::HashMap;
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Member contributions: confirmed income lines (`l`, from `financial_entry_lines`)
+/// linked to the member. Reversed entries and their reversals are left out (they
+/// cancel each other).
+const MEMBER_CONTRIBUTIONS: &str = r#"
+    fe.church_id = $1 AND l.member_id = $2 AND fe.type = 'receita'
+    AND fe.status = 'confirmado' AND fe.reversal_of IS NULL AND fe.transfer_id IS NULL
+    AND fe.deleted_at IS NULL
+    AND EXTRACT(YEAR FROM COALESCE(fe.payment_date, fe.entry_date))::int = $3
+"#;
+
+/// RN-FIN-006 thresholds, in months with tithe out of the last 12
+const REGULAR_MIN_MONTHS: i32 = 10;
+const IRREGULAR_MIN_MONTHS: i32 = 6;
+const REGULARITY_WINDOW_MONTHS: u32 = 12;
+
+pub struct TitheService;
+
+impl TitheService {
+    /// Annual contribution statement of a member (RN-FIN-005)
+    pub async fn annual_statement(
+        pool: &PgPool,
+        church_id: Uuid,
+        member_id: Uuid,
+        year: i32,
+    ) -> Result<TitheStatement, AppError> {
+        let (member_name, member_cpf) = sqlx::query_as::<_, (String, Option<String>)>(
+            "SELECT full_name, cpf FROM members WHERE id = $1 AND church_id = $2 AND deleted_at IS NULL",
+        )
+        .bind(member_id)
+        .bind(church_id)
+        .fetch_optional(pool)
+        .await?
+        .ok_or_else(|| AppError::not_found("Membro"))?;
+
+        let entries = sqlx::query_as::<_, TitheStatementEntry>(&format!(
+            r#"
+            SELECT fe.id, COALESCE(fe.payment_date, fe.entry_date) AS date,
+                   ap.name AS account_plan_name, l.amount, fe.payment_method
+            FROM financial_entries fe
+            JOIN financial_entry_lines l ON l.entry_id = fe.id
+            JOIN account_plans ap ON ap.id = l.account_plan_id
+            WHERE {MEMBER_CONTRIBUTIONS}
+            ORDER BY date ASC, fe.created_at ASC
+            "#
+        ))
+        .bind(church_id)
+        .bind(member_id)
+        .bind(year)
+        .fetch_all(pool)
+        .await?;
+
+        let categories = sqlx::query_as::<_, TitheStatementCategory>(&format!(
+            r#"
+            SELECT ap.id AS account_plan_id, ap.name AS account_plan_name,
+                   SUM(l.amount) AS amount, COUNT(*) AS count
+            FROM financial_entries fe
+            JOIN financial_entry_lines l ON l.entry_id = fe.id
+            JOIN account_plans ap ON ap.id = l.account_plan_id
+            WHERE {MEMBER_CONTRIBUTIONS}
+            GROUP BY ap.id, ap.name, ap.code
+            ORDER BY ap.code
+            "#
+        ))
+        .bind(church_id)
+        .bind(member_id)
+        .bind(year)
+        .fetch_all(pool)
+        .await?;
+
+        let mut months: Vec<TitheStatementMonth> = (1..=12)
+            .map(|month| TitheStatementMonth {
+                month,
+                amount: Decimal::ZERO,
+                count: 0,
+            })
+            .collect();
+        for entry in &entries {
+            let month = &mut months[entry.date.month0() as usize];
+            month.amount += entry.amount;
+            month.count += 1;
+        }
+
+        Ok(TitheStatement {
+            member_id,
+            member_name,
+            member_cpf,
+            year,
+            total: entries.iter().map(|e| e.amount).sum(),
+            entry_count: entries.len() as i64,
+            months,
+            categories,
+            entries,
+        })
+    }
+
+    /// Annual statement as a PDF with the church letterhead
+    pub async fn annual_statement_pdf(
+        pool: &PgPool,
+        church_id: Uuid,
+        member_id: Uuid,
+        year: i32,
+    ) -> Result<Vec<u8>, AppError> {
+        let statement = Self::annual_statement(pool, church_id, member_id, year).await?;
+        let church = ChurchService::get_by_id(pool, church_id).await?;
+        let letterhead = Letterhead::for_church(&church).await;
+
+        let mut report = PdfReport::new(
+            letterhead,
+            &format!("Declaração Anual de Contribuições — {year}"),
+        );
+
+        let identification = match statement.member_cpf.as_deref().filter(|c| !c.is_empty()) {
+            Some(cpf) => format!("{}, CPF {cpf},", statement.member_name),
+            None => format!("{},", statement.member_name),
+        };
+        report.paragraph(&format!(
+            "Declaramos, para os devidos fins, que {identification} contribuiu com esta igreja \
+             no ano de {year} com o valor total de {}, conforme discriminado abaixo.",
+            pdf::brl(statement.total)
+        ));
+
+        report.heading("Contribuições por mês");
+        let mut rows: Vec<Row> = statement
+            .months
+            .iter()
+            .map(|m| {
+                Row::new(vec![
+                    pdf::month_name(m.month).to_string(),
+                    m.count.to_string(),
+                    pdf::brl(m.amount),
+                ])
+            })
+            .collect();
+        rows.push(Row::bold(vec![
+            "Total".to_string(),
+            statement.entry_count.to_string(),
+            pdf::brl(statement.total),
+        ]));
+        report.table(
+            &[
+                Column::left("Mês", 275.0),
+                Column::center("Lançamentos", 100.0),
+                Column::right("Valor", 140.28),
+            ],
+            &rows,
+        );
+
+        if !statement.categories.is_empty() {
+            report.heading("Contribuições por categoria");
+            let rows: Vec<Row> = statement
+                .categories
+                .iter()
+                .map(|c| {
+                    Row::new(vec![
+                        c.account_plan_name.clone(),
+                        c.count.to_string(),
+                        pdf::brl(c.amount),
+                    ])
+                })
+                .collect();
+            report.table(
+                &[
+                    Column::left("Categoria", 275.0),
+                    Column::center("Lançamentos", 100.0),
+                    Column::right("Valor", 140.28),
+                ],
+                &rows,
+            );
+        }
+
+        if !statement.entries.is_empty() {
+            report.heading("Lançamentos");
+            let rows: Vec<Row> = statement
+                .entries
+                .iter()
+                .map(|e| {
+                    Row::new(vec![
+                        pdf::date_br(e.date),
+                        e.account_plan_name.clone(),
+                        e.payment_method.clone().unwrap_or_default(),
+                        pdf::brl(e.amount),
+                    ])
+                })
+                .collect();
+            report.table(
+                &[
+                    Column::left("Data", 75.0),
+                    Column::left("Categoria", 200.0),
+                    Column::left("Forma", 100.0),
+                    Column::right("Valor", 140.28),
+                ],
+                &rows,
+            );
+        }
+
+        report.key_value("Total do ano", &pdf::brl(statement.total), true);
+
+        let today = Local::now().date_naive();
+        let place = church.city.as_deref().map(|c| format!("{c}, ")).unwrap_or_default();
+        report.spacer(10.0);
+        report.paragraph(&format!(
+            "{place}{} de {} de {}.",
+            today.day(),
+            pdf::month_name(today.month()).to_lowercase(),
+            today.year()
+        ));
+
+        match church.pastor_name.as_deref().filter(|p| !p.is_empty()) {
+            Some(pastor) => report.signature(pastor, "Pastor Presidente"),
+            None => report.signature("Tesouraria", &church.name),
+        }
+
+        Ok(report.finish())
+    }
+
+    /// Classify active members (or only `member_id`, whatever its status) as regular,
+    /// irregular or inactive tithers (RN-FIN-006), from confirmed entries in tithe
+    /// categories over the 12 months ending at `reference_month`.
+    /// The congregation filter applies to the member's congregation.
+    pub async fn regularity(
+        pool: &PgPool,
+        church_id: Uuid,
+        congregation_id: Option<Uuid>,
+        member_id: Option<Uuid>,
+        reference_month: NaiveDate,
+    ) -> Result<TitherRegularityReport, AppError> {
+        let period_end_month = reference_month.with_day(1).unwrap_or(reference_month);
+        let period_start = period_end_month - Months::new(REGULARITY_WINDOW_MONTHS - 1);
+        let period_end = period_end_month + Months::new(1) - chrono::Days::new(1);
+
+        let members = sqlx::query_as::<_, (Uuid, String, Option<Uuid>, Option<String>)>(
+            r#"
+            SELECT m.id, m.full_name, m.congregation_id, c.name
+            FROM members m
+            LEFT JOIN congregations c ON c.id = m.congregation_id
+            WHERE m.church_id = $1 AND m.deleted_at IS NULL
+              AND ($2::uuid IS NULL OR m.congregation_id = $2)
+              AND ($3::uuid IS NULL AND m.status IN ('ativo', 'congregado') OR m.id = $3)
+            ORDER BY m.full_name
+            "#,
+        )
+        .bind(church_id)
+        .bind(congregation_id)
+        .bind(member_id)
+        .fetch_all(pool)
+        .await?;
+
+        let tithe_entries = r#"
+            FROM financial_entries fe
+            JOIN financial_entry_lines l ON l.entry_id = fe.id
+            JOIN account_plans ap ON ap.id = l.account_plan_id AND ap.is_tithe = TRUE
+            WHERE fe.church_id = $1 AND l.member_id IS NOT NULL AND fe.type = 'receita'
+              AND fe.status = 'confirmado' AND fe.reversal_of IS NULL AND fe.deleted_at IS NULL
+              AND ($2::uuid IS NULL OR l.member_id = $2)
+        "#;
+
+        let monthly = sqlx::query_as::<_, (Uuid, NaiveDate, Decimal)>(&format!(
+            r#"
+            SELECT l.member_id,
+                   date_trunc('month', COALESCE(fe.payment_date, fe.entry_date))::date AS month,
+                   SUM(l.amount)
+            {tithe_entries}
+              AND COALESCE(fe.payment_date, fe.entry_date) BETWEEN $3 AND $4
+            GROUP BY 1, 2
+            "#
+        ))
+        .bind(church_id)
+        .bind(member_id)
+        .bind(period_start)
+        .bind(period_end)
+        .fetch_all(pool)
+        .await?;
+
+        let last_tithes = sqlx::query_as::<_, (Uuid, NaiveDate)>(&format!(
+            r#"
+            SELECT l.member_id, MAX(COALESCE(fe.payment_date, fe.entry_date))
+            {tithe_entries}
+              AND COALESCE(fe.payment_date, fe.entry_date) <= $3
+            GROUP BY 1
+            "#
+        ))
+        .bind(church_id)
+        .bind(member_id)
+        .bind(period_end)
+        .fetch_all(pool)
+        .await?;
+
+        let mut amounts: HashMap<(Uuid, NaiveDate), Decimal> = HashMap::new();
+        for (member, month, amount) in monthly {
+            amounts.insert((member, month), amount);
+        }
+        let last_tithes: HashMap<Uuid, NaiveDate> = last_tithes.into_iter().collect();
+
+        let months: Vec<NaiveDate> = (0..REGULARITY_WINDOW_MONTHS)
+            .map(|i| period_start + Months::new(i))
+            .collect();
+
+        let mut report = TitherRegularityReport {
+            period_start,
+            period_end,
+            congregation_id,
+            regular_count: 0,
+            irregular_count: 0,
+            inactive_count: 0,
+            members: Vec::with_capacity(members.len()),
+        };
+
+        for (id, name, member_congregation_id, congregation_name) in members {
+            let history: Vec<TitherMonth> = months
+                .iter()
+                .map(|&month| TitherMonth {
+                    month,
+                    amount: amounts.get(&(id, month)).copied().unwrap_or(Decimal::ZERO),
+                })
+                .collect();
+            let months_with_tithe = history.iter().filter(|m| m.amount > Decimal::ZERO).count() as i32;
+
+            let status = if months_with_tithe >= REGULAR_MIN_MONTHS {
+                report.regular_count += 1;
+                "regular"
+            } else if months_with_tithe >= IRREGULAR_MIN_MONTHS {
+                report.irregular_count += 1;
+                "irregular"
+            } else {
+                report.inactive_count += 1;
+                "inativo"
+            };
+
+            report.members.push(TitherRegularity {
+                member_id: id,
+                member_name: name,
+                congregation_id: member_congregation_id,
+                congregation_name,
+                status: status.to_string(),
+                months_with_tithe,
+                total: history.iter().map(|m| m.amount).sum(),
+                last_tithe_date: last_tithes.get(&id).copied(),
+                history,
+            });
+        }
+
+        Ok(report)
+    }
+}
@@ -0,0 +1,331 @@
+use crate::application::dto::{CreateTransferRequest, TransferFilter};
+use crate::application::services::financial_service::ENTRY_COLUMNS;
+use crate::application::services::FinancialEntryService;
+use crate::domain::entities::{FinancialEntry, FinancialTransfer, FinancialTransferSummary};
+use crate::errors::AppError;
+use rust_decimal::Decimal;
+use sqlx::{PgPool, Postgres, Transaction};
+use uuid::Uuid;
+
+/// Account plan codes reserved for the two legs of a transfer
+const TRANSFER_IN_PLAN_CODE: &str = "TRF-E";
+const TRANSFER_OUT_PLAN_CODE: &str = "TRF-S";
+
+pub struct TransferService;
+
+impl TransferService {
+    /// List transfers (one row per transfer, not per leg)
+    pub async fn list(
+        pool: &PgPool,
+        church_id: Uuid,
+        filter: &TransferFilter,
+        limit: i64,
+        offset: i64,
+    ) -> Result<(Vec<FinancialTransferSummary>, i64), AppError> {
+        let mut conditions = vec![
+            "o.church_id = $1".to_string(),
+            "o.type = 'despesa'".to_string(),
+            "o.transfer_id IS NOT NULL".to_string(),
+            "o.deleted_at IS NULL".to_string(),
+        ];
+        let mut param_idx = 2u32;
+
+        if filter.bank_account_id.is_some() {
+            conditions.push(format!(
+                "(o.bank_account_id = ${param_idx} OR i.bank_account_id = ${param_idx})"
+            ));
+            param_idx += 1;
+        }
+        if filter.date_from.is_some() {
+            conditions.push(format!("o.entry_date >= ${param_idx}"));
+            param_idx += 1;
+        }
+        if filter.date_to.is_some() {
+            conditions.push(format!("o.entry_date <= ${param_idx}"));
+            param_idx += 1;
+        }
+
+        let _ = param_idx;
+        let where_clause = conditions.join(" AND ");
+
+        let from_clause = r#"
+            FROM financial_entries o
+            JOIN financial_entries i ON i.transfer_id = o.transfer_id
+                 AND i.type = 'receita' AND i.deleted_at IS NULL
+        "#;
+
+        let count_sql = format!("SELECT COUNT(*) {from_clause} WHERE {where_clause}");
+
+        let query_sql = format!(
+            r#"
+            SELECT o.transfer_id, o.entry_date AS transfer_date, o.amount, o.description,
+                   o.bank_account_id AS from_bank_account_id,
+                   bo.name AS from_bank_account_name,
+                   i.bank_account_id AS to_bank_account_id,
+                   bi.name AS to_bank_account_name,
+                   o.is_closed, o.created_at
+            {from_clause}
+            LEFT JOIN bank_accounts bo ON bo.id = o.bank_account_id
+            LEFT JOIN bank_accounts bi ON bi.id = i.bank_account_id
+            WHERE {where_clause}
+            ORDER BY o.entry_date DESC, o.created_at DESC
+            LIMIT {limit} OFFSET {offset}
+            "#
+        );
+
+        let mut count_args = sqlx::postgres::PgArguments::default();
+        let mut data_args = sqlx::postgres::PgArguments::default();
+
+        sqlx::Arguments::add(&mut count_args, church_id).unwrap();
+        sqlx::Arguments::add(&mut data_args, church_id).unwrap();
+
+        if let Some(ba_id) = filter.bank_account_id {
+            sqlx::Arguments::add(&mut count_args, ba_id).unwrap();
+            sqlx::Arguments::add(&mut data_args, ba_id).unwrap();
+        }
+        if let Some(date_from) = filter.date_from {
+            sqlx::Arguments::add(&mut count_args, date_from).unwrap();
+            sqlx::Arguments::add(&mut data_args, date_from).unwrap();
+        }
+        if let Some(date_to) = filter.date_to {
+            sqlx::Arguments::add(&mut count_args, date_to).unwrap();
+            sqlx::Arguments::add(&mut data_args, date_to).unwrap();
+        }
+
+        let total = sqlx::query_scalar_with::<_, i64, _>(&count_sql, count_args)
+            .fetch_one(pool)
+            .await?;
+
+        let transfers =
+            sqlx::query_as_with::<_, FinancialTransferSummary, _>(&query_sql, data_args)
+                .fetch_all(pool)
+                .await?;
+
+        Ok((transfers, total))
+    }
+
+    /// Get both legs of a transfer
+    pub async fn get_by_id(
+        pool: &PgPool,
+        church_id: Uuid,
+        transfer_id: Uuid,
+    ) -> Result<FinancialTransfer, AppError> {
+        let legs = sqlx::query_as::<_, FinancialEntry>(&format!(
+            r#"SELECT {ENTRY_COLUMNS} FROM financial_entries
+               WHERE transfer_id = $1 AND church_id = $2 AND deleted_at IS NULL"#
+        ))
+        .bind(transfer_id)
+        .bind(church_id)
+        .fetch_all(pool)
+        .await?;
+
+        Self::pair_legs(transfer_id, legs)
+    }
+
+    /// Create a transfer between two bank accounts (RN-FIN-009).
+    /// Writes a "despesa" on the source and a "receita" on the destination, linked by
+    /// the same `transfer_id`, and moves both balances in a single transaction.
+    pub async fn create(
+        pool: &PgPool,
+        church_id: Uuid,
+        user_id: Uuid,
+        req: &CreateTransferRequest,
+    ) -> Result<FinancialTransfer, AppError> {
+        if req.amount <= Decimal::ZERO {
+            return Err(AppError::validation("Valor deve ser maior que zero"));
+        }
+
+        if req.from_bank_account_id == req.to_bank_account_id {
+            return Err(AppError::validation(
+                "Conta de origem e destino devem ser diferentes",
+            ));
+        }
+
+        let from_name = Self::active_account_name(pool, church_id, req.from_bank_account_id)
+            .await?
+            .ok_or_else(|| AppError::validation("Conta de origem não encontrada ou inativa"))?;
+        let to_name = Self::active_account_name(pool, church_id, req.to_bank_account_id)
+            .await?
+            .ok_or_else(|| AppError::validation("Conta de destino não encontrada ou inativa"))?;
+
+        let description = req
+            .description
+            .clone()
+            .unwrap_or_else(|| format!("Transferência: {from_name} → {to_name}"));
+
+        let transfer_id = Uuid::new_v4();
+        let mut tx = pool.begin().await?;
+
+        let out_plan_id = Self::transfer_plan_id(&mut tx, church_id, "despesa").await?;
+        let in_plan_id = Self::transfer_plan_id(&mut tx, church_id, "receita").await?;
+
+        let insert_sql = format!(
+            r#"
+            INSERT INTO financial_entries (
+                church_id, type, account_plan_id, bank_account_id,
+                amount, entry_date, payment_date, description, payment_method,
+                status, registered_by, notes, congregation_id, transfer_id
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $6, $7, $8, 'confirmado', $9, $10, $11, $12)
+            RETURNING {ENTRY_COLUMNS}
+            "#
+        );
+
+        let mut legs = Vec::with_capacity(2);
+        for (entry_type, plan_id, bank_account_id) in [
+            ("despesa", out_plan_id, req.from_bank_account_id),
+            ("receita", in_plan_id, req.to_bank_account_id),
+        ] {
+            let leg = sqlx::query_as::<_, FinancialEntry>(&insert_sql)
+                .bind(church_id)
+                .bind(entry_type)
+                .bind(plan_id)
+                .bind(bank_account_id)
+                .bind(req.amount)
+                .bind(req.transfer_date)
+                .bind(&description)
+                .bind(&req.payment_method)
+                .bind(user_id)
+                .bind(&req.notes)
+                .bind(req.congregation_id)
+                .bind(transfer_id)
+                .fetch_one(&mut *tx)
+                .await?;
+
+            legs.push(leg);
+        }
+
+        FinancialEntryService::sync_bank_balances(
+            &mut tx,
+            &[req.from_bank_account_id, req.to_bank_account_id],
+        )
+        .await?;
+
+        tx.commit().await?;
+
+        Self::pair_legs(transfer_id, legs)
+    }
+
+    /// Cancel a transfer: soft-deletes both legs and restores both balances
+    pub async fn cancel(
+        pool: &PgPool,
+        church_id: Uuid,
+        transfer_id: Uuid,
+    ) -> Result<(), AppError> {
+        let transfer = Self::get_by_id(pool, church_id, transfer_id).await?;
+
+        if transfer.outgoing.is_closed || transfer.incoming.is_closed {
+            return Err(AppError::validation(
+                "Não é possível cancelar uma transferência de mês já fechado",
+            ));
+        }
+
+        if transfer.outgoing.reconciled_at.is_some() || transfer.incoming.reconciled_at.is_some() {
+            return Err(AppError::validation(
+                "Transferência conciliada com o extrato: desfaça a conciliação antes de cancelar",
+            ));
+        }
+
+        let mut tx = pool.begin().await?;
+
+        let result = sqlx::query(
+            r#"UPDATE financial_entries SET deleted_at = NOW(), status = 'cancelado'
+               WHERE transfer_id = $1 AND church_id = $2 AND deleted_at IS NULL AND is_closed = FALSE"#,
+        )
+        .bind(transfer_id)
+        .bind(church_id)
+        .execute(&mut *tx)
+        .await?;
+
+        if result.rows_affected() != 2 {
+            return Err(AppError::conflict(
+                "A transferência foi alterada por outra operação, tente novamente",
+            ));
+        }
+
+        FinancialEntryService::sync_bank_balances(
+            &mut tx,
+            &[transfer.outgoing.bank_account_id, transfer.incoming.bank_account_id],
+        )
+        .await?;
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn active_account_name(
+        pool: &PgPool,
+        church_id: Uuid,
+        bank_account_id: Uuid,
+    ) -> Result<Option<String>, AppError> {
+        let name = sqlx::query_scalar::<_, String>(
+            "SELECT name FROM bank_accounts WHERE id = $1 AND church_id = $2 AND is_active = TRUE",
+        )
+        .bind(bank_account_id)
+        .bind(church_id)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(name)
+    }
+
+    /// Get (or lazily create) the church's reserved account plan for a transfer leg
+    async fn transfer_plan_id(
+        tx: &mut Transaction<'_, Postgres>,
+        church_id: Uuid,
+        plan_type: &str,
+    ) -> Result<Uuid, AppError> {
+        let (code, name) = if plan_type == "receita" {
+            (TRANSFER_IN_PLAN_CODE, "Transferência entre contas (entrada)")
+        } else {
+            (TRANSFER_OUT_PLAN_CODE, "Transferência entre contas (saída)")
+        };
+
+        let (plan_id, existing_type) = sqlx::query_as::<_, (Uuid, String)>(
+            r#"
+            INSERT INTO account_plans (church_id, code, name, type, level)
+            VALUES ($1, $2, $3, $4, 1)
+            ON CONFLICT (church_id, code) DO UPDATE SET code = EXCLUDED.code
+            RETURNING id, type
+            "#,
+        )
+        .bind(church_id)
+        .bind(code)
+        .bind(name)
+        .bind(plan_type)
+        .fetch_one(&mut **tx)
+        .await?;
+
+        if existing_type != plan_type {
+            return Err(AppError::conflict(format!(
+                "O código de plano de contas '{code}' é reservado para transferências"
+            )));
+        }
+
+        Ok(plan_id)
+    }
+
+    fn pair_legs(
+        transfer_id: Uuid,
+        legs: Vec<FinancialEntry>,
+    ) -> Result<FinancialTransfer, AppError> {
+        let mut outgoing = None;
+        let mut incoming = None;
+        for leg in legs {
+            if leg.entry_type == "despesa" {
+                outgoing = Some(leg);
+            } else {
+                incoming = Some(leg);
+            }
+        }
+
+        match (outgoing, incoming) {
+            (Some(outgoing), Some(incoming)) => Ok(FinancialTransfer {
+                transfer_id,
+                outgoing,
+                incoming,
+            }),
+            _ => Err(AppError::not_found("Transferência")),
+        }
+    }
+}
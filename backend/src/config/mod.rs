@@ -20,6 +20,8 @@ pub struct AppConfig {
     pub smtp_username: String,
     pub smtp_password: String,
     pub smtp_from: String,
+    /// Accepts webhooks from the `mock` payment provider (development only)
+    pub payment_mock_provider: bool,
 }
 
 impl AppConfig {
@@ -56,6 +58,9 @@ impl AppConfig {
             smtp_username: env::var("SMTP_USERNAME").unwrap_or_default(),
             smtp_password: env::var("SMTP_PASSWORD").unwrap_or_default(),
             smtp_from: env::var("SMTP_FROM").unwrap_or_else(|_| "noreply@igrejamanager.com".to_string()),
+            payment_mock_provider: env::var("PAYMENT_MOCK_PROVIDER")
+                .map(|v| v == "true")
+                .unwrap_or(false),
         }
     }
 
@@ -16,6 +16,8 @@ pub struct AccountPlan {
     pub plan_type: String,
     pub level: i16,
     pub is_active: bool,
+    /// Income category whose entries count as tithes (RN-FIN-006)
+    pub is_tithe: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -32,6 +34,7 @@ pub struct AccountPlanSummary {
     pub plan_type: String,
     pub level: i16,
     pub is_active: bool,
+    pub is_tithe: bool,
     pub parent_name: Option<String>,
     pub children_count: Option<i64>,
     pub created_at: DateTime<Utc>,
@@ -0,0 +1,37 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Column of an accounting export: header text and the entry field it shows.
+/// Fields: date, document, entry_type, side, account_code, account_name,
+/// debit_account, credit_account, bank_account_code, bank_account_name, amount,
+/// debit, credit, history, description, payment_method, member, supplier,
+/// campaign, congregation
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ExportColumn {
+    pub header: String,
+    pub field: String,
+}
+
+/// Layout of the accountant export (RN-FIN-019)
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct AccountingExportLayout {
+    pub id: Uuid,
+    pub church_id: Uuid,
+    pub name: String,
+    /// List of `ExportColumn`
+    pub columns: serde_json::Value,
+    /// Account plan code → accountant's account (applies to subcategories too)
+    pub account_codes: serde_json::Value,
+    /// Bank account ID → accountant's account
+    pub bank_account_codes: serde_json::Value,
+    pub delimiter: String,
+    /// "1234,56" instead of "1234.56" in CSV
+    pub decimal_comma: bool,
+    /// Append income/expense totals (checked against the closing)
+    pub include_totals: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
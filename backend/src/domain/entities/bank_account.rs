@@ -16,9 +16,33 @@ pub struct BankAccount {
     pub bank_name: Option<String>,
     pub agency: Option<String>,
     pub account_number: Option<String>,
+    /// "cpf", "cnpj", "email", "telefone" or "aleatoria"
+    pub pix_key_type: Option<String>,
+    pub pix_key: Option<String>,
     pub initial_balance: Decimal,
     pub current_balance: Decimal,
     pub is_active: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
+
+/// Cached balance of a bank account compared with its ledger
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct BankBalanceCheck {
+    pub bank_account_id: Uuid,
+    pub name: String,
+    pub initial_balance: Decimal,
+    pub current_balance: Decimal,
+    /// initial_balance + confirmed/reversed entries
+    pub ledger_balance: Decimal,
+    /// current_balance - ledger_balance
+    pub difference: Decimal,
+}
+
+/// Result of a balance verification; `repaired` is set when drift was fixed
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BankBalanceVerification {
+    pub checked_accounts: usize,
+    pub drifted: Vec<BankBalanceCheck>,
+    pub repaired: bool,
+}
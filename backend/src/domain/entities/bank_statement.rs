@@ -0,0 +1,101 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Imported bank statement (OFX/CSV) for one bank account
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct BankStatement {
+    pub id: Uuid,
+    pub church_id: Uuid,
+    pub bank_account_id: Uuid,
+    pub file_name: Option<String>,
+    /// "ofx" or "csv"
+    pub format: String,
+    pub period_start: Option<NaiveDate>,
+    pub period_end: Option<NaiveDate>,
+    pub statement_balance: Option<Decimal>,
+    pub balance_date: Option<NaiveDate>,
+    pub imported_by: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Statement row for list views, with reconciliation progress
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct BankStatementSummary {
+    pub id: Uuid,
+    pub file_name: Option<String>,
+    pub format: String,
+    pub period_start: Option<NaiveDate>,
+    pub period_end: Option<NaiveDate>,
+    pub statement_balance: Option<Decimal>,
+    pub total_lines: Option<i64>,
+    pub reconciled_lines: Option<i64>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// One movement of a statement and the entry it was reconciled with
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct BankStatementLine {
+    pub id: Uuid,
+    pub statement_id: Uuid,
+    pub line_date: NaiveDate,
+    /// Positive = credit, negative = debit
+    pub amount: Decimal,
+    pub description: String,
+    pub external_id: String,
+    /// "pendente" or "conciliado"
+    pub status: String,
+    pub matched_entry_id: Option<Uuid>,
+    pub matched_entry_description: Option<String>,
+    pub matched_at: Option<DateTime<Utc>>,
+}
+
+/// Candidate entry for an unreconciled statement line
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct ReconciliationSuggestion {
+    pub line_id: Uuid,
+    pub entry_id: Uuid,
+    pub entry_date: NaiveDate,
+    pub description: String,
+    pub amount: Decimal,
+    pub status: String,
+    /// Days between the line and the entry's payment (or entry) date
+    pub day_difference: i32,
+    /// 0-100, higher is a better match
+    #[sqlx(default)]
+    pub score: i32,
+}
+
+/// Statement balance compared with the account's book balance
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ReconciliationBalance {
+    pub statement_balance: Option<Decimal>,
+    pub balance_date: Option<NaiveDate>,
+    pub current_balance: Decimal,
+    /// statement_balance - current_balance
+    pub difference: Option<Decimal>,
+    pub total_lines: i64,
+    pub reconciled_lines: i64,
+    /// Entries of this account within the statement period not matched to any line
+    pub unreconciled_entries: i64,
+}
+
+/// Statement with its lines, match suggestions and balance comparison
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BankStatementDetail {
+    pub statement: BankStatement,
+    pub lines: Vec<BankStatementLine>,
+    pub suggestions: Vec<ReconciliationSuggestion>,
+    pub balance: ReconciliationBalance,
+}
+
+/// Result of an import: re-imported movements are skipped by `external_id`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BankStatementImport {
+    pub statement: BankStatement,
+    pub imported_lines: i64,
+    pub skipped_lines: i64,
+}
@@ -0,0 +1,89 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Planned amount for an account plan category in a year or month
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct Budget {
+    pub id: Uuid,
+    pub church_id: Uuid,
+    pub account_plan_id: Uuid,
+    /// None = church-wide budget
+    pub congregation_id: Option<Uuid>,
+    pub year: i16,
+    /// None = whole year
+    pub month: Option<i16>,
+    pub amount: Decimal,
+    pub notes: Option<String>,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+/// Budget with related names for list views
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct BudgetSummary {
+    pub id: Uuid,
+    pub account_plan_id: Uuid,
+    pub account_plan_code: String,
+    pub account_plan_name: String,
+    #[sqlx(rename = "type")]
+    #[serde(rename = "type")]
+    pub plan_type: String,
+    pub congregation_id: Option<Uuid>,
+    pub congregation_name: Option<String>,
+    pub year: i16,
+    pub month: Option<i16>,
+    pub amount: Decimal,
+    pub notes: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Budget vs actual of an account plan; values include its subcategories
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BudgetReportLine {
+    pub account_plan_id: Uuid,
+    pub parent_id: Option<Uuid>,
+    pub code: String,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub plan_type: String,
+    pub level: i16,
+    pub budgeted: Decimal,
+    pub actual: Decimal,
+    /// budgeted - actual
+    pub variance: Decimal,
+    /// actual / budgeted × 100; None without budget
+    pub percent_used: Option<Decimal>,
+    pub over_budget: bool,
+}
+
+/// Expense category whose actual exceeded the budget
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BudgetAlert {
+    pub account_plan_id: Uuid,
+    pub code: String,
+    pub name: String,
+    pub budgeted: Decimal,
+    pub actual: Decimal,
+    pub exceeded_by: Decimal,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BudgetReport {
+    pub year: i16,
+    pub month: Option<i16>,
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+    pub congregation_id: Option<Uuid>,
+    pub total_income_budgeted: Decimal,
+    pub total_income_actual: Decimal,
+    pub total_expense_budgeted: Decimal,
+    pub total_expense_actual: Decimal,
+    pub lines: Vec<BudgetReportLine>,
+    pub alerts: Vec<BudgetAlert>,
+}
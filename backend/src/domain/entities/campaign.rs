@@ -34,3 +34,62 @@ pub struct CampaignSummary {
     pub entries_count: Option<i64>,
     pub created_at: DateTime<Utc>,
 }
+
+/// Amount a member promised to give to a campaign, in monthly installments
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct CampaignPledge {
+    pub id: Uuid,
+    pub church_id: Uuid,
+    pub campaign_id: Uuid,
+    pub member_id: Uuid,
+    pub pledged_amount: Decimal,
+    pub installments: i16,
+    pub start_date: NaiveDate,
+    pub notes: Option<String>,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+/// Pledge with what the member has already given to the campaign
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct CampaignPledgeSummary {
+    pub id: Uuid,
+    pub member_id: Uuid,
+    pub member_name: String,
+    pub pledged_amount: Decimal,
+    pub installments: i16,
+    pub installment_amount: Decimal,
+    pub start_date: NaiveDate,
+    /// Installments due up to today, in money
+    pub expected_to_date: Decimal,
+    pub paid_amount: Decimal,
+    pub remaining: Decimal,
+    pub notes: Option<String>,
+}
+
+/// Goal, pledged and raised amounts of a campaign
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CampaignReport {
+    pub campaign_id: Uuid,
+    pub name: String,
+    pub status: String,
+    pub start_date: NaiveDate,
+    pub end_date: Option<NaiveDate>,
+    pub goal_amount: Option<Decimal>,
+    pub pledged_amount: Decimal,
+    pub raised_amount: Decimal,
+    /// Raised from members who pledged
+    pub pledges_paid_amount: Decimal,
+    /// raised / goal × 100; None without goal
+    pub percent_of_goal: Option<Decimal>,
+    /// pledged / goal × 100; None without goal
+    pub percent_pledged: Option<Decimal>,
+    /// pledges paid / pledged × 100; None without pledges
+    pub percent_pledges_paid: Option<Decimal>,
+    pub pledges_count: i64,
+    pub donors_count: i64,
+    pub entries_count: i64,
+    pub pledges: Vec<CampaignPledgeSummary>,
+}
@@ -0,0 +1,28 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Numbered receipt for an income entry. Numbers are sequential per church and
+/// never reused: cancelling the entry only voids the receipt.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct DonationReceipt {
+    pub id: Uuid,
+    pub church_id: Uuid,
+    pub number: i32,
+    pub financial_entry_id: Uuid,
+    pub member_id: Option<Uuid>,
+    pub donor_name: String,
+    pub donor_document: Option<String>,
+    pub amount: Decimal,
+    pub donation_date: NaiveDate,
+    pub description: String,
+    /// "emitido" or "cancelado"
+    pub status: String,
+    pub issued_by: Uuid,
+    pub issued_at: DateTime<Utc>,
+    pub voided_at: Option<DateTime<Utc>>,
+    pub void_reason: Option<String>,
+}
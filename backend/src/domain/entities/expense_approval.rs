@@ -0,0 +1,21 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Expenses matching the rule need a second signature before they count
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct ExpenseApprovalRule {
+    pub id: Uuid,
+    pub church_id: Uuid,
+    pub name: String,
+    /// Expenses strictly above this amount; None = any amount
+    pub amount_above: Option<Decimal>,
+    /// Expenses in this category or its subcategories; None = any category
+    pub account_plan_id: Option<Uuid>,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
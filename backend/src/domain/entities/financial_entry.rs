@@ -23,6 +23,7 @@ pub struct FinancialEntry {
     pub payment_method: Option<String>,
     pub member_id: Option<Uuid>,
     pub supplier_name: Option<String>,
+    pub supplier_id: Option<Uuid>,
     pub receipt_url: Option<String>,
     pub status: String,
     pub is_recurring: bool,
@@ -33,11 +34,51 @@ pub struct FinancialEntry {
     pub registered_by: Uuid,
     pub notes: Option<String>,
     pub congregation_id: Option<Uuid>,
+    /// Set only on compensating entries: the entry this one reverses
+    pub reversal_of: Option<Uuid>,
+    pub reversal_reason: Option<String>,
+    /// Shared by both legs of a transfer between bank accounts
+    pub transfer_id: Option<Uuid>,
+    /// Set when matched to a bank statement line
+    pub reconciled_at: Option<DateTime<Utc>>,
+    /// While awaiting approval: the status the expense takes once approved
+    pub requested_status: Option<String>,
+    pub approved_by: Option<Uuid>,
+    pub approved_at: Option<DateTime<Utc>>,
+    pub rejection_reason: Option<String>,
+    /// Installment of a purchase paid in parts, numbered from 1
+    pub installment_plan_id: Option<Uuid>,
+    pub installment_number: Option<i16>,
+    /// Generated by an offering counting sheet
+    pub offering_count_id: Option<Uuid>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub deleted_at: Option<DateTime<Utc>>,
 }
 
+/// Allocation line of a split entry: the share of the payment that goes to one
+/// account plan category, campaign and member
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct FinancialEntryAllocation {
+    pub id: Uuid,
+    pub entry_id: Uuid,
+    pub account_plan_id: Uuid,
+    pub account_plan_name: Option<String>,
+    pub campaign_id: Option<Uuid>,
+    pub campaign_name: Option<String>,
+    pub member_id: Option<Uuid>,
+    pub member_name: Option<String>,
+    pub amount: Decimal,
+    pub description: Option<String>,
+}
+
+/// Result of a reversal (estorno): the original entry and its compensating entry
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct FinancialEntryReversal {
+    pub original: FinancialEntry,
+    pub reversal: FinancialEntry,
+}
+
 /// Financial entry with related names for list views
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
 pub struct FinancialEntrySummary {
@@ -58,8 +99,42 @@ pub struct FinancialEntrySummary {
     pub member_name: Option<String>,
     pub campaign_name: Option<String>,
     pub supplier_name: Option<String>,
+    pub supplier_id: Option<Uuid>,
     pub congregation_id: Option<Uuid>,
     pub congregation_name: Option<String>,
+    pub transfer_id: Option<Uuid>,
+    pub reconciled_at: Option<DateTime<Utc>>,
+    /// Split into allocation lines (`account_plan_name` is the largest line's)
+    pub is_split: bool,
+    /// "Installment 3 of 10": this entry's number and the plan's installment count
+    pub installment_plan_id: Option<Uuid>,
+    pub installment_number: Option<i16>,
+    pub installment_count: Option<i16>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Both legs of a transfer between bank accounts (RN-FIN-009)
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct FinancialTransfer {
+    pub transfer_id: Uuid,
+    /// "despesa" on the source account
+    pub outgoing: FinancialEntry,
+    /// "receita" on the destination account
+    pub incoming: FinancialEntry,
+}
+
+/// Transfer row for list views
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct FinancialTransferSummary {
+    pub transfer_id: Uuid,
+    pub transfer_date: NaiveDate,
+    pub amount: Decimal,
+    pub description: String,
+    pub from_bank_account_id: Uuid,
+    pub from_bank_account_name: Option<String>,
+    pub to_bank_account_id: Uuid,
+    pub to_bank_account_name: Option<String>,
+    pub is_closed: bool,
     pub created_at: DateTime<Utc>,
 }
 
@@ -0,0 +1,64 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Template for a recurring financial entry (rent, utilities, pastor salary...)
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct FinancialRecurrence {
+    pub id: Uuid,
+    pub church_id: Uuid,
+    #[sqlx(rename = "type")]
+    #[serde(rename = "type")]
+    pub entry_type: String,
+    pub account_plan_id: Uuid,
+    pub bank_account_id: Uuid,
+    pub campaign_id: Option<Uuid>,
+    pub amount: Decimal,
+    pub description: String,
+    pub payment_method: Option<String>,
+    pub member_id: Option<Uuid>,
+    pub supplier_name: Option<String>,
+    pub congregation_id: Option<Uuid>,
+    pub notes: Option<String>,
+    /// "mensal", "bimestral", "trimestral", "semestral", "anual"
+    pub frequency: String,
+    pub day_of_month: i16,
+    pub start_date: NaiveDate,
+    pub end_date: Option<NaiveDate>,
+    /// How many days before the due date an occurrence is generated
+    pub lead_days: i16,
+    /// Next due date not yet generated
+    pub next_due_date: NaiveDate,
+    pub is_active: bool,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+/// Recurrence template with related names for list views
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct FinancialRecurrenceSummary {
+    pub id: Uuid,
+    #[sqlx(rename = "type")]
+    #[serde(rename = "type")]
+    pub entry_type: String,
+    pub amount: Decimal,
+    pub description: String,
+    pub frequency: String,
+    pub day_of_month: i16,
+    pub start_date: NaiveDate,
+    pub end_date: Option<NaiveDate>,
+    pub next_due_date: NaiveDate,
+    pub is_active: bool,
+    pub account_plan_name: Option<String>,
+    pub bank_account_name: Option<String>,
+    pub supplier_name: Option<String>,
+    pub congregation_name: Option<String>,
+    /// Generated occurrences still awaiting payment
+    pub pending_count: Option<i64>,
+    pub created_at: DateTime<Utc>,
+}
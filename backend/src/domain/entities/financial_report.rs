@@ -0,0 +1,113 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Accountability report for the members' assembly (RN-FIN-011).
+/// Safe to publish: tithes appear as a single aggregated line.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AssemblyReport {
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+    pub congregation_id: Option<Uuid>,
+    pub congregation_name: Option<String>,
+    /// Every month of the period has a monthly closing (otherwise the report is provisional)
+    pub is_closed: bool,
+    pub previous_balance: Decimal,
+    pub income_by_category: Vec<ReportLine>,
+    pub expense_by_category: Vec<ReportLine>,
+    pub total_income: Decimal,
+    pub total_expense: Decimal,
+    /// total_income - total_expense
+    pub result: Decimal,
+    pub final_balance: Decimal,
+}
+
+/// Aggregated amount of one account plan category
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct ReportLine {
+    pub code: Option<String>,
+    pub name: String,
+    pub amount: Decimal,
+}
+
+/// Movement of a single bank account over a period, as on a bank statement
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BankAccountStatement {
+    pub bank_account_id: Uuid,
+    pub bank_account_name: String,
+    pub bank_name: Option<String>,
+    pub agency: Option<String>,
+    pub account_number: Option<String>,
+    pub congregation_id: Option<Uuid>,
+    pub date_from: NaiveDate,
+    pub date_to: NaiveDate,
+    /// Balance at the end of the day before `date_from`
+    pub opening_balance: Decimal,
+    pub total_credits: Decimal,
+    pub total_debits: Decimal,
+    pub closing_balance: Decimal,
+    pub movements: Vec<StatementMovement>,
+}
+
+/// One confirmed (or later reversed) entry with the account balance after it
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct StatementMovement {
+    pub entry_id: Uuid,
+    pub entry_date: NaiveDate,
+    pub description: String,
+    pub account_plan_name: Option<String>,
+    #[sqlx(rename = "type")]
+    #[serde(rename = "type")]
+    pub entry_type: String,
+    pub amount: Decimal,
+    /// Signed amount: positive for credits, negative for debits
+    pub signed_amount: Decimal,
+    pub balance: Decimal,
+    pub transfer_id: Option<Uuid>,
+    pub reversal_of: Option<Uuid>,
+    pub reconciled: bool,
+}
+
+/// Income statement (DRE) over the account plan tree: every category carries
+/// the sum of its own entries and of all its subcategories
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct IncomeStatement {
+    pub date_from: NaiveDate,
+    pub date_to: NaiveDate,
+    pub congregation_id: Option<Uuid>,
+    pub congregation_name: Option<String>,
+    /// First day of each month column; empty unless month columns were requested
+    pub months: Vec<NaiveDate>,
+    pub income: Vec<IncomeStatementLine>,
+    pub expense: Vec<IncomeStatementLine>,
+    pub total_income: Decimal,
+    pub total_expense: Decimal,
+    /// total_income - total_expense
+    pub result: Decimal,
+    /// Month columns of the totals, aligned with `months`
+    pub monthly_income: Vec<Decimal>,
+    pub monthly_expense: Vec<Decimal>,
+    pub monthly_result: Vec<Decimal>,
+}
+
+/// One account plan category, in code order (parents before their children)
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct IncomeStatementLine {
+    pub account_plan_id: Uuid,
+    pub parent_id: Option<Uuid>,
+    pub code: String,
+    pub name: String,
+    /// Depth in the plan tree, 1 for top-level categories
+    pub level: i16,
+    /// Has subcategories, i.e. the amount is a subtotal
+    pub is_group: bool,
+    /// Net of reversals
+    pub amount: Decimal,
+    /// Share of the total income (0-100)
+    pub percentage_of_income: Decimal,
+    /// Aligned with `IncomeStatement::months`
+    pub monthly: Vec<Decimal>,
+}
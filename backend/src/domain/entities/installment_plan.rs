@@ -0,0 +1,63 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::FinancialEntry;
+
+/// Purchase paid in monthly installments (sound system by boleto, credit card...)
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct InstallmentPlan {
+    pub id: Uuid,
+    pub church_id: Uuid,
+    pub account_plan_id: Uuid,
+    pub bank_account_id: Uuid,
+    pub description: String,
+    pub payment_method: Option<String>,
+    pub supplier_id: Option<Uuid>,
+    pub supplier_name: Option<String>,
+    pub congregation_id: Option<Uuid>,
+    pub notes: Option<String>,
+    pub purchase_date: NaiveDate,
+    /// Paid installments plus the open ones (changes on renegotiation)
+    pub total_amount: Decimal,
+    pub installment_count: i16,
+    pub first_due_date: NaiveDate,
+    pub renegotiated_at: Option<DateTime<Utc>>,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+/// Installment plan with payment progress for list views
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct InstallmentPlanSummary {
+    pub id: Uuid,
+    pub description: String,
+    pub purchase_date: NaiveDate,
+    pub total_amount: Decimal,
+    pub installment_count: i16,
+    pub payment_method: Option<String>,
+    pub supplier_name: Option<String>,
+    pub account_plan_name: Option<String>,
+    pub congregation_name: Option<String>,
+    pub paid_count: i64,
+    pub paid_amount: Decimal,
+    pub open_amount: Decimal,
+    /// Due date of the first open installment
+    pub next_due_date: Option<NaiveDate>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Installment plan with its installments in order
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct InstallmentPlanDetail {
+    #[serde(flatten)]
+    pub plan: InstallmentPlan,
+    pub paid_amount: Decimal,
+    pub open_amount: Decimal,
+    pub installments: Vec<FinancialEntry>,
+}
@@ -15,6 +15,7 @@ pub struct Maintenance {
     pub maintenance_type: String,
     pub description: String,
     pub supplier_name: Option<String>,
+    pub supplier_id: Option<Uuid>,
     pub cost: Option<Decimal>,
     pub scheduled_date: Option<NaiveDate>,
     pub execution_date: Option<NaiveDate>,
@@ -36,6 +37,7 @@ pub struct MaintenanceSummary {
     pub maintenance_type: String,
     pub description: String,
     pub supplier_name: Option<String>,
+    pub supplier_id: Option<Uuid>,
     pub cost: Option<Decimal>,
     pub scheduled_date: Option<NaiveDate>,
     pub execution_date: Option<NaiveDate>,
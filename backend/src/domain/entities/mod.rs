@@ -1,15 +1,30 @@
 pub mod account_plan;
+pub mod accounting_export;
 pub mod bank_account;
+pub mod bank_statement;
+pub mod budget;
 pub mod campaign;
 pub mod church;
 pub mod church_role;
 pub mod congregation;
+pub mod donation_receipt;
+pub mod expense_approval;
 pub mod family;
 pub mod financial_entry;
+pub mod financial_recurrence;
+pub mod financial_report;
+pub mod installment_plan;
 pub mod member;
 pub mod member_history;
 pub mod ministry;
 pub mod monthly_closing;
+pub mod offering_count;
+pub mod payable;
+pub mod payment_notification;
+pub mod pix_charge;
+pub mod remittance;
+pub mod supplier;
+pub mod tithe;
 pub mod user;
 pub mod asset;
 pub mod asset_category;
@@ -29,6 +44,7 @@ pub mod inventory;
 pub mod maintenance;
 
 pub use account_plan::{AccountPlan, AccountPlanSummary};
+pub use accounting_export::{AccountingExportLayout, ExportColumn};
 pub use asset::{Asset, AssetSummary};
 pub use asset_category::{AssetCategory, AssetCategorySummary};
 pub use asset_loan::{AssetLoan, AssetLoanSummary};
@@ -43,15 +59,31 @@ pub use ebd_lesson_material::EbdLessonMaterial;
 pub use ebd_student_note::{EbdStudentNote, EbdStudentNoteDetail};
 pub use ebd_student_profile::{EbdEnrollmentHistory, EbdStudentSummary};
 pub use ebd_term::EbdTerm;
-pub use bank_account::BankAccount;
-pub use campaign::{Campaign, CampaignSummary};
+pub use bank_account::{BankAccount, BankBalanceCheck, BankBalanceVerification};
+pub use bank_statement::{BankStatement, BankStatementDetail, BankStatementImport, BankStatementLine, BankStatementSummary, ReconciliationBalance, ReconciliationSuggestion};
+pub use budget::{Budget, BudgetAlert, BudgetReport, BudgetReportLine, BudgetSummary};
+pub use campaign::{Campaign, CampaignPledge, CampaignPledgeSummary, CampaignReport, CampaignSummary};
+pub use donation_receipt::DonationReceipt;
+pub use expense_approval::ExpenseApprovalRule;
 pub use inventory::{Inventory, InventoryItem, InventoryItemDetail, InventorySummary};
 pub use maintenance::{Maintenance, MaintenanceSummary};
 pub use family::{Family, FamilyDetail, FamilyMemberInfo, FamilyRelationship};
-pub use financial_entry::{CategoryAmount, FinancialBalance, FinancialEntry, FinancialEntrySummary};
+pub use financial_entry::{CategoryAmount, FinancialBalance, FinancialEntry, FinancialEntryAllocation, FinancialEntryReversal, FinancialEntrySummary, FinancialTransfer, FinancialTransferSummary};
+pub use financial_recurrence::{FinancialRecurrence, FinancialRecurrenceSummary};
+pub use installment_plan::{InstallmentPlan, InstallmentPlanDetail, InstallmentPlanSummary};
+pub use financial_report::{AssemblyReport, BankAccountStatement, IncomeStatement, IncomeStatementLine, ReportLine, StatementMovement};
 pub use member::{Member, MemberSummary};
 pub use member_history::MemberHistory;
 pub use ministry::{MemberMinistry, Ministry, MinistryMemberInfo, MinistrySummary};
-pub use monthly_closing::{MonthlyClosing, MonthlyClosingSummary};
+pub use monthly_closing::{
+    MonthlyClosing, MonthlyClosingBankBalance, MonthlyClosingDetail, MonthlyClosingSummary,
+};
+pub use offering_count::{OfferingCount, OfferingCountDenomination, OfferingCountDetail, OfferingCountEnvelope, OfferingCountSummary};
+pub use payable::{AccountsPayable, PayableEntry, PayablesBucket};
+pub use payment_notification::{PaymentIntegration, PaymentNotification, WebhookIngestResult};
+pub use pix_charge::{PixCharge, PixChargeSummary};
+pub use remittance::{CongregationRemittance, CongregationRemittanceSummary, OutstandingRemittance, RemittanceOutstandingReport, RemittanceRule};
+pub use supplier::{Supplier, SupplierSpendingLine, SupplierSpendingReport};
+pub use tithe::{TitheStatement, TitheStatementCategory, TitheStatementEntry, TitheStatementMonth, TitherMonth, TitherRegularity, TitherRegularityReport};
 pub use church_role::ChurchRole;
 pub use congregation::{AssignMembersResult, Congregation, CongregationCompareItem, CongregationCompareReport, CongregationDetail, CongregationOverviewItem, CongregationStats, CongregationSummary, CongregationUserInfo, CongregationsOverview, SkippedMember, UserCongregation};
@@ -9,6 +9,8 @@ use uuid::Uuid;
 pub struct MonthlyClosing {
     pub id: Uuid,
     pub church_id: Uuid,
+    /// None = church-wide closing
+    pub congregation_id: Option<Uuid>,
     pub reference_month: NaiveDate,
     pub total_income: Decimal,
     pub total_expense: Decimal,
@@ -24,6 +26,8 @@ pub struct MonthlyClosing {
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
 pub struct MonthlyClosingSummary {
     pub id: Uuid,
+    pub congregation_id: Option<Uuid>,
+    pub congregation_name: Option<String>,
     pub reference_month: NaiveDate,
     pub total_income: Decimal,
     pub total_expense: Decimal,
@@ -34,3 +38,19 @@ pub struct MonthlyClosingSummary {
     pub notes: Option<String>,
     pub created_at: DateTime<Utc>,
 }
+
+/// Bank account balance at the end of a closed month
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct MonthlyClosingBankBalance {
+    pub bank_account_id: Uuid,
+    pub bank_account_name: String,
+    pub closing_balance: Decimal,
+}
+
+/// Monthly closing with the bank account balances snapshot
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MonthlyClosingDetail {
+    #[serde(flatten)]
+    pub closing: MonthlyClosing,
+    pub bank_balances: Vec<MonthlyClosingBankBalance>,
+}
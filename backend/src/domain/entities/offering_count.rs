@@ -0,0 +1,90 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::FinancialEntry;
+
+/// Offering counting sheet filled in after a service (RN-FIN-025)
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct OfferingCount {
+    pub id: Uuid,
+    pub church_id: Uuid,
+    pub congregation_id: Option<Uuid>,
+    pub service_date: NaiveDate,
+    pub service_name: Option<String>,
+    pub bank_account_id: Uuid,
+    pub offering_account_plan_id: Uuid,
+    /// Default category of the envelopes
+    pub tithe_account_plan_id: Option<Uuid>,
+    /// "em_contagem" or "confirmada"
+    pub status: String,
+    pub first_counter_id: Option<Uuid>,
+    pub first_confirmed_at: Option<DateTime<Utc>>,
+    pub second_counter_id: Option<Uuid>,
+    pub second_confirmed_at: Option<DateTime<Utc>>,
+    pub notes: Option<String>,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+/// Bills or coins of one value found in the box
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct OfferingCountDenomination {
+    pub denomination: Decimal,
+    pub quantity: i32,
+    pub subtotal: Decimal,
+}
+
+/// Tithe envelope, usually from a member
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct OfferingCountEnvelope {
+    pub id: Uuid,
+    pub member_id: Option<Uuid>,
+    pub member_name: Option<String>,
+    pub contributor_name: Option<String>,
+    pub account_plan_id: Option<Uuid>,
+    pub account_plan_name: Option<String>,
+    pub amount: Decimal,
+    /// "dinheiro" or "cheque"
+    pub payment_method: String,
+}
+
+/// Counting sheet with totals for list views
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct OfferingCountSummary {
+    pub id: Uuid,
+    pub service_date: NaiveDate,
+    pub service_name: Option<String>,
+    pub status: String,
+    pub congregation_name: Option<String>,
+    pub bank_account_name: Option<String>,
+    pub cash_total: Decimal,
+    pub envelope_count: i64,
+    pub envelope_total: Decimal,
+    pub first_counter_name: Option<String>,
+    pub second_counter_name: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Counting sheet with its lines, totals and the entries it generated
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct OfferingCountDetail {
+    #[serde(flatten)]
+    pub count: OfferingCount,
+    pub denominations: Vec<OfferingCountDenomination>,
+    pub envelopes: Vec<OfferingCountEnvelope>,
+    /// Bills and coins counted, envelope cash included
+    pub cash_total: Decimal,
+    pub envelope_total: Decimal,
+    /// Cash not in envelopes
+    pub loose_offering: Decimal,
+    /// Loose offering plus every envelope
+    pub total: Decimal,
+    /// Filled in on the second confirmation
+    pub entries: Vec<FinancialEntry>,
+}
@@ -0,0 +1,41 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Pending expense with its due date
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct PayableEntry {
+    pub id: Uuid,
+    pub description: String,
+    pub amount: Decimal,
+    pub due_date: NaiveDate,
+    /// Days until the due date (negative when overdue)
+    pub days_to_due: i32,
+    pub account_plan_name: Option<String>,
+    pub bank_account_name: Option<String>,
+    pub supplier_name: Option<String>,
+    pub congregation_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct PayablesBucket {
+    pub count: i64,
+    pub total: Decimal,
+    pub entries: Vec<PayableEntry>,
+}
+
+/// Pending expenses grouped by due date (RN-FIN-003)
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AccountsPayable {
+    pub as_of: NaiveDate,
+    pub congregation_id: Option<Uuid>,
+    pub overdue: PayablesBucket,
+    /// Due today up to 7 days ahead
+    pub due_7_days: PayablesBucket,
+    /// Due from 8 up to 30 days ahead
+    pub due_30_days: PayablesBucket,
+    pub total: Decimal,
+}
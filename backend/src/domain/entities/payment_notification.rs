@@ -0,0 +1,60 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Payment provider configured for a church
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct PaymentIntegration {
+    pub id: Uuid,
+    pub church_id: Uuid,
+    pub provider: String,
+    /// Never returned by the API
+    #[serde(skip_serializing)]
+    pub webhook_secret: String,
+    /// Author of the entries created by the webhook
+    pub registered_by: Uuid,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Payment received through a provider webhook
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct PaymentNotification {
+    pub id: Uuid,
+    pub church_id: Uuid,
+    pub provider: String,
+    pub provider_transaction_id: String,
+    /// "pix" or "cartao"
+    pub method: String,
+    pub amount: Decimal,
+    pub paid_at: DateTime<Utc>,
+    pub txid: Option<String>,
+    pub payer_name: Option<String>,
+    pub payer_document: Option<String>,
+    pub pix_charge_id: Option<Uuid>,
+    /// "pendente_revisao", "lancado" or "descartado"
+    pub status: String,
+    pub review_reason: Option<String>,
+    pub entry_id: Option<Uuid>,
+    pub resolved_by: Option<Uuid>,
+    pub resolved_at: Option<DateTime<Utc>>,
+    pub raw_payload: serde_json::Value,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Outcome of one webhook delivery
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct WebhookIngestResult {
+    pub received: usize,
+    /// Confirmed income entries created
+    pub created: usize,
+    /// Sent to the review queue
+    pub queued: usize,
+    /// Already received before (provider redelivery)
+    pub duplicates: usize,
+}
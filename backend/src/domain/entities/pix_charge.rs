@@ -0,0 +1,40 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Static PIX BR Code generated for a bank account
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct PixCharge {
+    pub id: Uuid,
+    pub church_id: Uuid,
+    pub bank_account_id: Uuid,
+    /// Identifier printed in the code; matches an incoming credit back to its purpose
+    pub txid: String,
+    /// None = the payer types the amount
+    pub amount: Option<Decimal>,
+    pub description: Option<String>,
+    pub account_plan_id: Option<Uuid>,
+    pub campaign_id: Option<Uuid>,
+    pub congregation_id: Option<Uuid>,
+    /// "Copia e cola" payload
+    pub payload: String,
+    pub created_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct PixChargeSummary {
+    pub id: Uuid,
+    pub bank_account_id: Uuid,
+    pub bank_account_name: String,
+    pub txid: String,
+    pub amount: Option<Decimal>,
+    pub description: Option<String>,
+    pub account_plan_name: Option<String>,
+    pub campaign_name: Option<String>,
+    pub payload: String,
+    pub created_at: DateTime<Utc>,
+}
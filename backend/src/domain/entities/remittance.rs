@@ -0,0 +1,65 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Remittance rule of a congregation, stored in `congregations.settings.remittance`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RemittanceRule {
+    /// Share of the base sent to the sede (0–100)
+    pub percentage: Decimal,
+    /// Income account plans (with their subcategories) the percentage applies to
+    pub account_plan_ids: Vec<Uuid>,
+    pub is_active: bool,
+}
+
+/// Amount a congregation owes the sede for one month
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct CongregationRemittance {
+    pub id: Uuid,
+    pub church_id: Uuid,
+    pub congregation_id: Uuid,
+    pub reference_month: NaiveDate,
+    pub base_amount: Decimal,
+    pub percentage: Decimal,
+    pub amount_due: Decimal,
+    /// "pendente" or "pago"
+    pub status: String,
+    pub expense_entry_id: Option<Uuid>,
+    pub income_entry_id: Option<Uuid>,
+    pub paid_at: Option<DateTime<Utc>>,
+    pub paid_by: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct CongregationRemittanceSummary {
+    pub id: Uuid,
+    pub congregation_id: Uuid,
+    pub congregation_name: String,
+    pub reference_month: NaiveDate,
+    pub base_amount: Decimal,
+    pub percentage: Decimal,
+    pub amount_due: Decimal,
+    pub status: String,
+    pub paid_at: Option<DateTime<Utc>>,
+}
+
+/// Pending remittances of one congregation
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct OutstandingRemittance {
+    pub congregation_id: Uuid,
+    pub congregation_name: String,
+    pub pending_months: i64,
+    pub oldest_month: NaiveDate,
+    pub outstanding_amount: Decimal,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RemittanceOutstandingReport {
+    pub congregations: Vec<OutstandingRemittance>,
+    pub total: Decimal,
+}
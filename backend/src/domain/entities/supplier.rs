@@ -0,0 +1,51 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct Supplier {
+    pub id: Uuid,
+    pub church_id: Uuid,
+    pub name: String,
+    /// CPF (11 digits) or CNPJ (14 digits), digits only
+    pub document: Option<String>,
+    pub category: Option<String>,
+    pub contact_name: Option<String>,
+    pub phone: Option<String>,
+    pub email: Option<String>,
+    pub notes: Option<String>,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+/// Net spending with one supplier in the period
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SupplierSpendingLine {
+    pub supplier_id: Uuid,
+    pub supplier_name: String,
+    pub document: Option<String>,
+    pub category: Option<String>,
+    pub entry_count: i64,
+    pub total: Decimal,
+    /// Share of the spending with registered suppliers (0-100)
+    pub percentage: Decimal,
+    pub last_entry_date: Option<NaiveDate>,
+}
+
+/// Spending by supplier, largest first
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SupplierSpendingReport {
+    pub date_from: NaiveDate,
+    pub date_to: NaiveDate,
+    pub congregation_id: Option<Uuid>,
+    pub category: Option<String>,
+    pub suppliers: Vec<SupplierSpendingLine>,
+    pub total: Decimal,
+    /// Expenses of the period with no supplier linked
+    pub unlinked_total: Decimal,
+}
@@ -0,0 +1,81 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Annual contribution statement of a member (RN-FIN-005)
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TitheStatement {
+    pub member_id: Uuid,
+    pub member_name: String,
+    pub member_cpf: Option<String>,
+    pub year: i32,
+    pub total: Decimal,
+    pub entry_count: i64,
+    /// Always 12 months, zero-filled
+    pub months: Vec<TitheStatementMonth>,
+    pub categories: Vec<TitheStatementCategory>,
+    pub entries: Vec<TitheStatementEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TitheStatementMonth {
+    pub month: u32,
+    pub amount: Decimal,
+    pub count: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct TitheStatementCategory {
+    pub account_plan_id: Uuid,
+    pub account_plan_name: String,
+    pub amount: Decimal,
+    pub count: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct TitheStatementEntry {
+    pub id: Uuid,
+    /// Payment date, or entry date when not informed
+    pub date: NaiveDate,
+    pub account_plan_name: String,
+    pub amount: Decimal,
+    pub payment_method: Option<String>,
+}
+
+/// Tither classification over a 12-month window (RN-FIN-006)
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TitherRegularity {
+    pub member_id: Uuid,
+    pub member_name: String,
+    pub congregation_id: Option<Uuid>,
+    pub congregation_name: Option<String>,
+    /// "regular" (10+ months), "irregular" (6-9) or "inativo" (below 6)
+    pub status: String,
+    pub months_with_tithe: i32,
+    pub total: Decimal,
+    /// Most recent tithe, even before the window
+    pub last_tithe_date: Option<NaiveDate>,
+    /// One item per month of the window, oldest first
+    pub history: Vec<TitherMonth>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TitherMonth {
+    /// First day of the month
+    pub month: NaiveDate,
+    pub amount: Decimal,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TitherRegularityReport {
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+    pub congregation_id: Option<Uuid>,
+    pub regular_count: i64,
+    pub irregular_count: i64,
+    pub inactive_count: i64,
+    pub members: Vec<TitherRegularity>,
+}
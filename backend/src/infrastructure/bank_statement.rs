@@ -0,0 +1,438 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use sha1::{Digest, Sha1};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use crate::errors::AppError;
+
+/// A bank statement file parsed into movements, independent of its format.
+#[derive(Debug)]
+pub struct ParsedStatement {
+    pub format: &'static str,
+    pub lines: Vec<ParsedLine>,
+    pub period_start: Option<NaiveDate>,
+    pub period_end: Option<NaiveDate>,
+    /// Balance reported by the bank, when the file carries one
+    pub balance: Option<Decimal>,
+    pub balance_date: Option<NaiveDate>,
+}
+
+#[derive(Debug)]
+pub struct ParsedLine {
+    pub date: NaiveDate,
+    /// Positive for credits, negative for debits
+    pub amount: Decimal,
+    pub description: String,
+    /// FITID for OFX; a stable hash of the row for CSV
+    pub external_id: String,
+}
+
+/// Parse an OFX or CSV statement, detecting the format from the content.
+pub fn parse(bytes: &[u8]) -> Result<ParsedStatement, AppError> {
+    let text = decode(bytes);
+    let statement = if text.contains("<OFX>") || text.contains("<ofx>") {
+        parse_ofx(&text)?
+    } else {
+        parse_csv(&text)?
+    };
+
+    if statement.lines.is_empty() {
+        return Err(AppError::validation(
+            "Nenhuma movimentação encontrada no extrato",
+        ));
+    }
+
+    Ok(statement)
+}
+
+/// Brazilian banks still export OFX/CSV in Windows-1252; fall back to Latin-1
+/// when the file is not valid UTF-8.
+fn decode(bytes: &[u8]) -> String {
+    let bytes = bytes.strip_prefix(&[0xEF, 0xBB, 0xBF]).unwrap_or(bytes);
+    match std::str::from_utf8(bytes) {
+        Ok(text) => text.to_string(),
+        Err(_) => bytes.iter().map(|&b| b as char).collect(),
+    }
+}
+
+// ==========================================
+// OFX
+// ==========================================
+
+fn parse_ofx(text: &str) -> Result<ParsedStatement, AppError> {
+    let mut lines = Vec::new();
+
+    for block in blocks(text, "STMTTRN") {
+        let date = tag_value(block, "DTPOSTED")
+            .and_then(ofx_date)
+            .ok_or_else(|| AppError::validation("OFX inválido: transação sem DTPOSTED"))?;
+        let amount = tag_value(block, "TRNAMT")
+            .and_then(parse_amount)
+            .ok_or_else(|| AppError::validation("OFX inválido: transação sem TRNAMT"))?;
+
+        if amount.is_zero() {
+            continue;
+        }
+
+        let description = match (tag_value(block, "NAME"), tag_value(block, "MEMO")) {
+            (Some(name), Some(memo)) if name != memo => format!("{name} - {memo}"),
+            (Some(name), _) => name.to_string(),
+            (None, Some(memo)) => memo.to_string(),
+            (None, None) => String::new(),
+        };
+
+        let external_id = match tag_value(block, "FITID") {
+            Some(fit_id) => fit_id.to_string(),
+            None => row_hash(date, amount, &description, 0),
+        };
+
+        lines.push(ParsedLine {
+            date,
+            amount,
+            description,
+            external_id,
+        });
+    }
+
+    let ledger = blocks(text, "LEDGERBAL").into_iter().next();
+
+    Ok(ParsedStatement {
+        format: "ofx",
+        lines,
+        period_start: tag_value(text, "DTSTART").and_then(ofx_date),
+        period_end: tag_value(text, "DTEND").and_then(ofx_date),
+        balance: ledger.and_then(|b| tag_value(b, "BALAMT")).and_then(parse_amount),
+        balance_date: ledger.and_then(|b| tag_value(b, "DTASOF")).and_then(ofx_date),
+    })
+}
+
+/// Contents of every `<TAG>...</TAG>` aggregate
+fn blocks<'a>(text: &'a str, tag: &str) -> Vec<&'a str> {
+    let open = format!("<{tag}>");
+    let close = format!("</{tag}>");
+    let mut result = Vec::new();
+    let mut rest = text;
+
+    while let Some(start) = rest.find(&open) {
+        let body = &rest[start + open.len()..];
+        let end = body.find(&close).unwrap_or(body.len());
+        result.push(&body[..end]);
+        rest = &body[end..];
+    }
+
+    result
+}
+
+/// Value of a leaf element; OFX 1.x (SGML) leaves are not closed, so the value
+/// runs until the next tag or line break.
+fn tag_value<'a>(text: &'a str, tag: &str) -> Option<&'a str> {
+    let open = format!("<{tag}>");
+    let start = text.find(&open)? + open.len();
+    let value = text[start..]
+        .split(['<', '\r', '\n'])
+        .next()
+        .unwrap_or("")
+        .trim();
+
+    if value.is_empty() { None } else { Some(value) }
+}
+
+/// "20260215120000[-3:BRT]" → 2026-02-15
+fn ofx_date(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value.get(..8)?, "%Y%m%d").ok()
+}
+
+// ==========================================
+// CSV
+// ==========================================
+
+fn parse_csv(text: &str) -> Result<ParsedStatement, AppError> {
+    let first_line = text.lines().next().unwrap_or("");
+    let delimiter = [b';', b'\t', b',']
+        .into_iter()
+        .max_by_key(|d| first_line.matches(*d as char).count())
+        .unwrap_or(b';');
+
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .flexible(true)
+        .from_reader(text.as_bytes());
+
+    let headers: Vec<String> = reader
+        .headers()
+        .map_err(|e| AppError::validation(format!("CSV inválido: {e}")))?
+        .iter()
+        .map(normalize_header)
+        .collect();
+
+    let find = |names: &[&str]| headers.iter().position(|h| names.iter().any(|n| h.starts_with(n)));
+
+    let date_col = find(&["data", "date", "dt"])
+        .ok_or_else(|| AppError::validation("CSV sem coluna de data"))?;
+    let description_col = find(&["descricao", "historico", "lancamento", "memo", "description"]);
+    let amount_col = find(&["valor", "amount", "montante"]);
+    let credit_col = find(&["credito", "entrada"]);
+    let debit_col = find(&["debito", "saida"]);
+    let balance_col = find(&["saldo", "balance"]);
+    let id_col = find(&["documento", "doc", "fitid", "id", "numero"]);
+
+    if amount_col.is_none() && credit_col.is_none() && debit_col.is_none() {
+        return Err(AppError::validation(
+            "CSV sem coluna de valor (valor, ou crédito/débito)",
+        ));
+    }
+
+    let mut lines = Vec::new();
+    let mut balance = None;
+    let mut balance_date = None;
+    let mut seen: HashMap<String, u32> = HashMap::new();
+
+    for record in reader.records() {
+        let record = record.map_err(|e| AppError::validation(format!("CSV inválido: {e}")))?;
+        let field = |col: Option<usize>| col.and_then(|c| record.get(c)).map(str::trim).unwrap_or("");
+
+        // Rows without a valid date are headers/footers such as "Saldo anterior"
+        let Some(date) = csv_date(field(Some(date_col))) else {
+            continue;
+        };
+
+        let amount = match amount_col {
+            Some(_) => parse_amount(field(amount_col)),
+            None => {
+                let credit = parse_amount(field(credit_col)).map(|v| v.abs());
+                let debit = parse_amount(field(debit_col)).map(|v| -v.abs());
+                credit.filter(|c| !c.is_zero()).or(debit)
+            }
+        };
+
+        if let Some(b) = parse_amount(field(balance_col)) {
+            balance = Some(b);
+            balance_date = Some(date);
+        }
+
+        let Some(amount) = amount.filter(|a| !a.is_zero()) else {
+            continue;
+        };
+
+        let description = field(description_col).to_string();
+        let external_id = match field(id_col) {
+            "" => {
+                // Identical rows on the same day are legitimate (two equal fees);
+                // the occurrence counter keeps their hashes distinct but stable.
+                let key = format!("{date}|{amount}|{description}");
+                let occurrence = seen.entry(key).or_insert(0);
+                *occurrence += 1;
+                row_hash(date, amount, &description, *occurrence)
+            }
+            doc => format!("{date}:{doc}:{amount}"),
+        };
+
+        lines.push(ParsedLine {
+            date,
+            amount,
+            description,
+            external_id,
+        });
+    }
+
+    Ok(ParsedStatement {
+        format: "csv",
+        period_start: lines.iter().map(|l| l.date).min(),
+        period_end: lines.iter().map(|l| l.date).max(),
+        lines,
+        balance,
+        balance_date,
+    })
+}
+
+/// Lowercase, accents stripped: "Histórico" → "historico"
+fn normalize_header(header: &str) -> String {
+    header
+        .trim()
+        .to_lowercase()
+        .chars()
+        .map(|c| match c {
+            'á' | 'à' | 'â' | 'ã' => 'a',
+            'é' | 'ê' => 'e',
+            'í' => 'i',
+            'ó' | 'ô' | 'õ' => 'o',
+            'ú' | 'ü' => 'u',
+            'ç' => 'c',
+            other => other,
+        })
+        .collect()
+}
+
+fn csv_date(value: &str) -> Option<NaiveDate> {
+    let value = value.get(..10).unwrap_or(value);
+    ["%d/%m/%Y", "%Y-%m-%d", "%d-%m-%Y", "%d.%m.%Y"]
+        .iter()
+        .find_map(|fmt| NaiveDate::parse_from_str(value, fmt).ok())
+}
+
+fn row_hash(date: NaiveDate, amount: Decimal, description: &str, occurrence: u32) -> String {
+    let mut hasher = Sha1::new();
+    hasher.update(format!("{date}|{amount}|{description}|{occurrence}").as_bytes());
+    hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+/// Parse amounts as banks print them: "1.234,56", "-1234.56", "R$ 10,00",
+/// "(50,00)" and "50,00 D" are all accepted.
+pub fn parse_amount(value: &str) -> Option<Decimal> {
+    let mut s: String = value
+        .trim()
+        .replace("R$", "")
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect();
+
+    let mut negative = false;
+    if s.starts_with('(') && s.ends_with(')') {
+        negative = true;
+        s = s[1..s.len() - 1].to_string();
+    }
+    if let Some(stripped) = s.strip_suffix(['D', 'd']) {
+        negative = true;
+        s = stripped.to_string();
+    } else if let Some(stripped) = s.strip_suffix(['C', 'c']) {
+        s = stripped.to_string();
+    }
+    if let Some(stripped) = s.strip_prefix('-') {
+        negative = !negative;
+        s = stripped.to_string();
+    } else if let Some(stripped) = s.strip_prefix('+') {
+        s = stripped.to_string();
+    }
+
+    // The right-most separator is the decimal one
+    let normalized = match (s.rfind(','), s.rfind('.')) {
+        (Some(comma), Some(dot)) if comma > dot => s.replace('.', "").replace(',', "."),
+        (Some(_), Some(_)) => s.replace(',', ""),
+        (Some(_), None) => s.replace(',', "."),
+        _ => s,
+    };
+
+    let amount = Decimal::from_str(&normalized).ok()?;
+    Some(if negative { -amount } else { amount })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dec(value: &str) -> Decimal {
+        Decimal::from_str(value).unwrap()
+    }
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn parse_amount_separators() {
+        assert_eq!(parse_amount("1.234,56"), Some(dec("1234.56")));
+        assert_eq!(parse_amount("1,234.56"), Some(dec("1234.56")));
+        assert_eq!(parse_amount("-1234.56"), Some(dec("-1234.56")));
+        assert_eq!(parse_amount("R$ 10,00"), Some(dec("10.00")));
+        assert_eq!(parse_amount(""), None);
+        assert_eq!(parse_amount("abc"), None);
+    }
+
+    #[test]
+    fn parse_amount_signs() {
+        assert_eq!(parse_amount("(50,00)"), Some(dec("-50.00")));
+        assert_eq!(parse_amount("50,00 D"), Some(dec("-50.00")));
+        assert_eq!(parse_amount("50,00 C"), Some(dec("50.00")));
+        assert_eq!(parse_amount("+50,00"), Some(dec("50.00")));
+    }
+
+    #[test]
+    fn csv_with_credit_and_debit_columns() {
+        let csv = "Data;Histórico;Crédito;Débito;Saldo\n\
+                   01/03/2027;Saldo anterior;;;1.000,00\n\
+                   02/03/2027;PIX recebido;150,00;;1.150,00\n\
+                   03/03/2027;Conta de luz;;230,45;919,55\n";
+
+        let statement = parse(csv.as_bytes()).unwrap();
+        assert_eq!(statement.format, "csv");
+        assert_eq!(statement.lines.len(), 2);
+        assert_eq!(statement.lines[0].amount, dec("150.00"));
+        assert_eq!(statement.lines[0].description, "PIX recebido");
+        assert_eq!(statement.lines[1].amount, dec("-230.45"));
+        assert_eq!(statement.period_start, Some(date(2027, 3, 2)));
+        assert_eq!(statement.period_end, Some(date(2027, 3, 3)));
+        assert_eq!(statement.balance, Some(dec("919.55")));
+        assert_eq!(statement.balance_date, Some(date(2027, 3, 3)));
+    }
+
+    #[test]
+    fn csv_identical_rows_get_distinct_stable_ids() {
+        let csv = "data,descricao,valor\n\
+                   2027-03-05,Tarifa,-2.50\n\
+                   2027-03-05,Tarifa,-2.50\n";
+
+        let first = parse(csv.as_bytes()).unwrap();
+        let again = parse(csv.as_bytes()).unwrap();
+        assert_eq!(first.lines.len(), 2);
+        assert_ne!(first.lines[0].external_id, first.lines[1].external_id);
+        assert_eq!(first.lines[0].external_id, again.lines[0].external_id);
+        assert_eq!(first.lines[1].external_id, again.lines[1].external_id);
+    }
+
+    #[test]
+    fn csv_document_column_is_the_id() {
+        let csv = "Data\tDescrição\tDocumento\tValor\n10/03/2027\tDepósito\t123\t80,00\n";
+
+        let statement = parse(csv.as_bytes()).unwrap();
+        assert_eq!(statement.lines[0].external_id, "2027-03-10:123:80.00");
+    }
+
+    const OFX: &str = "OFXHEADER:100\nDATA:OFXSGML\n\n<OFX>\n<BANKMSGSRSV1><STMTTRNRS><STMTRS>\n\
+        <BANKTRANLIST>\n<DTSTART>20270301000000[-3:BRT]\n<DTEND>20270331000000[-3:BRT]\n\
+        <STMTTRN>\n<TRNTYPE>CREDIT\n<DTPOSTED>20270302120000[-3:BRT]\n<TRNAMT>150.00\n\
+        <FITID>202703020001\n<NAME>PIX MARIA\n<MEMO>Oferta\n</STMTTRN>\n\
+        <STMTTRN>\n<TRNTYPE>DEBIT\n<DTPOSTED>20270303\n<TRNAMT>-230.45\n\
+        <MEMO>Conta de luz\n</STMTTRN>\n\
+        </BANKTRANLIST>\n<LEDGERBAL>\n<BALAMT>919.55\n<DTASOF>20270331\n</LEDGERBAL>\n\
+        </STMTRS></STMTTRNRS></BANKMSGSRSV1>\n</OFX>\n";
+
+    #[test]
+    fn ofx_sgml_statement() {
+        let statement = parse(OFX.as_bytes()).unwrap();
+        assert_eq!(statement.format, "ofx");
+        assert_eq!(statement.period_start, Some(date(2027, 3, 1)));
+        assert_eq!(statement.period_end, Some(date(2027, 3, 31)));
+        assert_eq!(statement.balance, Some(dec("919.55")));
+        assert_eq!(statement.balance_date, Some(date(2027, 3, 31)));
+        assert_eq!(statement.lines.len(), 2);
+
+        let credit = &statement.lines[0];
+        assert_eq!(credit.date, date(2027, 3, 2));
+        assert_eq!(credit.amount, dec("150.00"));
+        assert_eq!(credit.description, "PIX MARIA - Oferta");
+        assert_eq!(credit.external_id, "202703020001");
+    }
+
+    #[test]
+    fn ofx_without_fitid_uses_a_stable_hash() {
+        let first = parse(OFX.as_bytes()).unwrap();
+        let again = parse(OFX.as_bytes()).unwrap();
+
+        let debit = &first.lines[1];
+        assert_eq!(debit.amount, dec("-230.45"));
+        assert_eq!(debit.description, "Conta de luz");
+        assert_eq!(debit.external_id.len(), 40);
+        assert_eq!(debit.external_id, again.lines[1].external_id);
+    }
+
+    #[test]
+    fn empty_statement_is_rejected() {
+        assert!(parse(b"data;valor\n").is_err());
+        assert!(parse(b"descricao;valor\nx;1,00\n").is_err());
+    }
+}
@@ -0,0 +1,45 @@
+/// Keep only the ASCII digits of a document number ("123.456.789-09" → "12345678909")
+pub fn only_digits(value: &str) -> String {
+    value.chars().filter(|c| c.is_ascii_digit()).collect()
+}
+
+/// Validate a CPF (11 digits with both check digits), punctuation ignored
+pub fn is_valid_cpf(value: &str) -> bool {
+    let digits: Vec<u32> = only_digits(value).chars().filter_map(|c| c.to_digit(10)).collect();
+    if digits.len() != 11 || digits.iter().all(|&d| d == digits[0]) {
+        return false;
+    }
+
+    let check = |len: usize| {
+        let sum: u32 = digits[..len]
+            .iter()
+            .enumerate()
+            .map(|(i, d)| d * (len as u32 + 1 - i as u32))
+            .sum();
+        let rest = (sum * 10) % 11;
+        if rest == 10 { 0 } else { rest }
+    };
+
+    check(9) == digits[9] && check(10) == digits[10]
+}
+
+/// Validate a CNPJ (14 digits with both check digits), punctuation ignored
+pub fn is_valid_cnpj(value: &str) -> bool {
+    let digits: Vec<u32> = only_digits(value).chars().filter_map(|c| c.to_digit(10)).collect();
+    if digits.len() != 14 || digits.iter().all(|&d| d == digits[0]) {
+        return false;
+    }
+
+    let check = |len: usize| {
+        let weights: &[u32] = if len == 12 {
+            &[5, 4, 3, 2, 9, 8, 7, 6, 5, 4, 3, 2]
+        } else {
+            &[6, 5, 4, 3, 2, 9, 8, 7, 6, 5, 4, 3, 2]
+        };
+        let sum: u32 = digits[..len].iter().zip(weights).map(|(d, w)| d * w).sum();
+        let rest = sum % 11;
+        if rest < 2 { 0 } else { 11 - rest }
+    };
+
+    check(12) == digits[12] && check(13) == digits[13]
+}
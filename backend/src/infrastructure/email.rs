@@ -0,0 +1,46 @@
+use lettre::message::header::ContentType;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+
+use crate::config::AppConfig;
+use crate::errors::AppError;
+
+/// Send an HTML email through the configured SMTP relay
+pub async fn send_html(
+    config: &AppConfig,
+    to_email: &str,
+    subject: &str,
+    html: String,
+) -> Result<(), AppError> {
+    let email = Message::builder()
+        .from(
+            config
+                .smtp_from
+                .parse()
+                .map_err(|e| AppError::Internal(format!("Invalid SMTP from address: {e}")))?,
+        )
+        .to(to_email
+            .parse()
+            .map_err(|e| AppError::Internal(format!("Invalid recipient address: {e}")))?)
+        .subject(subject)
+        .header(ContentType::TEXT_HTML)
+        .body(html)
+        .map_err(|e| AppError::Internal(format!("Failed to build email: {e}")))?;
+
+    let creds = Credentials::new(
+        config.smtp_username.clone(),
+        config.smtp_password.clone(),
+    );
+
+    let mailer = AsyncSmtpTransport::<Tokio1Executor>::relay(&config.smtp_host)
+        .map_err(|e| AppError::Internal(format!("SMTP relay error: {e}")))?
+        .credentials(creds)
+        .build();
+
+    mailer
+        .send(email)
+        .await
+        .map_err(|e| AppError::Internal(format!("Failed to send email: {e}")))?;
+
+    Ok(())
+}
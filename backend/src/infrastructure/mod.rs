@@ -1,3 +1,10 @@
+pub mod bank_statement;
 pub mod cache;
 pub mod cloudinary;
 pub mod database;
+pub mod documents;
+pub mod email;
+pub mod payment_provider;
+pub mod pdf;
+pub mod pix;
+pub mod scheduler;
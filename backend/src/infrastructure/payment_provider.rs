@@ -0,0 +1,314 @@
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use sha2::Sha256;
+use std::str::FromStr;
+
+use crate::errors::AppError;
+
+/// A payment reported by a provider, normalized
+#[derive(Debug)]
+pub struct ProviderPayment {
+    /// Provider's unique id (end-to-end id for PIX); used to drop redeliveries
+    pub transaction_id: String,
+    /// "pix" or "cartao"
+    pub method: &'static str,
+    pub amount: Decimal,
+    pub paid_at: DateTime<Utc>,
+    /// txid of our PIX code, or the order reference on card payments
+    pub txid: Option<String>,
+    pub payer_name: Option<String>,
+    /// CPF/CNPJ digits
+    pub payer_document: Option<String>,
+    pub raw: serde_json::Value,
+}
+
+/// A payment provider that notifies us through a signed webhook.
+/// Implementations only parse and authenticate; matching and entries are up to
+/// `PaymentWebhookService`.
+pub trait PaymentProvider: Send + Sync {
+    /// Name used in the webhook URL and in `payment_integrations.provider`
+    fn name(&self) -> &'static str;
+
+    /// Header carrying the signature of the raw body
+    fn signature_header(&self) -> &'static str {
+        "X-Webhook-Signature"
+    }
+
+    /// Default scheme: hex HMAC-SHA256 of the raw body with the integration secret
+    fn verify_signature(&self, secret: &str, body: &[u8], signature: &str) -> bool {
+        verify_hmac_sha256(secret, body, signature)
+    }
+
+    fn parse(&self, body: &[u8]) -> Result<Vec<ProviderPayment>, AppError>;
+}
+
+/// Provider registered under `name`. `mock` only exists when `allow_mock` is set
+/// (`PAYMENT_MOCK_PROVIDER=true`), so it cannot be enabled in production.
+pub fn provider(name: &str, allow_mock: bool) -> Option<Box<dyn PaymentProvider>> {
+    match name {
+        "pix" => Some(Box::new(PixApiProvider)),
+        "mock" if allow_mock => Some(Box::new(MockProvider)),
+        _ => None,
+    }
+}
+
+/// Constant-time check of a hex HMAC-SHA256 signature ("sha256=" prefix accepted)
+pub fn verify_hmac_sha256(secret: &str, body: &[u8], signature: &str) -> bool {
+    let signature = signature.trim();
+    let signature = signature.strip_prefix("sha256=").unwrap_or(signature);
+    let Some(expected) = hex_decode(signature) else {
+        return false;
+    };
+
+    let Ok(mut mac) = Hmac::<Sha256>::new_from_slice(secret.as_bytes()) else {
+        return false;
+    };
+    mac.update(body);
+    mac.verify_slice(&expected).is_ok()
+}
+
+fn hex_decode(value: &str) -> Option<Vec<u8>> {
+    if value.len() % 2 != 0 {
+        return None;
+    }
+    (0..value.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(value.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+fn invalid_payload(e: impl std::fmt::Display) -> AppError {
+    AppError::validation(format!("Notificação de pagamento inválida: {e}"))
+}
+
+/// Webhook of the Banco Central "API Pix" standard, used by most PSPs:
+/// `{"pix": [{"endToEndId", "txid", "valor", "horario", "pagador": {...}}]}`
+pub struct PixApiProvider;
+
+#[derive(Deserialize)]
+struct PixApiNotification {
+    pix: Vec<serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PixApiPayment {
+    end_to_end_id: String,
+    txid: Option<String>,
+    valor: String,
+    horario: DateTime<Utc>,
+    pagador: Option<PixApiPayer>,
+}
+
+#[derive(Deserialize)]
+struct PixApiPayer {
+    nome: Option<String>,
+    cpf: Option<String>,
+    cnpj: Option<String>,
+}
+
+impl PaymentProvider for PixApiProvider {
+    fn name(&self) -> &'static str {
+        "pix"
+    }
+
+    fn parse(&self, body: &[u8]) -> Result<Vec<ProviderPayment>, AppError> {
+        let notification: PixApiNotification =
+            serde_json::from_slice(body).map_err(invalid_payload)?;
+
+        notification
+            .pix
+            .into_iter()
+            .map(|raw| {
+                let payment: PixApiPayment =
+                    serde_json::from_value(raw.clone()).map_err(invalid_payload)?;
+                let amount = Decimal::from_str(&payment.valor).map_err(invalid_payload)?;
+                let (payer_name, payer_document) = match payment.pagador {
+                    Some(p) => (p.nome, p.cpf.or(p.cnpj)),
+                    None => (None, None),
+                };
+
+                Ok(ProviderPayment {
+                    transaction_id: payment.end_to_end_id,
+                    method: "pix",
+                    amount,
+                    paid_at: payment.horario,
+                    txid: payment.txid,
+                    payer_name,
+                    payer_document,
+                    raw,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Local provider with a flat format, for development and integration tests:
+/// `{"payments": [{"transaction_id", "method", "amount", "paid_at", "reference", "payer_name", "payer_document"}]}`
+pub struct MockProvider;
+
+#[derive(Deserialize)]
+struct MockNotification {
+    payments: Vec<serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+struct MockPayment {
+    transaction_id: String,
+    method: String,
+    amount: Decimal,
+    paid_at: DateTime<Utc>,
+    reference: Option<String>,
+    payer_name: Option<String>,
+    payer_document: Option<String>,
+}
+
+impl PaymentProvider for MockProvider {
+    fn name(&self) -> &'static str {
+        "mock"
+    }
+
+    fn parse(&self, body: &[u8]) -> Result<Vec<ProviderPayment>, AppError> {
+        let notification: MockNotification =
+            serde_json::from_slice(body).map_err(invalid_payload)?;
+
+        notification
+            .payments
+            .into_iter()
+            .map(|raw| {
+                let payment: MockPayment =
+                    serde_json::from_value(raw.clone()).map_err(invalid_payload)?;
+                let method = match payment.method.as_str() {
+                    "pix" => "pix",
+                    "cartao" => "cartao",
+                    other => return Err(invalid_payload(format!("método '{other}'"))),
+                };
+
+                Ok(ProviderPayment {
+                    transaction_id: payment.transaction_id,
+                    method,
+                    amount: payment.amount,
+                    paid_at: payment.paid_at,
+                    txid: payment.reference,
+                    payer_name: payment.payer_name,
+                    payer_document: payment.payer_document,
+                    raw,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "segredo-do-webhook";
+    const BODY: &[u8] = br#"{"pix":[]}"#;
+
+    fn sign(body: &[u8]) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(SECRET.as_bytes()).unwrap();
+        mac.update(body);
+        mac.finalize()
+            .into_bytes()
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect()
+    }
+
+    #[test]
+    fn hmac_accepts_valid_signature() {
+        let signature = sign(BODY);
+        assert!(verify_hmac_sha256(SECRET, BODY, &signature));
+        assert!(verify_hmac_sha256(SECRET, BODY, &signature.to_uppercase()));
+    }
+
+    #[test]
+    fn hmac_accepts_sha256_prefix() {
+        assert!(verify_hmac_sha256(SECRET, BODY, &format!("sha256={}", sign(BODY))));
+    }
+
+    #[test]
+    fn hmac_rejects_tampered_body_or_wrong_secret() {
+        let signature = sign(BODY);
+        assert!(!verify_hmac_sha256(SECRET, br#"{"pix":[{}]}"#, &signature));
+        assert!(!verify_hmac_sha256("outro-segredo", BODY, &signature));
+    }
+
+    #[test]
+    fn hmac_rejects_malformed_hex() {
+        let signature = sign(BODY);
+        assert!(!verify_hmac_sha256(SECRET, BODY, &signature[1..]));
+        assert!(!verify_hmac_sha256(SECRET, BODY, &format!("zz{}", &signature[2..])));
+        assert!(!verify_hmac_sha256(SECRET, BODY, ""));
+    }
+
+    #[test]
+    fn mock_provider_needs_flag() {
+        assert!(provider("mock", false).is_none());
+        assert_eq!(provider("mock", true).unwrap().name(), "mock");
+        assert_eq!(provider("pix", false).unwrap().name(), "pix");
+        assert!(provider("outro", true).is_none());
+    }
+
+    #[test]
+    fn pix_api_parses_payments() {
+        let body = br#"{"pix": [
+            {"endToEndId": "E1234", "txid": "CP7KQ2M9XH4TRW8ZNB", "valor": "150.00",
+             "horario": "2027-03-01T15:00:00Z", "pagador": {"nome": "Maria Souza", "cpf": "12345678909"}},
+            {"endToEndId": "E5678", "valor": "20.50", "horario": "2027-03-01T16:00:00Z",
+             "pagador": {"nome": "Igreja Irma", "cnpj": "12345678000195"}}
+        ]}"#;
+
+        let payments = PixApiProvider.parse(body).unwrap();
+        assert_eq!(payments.len(), 2);
+
+        let first = &payments[0];
+        assert_eq!(first.transaction_id, "E1234");
+        assert_eq!(first.method, "pix");
+        assert_eq!(first.amount, Decimal::new(15000, 2));
+        assert_eq!(first.paid_at.to_rfc3339(), "2027-03-01T15:00:00+00:00");
+        assert_eq!(first.txid.as_deref(), Some("CP7KQ2M9XH4TRW8ZNB"));
+        assert_eq!(first.payer_name.as_deref(), Some("Maria Souza"));
+        assert_eq!(first.payer_document.as_deref(), Some("12345678909"));
+
+        let second = &payments[1];
+        assert_eq!(second.txid, None);
+        assert_eq!(second.amount, Decimal::new(2050, 2));
+        assert_eq!(second.payer_document.as_deref(), Some("12345678000195"));
+    }
+
+    #[test]
+    fn pix_api_rejects_invalid_payload() {
+        assert!(PixApiProvider.parse(b"not json").is_err());
+        assert!(PixApiProvider
+            .parse(br#"{"pix": [{"endToEndId": "E1", "valor": "abc", "horario": "2027-03-01T15:00:00Z"}]}"#)
+            .is_err());
+    }
+
+    #[test]
+    fn mock_provider_parses_payments() {
+        let body = br#"{"payments": [
+            {"transaction_id": "t-1", "method": "cartao", "amount": 80.00,
+             "paid_at": "2027-03-01T15:00:00Z", "reference": "CP7KQ2M9XH4TRW8ZNB", "payer_name": "Maria Souza"}
+        ]}"#;
+
+        let payments = MockProvider.parse(body).unwrap();
+        assert_eq!(payments.len(), 1);
+        assert_eq!(payments[0].transaction_id, "t-1");
+        assert_eq!(payments[0].method, "cartao");
+        assert_eq!(payments[0].amount, Decimal::new(80, 0));
+        assert_eq!(payments[0].txid.as_deref(), Some("CP7KQ2M9XH4TRW8ZNB"));
+        assert_eq!(payments[0].payer_document, None);
+    }
+
+    #[test]
+    fn mock_provider_rejects_unknown_method() {
+        let body = br#"{"payments": [
+            {"transaction_id": "t-1", "method": "boleto", "amount": 80.00, "paid_at": "2027-03-01T15:00:00Z"}
+        ]}"#;
+        assert!(MockProvider.parse(body).is_err());
+    }
+}
@@ -0,0 +1,1028 @@
+use chrono::{Datelike, NaiveDate};
+use flate2::read::ZlibDecoder;
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
+use rust_decimal::Decimal;
+use std::io::{Read, Write};
+use std::time::Duration;
+
+use crate::domain::entities::church::Church;
+
+/// A4 in points
+pub const PAGE_WIDTH: f32 = 595.28;
+pub const PAGE_HEIGHT: f32 = 841.89;
+pub const MARGIN: f32 = 40.0;
+pub const CONTENT_WIDTH: f32 = PAGE_WIDTH - 2.0 * MARGIN;
+
+const LOGO_SIZE: f32 = 56.0;
+const ROW_HEIGHT: f32 = 16.0;
+const FOOTER_HEIGHT: f32 = 30.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Font {
+    Regular,
+    Bold,
+}
+
+impl Font {
+    fn resource(self) -> &'static str {
+        match self {
+            Font::Regular => "F1",
+            Font::Bold => "F2",
+        }
+    }
+}
+
+// ==========================================
+// Low-level document
+// ==========================================
+
+/// Minimal PDF writer: standard Helvetica fonts (WinAnsi), lines, filled
+/// rectangles and JPEG/PNG images. Coordinates are in points from the
+/// top-left corner of the page.
+pub struct PdfDocument {
+    pages: Vec<Vec<u8>>,
+    images: Vec<PdfImage>,
+    current: usize,
+}
+
+impl Default for PdfDocument {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PdfDocument {
+    pub fn new() -> Self {
+        Self {
+            pages: Vec::new(),
+            images: Vec::new(),
+            current: 0,
+        }
+    }
+
+    pub fn add_page(&mut self) {
+        self.pages.push(Vec::new());
+        self.current = self.pages.len() - 1;
+    }
+
+    pub fn page_count(&self) -> usize {
+        self.pages.len()
+    }
+
+    /// Register an image; returns the handle used by [`PdfDocument::image`]
+    pub fn add_image(&mut self, image: PdfImage) -> usize {
+        self.images.push(image);
+        self.images.len() - 1
+    }
+
+    pub fn text(&mut self, x: f32, y: f32, size: f32, font: Font, text: &str) {
+        let mut op = format!(
+            "BT /{} {size:.1} Tf {x:.2} {:.2} Td (",
+            font.resource(),
+            PAGE_HEIGHT - y
+        )
+        .into_bytes();
+        op.extend(escape(&win_ansi(text)));
+        op.extend_from_slice(b") Tj ET\n");
+        self.current().extend(op);
+    }
+
+    pub fn text_right(&mut self, right: f32, y: f32, size: f32, font: Font, text: &str) {
+        self.text(right - text_width(text, size, font), y, size, font, text);
+    }
+
+    pub fn text_center(&mut self, center: f32, y: f32, size: f32, font: Font, text: &str) {
+        self.text(center - text_width(text, size, font) / 2.0, y, size, font, text);
+    }
+
+    /// Gray level for the following text and fills (0 = black, 1 = white)
+    pub fn fill_gray(&mut self, gray: f32) {
+        let op = format!("{gray:.2} g\n");
+        self.current().extend(op.into_bytes());
+    }
+
+    pub fn line(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, width: f32) {
+        let op = format!(
+            "{width:.2} w {x1:.2} {:.2} m {x2:.2} {:.2} l S\n",
+            PAGE_HEIGHT - y1,
+            PAGE_HEIGHT - y2
+        );
+        self.current().extend(op.into_bytes());
+    }
+
+    pub fn fill_rect(&mut self, x: f32, y: f32, width: f32, height: f32, gray: f32) {
+        let op = format!(
+            "q {gray:.2} g {x:.2} {:.2} {width:.2} {height:.2} re f Q\n",
+            PAGE_HEIGHT - y - height
+        );
+        self.current().extend(op.into_bytes());
+    }
+
+    pub fn image(&mut self, handle: usize, x: f32, y: f32, width: f32, height: f32) {
+        let op = format!(
+            "q {width:.2} 0 0 {height:.2} {x:.2} {:.2} cm /Im{handle} Do Q\n",
+            PAGE_HEIGHT - y - height
+        );
+        self.current().extend(op.into_bytes());
+    }
+
+    /// Draw on a page already written (e.g. "página x de y" footers)
+    pub fn select_page(&mut self, index: usize) {
+        self.current = index.min(self.pages.len().saturating_sub(1));
+    }
+
+    /// Serialize the document
+    pub fn finish(mut self) -> Vec<u8> {
+        if self.pages.is_empty() {
+            self.add_page();
+        }
+
+        let mut objects: Vec<Vec<u8>> = Vec::new();
+        // 1: catalog, 2: page tree, 3-4: fonts
+        objects.push(b"<< /Type /Catalog /Pages 2 0 R >>".to_vec());
+        objects.push(Vec::new());
+        for base in ["Helvetica", "Helvetica-Bold"] {
+            objects.push(
+                format!(
+                    "<< /Type /Font /Subtype /Type1 /BaseFont /{base} /Encoding /WinAnsiEncoding >>"
+                )
+                .into_bytes(),
+            );
+        }
+
+        let mut image_refs = Vec::new();
+        for image in &self.images {
+            let smask_ref = image.alpha.as_ref().map(|alpha| {
+                objects.push(stream_object(
+                    &format!(
+                        "/Type /XObject /Subtype /Image /Width {} /Height {} /ColorSpace /DeviceGray /BitsPerComponent 8 /Filter /FlateDecode",
+                        image.width, image.height
+                    ),
+                    alpha,
+                ));
+                objects.len()
+            });
+            let smask = smask_ref
+                .map(|r| format!(" /SMask {r} 0 R"))
+                .unwrap_or_default();
+            objects.push(stream_object(
+                &format!(
+                    "/Type /XObject /Subtype /Image /Width {} /Height {} /ColorSpace /{} /BitsPerComponent 8 /Filter /{}{smask}",
+                    image.width, image.height, image.color_space, image.filter
+                ),
+                &image.data,
+            ));
+            image_refs.push(objects.len());
+        }
+
+        let xobjects: String = image_refs
+            .iter()
+            .enumerate()
+            .map(|(i, r)| format!(" /Im{i} {r} 0 R"))
+            .collect();
+
+        let mut page_refs = Vec::new();
+        for content in &self.pages {
+            objects.push(stream_object("/Filter /FlateDecode", &deflate(content)));
+            let content_ref = objects.len();
+            objects.push(
+                format!(
+                    "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {PAGE_WIDTH} {PAGE_HEIGHT}] \
+                     /Resources << /Font << /F1 3 0 R /F2 4 0 R >> /XObject <<{xobjects} >> >> \
+                     /Contents {content_ref} 0 R >>"
+                )
+                .into_bytes(),
+            );
+            page_refs.push(objects.len());
+        }
+
+        let kids: String = page_refs.iter().map(|r| format!("{r} 0 R ")).collect();
+        objects[1] = format!(
+            "<< /Type /Pages /Kids [{}] /Count {} >>",
+            kids.trim_end(),
+            page_refs.len()
+        )
+        .into_bytes();
+
+        let mut out = b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n".to_vec();
+        let mut offsets = Vec::with_capacity(objects.len());
+        for (i, object) in objects.iter().enumerate() {
+            offsets.push(out.len());
+            out.extend(format!("{} 0 obj\n", i + 1).into_bytes());
+            out.extend(object);
+            out.extend_from_slice(b"\nendobj\n");
+        }
+
+        let xref_offset = out.len();
+        out.extend(format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1).into_bytes());
+        for offset in offsets {
+            out.extend(format!("{offset:010} 00000 n \n").into_bytes());
+        }
+        out.extend(
+            format!(
+                "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref_offset}\n%%EOF\n",
+                objects.len() + 1
+            )
+            .into_bytes(),
+        );
+        out
+    }
+
+    fn current(&mut self) -> &mut Vec<u8> {
+        if self.pages.is_empty() {
+            self.add_page();
+        }
+        &mut self.pages[self.current]
+    }
+}
+
+fn stream_object(dict: &str, data: &[u8]) -> Vec<u8> {
+    let mut object = format!("<< {dict} /Length {} >>\nstream\n", data.len()).into_bytes();
+    object.extend_from_slice(data);
+    object.extend_from_slice(b"\nendstream");
+    object
+}
+
+fn deflate(data: &[u8]) -> Vec<u8> {
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(data).ok();
+    encoder.finish().unwrap_or_default()
+}
+
+/// PDF literal strings only need parentheses and backslashes escaped
+fn escape(bytes: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(bytes.len());
+    for &b in bytes {
+        if matches!(b, b'(' | b')' | b'\\') {
+            out.push(b'\\');
+        }
+        out.push(b);
+    }
+    out
+}
+
+/// Encode text as WinAnsi (Latin-1 plus typographic punctuation)
+fn win_ansi(text: &str) -> Vec<u8> {
+    text.chars()
+        .map(|c| match c {
+            ' '..='~' => c as u8,
+            '\u{A0}'..='\u{FF}' => c as u32 as u8,
+            '€' => 0x80,
+            '…' => 0x85,
+            '‘' => 0x91,
+            '’' => 0x92,
+            '“' => 0x93,
+            '”' => 0x94,
+            '•' => 0x95,
+            '–' => 0x96,
+            '—' => 0x97,
+            '→' => b'>',
+            _ => b'?',
+        })
+        .collect()
+}
+
+// Helvetica / Helvetica-Bold advance widths (1/1000 em) for ' '..='~'
+const HELVETICA_WIDTHS: [u16; 95] = [
+    278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278, 556, 556,
+    556, 556, 556, 556, 556, 556, 556, 556, 278, 278, 584, 584, 584, 556, 1015, 667, 667, 722,
+    722, 667, 611, 778, 722, 278, 500, 667, 556, 833, 722, 778, 667, 778, 722, 667, 611, 722,
+    667, 944, 667, 667, 611, 278, 278, 278, 469, 556, 333, 556, 556, 500, 556, 556, 278, 556,
+    556, 222, 222, 500, 222, 833, 556, 556, 556, 556, 333, 500, 278, 556, 500, 722, 500, 500,
+    500, 334, 260, 334, 584,
+];
+const HELVETICA_BOLD_WIDTHS: [u16; 95] = [
+    278, 333, 474, 556, 556, 889, 722, 238, 333, 333, 389, 584, 278, 333, 278, 278, 556, 556,
+    556, 556, 556, 556, 556, 556, 556, 556, 333, 333, 584, 584, 584, 611, 975, 722, 722, 722,
+    722, 667, 611, 778, 722, 278, 556, 722, 611, 833, 722, 778, 667, 778, 722, 667, 611, 722,
+    667, 944, 667, 667, 611, 333, 278, 333, 584, 556, 333, 556, 611, 556, 611, 556, 333, 611,
+    611, 278, 278, 556, 278, 889, 611, 611, 611, 611, 389, 556, 333, 611, 556, 778, 556, 556,
+    500, 389, 280, 389, 584,
+];
+
+/// Width of `text` in points
+pub fn text_width(text: &str, size: f32, font: Font) -> f32 {
+    let table = match font {
+        Font::Regular => &HELVETICA_WIDTHS,
+        Font::Bold => &HELVETICA_BOLD_WIDTHS,
+    };
+    let units: u32 = text
+        .chars()
+        .map(|c| {
+            let base = unaccent(c);
+            match base {
+                ' '..='~' => table[base as usize - 32] as u32,
+                _ => 556,
+            }
+        })
+        .sum();
+    units as f32 * size / 1000.0
+}
+
+/// Strip Portuguese diacritics from a character
+pub fn unaccent(c: char) -> char {
+    match c {
+        'á' | 'à' | 'â' | 'ã' | 'ä' => 'a',
+        'Á' | 'À' | 'Â' | 'Ã' | 'Ä' => 'A',
+        'é' | 'è' | 'ê' | 'ë' => 'e',
+        'É' | 'È' | 'Ê' | 'Ë' => 'E',
+        'í' | 'ì' | 'î' | 'ï' => 'i',
+        'Í' | 'Ì' | 'Î' | 'Ï' => 'I',
+        'ó' | 'ò' | 'ô' | 'õ' | 'ö' => 'o',
+        'Ó' | 'Ò' | 'Ô' | 'Õ' | 'Ö' => 'O',
+        'ú' | 'ù' | 'û' | 'ü' => 'u',
+        'Ú' | 'Ù' | 'Û' | 'Ü' => 'U',
+        'ç' => 'c',
+        'Ç' => 'C',
+        'ñ' => 'n',
+        'Ñ' => 'N',
+        '\u{A0}' => ' ',
+        other => other,
+    }
+}
+
+/// Cut `text` with an ellipsis so it fits in `max_width`
+fn fit(text: &str, max_width: f32, size: f32, font: Font) -> String {
+    if text_width(text, size, font) <= max_width {
+        return text.to_string();
+    }
+    let mut chars: Vec<char> = text.chars().collect();
+    while !chars.is_empty() {
+        chars.pop();
+        let candidate: String = chars.iter().collect::<String>() + "…";
+        if text_width(&candidate, size, font) <= max_width {
+            return candidate;
+        }
+    }
+    String::new()
+}
+
+/// Greedy word wrap
+fn wrap(text: &str, max_width: f32, size: f32, font: Font) -> Vec<String> {
+    let mut lines = Vec::new();
+    for paragraph in text.split('\n') {
+        let mut line = String::new();
+        for word in paragraph.split_whitespace() {
+            let candidate = if line.is_empty() {
+                word.to_string()
+            } else {
+                format!("{line} {word}")
+            };
+            if text_width(&candidate, size, font) > max_width && !line.is_empty() {
+                lines.push(std::mem::replace(&mut line, word.to_string()));
+            } else {
+                line = candidate;
+            }
+        }
+        lines.push(line);
+    }
+    lines
+}
+
+// ==========================================
+// Images
+// ==========================================
+
+/// Image ready to embed: JPEG is passed through, PNG is decoded and re-deflated
+/// with its alpha channel split into a soft mask.
+pub struct PdfImage {
+    pub width: u32,
+    pub height: u32,
+    color_space: &'static str,
+    filter: &'static str,
+    data: Vec<u8>,
+    alpha: Option<Vec<u8>>,
+}
+
+impl PdfImage {
+    /// Returns `None` for unsupported formats (interlaced or 16-bit PNG, CMYK JPEG, ...)
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.starts_with(&[0xFF, 0xD8]) {
+            Self::from_jpeg(bytes)
+        } else if bytes.starts_with(&[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A]) {
+            Self::from_png(bytes)
+        } else {
+            None
+        }
+    }
+
+    fn from_jpeg(bytes: &[u8]) -> Option<Self> {
+        let mut i = 2;
+        while i + 9 < bytes.len() {
+            if bytes[i] != 0xFF {
+                return None;
+            }
+            let marker = bytes[i + 1];
+            let length = u16::from_be_bytes([bytes[i + 2], bytes[i + 3]]) as usize;
+            // SOFn frames (except DHT, JPG and DAC markers) carry the dimensions
+            if (0xC0..=0xCF).contains(&marker) && !matches!(marker, 0xC4 | 0xC8 | 0xCC) {
+                let height = u16::from_be_bytes([bytes[i + 5], bytes[i + 6]]) as u32;
+                let width = u16::from_be_bytes([bytes[i + 7], bytes[i + 8]]) as u32;
+                let color_space = match bytes[i + 9] {
+                    1 => "DeviceGray",
+                    3 => "DeviceRGB",
+                    _ => return None,
+                };
+                return Some(Self {
+                    width,
+                    height,
+                    color_space,
+                    filter: "DCTDecode",
+                    data: bytes.to_vec(),
+                    alpha: None,
+                });
+            }
+            i += 2 + length;
+        }
+        None
+    }
+
+    fn from_png(bytes: &[u8]) -> Option<Self> {
+        let mut pos = 8;
+        let (mut width, mut height, mut color_type) = (0u32, 0u32, 0u8);
+        let mut palette = Vec::new();
+        let mut compressed = Vec::new();
+
+        while pos + 8 <= bytes.len() {
+            let length = u32::from_be_bytes(bytes[pos..pos + 4].try_into().ok()?) as usize;
+            let kind = &bytes[pos + 4..pos + 8];
+            let data = bytes.get(pos + 8..pos + 8 + length)?;
+            match kind {
+                b"IHDR" => {
+                    width = u32::from_be_bytes(data[0..4].try_into().ok()?);
+                    height = u32::from_be_bytes(data[4..8].try_into().ok()?);
+                    let (bit_depth, interlace) = (data[8], data[12]);
+                    color_type = data[9];
+                    if bit_depth != 8 || interlace != 0 {
+                        return None;
+                    }
+                }
+                b"PLTE" => palette = data.to_vec(),
+                b"IDAT" => compressed.extend_from_slice(data),
+                b"IEND" => break,
+                _ => {}
+            }
+            pos += 12 + length;
+        }
+
+        let channels = match color_type {
+            0 | 3 => 1,
+            4 => 2,
+            2 => 3,
+            6 => 4,
+            _ => return None,
+        };
+
+        let mut raw = Vec::new();
+        ZlibDecoder::new(compressed.as_slice())
+            .read_to_end(&mut raw)
+            .ok()?;
+        let pixels = unfilter(&raw, width as usize, height as usize, channels)?;
+
+        let mut color = Vec::with_capacity(pixels.len());
+        let mut alpha = Vec::new();
+        for px in pixels.chunks(channels) {
+            match color_type {
+                0 => color.push(px[0]),
+                2 => color.extend_from_slice(px),
+                3 => {
+                    let i = px[0] as usize * 3;
+                    color.extend_from_slice(palette.get(i..i + 3)?);
+                }
+                4 => {
+                    color.push(px[0]);
+                    alpha.push(px[1]);
+                }
+                _ => {
+                    color.extend_from_slice(&px[..3]);
+                    alpha.push(px[3]);
+                }
+            }
+        }
+
+        Some(Self {
+            width,
+            height,
+            color_space: if matches!(color_type, 0 | 4) { "DeviceGray" } else { "DeviceRGB" },
+            filter: "FlateDecode",
+            data: deflate(&color),
+            alpha: if alpha.is_empty() { None } else { Some(deflate(&alpha)) },
+        })
+    }
+}
+
+/// Reverse the per-scanline PNG filters (None, Sub, Up, Average, Paeth)
+fn unfilter(raw: &[u8], width: usize, height: usize, bpp: usize) -> Option<Vec<u8>> {
+    let stride = width * bpp;
+    let mut out = vec![0u8; stride * height];
+
+    for row in 0..height {
+        let start = row * (stride + 1);
+        let filter = *raw.get(start)?;
+        let line = raw.get(start + 1..start + 1 + stride)?;
+        let (done, current) = out.split_at_mut(row * stride);
+        let previous = if row == 0 { None } else { Some(&done[(row - 1) * stride..]) };
+        let current = &mut current[..stride];
+
+        for i in 0..stride {
+            let a = if i >= bpp { current[i - bpp] as i16 } else { 0 };
+            let b = previous.map(|p| p[i] as i16).unwrap_or(0);
+            let c = match previous {
+                Some(p) if i >= bpp => p[i - bpp] as i16,
+                _ => 0,
+            };
+            let predictor = match filter {
+                0 => 0,
+                1 => a,
+                2 => b,
+                3 => (a + b) / 2,
+                4 => {
+                    let p = a + b - c;
+                    let (pa, pb, pc) = ((p - a).abs(), (p - b).abs(), (p - c).abs());
+                    if pa <= pb && pa <= pc {
+                        a
+                    } else if pb <= pc {
+                        b
+                    } else {
+                        c
+                    }
+                }
+                _ => return None,
+            };
+            current[i] = line[i].wrapping_add(predictor as u8);
+        }
+    }
+
+    Some(out)
+}
+
+// ==========================================
+// Report layout
+// ==========================================
+
+/// Church header printed at the top of every report page
+pub struct Letterhead {
+    pub name: String,
+    pub details: Vec<String>,
+    pub logo: Option<PdfImage>,
+}
+
+impl Letterhead {
+    /// Build from the church record; the logo is downloaded from `logo_url` and
+    /// silently left out when unreachable or in an unsupported format.
+    pub async fn for_church(church: &Church) -> Self {
+        let mut details = Vec::new();
+        let mut identification = Vec::new();
+        if let Some(legal_name) = church.legal_name.as_deref().filter(|s| !s.is_empty()) {
+            identification.push(legal_name.to_string());
+        }
+        if let Some(cnpj) = church.cnpj.as_deref().filter(|s| !s.is_empty()) {
+            identification.push(format!("CNPJ {cnpj}"));
+        }
+        if !identification.is_empty() {
+            details.push(identification.join(" — "));
+        }
+
+        let address: Vec<String> = [
+            church.street.as_deref().map(|street| match church.number.as_deref() {
+                Some(number) => format!("{street}, {number}"),
+                None => street.to_string(),
+            }),
+            church.neighborhood.clone(),
+            match (church.city.as_deref(), church.state.as_deref()) {
+                (Some(city), Some(state)) => Some(format!("{city}/{state}")),
+                (Some(city), None) => Some(city.to_string()),
+                _ => None,
+            },
+            church.zip_code.as_deref().map(|zip| format!("CEP {zip}")),
+        ]
+        .into_iter()
+        .flatten()
+        .filter(|s| !s.is_empty())
+        .collect();
+        if !address.is_empty() {
+            details.push(address.join(" — "));
+        }
+
+        let logo = match church.logo_url.as_deref() {
+            Some(url) if !url.is_empty() => fetch_logo(url).await,
+            _ => None,
+        };
+
+        Self {
+            name: church.name.clone(),
+            details,
+            logo,
+        }
+    }
+}
+
+async fn fetch_logo(url: &str) -> Option<PdfImage> {
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(5))
+        .build()
+        .ok()?;
+    let response = client.get(url).send().await.ok()?.error_for_status().ok()?;
+    let bytes = response.bytes().await.ok()?;
+    let image = PdfImage::from_bytes(&bytes);
+    if image.is_none() {
+        tracing::warn!("Logo em formato não suportado para PDF: {url}");
+    }
+    image
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Align {
+    Left,
+    Right,
+    Center,
+}
+
+/// Table column; widths are in points and should add up to [`CONTENT_WIDTH`]
+pub struct Column {
+    pub title: String,
+    pub width: f32,
+    pub align: Align,
+}
+
+impl Column {
+    pub fn left(title: impl Into<String>, width: f32) -> Self {
+        Self { title: title.into(), width, align: Align::Left }
+    }
+
+    pub fn right(title: impl Into<String>, width: f32) -> Self {
+        Self { title: title.into(), width, align: Align::Right }
+    }
+
+    pub fn center(title: impl Into<String>, width: f32) -> Self {
+        Self { title: title.into(), width, align: Align::Center }
+    }
+}
+
+pub struct Row {
+    pub cells: Vec<String>,
+    pub bold: bool,
+}
+
+impl Row {
+    pub fn new(cells: Vec<String>) -> Self {
+        Self { cells, bold: false }
+    }
+
+    /// Highlighted row, for subtotals and totals
+    pub fn bold(cells: Vec<String>) -> Self {
+        Self { cells, bold: true }
+    }
+}
+
+/// Flowing A4 report: letterhead and title on every page, automatic page
+/// breaks and a "página x de y" footer.
+pub struct PdfReport {
+    doc: PdfDocument,
+    letterhead: Letterhead,
+    logo: Option<usize>,
+    title: String,
+    issued_at: String,
+    y: f32,
+}
+
+impl PdfReport {
+    pub fn new(mut letterhead: Letterhead, title: &str) -> Self {
+        let mut doc = PdfDocument::new();
+        let logo = letterhead.logo.take().map(|image| doc.add_image(image));
+        let mut report = Self {
+            doc,
+            letterhead,
+            logo,
+            title: title.to_string(),
+            issued_at: chrono::Local::now().format("%d/%m/%Y %H:%M").to_string(),
+            y: 0.0,
+        };
+        report.new_page();
+        report
+    }
+
+    pub fn heading(&mut self, text: &str) {
+        self.ensure_space(ROW_HEIGHT * 3.0);
+        self.y += 8.0;
+        self.doc.text(MARGIN, self.y + 10.0, 11.0, Font::Bold, text);
+        self.y += 18.0;
+    }
+
+    pub fn paragraph(&mut self, text: &str) {
+        for line in wrap(text, CONTENT_WIDTH, 10.0, Font::Regular) {
+            self.ensure_space(14.0);
+            self.doc.text(MARGIN, self.y + 10.0, 10.0, Font::Regular, &line);
+            self.y += 14.0;
+        }
+        self.y += 4.0;
+    }
+
+    /// Label on the left, value right-aligned, e.g. summary totals
+    pub fn key_value(&mut self, label: &str, value: &str, bold: bool) {
+        self.ensure_space(ROW_HEIGHT);
+        let font = if bold { Font::Bold } else { Font::Regular };
+        self.doc.text(MARGIN, self.y + 11.0, 10.0, font, label);
+        self.doc.text_right(MARGIN + CONTENT_WIDTH, self.y + 11.0, 10.0, font, value);
+        self.y += ROW_HEIGHT;
+    }
+
+    pub fn spacer(&mut self, height: f32) {
+        self.y += height;
+    }
+
+    /// Table with a shaded header, repeated after page breaks
+    pub fn table(&mut self, columns: &[Column], rows: &[Row]) {
+        self.ensure_space(ROW_HEIGHT * 2.0);
+        self.table_header(columns);
+
+        for row in rows {
+            if self.y + ROW_HEIGHT > PAGE_HEIGHT - MARGIN - FOOTER_HEIGHT {
+                self.new_page();
+                self.table_header(columns);
+            }
+            if row.bold {
+                self.doc.fill_rect(MARGIN, self.y, CONTENT_WIDTH, ROW_HEIGHT, 0.95);
+            }
+            let font = if row.bold { Font::Bold } else { Font::Regular };
+            self.cells(columns, row.cells.iter().map(String::as_str), font, 9.0);
+            self.y += ROW_HEIGHT;
+            self.doc.fill_gray(0.85);
+            self.doc.line(MARGIN, self.y, MARGIN + CONTENT_WIDTH, self.y, 0.3);
+            self.doc.fill_gray(0.0);
+        }
+        self.y += 6.0;
+    }
+
+    /// Signature line centred on the page
+    pub fn signature(&mut self, name: &str, role: &str) {
+        self.ensure_space(70.0);
+        self.y += 40.0;
+        let center = PAGE_WIDTH / 2.0;
+        self.doc.line(center - 120.0, self.y, center + 120.0, self.y, 0.5);
+        self.doc.text_center(center, self.y + 12.0, 10.0, Font::Bold, name);
+        self.doc.text_center(center, self.y + 24.0, 9.0, Font::Regular, role);
+        self.y += 30.0;
+    }
+
+    pub fn finish(mut self) -> Vec<u8> {
+        let total = self.doc.page_count();
+        let issued_at = self.issued_at.clone();
+        let y = PAGE_HEIGHT - MARGIN + 8.0;
+        for index in 0..total {
+            let page = &mut self.doc;
+            page.select_page(index);
+            page.fill_gray(0.4);
+            page.text(MARGIN, y, 8.0, Font::Regular, &format!("Emitido em {issued_at}"));
+            page.text_right(
+                MARGIN + CONTENT_WIDTH,
+                y,
+                8.0,
+                Font::Regular,
+                &format!("Página {} de {total}", index + 1),
+            );
+            page.fill_gray(0.0);
+        }
+        self.doc.finish()
+    }
+
+    fn table_header(&mut self, columns: &[Column]) {
+        self.doc.fill_rect(MARGIN, self.y, CONTENT_WIDTH, ROW_HEIGHT, 0.88);
+        self.cells(columns, columns.iter().map(|c| c.title.as_str()), Font::Bold, 9.0);
+        self.y += ROW_HEIGHT;
+    }
+
+    fn cells<'a>(
+        &mut self,
+        columns: &[Column],
+        values: impl Iterator<Item = &'a str>,
+        font: Font,
+        size: f32,
+    ) {
+        let mut x = MARGIN;
+        for (column, value) in columns.iter().zip(values) {
+            let text = fit(value, column.width - 8.0, size, font);
+            let baseline = self.y + 11.0;
+            match column.align {
+                Align::Left => self.doc.text(x + 4.0, baseline, size, font, &text),
+                Align::Right => self.doc.text_right(x + column.width - 4.0, baseline, size, font, &text),
+                Align::Center => self.doc.text_center(x + column.width / 2.0, baseline, size, font, &text),
+            }
+            x += column.width;
+        }
+    }
+
+    fn ensure_space(&mut self, height: f32) {
+        if self.y + height > PAGE_HEIGHT - MARGIN - FOOTER_HEIGHT {
+            self.new_page();
+        }
+    }
+
+    fn new_page(&mut self) {
+        self.doc.add_page();
+        let mut y = MARGIN;
+        let mut text_x = MARGIN;
+
+        if let Some(logo) = self.logo {
+            let image = &self.doc.images[logo];
+            let scale = LOGO_SIZE / image.width.max(image.height) as f32;
+            let (w, h) = (image.width as f32 * scale, image.height as f32 * scale);
+            self.doc.image(logo, MARGIN, y + (LOGO_SIZE - h) / 2.0, w, h);
+            text_x += LOGO_SIZE + 12.0;
+        }
+
+        self.doc.text(text_x, y + 16.0, 14.0, Font::Bold, &self.letterhead.name);
+        let mut detail_y = y + 30.0;
+        for detail in &self.letterhead.details {
+            let line = fit(detail, MARGIN + CONTENT_WIDTH - text_x, 9.0, Font::Regular);
+            self.doc.text(text_x, detail_y, 9.0, Font::Regular, &line);
+            detail_y += 12.0;
+        }
+
+        y += LOGO_SIZE.max(detail_y - y) + 6.0;
+        self.doc.line(MARGIN, y, MARGIN + CONTENT_WIDTH, y, 0.8);
+        y += 22.0;
+        self.doc.text_center(PAGE_WIDTH / 2.0, y, 13.0, Font::Bold, &self.title);
+        self.y = y + 14.0;
+    }
+}
+
+// ==========================================
+// Formatting
+// ==========================================
+
+/// "R$ 1.234,56"
+pub fn brl(value: Decimal) -> String {
+    let rounded = value.round_dp(2);
+    let negative = rounded.is_sign_negative() && !rounded.is_zero();
+    let text = format!("{:.2}", rounded.abs());
+    let (integer, cents) = text.split_once('.').unwrap_or((&text, "00"));
+
+    let mut grouped = String::new();
+    for (i, digit) in integer.chars().enumerate() {
+        if i > 0 && (integer.len() - i) % 3 == 0 {
+            grouped.push('.');
+        }
+        grouped.push(digit);
+    }
+
+    format!("{}R$ {grouped},{cents}", if negative { "-" } else { "" })
+}
+
+/// Amount written out in Portuguese, as on receipts:
+/// "mil duzentos e trinta reais e cinco centavos"
+pub fn amount_in_words(value: Decimal) -> String {
+    let text = format!("{:.2}", value.abs().round_dp(2));
+    let (integer, cents) = text.split_once('.').unwrap_or((&text, "00"));
+    let reais: u64 = integer.parse().unwrap_or(0);
+    let centavos: u64 = cents.parse().unwrap_or(0);
+
+    let mut parts = Vec::new();
+    if reais > 0 {
+        let unit = match reais {
+            1 => "real",
+            // "um milhão de reais"
+            r if r % 1_000_000 == 0 => "de reais",
+            _ => "reais",
+        };
+        parts.push(format!("{} {unit}", number_in_words(reais)));
+    }
+    if centavos > 0 {
+        let unit = if centavos == 1 { "centavo" } else { "centavos" };
+        parts.push(format!("{} {unit}", number_in_words(centavos)));
+    }
+
+    if parts.is_empty() {
+        "zero reais".to_string()
+    } else {
+        parts.join(" e ")
+    }
+}
+
+fn number_in_words(n: u64) -> String {
+    const SCALES: [(&str, &str); 5] = [
+        ("", ""),
+        ("mil", "mil"),
+        ("milhão", "milhões"),
+        ("bilhão", "bilhões"),
+        ("trilhão", "trilhões"),
+    ];
+
+    let mut groups = Vec::new();
+    let (mut rest, mut scale) = (n, 0);
+    while rest > 0 && scale < SCALES.len() {
+        if rest % 1000 > 0 {
+            groups.push((rest % 1000, scale));
+        }
+        rest /= 1000;
+        scale += 1;
+    }
+    groups.reverse();
+
+    let mut words = String::new();
+    for (i, &(group, scale)) in groups.iter().enumerate() {
+        if i > 0 {
+            // "e" before the last group when it is below 100 or a round hundred:
+            // "mil e quinhentos", "dois mil e vinte", but "mil duzentos e trinta"
+            let last = i == groups.len() - 1;
+            words.push_str(if last && (group < 100 || group % 100 == 0) { " e " } else { " " });
+        }
+        match (scale, group) {
+            (0, g) => words.push_str(&hundreds_in_words(g)),
+            (1, 1) => words.push_str("mil"),
+            (s, 1) => words.push_str(&format!("um {}", SCALES[s].0)),
+            (s, g) => words.push_str(&format!("{} {}", hundreds_in_words(g), SCALES[s].1)),
+        }
+    }
+
+    if words.is_empty() { "zero".to_string() } else { words }
+}
+
+/// 1..=999 in words
+fn hundreds_in_words(n: u64) -> String {
+    const UNITS: [&str; 20] = [
+        "", "um", "dois", "três", "quatro", "cinco", "seis", "sete", "oito", "nove", "dez",
+        "onze", "doze", "treze", "quatorze", "quinze", "dezesseis", "dezessete", "dezoito",
+        "dezenove",
+    ];
+    const TENS: [&str; 10] = [
+        "", "", "vinte", "trinta", "quarenta", "cinquenta", "sessenta", "setenta", "oitenta",
+        "noventa",
+    ];
+    const HUNDREDS: [&str; 10] = [
+        "", "cento", "duzentos", "trezentos", "quatrocentos", "quinhentos", "seiscentos",
+        "setecentos", "oitocentos", "novecentos",
+    ];
+
+    if n == 100 {
+        return "cem".to_string();
+    }
+
+    let (hundreds, rest) = ((n / 100) as usize, (n % 100) as usize);
+    let mut parts = Vec::new();
+    if hundreds > 0 {
+        parts.push(HUNDREDS[hundreds]);
+    }
+    if rest >= 20 {
+        parts.push(TENS[rest / 10]);
+        if rest % 10 > 0 {
+            parts.push(UNITS[rest % 10]);
+        }
+    } else if rest > 0 {
+        parts.push(UNITS[rest]);
+    }
+    parts.join(" e ")
+}
+
+/// "15/02/2026"
+pub fn date_br(date: NaiveDate) -> String {
+    date.format("%d/%m/%Y").to_string()
+}
+
+pub fn month_name(month: u32) -> &'static str {
+    const MONTHS: [&str; 12] = [
+        "Janeiro", "Fevereiro", "Março", "Abril", "Maio", "Junho", "Julho", "Agosto",
+        "Setembro", "Outubro", "Novembro", "Dezembro",
+    ];
+    MONTHS.get(month.wrapping_sub(1) as usize).copied().unwrap_or("")
+}
+
+
+/// "Fevereiro/2026"
+pub fn month_year(date: NaiveDate) -> String {
+    format!("{}/{}", month_name(date.month()), date.year())
+}
+
+/// "Fev/2026", for month columns
+pub fn month_short(date: NaiveDate) -> String {
+    let name: String = month_name(date.month()).chars().take(3).collect();
+    format!("{name}/{}", date.year())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(value: &str) -> String {
+        amount_in_words(value.parse().unwrap())
+    }
+
+    #[test]
+    fn amount_in_words_singular() {
+        assert_eq!(words("1.00"), "um real");
+        assert_eq!(words("0.01"), "um centavo");
+    }
+
+    #[test]
+    fn amount_in_words_round_values() {
+        assert_eq!(words("100.00"), "cem reais");
+        assert_eq!(words("1200.00"), "mil e duzentos reais");
+        assert_eq!(words("1000000.00"), "um milhão de reais");
+    }
+
+    #[test]
+    fn amount_in_words_with_cents() {
+        assert_eq!(words("1230.05"), "mil duzentos e trinta reais e cinco centavos");
+    }
+}
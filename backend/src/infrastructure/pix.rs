@@ -0,0 +1,261 @@
+use qrcode::{EcLevel, QrCode};
+use rust_decimal::Decimal;
+use std::io::Cursor;
+
+use crate::errors::AppError;
+use crate::infrastructure::documents;
+use crate::infrastructure::pdf::unaccent;
+
+/// Field limits of the BR Code (Manual de Padrões para Iniciação do Pix)
+const MAX_NAME_LEN: usize = 25;
+const MAX_CITY_LEN: usize = 15;
+const MAX_TXID_LEN: usize = 25;
+const MAX_ACCOUNT_INFO_LEN: usize = 99;
+const PIX_GUI: &str = "br.gov.bcb.pix";
+
+/// Data of a static PIX charge
+#[derive(Debug)]
+pub struct StaticCharge<'a> {
+    pub key: &'a str,
+    pub merchant_name: &'a str,
+    pub merchant_city: &'a str,
+    /// None lets the payer type the amount
+    pub amount: Option<Decimal>,
+    /// Alphanumeric, up to 25 characters; None = "***"
+    pub txid: Option<&'a str>,
+    /// Shown to the payer by most bank apps
+    pub description: Option<&'a str>,
+}
+
+/// Validate a PIX key and return it in the canonical form used in the payload.
+/// Types: "cpf", "cnpj", "email", "telefone" (stored as +55DDDNUMERO) and "aleatoria".
+pub fn normalize_key(key_type: &str, key: &str) -> Result<String, AppError> {
+    let key = key.trim();
+    match key_type {
+        "cpf" if documents::is_valid_cpf(key) => Ok(documents::only_digits(key)),
+        "cpf" => Err(AppError::validation("Chave PIX: CPF inválido")),
+        "cnpj" if documents::is_valid_cnpj(key) => Ok(documents::only_digits(key)),
+        "cnpj" => Err(AppError::validation("Chave PIX: CNPJ inválido")),
+        "email" => {
+            let email = key.to_lowercase();
+            let valid = email.len() <= 77
+                && email.split_once('@').is_some_and(|(user, domain)| {
+                    !user.is_empty() && domain.contains('.') && !email.contains(' ')
+                });
+            if valid {
+                Ok(email)
+            } else {
+                Err(AppError::validation("Chave PIX: e-mail inválido"))
+            }
+        }
+        "telefone" => {
+            let digits = documents::only_digits(key);
+            let national = match digits.len() {
+                10 | 11 => digits,
+                12 | 13 if digits.starts_with("55") => digits[2..].to_string(),
+                _ => return Err(AppError::validation("Chave PIX: telefone inválido (DDD + número)")),
+            };
+            Ok(format!("+55{national}"))
+        }
+        "aleatoria" => uuid::Uuid::parse_str(key)
+            .map(|id| id.to_string())
+            .map_err(|_| AppError::validation("Chave PIX: chave aleatória inválida")),
+        _ => Err(AppError::validation(
+            "Tipo de chave PIX deve ser: cpf, cnpj, email, telefone ou aleatoria",
+        )),
+    }
+}
+
+/// Build the EMV "copia e cola" payload of a static PIX BR Code, CRC16 included.
+pub fn static_payload(charge: &StaticCharge<'_>) -> Result<String, AppError> {
+    let txid = match charge.txid {
+        Some(txid) => {
+            if txid.is_empty()
+                || txid.len() > MAX_TXID_LEN
+                || !txid.chars().all(|c| c.is_ascii_alphanumeric())
+            {
+                return Err(AppError::validation(
+                    "Identificador (txid) deve ter até 25 letras ou números",
+                ));
+            }
+            txid.to_string()
+        }
+        None => "***".to_string(),
+    };
+
+    let name = ascii_upper(charge.merchant_name, MAX_NAME_LEN);
+    let city = ascii_upper(charge.merchant_city, MAX_CITY_LEN);
+    if name.is_empty() || city.is_empty() {
+        return Err(AppError::validation(
+            "Nome e cidade do recebedor são obrigatórios no QR Code PIX",
+        ));
+    }
+
+    let mut account_info = field("00", PIX_GUI) + &field("01", charge.key);
+    if let Some(description) = charge.description {
+        // Whatever room the key leaves in the 99-character account field
+        let room = MAX_ACCOUNT_INFO_LEN.saturating_sub(account_info.len() + 4);
+        let description = ascii_text(description, room);
+        if !description.is_empty() {
+            account_info.push_str(&field("02", &description));
+        }
+    }
+    if account_info.len() > MAX_ACCOUNT_INFO_LEN {
+        return Err(AppError::validation("Chave PIX longa demais para o QR Code"));
+    }
+
+    let mut payload = field("00", "01");
+    payload.push_str(&field("01", "11"));
+    payload.push_str(&field("26", &account_info));
+    payload.push_str(&field("52", "0000"));
+    payload.push_str(&field("53", "986"));
+    if let Some(amount) = charge.amount {
+        if amount <= Decimal::ZERO {
+            return Err(AppError::validation("Valor deve ser maior que zero"));
+        }
+        payload.push_str(&field("54", &format!("{:.2}", amount.round_dp(2))));
+    }
+    payload.push_str(&field("58", "BR"));
+    payload.push_str(&field("59", &name));
+    payload.push_str(&field("60", &city));
+    payload.push_str(&field("62", &field("05", &txid)));
+    payload.push_str("6304");
+
+    let crc = crc16(payload.as_bytes());
+    payload.push_str(&format!("{crc:04X}"));
+
+    Ok(payload)
+}
+
+/// Render a payload as a PNG QR code of at least `size` pixels
+pub fn qr_png(payload: &str, size: u32) -> Result<Vec<u8>, AppError> {
+    let image = qr_code(payload)?
+        .render::<image::Luma<u8>>()
+        .min_dimensions(size, size)
+        .build();
+
+    let mut bytes = Cursor::new(Vec::new());
+    image::DynamicImage::ImageLuma8(image)
+        .write_to(&mut bytes, image::ImageFormat::Png)
+        .map_err(|e| AppError::Internal(format!("Falha ao gerar PNG do QR Code: {e}")))?;
+
+    Ok(bytes.into_inner())
+}
+
+/// Render a payload as an SVG QR code, scalable for banners and print
+pub fn qr_svg(payload: &str, size: u32) -> Result<String, AppError> {
+    Ok(qr_code(payload)?
+        .render::<qrcode::render::svg::Color<'_>>()
+        .min_dimensions(size, size)
+        .build())
+}
+
+fn qr_code(payload: &str) -> Result<QrCode, AppError> {
+    QrCode::with_error_correction_level(payload.as_bytes(), EcLevel::M)
+        .map_err(|e| AppError::Internal(format!("Falha ao gerar QR Code: {e}")))
+}
+
+/// EMV TLV field: 2-digit id, 2-digit length, value
+fn field(id: &str, value: &str) -> String {
+    format!("{id}{:02}{value}", value.len())
+}
+
+/// CRC16/CCITT-FALSE (polynomial 0x1021, initial value 0xFFFF)
+fn crc16(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0xFFFF;
+    for &byte in data {
+        crc ^= (byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
+/// Upper-case ASCII letters, digits and spaces, cut to `max_len`
+fn ascii_upper(text: &str, max_len: usize) -> String {
+    text.chars()
+        .map(unaccent)
+        .filter(|c| c.is_ascii_alphanumeric() || *c == ' ')
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_uppercase()
+        .chars()
+        .take(max_len)
+        .collect::<String>()
+        .trim_end()
+        .to_string()
+}
+
+/// Printable ASCII only, cut to `max_len`
+fn ascii_text(text: &str, max_len: usize) -> String {
+    text.chars()
+        .map(unaccent)
+        .filter(|c| c.is_ascii_graphic() || *c == ' ')
+        .take(max_len)
+        .collect::<String>()
+        .trim()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Static BR Code example of the Manual de Padrões para Iniciação do Pix (BCB)
+    const BCB_EXAMPLE: &str = "00020126580014br.gov.bcb.pix0136123e4567-e12b-12d1-a456-4266554400005204000053039865802BR5913Fulano de Tal6008BRASILIA62070503***63041D3D";
+
+    #[test]
+    fn crc16_matches_published_example() {
+        let (data, crc) = BCB_EXAMPLE.split_at(BCB_EXAMPLE.len() - 4);
+        assert_eq!(format!("{:04X}", crc16(data.as_bytes())), crc);
+        // CRC-16/CCITT-FALSE check value
+        assert_eq!(crc16(b"123456789"), 0x29B1);
+    }
+
+    #[test]
+    fn static_payload_follows_published_example() {
+        let payload = static_payload(&StaticCharge {
+            key: "123e4567-e12b-12d1-a456-426655440000",
+            merchant_name: "Fulano de Tal",
+            merchant_city: "Brasília",
+            amount: None,
+            txid: None,
+            description: None,
+        })
+        .unwrap();
+
+        // Same fields as the example, plus the static point of initiation (01 = 11)
+        // and the name in upper case
+        assert_eq!(
+            payload,
+            "00020101021126580014br.gov.bcb.pix0136123e4567-e12b-12d1-a456-4266554400005204000053039865802BR5913FULANO DE TAL6008BRASILIA62070503***6304A5E2"
+        );
+        let (data, crc) = payload.split_at(payload.len() - 4);
+        assert_eq!(format!("{:04X}", crc16(data.as_bytes())), crc);
+    }
+
+    #[test]
+    fn static_payload_with_amount_and_txid() {
+        let payload = static_payload(&StaticCharge {
+            key: "123e4567-e12b-12d1-a456-426655440000",
+            merchant_name: "Fulano de Tal",
+            merchant_city: "Brasília",
+            amount: Some(Decimal::new(1, 0)),
+            txid: Some("CAMPANHA2027"),
+            description: None,
+        })
+        .unwrap();
+
+        assert!(payload.contains("54041.00"));
+        assert!(payload.contains("62160512CAMPANHA2027"));
+        let (data, crc) = payload.split_at(payload.len() - 4);
+        assert_eq!(format!("{:04X}", crc16(data.as_bytes())), crc);
+    }
+}
@@ -0,0 +1,40 @@
+use sqlx::PgPool;
+use std::time::Duration;
+
+use crate::application::services::{PayablesService, RecurrenceService, RemittanceService};
+use crate::config::AppConfig;
+
+/// How often the background jobs run
+const TICK: Duration = Duration::from_secs(60 * 60);
+
+/// Spawn the periodic background jobs.
+/// Every job is idempotent: a failed run is logged and simply retried on the next tick.
+pub fn spawn(pool: PgPool, config: AppConfig) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(TICK);
+        loop {
+            interval.tick().await;
+
+            // Recurring financial entries
+            match RecurrenceService::generate_due(&pool, None).await {
+                Ok(0) => {}
+                Ok(count) => tracing::info!("Generated {count} recurring financial entries"),
+                Err(e) => tracing::error!("Recurring entries generation failed: {e}"),
+            }
+
+            // Daily accounts payable digest to treasurers
+            match PayablesService::send_daily_digests(&pool, &config).await {
+                Ok(0) => {}
+                Ok(count) => tracing::info!("Sent accounts payable digest to {count} churches"),
+                Err(e) => tracing::error!("Accounts payable digest failed: {e}"),
+            }
+
+            // Last month's congregation remittances
+            match RemittanceService::generate_due(&pool).await {
+                Ok(0) => {}
+                Ok(count) => tracing::info!("Calculated {count} congregation remittances"),
+                Err(e) => tracing::error!("Congregation remittances generation failed: {e}"),
+            }
+        }
+    });
+}
@@ -11,12 +11,13 @@ use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 use utoipa::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
 
-use crate::api::handlers::{asset_handler, auth_handler, church_handler, church_role_handler, congregation_handler, ebd_handler, family_handler, financial_handler, health_handler, member_handler, member_history_handler, ministry_handler, upload_handler, user_handler};
+use crate::api::handlers::{asset_handler, auth_handler, church_handler, church_role_handler, congregation_handler, ebd_handler, family_handler, financial_handler, health_handler, member_handler, member_history_handler, ministry_handler, upload_handler, user_handler, webhook_handler};
 use crate::application::services::AuthService;
 use crate::config::AppConfig;
 use crate::infrastructure::database;
 use crate::infrastructure::cache::CacheService;
 use crate::infrastructure::cloudinary::CloudinaryService;
+use crate::infrastructure::scheduler;
 
 #[derive(OpenApi)]
 #[openapi(
@@ -88,18 +89,104 @@ use crate::infrastructure::cloudinary::CloudinaryService;
         financial_handler::list_bank_accounts,
         financial_handler::create_bank_account,
         financial_handler::update_bank_account,
+        financial_handler::bank_account_statement,
+        financial_handler::check_bank_balances,
+        financial_handler::repair_bank_balances,
+        financial_handler::create_pix_charge,
+        financial_handler::list_pix_charges,
+        financial_handler::pix_charge_qr,
+        financial_handler::list_payment_integrations,
+        financial_handler::update_payment_integration,
+        financial_handler::list_payment_notifications,
+        financial_handler::resolve_payment_notification,
+        financial_handler::discard_payment_notification,
+        webhook_handler::payment_webhook,
         financial_handler::list_campaigns,
         financial_handler::get_campaign,
         financial_handler::create_campaign,
         financial_handler::update_campaign,
+        financial_handler::list_campaign_pledges,
+        financial_handler::create_campaign_pledge,
+        financial_handler::update_campaign_pledge,
+        financial_handler::delete_campaign_pledge,
+        financial_handler::campaign_report,
         financial_handler::list_financial_entries,
         financial_handler::get_financial_entry,
+        financial_handler::list_entry_allocations,
         financial_handler::create_financial_entry,
         financial_handler::update_financial_entry,
         financial_handler::delete_financial_entry,
+        financial_handler::reverse_financial_entry,
+        financial_handler::pay_financial_entry,
+        financial_handler::accounts_payable,
+        financial_handler::list_transfers,
+        financial_handler::get_transfer,
+        financial_handler::create_transfer,
+        financial_handler::cancel_transfer,
+        financial_handler::list_recurrences,
+        financial_handler::get_recurrence,
+        financial_handler::create_recurrence,
+        financial_handler::update_recurrence,
+        financial_handler::cancel_recurrence,
+        financial_handler::generate_recurrences,
+        financial_handler::list_installment_plans,
+        financial_handler::get_installment_plan,
+        financial_handler::create_installment_plan,
+        financial_handler::renegotiate_installment_plan,
+        financial_handler::list_offering_counts,
+        financial_handler::get_offering_count,
+        financial_handler::create_offering_count,
+        financial_handler::update_offering_count,
+        financial_handler::delete_offering_count,
+        financial_handler::confirm_offering_count,
+        financial_handler::import_bank_statement,
+        financial_handler::list_bank_statements,
+        financial_handler::get_bank_statement,
+        financial_handler::match_statement_line,
+        financial_handler::unmatch_statement_line,
+        financial_handler::create_entry_from_statement_line,
+        financial_handler::member_tithe_statement,
+        financial_handler::tither_regularity,
+        financial_handler::member_tither_regularity,
+        financial_handler::issue_receipt,
+        financial_handler::list_receipts,
+        financial_handler::get_receipt,
+        financial_handler::receipt_pdf,
+        financial_handler::list_budgets,
+        financial_handler::create_budget,
+        financial_handler::update_budget,
+        financial_handler::delete_budget,
+        financial_handler::budget_report,
+        financial_handler::list_suppliers,
+        financial_handler::get_supplier,
+        financial_handler::create_supplier,
+        financial_handler::update_supplier,
+        financial_handler::delete_supplier,
+        financial_handler::supplier_spending_report,
+        financial_handler::list_approval_rules,
+        financial_handler::create_approval_rule,
+        financial_handler::update_approval_rule,
+        financial_handler::delete_approval_rule,
+        financial_handler::approve_financial_entry,
+        financial_handler::reject_financial_entry,
+        financial_handler::get_remittance_rule,
+        financial_handler::update_remittance_rule,
+        financial_handler::generate_remittances,
+        financial_handler::list_remittances,
+        financial_handler::outstanding_remittances,
+        financial_handler::pay_remittance,
         financial_handler::balance_report,
+        financial_handler::assembly_report,
+        financial_handler::income_statement_report,
         financial_handler::list_monthly_closings,
+        financial_handler::get_monthly_closing,
         financial_handler::create_monthly_closing,
+        financial_handler::reopen_monthly_closing,
+        financial_handler::list_export_layouts,
+        financial_handler::create_export_layout,
+        financial_handler::update_export_layout,
+        financial_handler::delete_export_layout,
+        financial_handler::accounting_export,
         // Assets
         asset_handler::list_asset_categories,
         asset_handler::create_asset_category,
@@ -266,6 +353,9 @@ async fn main() -> std::io::Result<()> {
     // Seed test data if no users exist
     seed_test_data(&pool).await;
 
+    // Background jobs (recurring entries generation, payables digest)
+    scheduler::spawn(pool.clone(), config.clone());
+
     // Connect to Redis cache (optional — fails gracefully)
     let cache = CacheService::connect(&config.redis_url).await;
 
@@ -365,22 +455,123 @@ async fn main() -> std::io::Result<()> {
             .service(financial_handler::list_bank_accounts)
             .service(financial_handler::create_bank_account)
             .service(financial_handler::update_bank_account)
+            .service(financial_handler::bank_account_statement)
+            .service(financial_handler::check_bank_balances)
+            .service(financial_handler::repair_bank_balances)
+            // Financial — PIX
+            .service(financial_handler::create_pix_charge)
+            .service(financial_handler::list_pix_charges)
+            .service(financial_handler::pix_charge_qr)
+            // Financial — Payment Providers
+            .service(financial_handler::list_payment_integrations)
+            .service(financial_handler::update_payment_integration)
+            .service(financial_handler::list_payment_notifications)
+            .service(financial_handler::resolve_payment_notification)
+            .service(financial_handler::discard_payment_notification)
+            // Webhooks (public, signature-authenticated)
+            .service(webhook_handler::payment_webhook)
             // Financial — Campaigns
             .service(financial_handler::list_campaigns)
             .service(financial_handler::get_campaign)
             .service(financial_handler::create_campaign)
             .service(financial_handler::update_campaign)
+            .service(financial_handler::list_campaign_pledges)
+            .service(financial_handler::create_campaign_pledge)
+            .service(financial_handler::update_campaign_pledge)
+            .service(financial_handler::delete_campaign_pledge)
+            .service(financial_handler::campaign_report)
             // Financial — Entries
             .service(financial_handler::list_financial_entries)
             .service(financial_handler::get_financial_entry)
+            .service(financial_handler::list_entry_allocations)
             .service(financial_handler::create_financial_entry)
             .service(financial_handler::update_financial_entry)
             .service(financial_handler::delete_financial_entry)
+            .service(financial_handler::reverse_financial_entry)
+            .service(financial_handler::pay_financial_entry)
+            .service(financial_handler::accounts_payable)
+            // Financial — Transfers
+            .service(financial_handler::list_transfers)
+            .service(financial_handler::get_transfer)
+            .service(financial_handler::create_transfer)
+            .service(financial_handler::cancel_transfer)
+            // Financial — Recurring Entries
+            .service(financial_handler::generate_recurrences) // before {id} route
+            .service(financial_handler::list_recurrences)
+            .service(financial_handler::get_recurrence)
+            .service(financial_handler::create_recurrence)
+            .service(financial_handler::update_recurrence)
+            .service(financial_handler::cancel_recurrence)
+            // Financial — Installment Plans
+            .service(financial_handler::list_installment_plans)
+            .service(financial_handler::get_installment_plan)
+            .service(financial_handler::create_installment_plan)
+            .service(financial_handler::renegotiate_installment_plan)
+            // Financial — Offering Counts
+            .service(financial_handler::list_offering_counts)
+            .service(financial_handler::get_offering_count)
+            .service(financial_handler::create_offering_count)
+            .service(financial_handler::update_offering_count)
+            .service(financial_handler::delete_offering_count)
+            .service(financial_handler::confirm_offering_count)
+            // Financial — Bank Reconciliation
+            .service(financial_handler::import_bank_statement)
+            .service(financial_handler::list_bank_statements)
+            .service(financial_handler::get_bank_statement)
+            .service(financial_handler::match_statement_line)
+            .service(financial_handler::unmatch_statement_line)
+            .service(financial_handler::create_entry_from_statement_line)
+            // Financial — Tithes
+            .service(financial_handler::member_tithe_statement)
+            .service(financial_handler::tither_regularity)
+            .service(financial_handler::member_tither_regularity)
+            // Financial — Donation Receipts
+            .service(financial_handler::issue_receipt)
+            .service(financial_handler::list_receipts)
+            .service(financial_handler::get_receipt)
+            .service(financial_handler::receipt_pdf)
+            // Financial — Budgets
+            .service(financial_handler::budget_report)
+            .service(financial_handler::list_budgets)
+            .service(financial_handler::create_budget)
+            .service(financial_handler::update_budget)
+            .service(financial_handler::delete_budget)
+            // Financial — Suppliers
+            .service(financial_handler::list_suppliers)
+            .service(financial_handler::create_supplier)
+            .service(financial_handler::get_supplier)
+            .service(financial_handler::update_supplier)
+            .service(financial_handler::delete_supplier)
+            .service(financial_handler::supplier_spending_report)
+            // Financial — Expense Approvals
+            .service(financial_handler::list_approval_rules)
+            .service(financial_handler::create_approval_rule)
+            .service(financial_handler::update_approval_rule)
+            .service(financial_handler::delete_approval_rule)
+            .service(financial_handler::approve_financial_entry)
+            .service(financial_handler::reject_financial_entry)
+            // Financial — Congregation Remittances
+            .service(financial_handler::get_remittance_rule)
+            .service(financial_handler::update_remittance_rule)
+            .service(financial_handler::outstanding_remittances)
+            .service(financial_handler::generate_remittances)
+            .service(financial_handler::list_remittances)
+            .service(financial_handler::pay_remittance)
             // Financial — Reports
             .service(financial_handler::balance_report)
+            .service(financial_handler::assembly_report)
+            .service(financial_handler::income_statement_report)
             // Financial — Monthly Closings
             .service(financial_handler::list_monthly_closings)
             .service(financial_handler::create_monthly_closing)
+            .service(financial_handler::get_monthly_closing)
+            .service(financial_handler::reopen_monthly_closing)
+            // Financial — Accounting Export
+            .service(financial_handler::list_export_layouts)
+            .service(financial_handler::create_export_layout)
+            .service(financial_handler::update_export_layout)
+            .service(financial_handler::delete_export_layout)
+            .service(financial_handler::accounting_export)
             // Assets — Categories
             .service(asset_handler::list_asset_categories)
             .service(asset_handler::create_asset_category)